@@ -1,8 +1,15 @@
 library errors;
 
+pub enum AccessError {
+    NotOwner: (),
+}
+
 pub enum InitError {
     BytecodeRootAlreadySet: (),
     BytecodeRootDoesNotMatch: (),
+    BytecodeRootNotApproved: (),
     BytecodeRootNotSet: (),
     PairDoesNotDefinePool: (),
+    PoolImplementationMismatch: (),
+    PoolNotOwned: (),
 }
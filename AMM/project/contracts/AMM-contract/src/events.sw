@@ -1,12 +1,24 @@
 library events;
 
+pub struct AddExchangeBytecodeRootEvent {
+    /// The bytecode root of an additional valid exchange contract implementation
+    root: b256,
+}
+
 pub struct RegisterPoolEvent {
     /// The pair of asset identifiers that make up the pool
     asset_pair: (ContractId, ContractId),
+    /// The fee tier of the pool, i.e., the liquidity miner fee of the exchange contract
+    liquidity_miner_fee: u64,
     /// The exchange contract identifier that manages the pool which also identifies the pool asset
     pool: ContractId,
 }
 
+pub struct RemoveExchangeBytecodeRootEvent {
+    /// The bytecode root of an exchange contract implementation that is no longer valid
+    root: b256,
+}
+
 pub struct SetExchangeBytecodeRootEvent {
     /// The bytecode root of the valid exchange contract implementation
     root: b256,
@@ -3,32 +3,80 @@ contract;
 dep errors;
 dep events;
 
-use errors::InitError;
-use events::{RegisterPoolEvent, SetExchangeBytecodeRootEvent};
-use libraries::{AMM, Exchange};
-use std::{constants::BASE_ASSET_ID, external::bytecode_root};
+use errors::{AccessError, InitError};
+use events::{
+    AddExchangeBytecodeRootEvent,
+    RegisterPoolEvent,
+    RemoveExchangeBytecodeRootEvent,
+    SetExchangeBytecodeRootEvent,
+};
+use libraries::{
+    data_structures::{
+        ProtocolFee,
+        RegisteredPool,
+    },
+    AMM,
+    Exchange,
+};
+use std::{
+    auth::msg_sender,
+    call_frames::contract_id,
+    constants::BASE_ASSET_ID,
+    external::bytecode_root,
+};
 
 storage {
-    /// The valid exchange contract bytecode root
-    exchange_bytecode_root: Option<b256> = Option::None,
-    /// Map that stores pools, i.e., asset identifier pairs as keys and corresponding exchange contract identifiers as values
-    pools: StorageMap<(ContractId, ContractId), ContractId> = StorageMap {},
+    /// The valid exchange contract bytecode roots
+    exchange_bytecode_roots: StorageMap<b256, bool> = StorageMap {},
+    /// The identity that initialized the AMM and approves exchange contract bytecode roots
+    owner: Option<Identity> = Option::None,
+    /// The number of asset pair and fee tier combinations that have a pool
+    pool_count: u64 = 0,
+    /// Map that stores the asset identifier pair and fee tier of every pool by the order in which they were first added
+    pool_keys: StorageMap<u64, ((ContractId, ContractId), u64)> = StorageMap {},
+    /// Map that stores pools, i.e., asset identifier pairs and fee tiers as keys and corresponding exchange contract identifiers as values
+    pools: StorageMap<((ContractId, ContractId), u64), ContractId> = StorageMap {},
 }
 
 impl AMM for Contract {
     #[storage(read, write)]
     fn initialize(exchange_bytecode_root: ContractId) {
-        require(storage.exchange_bytecode_root.is_none(), InitError::BytecodeRootAlreadySet);
-        storage.exchange_bytecode_root = Option::Some(exchange_bytecode_root.into());
+        require(storage.owner.is_none(), InitError::BytecodeRootAlreadySet);
+        storage.owner = Option::Some(msg_sender().unwrap());
+        storage.exchange_bytecode_roots.insert(exchange_bytecode_root.into(), true);
         log(SetExchangeBytecodeRootEvent {
             root: exchange_bytecode_root.into(),
         });
     }
 
+    #[storage(read, write)]
+    fn add_exchange_bytecode_root(exchange_bytecode_root: ContractId) {
+        require(storage.owner.is_some(), InitError::BytecodeRootNotSet);
+        require(storage.owner.unwrap() == msg_sender().unwrap(), AccessError::NotOwner);
+
+        storage.exchange_bytecode_roots.insert(exchange_bytecode_root.into(), true);
+        log(AddExchangeBytecodeRootEvent {
+            root: exchange_bytecode_root.into(),
+        });
+    }
+
+    #[storage(read, write)]
+    fn remove_exchange_bytecode_root(exchange_bytecode_root: ContractId) {
+        require(storage.owner.is_some(), InitError::BytecodeRootNotSet);
+        require(storage.owner.unwrap() == msg_sender().unwrap(), AccessError::NotOwner);
+        require(storage.exchange_bytecode_roots.get(exchange_bytecode_root.into()).unwrap_or(false), InitError::BytecodeRootNotApproved);
+
+        storage.exchange_bytecode_roots.insert(exchange_bytecode_root.into(), false);
+        log(RemoveExchangeBytecodeRootEvent {
+            root: exchange_bytecode_root.into(),
+        });
+    }
+
     #[storage(read, write)]
     fn add_pool(asset_pair: (ContractId, ContractId), pool: ContractId) {
-        require(storage.exchange_bytecode_root.is_some(), InitError::BytecodeRootNotSet);
-        require(storage.exchange_bytecode_root.unwrap() == bytecode_root(pool), InitError::BytecodeRootDoesNotMatch);
+        require(storage.owner.is_some(), InitError::BytecodeRootNotSet);
+        let root = bytecode_root(pool);
+        require(storage.exchange_bytecode_roots.get(root).unwrap_or(false), InitError::BytecodeRootDoesNotMatch);
 
         let exchange_contract = abi(Exchange, pool.into());
         let pool_info = exchange_contract.pool_info();
@@ -36,26 +84,74 @@ impl AMM for Contract {
         let pair_matches_exchange_pair = (pair.a.id == asset_pair.0 && pair.b.id == asset_pair.1) || (pair.a.id == asset_pair.1 && pair.b.id == asset_pair.0);
 
         require(pair_matches_exchange_pair, InitError::PairDoesNotDefinePool);
+        // the owner of the AMM controls the protocol fees of every registered pool
+        require(exchange_contract.protocol_fee_info().owner == Identity::ContractId(contract_id()), InitError::PoolNotOwned);
 
         let ordered_asset_pair = if asset_pair.0.into() < asset_pair.1.into() {
             asset_pair
         } else {
             (asset_pair.1, asset_pair.0)
         };
-        storage.pools.insert(ordered_asset_pair, pool);
+        let key = (ordered_asset_pair, pool_info.liquidity_miner_fee);
+
+        let registered_pool = storage.pools.get(key);
+
+        // a replaced pool keeps the index of the pool it replaces
+        if registered_pool.is_none() {
+            storage.pool_keys.insert(storage.pool_count, key);
+            storage.pool_count += 1;
+        } else {
+            // e.g., a stable pool must not replace a constant product pool in the same fee tier while both implementations are approved
+            let registered_root = bytecode_root(registered_pool.unwrap());
+            require(registered_root == root || !storage.exchange_bytecode_roots.get(registered_root).unwrap_or(false), InitError::PoolImplementationMismatch);
+        }
+
+        storage.pools.insert(key, pool);
         log(RegisterPoolEvent {
             asset_pair: ordered_asset_pair,
+            liquidity_miner_fee: pool_info.liquidity_miner_fee,
             pool,
         });
     }
 
     #[storage(read)]
-    fn pool(asset_pair: (ContractId, ContractId)) -> Option<ContractId> {
+    fn set_protocol_fee(pool: ContractId, protocol_fee: Option<ProtocolFee>) {
+        require(storage.owner.is_some(), InitError::BytecodeRootNotSet);
+        require(storage.owner.unwrap() == msg_sender().unwrap(), AccessError::NotOwner);
+
+        let exchange_contract = abi(Exchange, pool.into());
+        exchange_contract.set_protocol_fee(protocol_fee);
+    }
+
+    #[storage(read)]
+    fn pool(asset_pair: (ContractId, ContractId), liquidity_miner_fee: u64) -> Option<ContractId> {
         let ordered_asset_pair = if asset_pair.0.into() < asset_pair.1.into() {
             asset_pair
         } else {
             (asset_pair.1, asset_pair.0)
         };
-        storage.pools.get(ordered_asset_pair)
+        storage.pools.get((ordered_asset_pair, liquidity_miner_fee))
+    }
+
+    #[storage(read)]
+    fn pool_at(index: u64) -> Option<RegisteredPool> {
+        match storage.pool_keys.get(index) {
+            Option::Some(key) => Option::Some(RegisteredPool {
+                asset_pair: key.0,
+                liquidity_miner_fee: key.1,
+                pool: storage.pools.get(key).unwrap(),
+            }),
+            Option::None => Option::None,
+        }
+    }
+
+    #[storage(read)]
+    fn pool_count() -> u64 {
+        storage.pool_count
+    }
+
+    #[storage(read)]
+    fn is_approved_exchange_bytecode_root(exchange_bytecode_root: ContractId) -> bool {
+        storage.exchange_bytecode_roots.get(exchange_bytecode_root.into()).unwrap_or(false)
     }
 }
@@ -0,0 +1,104 @@
+use crate::utils::setup;
+use test_utils::{
+    interface::amm::add_exchange_bytecode_root, setup::common::stable_exchange_bytecode_root,
+};
+
+mod success {
+    use super::*;
+    use fuels::types::Bits256;
+    use test_utils::{
+        data_structures::{ExchangeContractConfiguration, LIQUIDITY_MINER_FEE},
+        interface::{amm::pool, AddExchangeBytecodeRootEvent},
+        setup::common::{deploy_and_construct_exchange, register_pool},
+    };
+
+    #[tokio::test]
+    async fn adds_exchange_bytecode_root() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        let calculated_bytecode_root = stable_exchange_bytecode_root().await;
+
+        let response = add_exchange_bytecode_root(&amm_instance, calculated_bytecode_root).await;
+        let log = response
+            .get_logs_with_type::<AddExchangeBytecodeRootEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            AddExchangeBytecodeRootEvent {
+                root: Bits256::from_hex_str(&calculated_bytecode_root.to_string()).unwrap()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_previously_approved_bytecode_roots() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+
+        add_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[0]), None, None, None),
+        )
+        .await;
+        let stable_exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                stable: true,
+                liquidity_miner_fee: 100,
+                ..ExchangeContractConfiguration::new(Some(asset_pairs[0]), None, None, None)
+            },
+        )
+        .await;
+
+        register_pool(&amm_instance, asset_pairs[0], &exchange).await;
+        register_pool(&amm_instance, asset_pairs[0], &stable_exchange).await;
+
+        assert_eq!(
+            pool(&amm_instance, asset_pairs[0], LIQUIDITY_MINER_FEE).await,
+            Some(exchange.id)
+        );
+        assert_eq!(
+            pool(&amm_instance, asset_pairs[0], 100).await,
+            Some(stable_exchange.id)
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+    use fuels::prelude::{TxParameters, WalletUnlocked, BASE_ASSET_ID};
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeRootNotSet")]
+    async fn when_uninitialized() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(false).await;
+
+        add_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_sender_is_not_owner() {
+        let (wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        let other_wallet = WalletUnlocked::new_random(Some(wallet.get_provider().unwrap().clone()));
+        wallet
+            .transfer(
+                other_wallet.address(),
+                1_000_000,
+                BASE_ASSET_ID,
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+
+        add_exchange_bytecode_root(
+            &amm_instance.with_wallet(other_wallet).unwrap(),
+            stable_exchange_bytecode_root().await,
+        )
+        .await;
+    }
+}
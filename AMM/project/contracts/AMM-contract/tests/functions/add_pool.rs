@@ -1,7 +1,8 @@
 use crate::utils::setup;
 use test_utils::{
-    data_structures::ExchangeContractConfiguration, interface::amm::add_pool,
-    setup::common::deploy_and_construct_exchange,
+    data_structures::{ExchangeContractConfiguration, LIQUIDITY_MINER_FEE},
+    interface::amm::add_pool,
+    setup::common::{deploy_and_construct_exchange, register_pool},
 };
 
 mod success {
@@ -20,16 +21,17 @@ mod success {
         .await;
 
         // adding pair to the AMM contract in the same order as the constructed exchange contract
-        let response = add_pool(&amm_instance, pair, exchange.id).await;
+        let response = register_pool(&amm_instance, pair, &exchange).await;
         let log = response.get_logs_with_type::<RegisterPoolEvent>().unwrap();
         let event = log.get(0).unwrap();
 
-        let exchange_contract_id_in_storage = pool(&amm_instance, pair).await;
+        let exchange_contract_id_in_storage = pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await;
 
         assert_eq!(
             *event,
             RegisterPoolEvent {
                 asset_pair: ordered_pair(pair),
+                liquidity_miner_fee: LIQUIDITY_MINER_FEE,
                 pool: exchange.id
             }
         );
@@ -49,16 +51,17 @@ mod success {
         .await;
 
         // adding pair to the AMM contract in the reverse order as the constructed exchange contract
-        let response = add_pool(&amm_instance, pair, exchange.id).await;
+        let response = register_pool(&amm_instance, pair, &exchange).await;
         let log = response.get_logs_with_type::<RegisterPoolEvent>().unwrap();
         let event = log.get(0).unwrap();
 
-        let exchange_contract_id_in_storage = pool(&amm_instance, pair).await;
+        let exchange_contract_id_in_storage = pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await;
 
         assert_eq!(
             *event,
             RegisterPoolEvent {
                 asset_pair: ordered_pair(pair),
+                liquidity_miner_fee: LIQUIDITY_MINER_FEE,
                 pool: exchange.id
             }
         );
@@ -83,21 +86,24 @@ mod success {
         )
         .await;
 
-        let response = add_pool(&amm_instance, pair_1, exchange_1.id).await;
+        let response = register_pool(&amm_instance, pair_1, &exchange_1).await;
         let log = response.get_logs_with_type::<RegisterPoolEvent>().unwrap();
         let event_1 = log.get(0).unwrap();
 
-        let response = add_pool(&amm_instance, pair_2, exchange_2.id).await;
+        let response = register_pool(&amm_instance, pair_2, &exchange_2).await;
         let log = response.get_logs_with_type::<RegisterPoolEvent>().unwrap();
         let event_2 = log.get(0).unwrap();
 
-        let exchange_contract_id_in_storage_of_pair_1 = pool(&amm_instance, pair_1).await;
-        let exchange_contract_id_in_storage_of_pair_2 = pool(&amm_instance, pair_2).await;
+        let exchange_contract_id_in_storage_of_pair_1 =
+            pool(&amm_instance, pair_1, LIQUIDITY_MINER_FEE).await;
+        let exchange_contract_id_in_storage_of_pair_2 =
+            pool(&amm_instance, pair_2, LIQUIDITY_MINER_FEE).await;
 
         assert_eq!(
             *event_1,
             RegisterPoolEvent {
                 asset_pair: ordered_pair(pair_1),
+                liquidity_miner_fee: LIQUIDITY_MINER_FEE,
                 pool: exchange_1.id
             }
         );
@@ -105,6 +111,7 @@ mod success {
             *event_2,
             RegisterPoolEvent {
                 asset_pair: ordered_pair(pair_2),
+                liquidity_miner_fee: LIQUIDITY_MINER_FEE,
                 pool: exchange_2.id
             }
         );
@@ -119,10 +126,61 @@ mod success {
             exchange_2.id
         );
     }
+
+    #[tokio::test]
+    async fn adds_same_pair_with_different_fee_tiers() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+        let other_liquidity_miner_fee = 100;
+
+        let exchange_1 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        let exchange_2 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                liquidity_miner_fee: other_liquidity_miner_fee,
+                ..ExchangeContractConfiguration::new(Some(pair), None, None, Some([1u8; 32]))
+            },
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange_1).await;
+        let response = register_pool(&amm_instance, pair, &exchange_2).await;
+        let log = response.get_logs_with_type::<RegisterPoolEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        let exchange_contract_id_in_storage_of_tier_1 =
+            pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await;
+        let exchange_contract_id_in_storage_of_tier_2 =
+            pool(&amm_instance, pair, other_liquidity_miner_fee).await;
+
+        assert_eq!(
+            *event,
+            RegisterPoolEvent {
+                asset_pair: ordered_pair(pair),
+                liquidity_miner_fee: other_liquidity_miner_fee,
+                pool: exchange_2.id
+            }
+        );
+        assert_eq!(
+            exchange_contract_id_in_storage_of_tier_1,
+            Some(exchange_1.id)
+        );
+        assert_eq!(
+            exchange_contract_id_in_storage_of_tier_2,
+            Some(exchange_2.id)
+        );
+    }
 }
 
 mod revert {
     use super::*;
+    use test_utils::{
+        interface::amm::add_exchange_bytecode_root, setup::common::stable_exchange_bytecode_root,
+    };
 
     #[tokio::test]
     #[should_panic(expected = "BytecodeRootNotSet")]
@@ -174,4 +232,66 @@ mod revert {
 
         add_pool(&amm_instance, pair, another_exchange.id).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeRootDoesNotMatch")]
+    async fn when_exchange_bytecode_root_is_not_approved() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        // the stable exchange bytecode root has not been added to the AMM contract
+        let stable_exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                stable: true,
+                ..ExchangeContractConfiguration::new(Some(pair), None, None, None)
+            },
+        )
+        .await;
+
+        add_pool(&amm_instance, pair, stable_exchange.id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "PoolImplementationMismatch")]
+    async fn when_replacing_pool_of_another_approved_implementation() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        add_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        // same asset pair and fee tier as the constant product pool
+        let stable_exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                stable: true,
+                ..ExchangeContractConfiguration::new(Some(pair), None, None, None)
+            },
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange).await;
+        register_pool(&amm_instance, pair, &stable_exchange).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "PoolNotOwned")]
+    async fn when_amm_does_not_own_exchange_contract() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        // the exchange contract is still owned by the wallet that constructed it
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+
+        add_pool(&amm_instance, pair, exchange.id).await;
+    }
 }
@@ -0,0 +1,44 @@
+mod success {
+    use crate::utils::setup;
+    use test_utils::{
+        interface::amm::{add_exchange_bytecode_root, is_approved_exchange_bytecode_root},
+        setup::common::{exchange_bytecode_root, stable_exchange_bytecode_root},
+    };
+
+    #[tokio::test]
+    async fn gets_true_for_initial_bytecode_root() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        assert!(
+            is_approved_exchange_bytecode_root(&amm_instance, exchange_bytecode_root().await).await
+        );
+    }
+
+    #[tokio::test]
+    async fn gets_true_for_added_bytecode_root() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        add_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+
+        assert!(
+            is_approved_exchange_bytecode_root(
+                &amm_instance,
+                stable_exchange_bytecode_root().await
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn gets_false() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        assert!(
+            !is_approved_exchange_bytecode_root(
+                &amm_instance,
+                stable_exchange_bytecode_root().await
+            )
+            .await
+        );
+    }
+}
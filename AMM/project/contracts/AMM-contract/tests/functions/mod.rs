@@ -1,3 +1,9 @@
+mod add_exchange_bytecode_root;
 mod add_pool;
 mod initialize;
+mod is_approved_exchange_bytecode_root;
 mod pool;
+mod pool_at;
+mod pool_count;
+mod remove_exchange_bytecode_root;
+mod set_protocol_fee;
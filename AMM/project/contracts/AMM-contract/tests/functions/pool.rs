@@ -1,9 +1,9 @@
 mod success {
     use crate::utils::setup;
     use test_utils::{
-        data_structures::ExchangeContractConfiguration,
-        interface::amm::{add_pool, pool},
-        setup::common::deploy_and_construct_exchange,
+        data_structures::{ExchangeContractConfiguration, LIQUIDITY_MINER_FEE},
+        interface::amm::pool,
+        setup::common::{deploy_and_construct_exchange, register_pool},
     };
 
     #[tokio::test]
@@ -17,9 +17,9 @@ mod success {
         )
         .await;
 
-        add_pool(&amm_instance, pair, exchange.id).await;
+        register_pool(&amm_instance, pair, &exchange).await;
 
-        let exchange_contract_id_in_storage = pool(&amm_instance, pair).await;
+        let exchange_contract_id_in_storage = pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await;
 
         assert_ne!(exchange_contract_id_in_storage, None);
         assert_eq!(exchange_contract_id_in_storage.unwrap(), exchange.id);
@@ -37,13 +37,32 @@ mod success {
         )
         .await;
 
-        add_pool(&amm_instance, pair, exchange.id).await;
+        register_pool(&amm_instance, pair, &exchange).await;
 
-        let exchange_contract_id_in_storage = pool(&amm_instance, pair).await;
-        let non_existent_exchange_contract_id_in_storage = pool(&amm_instance, another_pair).await;
+        let exchange_contract_id_in_storage = pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await;
+        let non_existent_exchange_contract_id_in_storage =
+            pool(&amm_instance, another_pair, LIQUIDITY_MINER_FEE).await;
 
         assert_ne!(exchange_contract_id_in_storage, None);
         assert_eq!(exchange_contract_id_in_storage.unwrap(), exchange.id);
         assert_eq!(non_existent_exchange_contract_id_in_storage, None);
     }
+
+    #[tokio::test]
+    async fn gets_none_for_another_fee_tier() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        let exchange_contract_id_in_storage = pool(&amm_instance, pair, 100).await;
+
+        assert_eq!(exchange_contract_id_in_storage, None);
+    }
 }
@@ -0,0 +1,110 @@
+mod success {
+    use crate::utils::{ordered_pair, setup};
+    use test_utils::{
+        data_structures::{ExchangeContractConfiguration, LIQUIDITY_MINER_FEE},
+        interface::{
+            amm::{pool_at, pool_count},
+            RegisteredPool,
+        },
+        setup::common::{deploy_and_construct_exchange, register_pool},
+    };
+
+    #[tokio::test]
+    async fn gets_pools_in_order_of_addition() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let other_liquidity_miner_fee = 100;
+
+        let exchange_1 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[1]), None, None, None),
+        )
+        .await;
+        let exchange_2 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[0]), None, None, Some([1u8; 32])),
+        )
+        .await;
+        let exchange_3 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                liquidity_miner_fee: other_liquidity_miner_fee,
+                ..ExchangeContractConfiguration::new(
+                    Some(asset_pairs[0]),
+                    None,
+                    None,
+                    Some([2u8; 32]),
+                )
+            },
+        )
+        .await;
+
+        register_pool(&amm_instance, asset_pairs[1], &exchange_1).await;
+        register_pool(&amm_instance, asset_pairs[0], &exchange_2).await;
+        register_pool(&amm_instance, asset_pairs[0], &exchange_3).await;
+
+        // page through every pool the way an indexer would
+        let mut pools = vec![];
+        for index in 0..pool_count(&amm_instance).await {
+            pools.push(pool_at(&amm_instance, index).await.unwrap());
+        }
+
+        assert_eq!(
+            pools,
+            vec![
+                RegisteredPool {
+                    asset_pair: ordered_pair(asset_pairs[1]),
+                    liquidity_miner_fee: LIQUIDITY_MINER_FEE,
+                    pool: exchange_1.id,
+                },
+                RegisteredPool {
+                    asset_pair: ordered_pair(asset_pairs[0]),
+                    liquidity_miner_fee: LIQUIDITY_MINER_FEE,
+                    pool: exchange_2.id,
+                },
+                RegisteredPool {
+                    asset_pair: ordered_pair(asset_pairs[0]),
+                    liquidity_miner_fee: other_liquidity_miner_fee,
+                    pool: exchange_3.id,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn gets_replaced_pool_at_same_index() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange_1 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        let exchange_2 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, Some([1u8; 32])),
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange_1).await;
+        register_pool(&amm_instance, pair, &exchange_2).await;
+
+        assert_eq!(pool_at(&amm_instance, 0).await.unwrap().pool, exchange_2.id);
+    }
+
+    #[tokio::test]
+    async fn gets_none() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        assert_eq!(pool_at(&amm_instance, 1).await, None);
+    }
+}
@@ -0,0 +1,58 @@
+mod success {
+    use crate::utils::setup;
+    use test_utils::{
+        data_structures::ExchangeContractConfiguration,
+        interface::amm::pool_count,
+        setup::common::{deploy_and_construct_exchange, register_pool},
+    };
+
+    #[tokio::test]
+    async fn gets_zero() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        assert_eq!(pool_count(&amm_instance).await, 0);
+    }
+
+    #[tokio::test]
+    async fn gets_count_of_added_pools() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+
+        let exchange_1 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[0]), None, None, None),
+        )
+        .await;
+        let exchange_2 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[1]), None, None, Some([1u8; 32])),
+        )
+        .await;
+
+        register_pool(&amm_instance, asset_pairs[0], &exchange_1).await;
+        register_pool(&amm_instance, asset_pairs[1], &exchange_2).await;
+
+        assert_eq!(pool_count(&amm_instance).await, 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_count_replaced_pools() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange_1 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        let exchange_2 = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, Some([1u8; 32])),
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange_1).await;
+        register_pool(&amm_instance, pair, &exchange_2).await;
+
+        assert_eq!(pool_count(&amm_instance).await, 1);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::utils::setup;
+use test_utils::{
+    interface::amm::remove_exchange_bytecode_root,
+    setup::common::{exchange_bytecode_root, stable_exchange_bytecode_root},
+};
+
+mod success {
+    use super::*;
+    use fuels::types::Bits256;
+    use test_utils::{
+        data_structures::{ExchangeContractConfiguration, LIQUIDITY_MINER_FEE},
+        interface::{
+            amm::{
+                add_exchange_bytecode_root, is_approved_exchange_bytecode_root, pool, pool_at,
+                pool_count,
+            },
+            RemoveExchangeBytecodeRootEvent,
+        },
+        setup::common::{deploy_and_construct_exchange, register_pool},
+    };
+
+    #[tokio::test]
+    async fn removes_exchange_bytecode_root() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        let calculated_bytecode_root = exchange_bytecode_root().await;
+
+        let response = remove_exchange_bytecode_root(&amm_instance, calculated_bytecode_root).await;
+        let log = response
+            .get_logs_with_type::<RemoveExchangeBytecodeRootEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            RemoveExchangeBytecodeRootEvent {
+                root: Bits256::from_hex_str(&calculated_bytecode_root.to_string()).unwrap()
+            }
+        );
+        assert!(!is_approved_exchange_bytecode_root(&amm_instance, calculated_bytecode_root).await);
+    }
+
+    #[tokio::test]
+    async fn upgrades_exchange_implementation() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        // the stable exchange stands in for the upgraded implementation
+        add_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+        remove_exchange_bytecode_root(&amm_instance, exchange_bytecode_root().await).await;
+
+        // pools of the previous implementation remain registered until they are replaced
+        assert_eq!(
+            pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await,
+            Some(exchange.id)
+        );
+
+        let upgraded_exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                stable: true,
+                ..ExchangeContractConfiguration::new(Some(pair), None, None, None)
+            },
+        )
+        .await;
+        register_pool(&amm_instance, pair, &upgraded_exchange).await;
+
+        assert_eq!(
+            pool(&amm_instance, pair, LIQUIDITY_MINER_FEE).await,
+            Some(upgraded_exchange.id)
+        );
+        assert_eq!(pool_count(&amm_instance).await, 1);
+        assert_eq!(
+            pool_at(&amm_instance, 0).await.unwrap().pool,
+            upgraded_exchange.id
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+    use fuels::prelude::{TxParameters, WalletUnlocked, BASE_ASSET_ID};
+    use test_utils::{
+        data_structures::ExchangeContractConfiguration,
+        interface::amm::add_exchange_bytecode_root,
+        setup::common::{deploy_and_construct_exchange, register_pool},
+    };
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeRootNotSet")]
+    async fn when_uninitialized() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(false).await;
+
+        remove_exchange_bytecode_root(&amm_instance, exchange_bytecode_root().await).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_sender_is_not_owner() {
+        let (wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        let other_wallet = WalletUnlocked::new_random(Some(wallet.get_provider().unwrap().clone()));
+        wallet
+            .transfer(
+                other_wallet.address(),
+                1_000_000,
+                BASE_ASSET_ID,
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+
+        remove_exchange_bytecode_root(
+            &amm_instance.with_wallet(other_wallet).unwrap(),
+            exchange_bytecode_root().await,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeRootNotApproved")]
+    async fn when_bytecode_root_is_not_approved() {
+        let (_wallet, amm_instance, _asset_pairs) = setup(true).await;
+
+        remove_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeRootDoesNotMatch")]
+    async fn when_adding_pool_of_removed_implementation() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        add_exchange_bytecode_root(&amm_instance, stable_exchange_bytecode_root().await).await;
+        remove_exchange_bytecode_root(&amm_instance, exchange_bytecode_root().await).await;
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+
+        register_pool(&amm_instance, pair, &exchange).await;
+    }
+}
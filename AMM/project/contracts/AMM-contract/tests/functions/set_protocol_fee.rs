@@ -0,0 +1,154 @@
+use crate::utils::setup;
+use fuels::{tx::Address, types::Identity};
+use test_utils::{
+    data_structures::ExchangeContractConfiguration,
+    interface::{amm::set_protocol_fee, ProtocolFee},
+    setup::common::{deploy_and_construct_exchange, register_pool},
+};
+
+mod success {
+    use super::*;
+    use test_utils::interface::exchange::protocol_fee_info;
+
+    #[tokio::test]
+    async fn sets_protocol_fee_of_pool() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        let protocol_fee = ProtocolFee {
+            recipient: Identity::Address(Address::from(wallet.address())),
+            share: 1_000,
+        };
+
+        set_protocol_fee(&amm_instance, exchange.id, Some(protocol_fee.clone())).await;
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(protocol_fee_info.protocol_fee, Some(protocol_fee));
+    }
+
+    #[tokio::test]
+    async fn removes_protocol_fee_of_pool() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        set_protocol_fee(
+            &amm_instance,
+            exchange.id,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 1_000,
+            }),
+        )
+        .await;
+        set_protocol_fee(&amm_instance, exchange.id, None).await;
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(protocol_fee_info.protocol_fee, None);
+    }
+}
+
+mod revert {
+    use super::*;
+    use fuels::prelude::{TxParameters, WalletUnlocked, BASE_ASSET_ID};
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeRootNotSet")]
+    async fn when_uninitialized() {
+        let (wallet, amm_instance, asset_pairs) = setup(false).await;
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[0]), None, None, None),
+        )
+        .await;
+
+        set_protocol_fee(&amm_instance, exchange.id, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_sender_is_not_owner() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        let other_wallet = WalletUnlocked::new_random(Some(wallet.get_provider().unwrap().clone()));
+        wallet
+            .transfer(
+                other_wallet.address(),
+                1_000_000,
+                BASE_ASSET_ID,
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+
+        set_protocol_fee(
+            &amm_instance.with_wallet(other_wallet).unwrap(),
+            exchange.id,
+            None,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_amm_does_not_own_pool() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+
+        // the exchange contract is still owned by the wallet that constructed it
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(asset_pairs[0]), None, None, None),
+        )
+        .await;
+
+        set_protocol_fee(&amm_instance, exchange.id, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProtocolFeeShareTooHigh")]
+    async fn when_share_is_too_high() {
+        let (wallet, amm_instance, asset_pairs) = setup(true).await;
+        let pair = asset_pairs[0];
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, None),
+        )
+        .await;
+        register_pool(&amm_instance, pair, &exchange).await;
+
+        set_protocol_fee(
+            &amm_instance,
+            exchange.id,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 3_334,
+            }),
+        )
+        .await;
+    }
+}
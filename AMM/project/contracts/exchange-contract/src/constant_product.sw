@@ -0,0 +1,81 @@
+library constant_product;
+
+use core::primitives::*;
+use libraries::{
+    curve::Curve,
+    data_structures::AssetPair,
+    exchange_utils::{
+        calculate_amount_with_fee,
+        optimal_swap_amount,
+    },
+};
+use std::{math::*, u128::U128};
+
+/// The constant product curve `x * y = k`
+pub struct ConstantProduct {}
+
+impl Curve for ConstantProduct {
+    fn initial_liquidity(self, amount_a: u64, amount_b: u64) -> u64 {
+        (amount_a * amount_b).sqrt()
+    }
+
+    fn invariant_holds(
+        self,
+        reserves: AssetPair,
+        new_reserves: AssetPair,
+        repaid: AssetPair,
+        liquidity_miner_fee: u64,
+    ) -> bool {
+        let adjusted_a = new_reserves.a.amount - repaid.a.amount / liquidity_miner_fee;
+        let adjusted_b = new_reserves.b.amount - repaid.b.amount / liquidity_miner_fee;
+
+        U128::from((0, adjusted_a)) * U128::from((0, adjusted_b)) >= U128::from((0, reserves.a.amount)) * U128::from((0, reserves.b.amount))
+    }
+
+    fn maximum_input_for_exact_output(
+        self,
+        output_amount: u64,
+        input_reserve: u64,
+        output_reserve: u64,
+        liquidity_miner_fee: u64,
+    ) -> u64 {
+        assert(input_reserve > 0 && output_reserve > 0);
+        let numerator = U128::from((0, input_reserve)) * U128::from((0, output_amount));
+        let denominator = U128::from((
+            0,
+            calculate_amount_with_fee(output_reserve - output_amount, liquidity_miner_fee),
+        ));
+        let result_wrapped = (numerator / denominator).as_u64();
+
+        if denominator > numerator {
+            0 // 0 < result < 1, round the result down since there are no floating points
+        } else {
+            result_wrapped.unwrap() + 1
+        }
+    }
+
+    fn minimum_output_given_exact_input(
+        self,
+        input_amount: u64,
+        input_reserve: u64,
+        output_reserve: u64,
+        liquidity_miner_fee: u64,
+    ) -> u64 {
+        assert(input_reserve > 0 && output_reserve > 0);
+        let input_amount_with_fee = calculate_amount_with_fee(input_amount, liquidity_miner_fee);
+        let numerator = U128::from((0, input_amount_with_fee)) * U128::from((0, output_reserve));
+        let denominator = U128::from((0, input_reserve)) + U128::from((0, input_amount_with_fee));
+        let result_wrapped = (numerator / denominator).as_u64();
+        result_wrapped.unwrap()
+    }
+
+    fn zap_swap_amount(
+        self,
+        input_amount: u64,
+        input_reserve: u64,
+        output_reserve: u64,
+        liquidity_miner_fee: u64,
+    ) -> u64 {
+        optimal_swap_amount(input_amount, input_reserve, liquidity_miner_fee)
+    }
+}
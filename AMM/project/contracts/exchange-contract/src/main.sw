@@ -1,49 +1,57 @@
 contract;
 
-dep errors;
-dep events;
-dep utils;
-
-use errors::{InitError, InputError, TransactionError};
-use events::{
-    AddLiquidityEvent,
-    DefineAssetPairEvent,
-    DepositEvent,
-    RemoveLiquidityEvent,
-    SwapEvent,
-    WithdrawEvent,
-};
+dep constant_product;
+
+use constant_product::ConstantProduct;
 use libraries::{
     data_structures::{
         Asset,
         AssetPair,
         PoolInfo,
+        PoolState,
         PreviewAddLiquidityInfo,
         PreviewSwapInfo,
+        PreviewZapInfo,
+        PriceObservation,
+        ProtocolFee,
+        ProtocolFeeInfo,
         RemoveLiquidityInfo,
     },
     Exchange,
-};
-use std::{
-    auth::msg_sender,
-    block::height,
-    call_frames::{
-        contract_id,
-        msg_asset_id,
+    exchange_errors::{
+        InitError,
+        InputError,
     },
-    context::msg_amount,
-    math::*,
-    token::{
-        burn,
-        mint,
-        transfer,
+    exchange_functions::{
+        add_liquidity,
+        define_asset_pair,
+        deposit,
+        flash_borrow,
+        flash_repay,
+        pool_info,
+        price_observation,
+        protocol_fee_info,
+        remove_liquidity,
+        set_protocol_fee,
+        swap_exact_input,
+        swap_exact_output,
+        transfer_ownership,
+        withdraw,
+        withdraw_protocol_fees,
+        zap,
     },
+    exchange_utils::{
+        preview_add_liquidity,
+        preview_swap_exact_input,
+        preview_swap_exact_output,
+        preview_zap,
+    },
+    FlashSwapCallee,
 };
-use utils::{
-    determine_assets,
-    maximum_input_for_exact_output,
-    minimum_output_given_exact_input,
-    proportional_value,
+use std::{
+    auth::msg_sender,
+    call_frames::msg_asset_id,
+    u128::U128,
 };
 
 storage {
@@ -51,357 +59,248 @@ storage {
     deposits: StorageMap<(Identity, ContractId), u64> = StorageMap {},
     /// Total amount of the liquidity pool asset that has a unique identifier different from the identifiers of assets on either side of the pool.
     liquidity_pool_supply: u64 = 0,
+    /// Fee tier of the pool that can be set only once using the `constructor`.
+    liquidity_miner_fee: u64 = 0,
+    /// The identity that controls the protocol fee, i.e., the identity that constructed the contract until it transfers ownership.
+    owner: Option<Identity> = Option::None,
     /// The unique identifiers that make up the pool that can be set only once using the `constructor`.
     pair: Option<AssetPair> = Option::None,
+    /// Share of the liquidity miner fee taken by the protocol and its recipient.
+    protocol_fee: Option<ProtocolFee> = Option::None,
+    /// Protocol fees per asset that have accrued since they were last withdrawn, which are not part of the reserves.
+    protocol_fees: StorageMap<ContractId, u64> = StorageMap {},
+    /// Cumulative prices of the pool assets as of the last time the reserves changed.
+    price_observation: PriceObservation = PriceObservation {
+        price_a_cumulative: U128 {
+            upper: 0,
+            lower: 0,
+        },
+        price_b_cumulative: U128 {
+            upper: 0,
+            lower: 0,
+        },
+        timestamp: 0,
+    },
 }
 
 impl Exchange for Contract {
     #[storage(read, write)]
     fn add_liquidity(desired_liquidity: u64, deadline: u64) -> u64 {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
-        require(deadline > height(), InputError::DeadlinePassed(deadline));
-        require(MINIMUM_LIQUIDITY <= desired_liquidity, InputError::CannotAddLessThanMinimumLiquidity(desired_liquidity));
-
+        let pool = pool_state();
         let sender = msg_sender().unwrap();
-        let reserves = storage.pair.unwrap();
-
-        let (deposit_a, deposit_b) = (
-            storage.deposits.get((sender, reserves.a.id)).unwrap_or(0),
-            storage.deposits.get((sender, reserves.b.id)).unwrap_or(0),
-        );
-        let deposits = AssetPair::new(Asset::new(reserves.a.id, deposit_a), Asset::new(reserves.b.id, deposit_b));
-
-        // checking this because this will either result in a math error or adding no liquidity at all
-        require(deposits.a.amount != 0, TransactionError::ExpectedNonZeroDeposit(deposits.a.id));
-        require(deposits.b.amount != 0, TransactionError::ExpectedNonZeroDeposit(deposits.b.id));
-
-        let total_liquidity = storage.liquidity_pool_supply;
-
-        let mut added_assets = AssetPair::new(Asset::new(reserves.a.id, 0), Asset::new(reserves.b.id, 0));
-        let mut added_liquidity = 0;
-
-        // adding liquidity for the first time
-        // use up all the deposited amounts of assets to determine the ratio
-        if reserves.a.amount == 0 && reserves.b.amount == 0 {
-            added_liquidity = (deposits.a.amount * deposits.b.amount).sqrt();
-            require(desired_liquidity <= added_liquidity, TransactionError::DesiredAmountTooHigh(desired_liquidity));
-            added_assets.a.amount = deposits.a.amount;
-            added_assets.b.amount = deposits.b.amount;
-
-            // add amounts to reserves
-            storage.pair = Option::Some(added_assets);
-
-            // mint liquidity pool asset and transfer to sender
-            mint(added_liquidity);
-            storage.liquidity_pool_supply = added_liquidity;
-            transfer(added_liquidity, contract_id(), sender);
-        } else { // adding further liquidity based on current ratio
-            // attempt to add liquidity by using up the deposited asset A amount
-            let b_to_attempt = proportional_value(deposits.a.amount, reserves.b.amount, reserves.a.amount);
-
-            // continue adding based on asset A if deposited asset B amount is sufficient
-            if b_to_attempt <= deposits.b.amount {
-                added_liquidity = proportional_value(b_to_attempt, total_liquidity, reserves.b.amount);
-                require(desired_liquidity <= added_liquidity, TransactionError::DesiredAmountTooHigh(desired_liquidity));
-                added_assets.a.amount = deposits.a.amount;
-                added_assets.b.amount = b_to_attempt;
-            } else { // attempt to add liquidity by using up the deposited asset B amount
-                let a_to_attempt = proportional_value(deposits.b.amount, reserves.a.amount, reserves.b.amount);
-                added_liquidity = proportional_value(a_to_attempt, total_liquidity, reserves.a.amount);
-                require(desired_liquidity <= added_liquidity, TransactionError::DesiredAmountTooHigh(desired_liquidity));
-                added_assets.a.amount = a_to_attempt;
-                added_assets.b.amount = deposits.b.amount;
-            }
-
-            // add new asset amounts to reserves
-            storage.pair = Option::Some(reserves + added_assets);
-
-            // mint liquidity pool asset and transfer to sender
-            mint(added_liquidity);
-            storage.liquidity_pool_supply = total_liquidity + added_liquidity;
-            transfer(added_liquidity, contract_id(), sender);
-
-            // transfer remaining deposit amounts back to the sender
-            let refund = deposits - added_assets;
-
-            if refund.a.amount > 0 {
-                transfer(refund.a.amount, refund.a.id, sender);
-            }
-
-            if refund.b.amount > 0 {
-                transfer(refund.b.amount, refund.b.id, sender);
-            }
-        }
-
-        storage.deposits.insert((sender, deposits.a.id), 0);
-        storage.deposits.insert((sender, deposits.b.id), 0);
-
-        log(AddLiquidityEvent {
-            added_assets,
-            liquidity: Asset::new(contract_id(), added_liquidity),
-        });
+
+        let (pool, added_liquidity) = add_liquidity(curve(), pool, deposits(sender, pool.reserves), desired_liquidity, MINIMUM_LIQUIDITY, deadline);
+
+        store_pool_state(pool);
+        store_deposits(sender, AssetPair::new(Asset::new(pool.reserves.a.id, 0), Asset::new(pool.reserves.b.id, 0)));
 
         added_liquidity
     }
 
     #[storage(read, write)]
-    fn constructor(asset_a: ContractId, asset_b: ContractId) {
-        require(storage.pair.is_none(), InitError::AssetPairAlreadySet);
-        require(asset_a != asset_b, InitError::IdenticalAssets);
-
-        storage.pair = Option::Some(AssetPair::new(Asset::new(asset_a, 0), Asset::new(asset_b, 0)));
-        log(DefineAssetPairEvent {
-            asset_a_id: asset_a,
-            asset_b_id: asset_b,
-        });
+    fn constructor(asset_a: ContractId, asset_b: ContractId, liquidity_miner_fee: u64) {
+        storage.pair = Option::Some(define_asset_pair(storage.pair, asset_a, asset_b, liquidity_miner_fee));
+        storage.liquidity_miner_fee = liquidity_miner_fee;
+        storage.owner = Option::Some(msg_sender().unwrap());
     }
 
     #[payable, storage(read, write)]
     fn deposit() {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
-
+        let reserves = reserves();
+        let sender = msg_sender().unwrap();
         let deposit_asset = msg_asset_id();
 
-        require(deposit_asset == storage.pair.unwrap().a.id || deposit_asset == storage.pair.unwrap().b.id, InputError::InvalidAsset);
+        let new_balance = deposit(reserves, storage.deposits.get((sender, deposit_asset)).unwrap_or(0));
 
-        let sender = msg_sender().unwrap();
-        let amount = msg_amount();
-        let new_balance = storage.deposits.get((sender, deposit_asset)).unwrap_or(0) + amount;
         storage.deposits.insert((sender, deposit_asset), new_balance);
-
-        log(DepositEvent {
-            deposited_asset: Asset::new(deposit_asset, amount),
-            new_balance,
-        });
     }
 
-    #[payable, storage(read, write)]
-    fn remove_liquidity(min_asset_a: u64, min_asset_b: u64, deadline: u64) -> RemoveLiquidityInfo {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
-
-        let total_liquidity = storage.liquidity_pool_supply;
-        require(total_liquidity > 0, TransactionError::NoLiquidityToRemove);
-
-        let reserves = storage.pair.unwrap();
+    #[storage(read, write)]
+    fn flash_swap(output: Asset, borrower: ContractId, deadline: u64) -> AssetPair {
+        let (borrowed, balances) = flash_borrow(pool_state(), output, borrower, deadline);
 
-        require(min_asset_a > 0, InputError::ExpectedNonZeroParameter(reserves.a.id));
-        require(min_asset_b > 0, InputError::ExpectedNonZeroParameter(reserves.b.id));
-        require(deadline > height(), InputError::DeadlinePassed(deadline));
+        let callee = abi(FlashSwapCallee, borrower.into());
+        callee.on_flash_swap(msg_sender().unwrap(), output);
 
-        let burned_liquidity = Asset::new(contract_id(), msg_amount());
+        let (pool, repaid) = flash_repay(curve(), pool_state(), output, borrower, borrowed, balances);
 
-        require(burned_liquidity.id == msg_asset_id(), InputError::InvalidAsset);
-        require(burned_liquidity.amount > 0, InputError::ExpectedNonZeroAmount(burned_liquidity.id));
+        store_pool_state(pool);
 
-        let mut removed_assets = AssetPair::new(Asset::new(reserves.a.id, 0), Asset::new(reserves.b.id, 0));
-        removed_assets.a.amount = proportional_value(burned_liquidity.amount, reserves.a.amount, total_liquidity);
-        removed_assets.b.amount = proportional_value(burned_liquidity.amount, reserves.b.amount, total_liquidity);
+        repaid
+    }
 
-        require(removed_assets.a.amount >= min_asset_a, TransactionError::DesiredAmountTooHigh(min_asset_a));
-        require(removed_assets.b.amount >= min_asset_b, TransactionError::DesiredAmountTooHigh(min_asset_b));
+    #[payable, storage(read, write)]
+    fn remove_liquidity(min_asset_a: u64, min_asset_b: u64, deadline: u64) -> RemoveLiquidityInfo {
+        let (pool, removed) = remove_liquidity(pool_state(), min_asset_a, min_asset_b, deadline);
 
-        burn(burned_liquidity.amount);
-        storage.liquidity_pool_supply = total_liquidity - burned_liquidity.amount;
-        storage.pair = Option::Some(reserves - removed_assets);
+        store_pool_state(pool);
 
-        let sender = msg_sender().unwrap();
-        transfer(removed_assets.a.amount, removed_assets.a.id, sender);
-        transfer(removed_assets.b.amount, removed_assets.b.id, sender);
-
-        log(RemoveLiquidityEvent {
-            removed_reserve: removed_assets,
-            burned_liquidity,
-        });
-
-        RemoveLiquidityInfo {
-            removed_amounts: removed_assets,
-            burned_liquidity,
-        }
+        removed
     }
 
     #[payable, storage(read, write)]
     fn swap_exact_input(min_output: Option<u64>, deadline: u64) -> u64 {
-        require(deadline >= height(), InputError::DeadlinePassed(deadline));
-
-        let reserves = storage.pair;
-        let (mut input_asset, mut output_asset) = determine_assets(msg_asset_id(), reserves);
+        let (pool, bought) = swap_exact_input(curve(), pool_state(), min_output, deadline);
 
-        let exact_input = msg_amount();
-        require(exact_input > 0, InputError::ExpectedNonZeroAmount(input_asset.id));
-
-        let bought = minimum_output_given_exact_input(exact_input, input_asset.amount, output_asset.amount, LIQUIDITY_MINER_FEE);
-
-        if min_output.is_some() {
-            require(bought >= min_output.unwrap(), TransactionError::DesiredAmountTooHigh(min_output.unwrap()));
-        }
-
-        transfer(bought, output_asset.id, msg_sender().unwrap());
-
-        input_asset.amount = input_asset.amount + exact_input;
-        output_asset.amount = output_asset.amount - bought;
-        storage.pair = Option::Some(AssetPair::new(input_asset, output_asset).sort(reserves.unwrap()));
-
-        log(SwapEvent {
-            input: input_asset,
-            output: output_asset,
-        });
+        store_pool_state(pool);
 
         bought
     }
 
     #[payable, storage(read, write)]
     fn swap_exact_output(output: u64, deadline: u64) -> u64 {
-        let reserves = storage.pair;
-        let (mut input_asset, mut output_asset) = determine_assets(msg_asset_id(), reserves);
+        let (pool, sold) = swap_exact_output(curve(), pool_state(), output, deadline);
 
-        require(deadline > height(), InputError::DeadlinePassed(deadline));
-        require(output > 0, InputError::ExpectedNonZeroParameter(output_asset.id));
-        require(output <= output_asset.amount, TransactionError::InsufficientReserve(output_asset.id));
+        store_pool_state(pool);
 
-        let input_amount = msg_amount();
-        require(input_amount > 0, InputError::ExpectedNonZeroAmount(input_asset.id));
+        sold
+    }
+
+    #[storage(read, write)]
+    fn set_protocol_fee(protocol_fee: Option<ProtocolFee>) {
+        require(storage.pair.is_some(), InitError::AssetPairNotSet);
 
-        let sold = maximum_input_for_exact_output(output, input_asset.amount, output_asset.amount, LIQUIDITY_MINER_FEE);
+        set_protocol_fee(storage.owner.unwrap(), protocol_fee);
 
-        require(sold > 0, TransactionError::DesiredAmountTooLow(output));
-        require(input_amount >= sold, TransactionError::DesiredAmountTooHigh(input_amount));
+        storage.protocol_fee = protocol_fee;
+    }
 
-        let sender = msg_sender().unwrap();
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        require(storage.pair.is_some(), InitError::AssetPairNotSet);
 
-        let refund = input_amount - sold;
-        if refund > 0 {
-            transfer(refund, input_asset.id, sender);
-        };
+        transfer_ownership(storage.owner.unwrap(), new_owner);
 
-        transfer(output, output_asset.id, sender);
+        storage.owner = Option::Some(new_owner);
+    }
 
-        input_asset.amount = input_asset.amount + sold;
-        output_asset.amount = output_asset.amount - output;
-        storage.pair = Option::Some(AssetPair::new(input_asset, output_asset).sort(reserves.unwrap()));
+    #[storage(read, write)]
+    fn withdraw_protocol_fees() -> AssetPair {
+        let (pool, withdrawn) = withdraw_protocol_fees(pool_state());
 
-        log(SwapEvent {
-            input: input_asset,
-            output: output_asset,
-        });
+        store_pool_state(pool);
 
-        sold
+        withdrawn
     }
 
     #[storage(read, write)]
     fn withdraw(asset: Asset) {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
+        let reserves = reserves();
+        let sender = msg_sender().unwrap();
 
-        require(asset.id == storage.pair.unwrap().a.id || asset.id == storage.pair.unwrap().b.id, InputError::InvalidAsset);
+        let remaining = withdraw(reserves, asset, storage.deposits.get((sender, asset.id)).unwrap_or(0));
 
-        let sender = msg_sender().unwrap();
-        let deposited_amount = storage.deposits.get((sender, asset.id)).unwrap_or(0);
+        storage.deposits.insert((sender, asset.id), remaining);
+    }
 
-        require(deposited_amount >= asset.amount, TransactionError::DesiredAmountTooHigh(asset.amount));
+    #[payable, storage(read, write)]
+    fn zap(min_liquidity: u64, deadline: u64) -> u64 {
+        let (pool, liquidity) = zap(curve(), pool_state(), min_liquidity, deadline);
 
-        let new_amount = deposited_amount - asset.amount;
-        storage.deposits.insert((sender, asset.id), new_amount);
-        transfer(asset.amount, asset.id, sender);
+        store_pool_state(pool);
 
-        log(WithdrawEvent {
-            withdrawn_asset: asset,
-            remaining_balance: new_amount,
-        });
+        liquidity
     }
 
     #[storage(read)]
     fn balance(asset_id: ContractId) -> u64 {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
-        require(asset_id == storage.pair.unwrap().a.id || asset_id == storage.pair.unwrap().b.id, InputError::InvalidAsset);
+        let reserves = reserves();
+        require(asset_id == reserves.a.id || asset_id == reserves.b.id, InputError::InvalidAsset);
 
         storage.deposits.get((msg_sender().unwrap(), asset_id)).unwrap_or(0)
     }
 
     #[storage(read)]
     fn pool_info() -> PoolInfo {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
+        pool_info(pool_state())
+    }
+
+    #[storage(read)]
+    fn protocol_fee_info() -> ProtocolFeeInfo {
+        protocol_fee_info(pool_state(), storage.owner.unwrap())
+    }
 
-        PoolInfo {
-            reserves: storage.pair.unwrap(),
-            liquidity: storage.liquidity_pool_supply,
-        }
+    #[storage(read)]
+    fn price_observation() -> PriceObservation {
+        price_observation(pool_state())
     }
 
     #[storage(read)]
     fn preview_add_liquidity(asset: Asset) -> PreviewAddLiquidityInfo {
-        require(storage.pair.is_some(), InitError::AssetPairNotSet);
+        let pool = pool_state();
 
-        let sender = msg_sender().unwrap();
-        let total_liquidity = storage.liquidity_pool_supply;
-        let reserves = storage.pair.unwrap();
-
-        let (deposit_a, deposit_b) = (
-            storage.deposits.get((sender, reserves.a.id)).unwrap_or(0),
-            storage.deposits.get((sender, reserves.b.id)).unwrap_or(0),
-        );
-        let deposits = AssetPair::new(Asset::new(reserves.a.id, deposit_a), Asset::new(reserves.b.id, deposit_b));
-
-        let mut added_assets = AssetPair::new(Asset::new(reserves.a.id, 0), Asset::new(reserves.b.id, 0));
-        let mut added_liquidity = 0;
-
-        if total_liquidity == 0 {
-            added_assets.a.amount = if deposits.a.amount == 0 {
-                asset.amount
-            } else {
-                deposits.a.amount
-            };
-            added_assets.b.amount = if deposits.b.amount == 0 {
-                asset.amount
-            } else {
-                deposits.b.amount
-            };
-            added_liquidity = (added_assets.a.amount * added_assets.b.amount).sqrt()
-        } else {
-            if asset.id == reserves.a.id {
-                added_assets.a.amount = asset.amount;
-                added_assets.b.amount = proportional_value(asset.amount, reserves.b.amount, reserves.a.amount);
-            } else {
-                added_assets.a.amount = proportional_value(asset.amount, reserves.a.amount, reserves.b.amount);
-                added_assets.b.amount = asset.amount;
-            }
-            added_liquidity = proportional_value(added_assets.b.amount, total_liquidity, reserves.b.amount);
-        }
-
-        PreviewAddLiquidityInfo {
-            other_asset_to_add: if asset.id == reserves.a.id {
-                added_assets.b
-            } else {
-                added_assets.a
-            },
-            liquidity_asset_to_receive: Asset::new(contract_id(), added_liquidity),
-        }
+        preview_add_liquidity(curve(), asset, deposits(msg_sender().unwrap(), pool.reserves), pool.reserves, pool.liquidity)
     }
 
     #[storage(read)]
     fn preview_swap_exact_input(exact_input_asset: Asset) -> PreviewSwapInfo {
-        let (input_asset, mut output_asset) = determine_assets(exact_input_asset.id, storage.pair);
-
-        output_asset.amount = minimum_output_given_exact_input(exact_input_asset.amount, input_asset.amount, output_asset.amount, LIQUIDITY_MINER_FEE);
-        let sufficient_reserve = output_asset.amount <= output_asset.amount;
-
-        PreviewSwapInfo {
-            other_asset: output_asset,
-            sufficient_reserve,
-        }
+        preview_swap_exact_input(curve(), exact_input_asset, storage.pair, storage.liquidity_miner_fee)
     }
 
     #[storage(read)]
     fn preview_swap_exact_output(exact_output_asset: Asset) -> PreviewSwapInfo {
-        let (output_asset, mut input_asset) = determine_assets(exact_output_asset.id, storage.pair);
+        preview_swap_exact_output(curve(), exact_output_asset, storage.pair, storage.liquidity_miner_fee)
+    }
 
-        require(exact_output_asset.amount <= output_asset.amount, TransactionError::DesiredAmountTooHigh(exact_output_asset.amount));
+    #[storage(read)]
+    fn preview_zap(asset: Asset) -> PreviewZapInfo {
+        preview_zap(curve(), asset, storage.pair, storage.liquidity_pool_supply, storage.liquidity_miner_fee, storage.protocol_fee)
+    }
+}
 
-        input_asset.amount = maximum_input_for_exact_output(exact_output_asset.amount, input_asset.amount, output_asset.amount, LIQUIDITY_MINER_FEE);
-        require(input_asset.amount > 0, TransactionError::DesiredAmountTooLow(exact_output_asset.amount));
-        let sufficient_reserve = exact_output_asset.amount <= output_asset.amount;
+/// Returns the curve along which the reserves of the pool trade
+fn curve() -> ConstantProduct {
+    ConstantProduct {}
+}
 
-        PreviewSwapInfo {
-            other_asset: input_asset,
-            sufficient_reserve,
-        }
+/// Returns the amounts of the pool assets deposited by `depositer`
+#[storage(read)]
+fn deposits(depositer: Identity, reserves: AssetPair) -> AssetPair {
+    AssetPair::new(
+        Asset::new(reserves.a.id, storage.deposits.get((depositer, reserves.a.id)).unwrap_or(0)),
+        Asset::new(reserves.b.id, storage.deposits.get((depositer, reserves.b.id)).unwrap_or(0)),
+    )
+}
+
+/// Returns the values of the pool held in storage
+#[storage(read)]
+fn pool_state() -> PoolState {
+    let reserves = reserves();
+
+    PoolState {
+        reserves,
+        liquidity: storage.liquidity_pool_supply,
+        liquidity_miner_fee: storage.liquidity_miner_fee,
+        protocol_fee: storage.protocol_fee,
+        accrued_protocol_fees: AssetPair::new(
+            Asset::new(reserves.a.id, storage.protocol_fees.get(reserves.a.id).unwrap_or(0)),
+            Asset::new(reserves.b.id, storage.protocol_fees.get(reserves.b.id).unwrap_or(0)),
+        ),
+        price_observation: storage.price_observation,
     }
 }
+
+/// Returns the reserves of the pool once the asset pair is set
+#[storage(read)]
+fn reserves() -> AssetPair {
+    require(storage.pair.is_some(), InitError::AssetPairNotSet);
+
+    storage.pair.unwrap()
+}
+
+/// Sets the amounts of the pool assets deposited by `depositer` to the `deposits`
+#[storage(write)]
+fn store_deposits(depositer: Identity, deposits: AssetPair) {
+    storage.deposits.insert((depositer, deposits.a.id), deposits.a.amount);
+    storage.deposits.insert((depositer, deposits.b.id), deposits.b.amount);
+}
+
+/// Writes the values of the `pool` that the exchange functions update back to storage
+#[storage(write)]
+fn store_pool_state(pool: PoolState) {
+    storage.pair = Option::Some(pool.reserves);
+    storage.liquidity_pool_supply = pool.liquidity;
+    storage.protocol_fees.insert(pool.reserves.a.id, pool.accrued_protocol_fees.a.amount);
+    storage.protocol_fees.insert(pool.reserves.b.id, pool.accrued_protocol_fees.b.amount);
+    storage.price_observation = pool.price_observation;
+}
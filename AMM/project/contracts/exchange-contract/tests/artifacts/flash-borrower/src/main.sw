@@ -0,0 +1,31 @@
+contract;
+
+use libraries::{data_structures::Asset, FlashSwapCallee};
+use std::{auth::msg_sender, token::transfer};
+
+abi FlashBorrower {
+    #[storage(write)]
+    fn set_repayment(repayment: Option<Asset>);
+}
+
+storage {
+    repayment: Option<Asset> = Option::None,
+}
+
+impl FlashBorrower for Contract {
+    #[storage(write)]
+    fn set_repayment(repayment: Option<Asset>) {
+        storage.repayment = repayment;
+    }
+}
+
+impl FlashSwapCallee for Contract {
+    #[storage(read, write)]
+    fn on_flash_swap(sender: Identity, borrowed: Asset) {
+        // keep the borrowed asset when there is nothing to repay
+        if storage.repayment.is_some() {
+            let repayment = storage.repayment.unwrap();
+            transfer(repayment.amount, repayment.id, msg_sender().unwrap());
+        }
+    }
+}
@@ -0,0 +1,21 @@
+contract;
+
+use libraries::{data_structures::Asset, Exchange, FlashSwapCallee};
+use std::auth::msg_sender;
+
+impl FlashSwapCallee for Contract {
+    #[storage(read, write)]
+    fn on_flash_swap(sender: Identity, borrowed: Asset) {
+        let exchange_id = match msg_sender().unwrap() {
+            Identity::ContractId(id) => id,
+            _ => revert(0),
+        };
+
+        // attempt to repay with a deposit which would remain withdrawable by the borrower
+        let exchange_contract = abi(Exchange, exchange_id.into());
+        exchange_contract.deposit {
+            coins: borrowed.amount,
+            asset_id: borrowed.id.into(),
+        }();
+    }
+}
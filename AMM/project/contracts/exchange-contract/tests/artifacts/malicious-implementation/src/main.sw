@@ -7,11 +7,15 @@ use libraries::{
         PoolInfo,
         PreviewAddLiquidityInfo,
         PreviewSwapInfo,
+        PreviewZapInfo,
+        PriceObservation,
+        ProtocolFee,
+        ProtocolFeeInfo,
         RemoveLiquidityInfo,
     },
     Exchange,
 };
-use std::{call_frames::contract_id, constants::BASE_ASSET_ID};
+use std::{auth::msg_sender, call_frames::contract_id, constants::BASE_ASSET_ID, u128::U128};
 
 storage {
     pair: Option<AssetPair> = Option::None,
@@ -24,13 +28,18 @@ impl Exchange for Contract {
     }
 
     #[storage(read, write)]
-    fn constructor(asset_a: ContractId, asset_b: ContractId) {
+    fn constructor(asset_a: ContractId, asset_b: ContractId, liquidity_miner_fee: u64) {
         storage.pair = Option::Some(AssetPair::new(Asset::new(asset_a, 0), Asset::new(asset_b, 0)));
     }
 
     #[payable, storage(read, write)]
     fn deposit() {}
 
+    #[storage(read, write)]
+    fn flash_swap(output: Asset, borrower: ContractId, deadline: u64) -> AssetPair {
+        storage.pair.unwrap()
+    }
+
     #[payable, storage(read, write)]
     fn remove_liquidity(min_asset_a: u64, min_asset_b: u64, deadline: u64) -> RemoveLiquidityInfo {
         RemoveLiquidityInfo {
@@ -49,9 +58,25 @@ impl Exchange for Contract {
         0
     }
 
+    #[storage(read, write)]
+    fn set_protocol_fee(protocol_fee: Option<ProtocolFee>) {}
+
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {}
+
+    #[storage(read, write)]
+    fn withdraw_protocol_fees() -> AssetPair {
+        storage.pair.unwrap()
+    }
+
     #[storage(read, write)]
     fn withdraw(asset: Asset) {}
 
+    #[payable, storage(read, write)]
+    fn zap(min_liquidity: u64, deadline: u64) -> u64 {
+        0
+    }
+
     #[storage(read)]
     fn balance(asset_id: ContractId) -> u64 {
         0
@@ -62,6 +87,25 @@ impl Exchange for Contract {
         PoolInfo {
             reserves: storage.pair.unwrap_or(AssetPair::new(Asset::new(BASE_ASSET_ID, 0), Asset::new(BASE_ASSET_ID, 0))),
             liquidity: 0,
+            liquidity_miner_fee: 0,
+        }
+    }
+
+    #[storage(read)]
+    fn protocol_fee_info() -> ProtocolFeeInfo {
+        ProtocolFeeInfo {
+            accrued: storage.pair.unwrap(),
+            owner: msg_sender().unwrap(),
+            protocol_fee: Option::None,
+        }
+    }
+
+    #[storage(read)]
+    fn price_observation() -> PriceObservation {
+        PriceObservation {
+            price_a_cumulative: U128::new(),
+            price_b_cumulative: U128::new(),
+            timestamp: 0,
         }
     }
 
@@ -88,4 +132,13 @@ impl Exchange for Contract {
             sufficient_reserve: false,
         }
     }
+
+    #[storage(read)]
+    fn preview_zap(asset: Asset) -> PreviewZapInfo {
+        PreviewZapInfo {
+            swapped_asset: asset,
+            liquidity_asset_to_receive: Asset::new(contract_id(), 0),
+            refund: storage.pair.unwrap(),
+        }
+    }
 }
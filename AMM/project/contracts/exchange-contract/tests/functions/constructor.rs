@@ -1,5 +1,5 @@
 use crate::utils::setup;
-use test_utils::interface::exchange::constructor;
+use test_utils::{data_structures::LIQUIDITY_MINER_FEE, interface::exchange::constructor};
 
 mod success {
     use super::*;
@@ -10,7 +10,12 @@ mod success {
     async fn constructs() {
         let (exchange_instance, _wallet, assets, _deadline) = setup().await;
 
-        let response = constructor(&exchange_instance, (assets.asset_1, assets.asset_2)).await;
+        let response = constructor(
+            &exchange_instance,
+            (assets.asset_1, assets.asset_2),
+            LIQUIDITY_MINER_FEE,
+        )
+        .await;
         let log = response
             .get_logs_with_type::<DefineAssetPairEvent>()
             .unwrap();
@@ -23,10 +28,12 @@ mod success {
             DefineAssetPairEvent {
                 asset_a_id: ContractId::new(*assets.asset_1),
                 asset_b_id: ContractId::new(*assets.asset_2),
+                liquidity_miner_fee: LIQUIDITY_MINER_FEE,
             }
         );
         assert_eq!(pool_info.reserves.a.id, ContractId::new(*assets.asset_1));
         assert_eq!(pool_info.reserves.b.id, ContractId::new(*assets.asset_2));
+        assert_eq!(pool_info.liquidity_miner_fee, LIQUIDITY_MINER_FEE);
     }
 }
 
@@ -38,9 +45,19 @@ mod revert {
     async fn when_reinitialized() {
         let (exchange_instance, _wallet, assets, _deadline) = setup().await;
 
-        constructor(&exchange_instance, (assets.asset_1, assets.asset_2)).await;
+        constructor(
+            &exchange_instance,
+            (assets.asset_1, assets.asset_2),
+            LIQUIDITY_MINER_FEE,
+        )
+        .await;
 
-        constructor(&exchange_instance, (assets.asset_1, assets.asset_2)).await;
+        constructor(
+            &exchange_instance,
+            (assets.asset_1, assets.asset_2),
+            LIQUIDITY_MINER_FEE,
+        )
+        .await;
     }
 
     #[tokio::test]
@@ -48,6 +65,19 @@ mod revert {
     async fn when_assets_in_pair_are_identical() {
         let (exchange_instance, _wallet, assets, _deadline) = setup().await;
 
-        constructor(&exchange_instance, (assets.asset_1, assets.asset_1)).await;
+        constructor(
+            &exchange_instance,
+            (assets.asset_1, assets.asset_1),
+            LIQUIDITY_MINER_FEE,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidLiquidityMinerFee")]
+    async fn when_liquidity_miner_fee_is_too_low() {
+        let (exchange_instance, _wallet, assets, _deadline) = setup().await;
+
+        constructor(&exchange_instance, (assets.asset_1, assets.asset_2), 1).await;
     }
 }
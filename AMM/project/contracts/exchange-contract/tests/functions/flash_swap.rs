@@ -0,0 +1,327 @@
+use test_utils::{interface::exchange::flash_swap, setup::common::deploy_flash_borrower};
+
+mod success {
+    use super::*;
+    use crate::utils::{fund_contract, minimum_flash_swap_repayment, setup_and_construct};
+    use fuels::{
+        prelude::{Bech32ContractId, ContractId},
+        types::Identity,
+    };
+    use test_utils::{
+        data_structures::LIQUIDITY_MINER_FEE,
+        interface::{
+            exchange::{pool_info, protocol_fee_info, set_protocol_fee},
+            flash_borrower::set_repayment,
+            Asset, AssetPair, FlashSwapEvent, ProtocolFee,
+        },
+    };
+
+    #[tokio::test]
+    async fn repays_with_other_asset() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, borrower) = deploy_flash_borrower(&wallet).await;
+        let output_amount = 1_000;
+
+        let repayment = minimum_flash_swap_repayment(
+            output_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+        fund_contract(&wallet, borrower_id, repayment, exchange.pair.0).await;
+        set_repayment(&borrower, Some((exchange.pair.0, repayment))).await;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+
+        let response = flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            output_amount,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+        let log = response.get_logs_with_type::<FlashSwapEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let borrower_balance = wallet
+            .get_provider()
+            .unwrap()
+            .get_contract_asset_balance(&Bech32ContractId::from(borrower_id), exchange.pair.1)
+            .await
+            .unwrap();
+
+        let repaid = AssetPair {
+            a: Asset {
+                id: ContractId::new(*exchange.pair.0),
+                amount: repayment,
+            },
+            b: Asset {
+                id: ContractId::new(*exchange.pair.1),
+                amount: 0,
+            },
+        };
+
+        assert_eq!(response.value, repaid);
+        assert_eq!(
+            *event,
+            FlashSwapEvent {
+                borrower: borrower_id,
+                borrowed: Asset {
+                    id: ContractId::new(*exchange.pair.1),
+                    amount: output_amount,
+                },
+                repaid,
+            }
+        );
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + repayment
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount - output_amount
+        );
+        assert_eq!(borrower_balance, output_amount);
+    }
+
+    #[tokio::test]
+    async fn repays_with_borrowed_asset() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, borrower) = deploy_flash_borrower(&wallet).await;
+        let output_amount = 1_000;
+
+        // the borrowed amount along with the liquidity miner fee on the repayment
+        let repayment = (output_amount..)
+            .find(|amount| amount - amount / LIQUIDITY_MINER_FEE >= output_amount)
+            .unwrap();
+        fund_contract(
+            &wallet,
+            borrower_id,
+            repayment - output_amount,
+            exchange.pair.1,
+        )
+        .await;
+        set_repayment(&borrower, Some((exchange.pair.1, repayment))).await;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+
+        let repaid = flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            output_amount,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await
+        .value;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+
+        assert_eq!(repaid.a.amount, 0);
+        assert_eq!(repaid.b.amount, repayment);
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount - output_amount + repayment
+        );
+    }
+
+    #[tokio::test]
+    async fn accrues_protocol_fee_on_repayment() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, borrower) = deploy_flash_borrower(&wallet).await;
+        let output_amount = 1_000;
+        // large enough for the liquidity miner fee to be charged
+        let repayment = 10_000;
+
+        fund_contract(&wallet, borrower_id, repayment, exchange.pair.0).await;
+        set_repayment(&borrower, Some((exchange.pair.0, repayment))).await;
+        set_protocol_fee(
+            &exchange.instance,
+            Some(ProtocolFee {
+                recipient: Identity::ContractId(borrower_id),
+                share: 2_500,
+            }),
+        )
+        .await;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+
+        flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            output_amount,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let accrued = protocol_fee_info(&exchange.instance).await.accrued;
+        let protocol_fee = repayment / LIQUIDITY_MINER_FEE / 4;
+
+        assert_eq!(accrued.a.amount, protocol_fee);
+        assert_eq!(accrued.b.amount, 0);
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + repayment - protocol_fee
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::{fund_contract, minimum_flash_swap_repayment, setup, setup_and_construct};
+    use test_utils::{
+        data_structures::LIQUIDITY_MINER_FEE, interface::flash_borrower::set_repayment,
+        setup::common::deploy_malicious_borrower,
+    };
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, wallet, assets, deadline) = setup().await;
+        let (borrower_id, _borrower) = deploy_flash_borrower(&wallet).await;
+
+        flash_swap(
+            &exchange_instance,
+            assets.asset_1,
+            1_000,
+            borrower_id,
+            deadline,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAsset")]
+    async fn when_output_asset_is_invalid() {
+        let (exchange, wallet, liquidity_parameters, asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, _borrower) = deploy_flash_borrower(&wallet).await;
+
+        flash_swap(
+            &exchange.instance,
+            asset_c_id,
+            1_000,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DeadlinePassed")]
+    async fn when_deadline_passed() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, _borrower) = deploy_flash_borrower(&wallet).await;
+
+        flash_swap(&exchange.instance, exchange.pair.1, 1_000, borrower_id, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ExpectedNonZeroParameter")]
+    async fn when_output_is_zero() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, _borrower) = deploy_flash_borrower(&wallet).await;
+
+        flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            0,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientReserve")]
+    async fn when_output_drains_reserve() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, _borrower) = deploy_flash_borrower(&wallet).await;
+
+        flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            liquidity_parameters.amounts.1,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "FlashSwapNotRepaid")]
+    async fn when_borrower_does_not_repay() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        // the borrower keeps the borrowed asset since no repayment is set
+        let (borrower_id, _borrower) = deploy_flash_borrower(&wallet).await;
+
+        flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            1_000,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "FlashSwapNotRepaid")]
+    async fn when_repayment_is_insufficient() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, borrower) = deploy_flash_borrower(&wallet).await;
+        let output_amount = 1_000;
+
+        let repayment = minimum_flash_swap_repayment(
+            output_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        ) - 1;
+        fund_contract(&wallet, borrower_id, repayment, exchange.pair.0).await;
+        set_repayment(&borrower, Some((exchange.pair.0, repayment))).await;
+
+        flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            output_amount,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NonReentrant")]
+    async fn when_borrower_repays_with_deposit() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let (borrower_id, _borrower) = deploy_malicious_borrower(&wallet).await;
+
+        flash_swap(
+            &exchange.instance,
+            exchange.pair.1,
+            1_000,
+            borrower_id,
+            liquidity_parameters.deadline,
+        )
+        .await;
+    }
+}
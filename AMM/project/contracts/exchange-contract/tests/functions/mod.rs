@@ -2,11 +2,19 @@ mod add_liquidity;
 mod balance;
 mod constructor;
 mod deposit;
+mod flash_swap;
 mod pool_info;
 mod preview_add_liquidity;
 mod preview_swap_exact_input;
 mod preview_swap_exact_output;
+mod preview_zap;
+mod price_observation;
+mod protocol_fee_info;
 mod remove_liquidity;
+mod set_protocol_fee;
 mod swap_exact_input;
 mod swap_exact_output;
+mod transfer_ownership;
 mod withdraw;
+mod withdraw_protocol_fees;
+mod zap;
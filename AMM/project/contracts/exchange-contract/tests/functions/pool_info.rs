@@ -4,7 +4,9 @@ mod success {
     use super::*;
     use crate::utils::setup_and_construct;
     use fuels::prelude::ContractId;
-    use test_utils::setup::common::deposit_and_add_liquidity;
+    use test_utils::{
+        data_structures::LIQUIDITY_MINER_FEE, setup::common::deposit_and_add_liquidity,
+    };
 
     #[tokio::test]
     async fn returns_empty_pool_info() {
@@ -17,6 +19,7 @@ mod success {
         assert_eq!(pool_info.reserves.b.id, ContractId::new(*exchange.pair.1));
         assert_eq!(pool_info.reserves.b.amount, 0);
         assert_eq!(pool_info.liquidity, 0);
+        assert_eq!(pool_info.liquidity_miner_fee, LIQUIDITY_MINER_FEE);
     }
 
     #[tokio::test]
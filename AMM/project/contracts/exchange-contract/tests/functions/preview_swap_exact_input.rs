@@ -3,7 +3,7 @@ use test_utils::interface::exchange::preview_swap_exact_input;
 
 mod success {
     use super::*;
-    use crate::utils::minimum_output_given_exact_input;
+    use crate::utils::{minimum_output_given_exact_input, setup_and_construct_with_fee};
     use fuels::prelude::AssetId;
 
     #[tokio::test]
@@ -20,7 +20,7 @@ mod success {
             333,
         );
         let expected_sufficient_reserve =
-            expected_min_output_amount <= liquidity_parameters.amounts.1;
+            expected_min_output_amount < liquidity_parameters.amounts.1;
 
         let preview_swap_info =
             preview_swap_exact_input(&exchange.instance, input_amount, exchange.pair.0, true).await;
@@ -52,7 +52,7 @@ mod success {
             333,
         );
         let expected_sufficient_reserve =
-            expected_min_output_amount <= liquidity_parameters.amounts.0;
+            expected_min_output_amount < liquidity_parameters.amounts.0;
 
         let preview_swap_info =
             preview_swap_exact_input(&exchange.instance, input_amount, exchange.pair.1, true).await;
@@ -70,6 +70,26 @@ mod success {
             expected_sufficient_reserve
         );
     }
+
+    #[tokio::test]
+    async fn previews_swap_in_fee_tier() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct_with_fee(true, true, 100).await;
+        let input_amount = 1_000;
+
+        // one in 100 of the input amount is charged as a fee
+        let input_amount_with_fee = input_amount - input_amount / 100;
+        let expected_min_output_amount = input_amount_with_fee * liquidity_parameters.amounts.1
+            / (liquidity_parameters.amounts.0 + input_amount_with_fee);
+
+        let preview_swap_info =
+            preview_swap_exact_input(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        assert_eq!(
+            preview_swap_info.other_asset.amount,
+            expected_min_output_amount
+        );
+    }
 }
 
 mod revert {
@@ -52,7 +52,7 @@ mod success {
             liquidity_parameters.amounts.0,
             333,
         );
-        let expected_sufficient_reserve = output_amount <= liquidity_parameters.amounts.0;
+        let expected_sufficient_reserve = output_amount < liquidity_parameters.amounts.0;
 
         let preview_swap_info =
             preview_swap_exact_output(&exchange.instance, output_amount, exchange.pair.0, true)
@@ -0,0 +1,88 @@
+use crate::utils::setup_and_construct;
+use test_utils::interface::exchange::preview_zap;
+
+mod success {
+    use super::*;
+    use crate::utils::optimal_swap_amount;
+    use fuels::prelude::ContractId;
+    use test_utils::{data_structures::LIQUIDITY_MINER_FEE, interface::Asset};
+
+    #[tokio::test]
+    async fn previews_zap_of_a() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let input_amount = 1000;
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        assert_eq!(
+            preview.swapped_asset,
+            Asset {
+                id: ContractId::new(*exchange.pair.0),
+                amount: optimal_swap_amount(
+                    input_amount,
+                    liquidity_parameters.amounts.0,
+                    LIQUIDITY_MINER_FEE
+                ),
+            }
+        );
+        assert_eq!(
+            preview.liquidity_asset_to_receive,
+            Asset {
+                id: exchange.id,
+                amount: 973,
+            }
+        );
+        assert_eq!(preview.refund.a.amount, 2);
+        assert_eq!(preview.refund.b.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn previews_zap_of_b() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let input_amount = 5000;
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.1, true).await;
+
+        assert_eq!(
+            preview.swapped_asset,
+            Asset {
+                id: ContractId::new(*exchange.pair.1),
+                amount: optimal_swap_amount(
+                    input_amount,
+                    liquidity_parameters.amounts.1,
+                    LIQUIDITY_MINER_FEE
+                ),
+            }
+        );
+        assert_eq!(preview.liquidity_asset_to_receive.amount, 1211);
+        assert_eq!(preview.refund.a.amount, 0);
+        assert_eq!(preview.refund.b.amount, 0);
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::setup;
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, _wallet, assets, _deadline) = setup().await;
+
+        preview_zap(&exchange_instance, 1000, assets.asset_1, false).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientReserve")]
+    async fn when_pool_has_no_liquidity() {
+        let (exchange, _wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        preview_zap(&exchange.instance, 1000, exchange.pair.0, false).await;
+    }
+}
@@ -0,0 +1,128 @@
+use test_utils::interface::exchange::price_observation;
+
+mod success {
+    use super::*;
+    use crate::utils::setup_and_construct;
+    use std::{thread::sleep, time::Duration};
+    use test_utils::{
+        interface::exchange::{pool_info, swap_exact_input},
+        twap::{time_weighted_average_prices, PRICE_PRECISION},
+    };
+
+    // block timestamps have a resolution of one second
+    const WINDOW: Duration = Duration::from_millis(1_100);
+
+    #[tokio::test]
+    async fn returns_empty_observation_without_liquidity() {
+        let (exchange, _wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        let start = price_observation(&exchange.instance).await;
+        sleep(WINDOW);
+        let end = price_observation(&exchange.instance).await;
+
+        assert!(end.timestamp > start.timestamp);
+        assert_eq!(
+            time_weighted_average_prices(&start, &end).unwrap().price_a,
+            0
+        );
+        assert_eq!(
+            time_weighted_average_prices(&start, &end).unwrap().price_b,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn accumulates_prices_over_time() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let start = price_observation(&exchange.instance).await;
+        sleep(WINDOW);
+        let end = price_observation(&exchange.instance).await;
+
+        let average_prices = time_weighted_average_prices(&start, &end).unwrap();
+
+        assert!(end.timestamp > start.timestamp);
+        assert_eq!(
+            average_prices.price_a,
+            liquidity_parameters.amounts.1 as u128 * PRICE_PRECISION
+                / liquidity_parameters.amounts.0 as u128
+        );
+        assert_eq!(
+            average_prices.price_b,
+            liquidity_parameters.amounts.0 as u128 * PRICE_PRECISION
+                / liquidity_parameters.amounts.1 as u128
+        );
+    }
+
+    #[tokio::test]
+    async fn averages_prices_before_and_after_swap() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+        let start = price_observation(&exchange.instance).await;
+        sleep(WINDOW);
+
+        swap_exact_input(
+            &exchange.instance,
+            exchange.pair.0,
+            1_000,
+            None,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        sleep(WINDOW);
+        let end = price_observation(&exchange.instance).await;
+
+        let price_a = |reserve_a: u64, reserve_b: u64| {
+            reserve_b as u128 * PRICE_PRECISION / reserve_a as u128
+        };
+        let initial_price_a = price_a(
+            initial_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.b.amount,
+        );
+        let final_price_a = price_a(
+            final_pool_info.reserves.a.amount,
+            final_pool_info.reserves.b.amount,
+        );
+
+        let average_prices = time_weighted_average_prices(&start, &end).unwrap();
+
+        // selling asset A lowers its price
+        assert!(final_price_a < initial_price_a);
+        assert!(average_prices.price_a < initial_price_a);
+        assert!(average_prices.price_a > final_price_a);
+    }
+
+    #[tokio::test]
+    async fn cannot_average_without_window() {
+        let (exchange, _wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let observation = price_observation(&exchange.instance).await;
+
+        assert_eq!(
+            time_weighted_average_prices(&observation, &observation),
+            None
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::setup;
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, _wallet, _assets, _deadline) = setup().await;
+
+        price_observation(&exchange_instance).await;
+    }
+}
@@ -0,0 +1,73 @@
+use test_utils::interface::exchange::protocol_fee_info;
+
+mod success {
+    use super::*;
+    use crate::utils::setup_and_construct;
+    use fuels::{tx::Address, types::Identity};
+    use test_utils::interface::{
+        exchange::{set_protocol_fee, swap_exact_output},
+        ProtocolFee,
+    };
+
+    #[tokio::test]
+    async fn returns_owner_without_protocol_fee() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(
+            protocol_fee_info.owner,
+            Identity::Address(Address::from(wallet.address()))
+        );
+        assert_eq!(protocol_fee_info.protocol_fee, None);
+        assert_eq!(protocol_fee_info.accrued.a.amount, 0);
+        assert_eq!(protocol_fee_info.accrued.b.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn returns_protocol_fees_accrued_by_exact_output_swap() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        // the protocol takes a quarter of the liquidity miner fee
+        set_protocol_fee(
+            &exchange.instance,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 2_500,
+            }),
+        )
+        .await;
+
+        let sold = swap_exact_output(
+            &exchange.instance,
+            exchange.pair.1,
+            10_000,
+            1_000,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(protocol_fee_info.accrued.a.amount, 0);
+        assert_eq!(protocol_fee_info.accrued.b.amount, sold / 333 / 4);
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::setup;
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, _wallet, _assets, _deadline) = setup().await;
+
+        protocol_fee_info(&exchange_instance).await;
+    }
+}
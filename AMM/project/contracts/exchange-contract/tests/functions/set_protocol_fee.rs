@@ -0,0 +1,128 @@
+use test_utils::interface::{exchange::set_protocol_fee, ProtocolFee};
+
+mod success {
+    use super::*;
+    use crate::utils::setup_and_construct;
+    use fuels::{tx::Address, types::Identity};
+    use test_utils::interface::{exchange::protocol_fee_info, SetProtocolFeeEvent};
+
+    #[tokio::test]
+    async fn sets_protocol_fee() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+        let protocol_fee = ProtocolFee {
+            recipient: Identity::Address(Address::from(wallet.address())),
+            share: 1_000,
+        };
+
+        let response = set_protocol_fee(&exchange.instance, Some(protocol_fee.clone())).await;
+        let log = response
+            .get_logs_with_type::<SetProtocolFeeEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(
+            *event,
+            SetProtocolFeeEvent {
+                protocol_fee: Some(protocol_fee.clone()),
+            }
+        );
+        assert_eq!(protocol_fee_info.protocol_fee, Some(protocol_fee));
+    }
+
+    #[tokio::test]
+    async fn removes_protocol_fee() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        set_protocol_fee(
+            &exchange.instance,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 1_000,
+            }),
+        )
+        .await;
+        let response = set_protocol_fee(&exchange.instance, None).await;
+        let log = response
+            .get_logs_with_type::<SetProtocolFeeEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(*event, SetProtocolFeeEvent { protocol_fee: None });
+        assert_eq!(protocol_fee_info.protocol_fee, None);
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::{setup, setup_and_construct};
+    use fuels::{
+        prelude::{TxParameters, WalletUnlocked, BASE_ASSET_ID},
+        tx::Address,
+        types::Identity,
+    };
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, wallet, _assets, _deadline) = setup().await;
+
+        set_protocol_fee(
+            &exchange_instance,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 1_000,
+            }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_sender_is_not_owner() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        let other_wallet = WalletUnlocked::new_random(Some(wallet.get_provider().unwrap().clone()));
+        wallet
+            .transfer(
+                other_wallet.address(),
+                1_000_000,
+                BASE_ASSET_ID,
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+
+        set_protocol_fee(
+            &exchange.instance.with_wallet(other_wallet.clone()).unwrap(),
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(other_wallet.address())),
+                share: 1_000,
+            }),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProtocolFeeShareTooHigh")]
+    async fn when_share_is_too_high() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        set_protocol_fee(
+            &exchange.instance,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 3_334,
+            }),
+        )
+        .await;
+    }
+}
@@ -393,6 +393,24 @@ mod revert {
         .await;
     }
 
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientReserve")]
+    async fn when_output_amount_is_entire_reserve() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        swap_exact_output(
+            &exchange.instance,
+            exchange.pair.0,
+            10,
+            // emptying the reserve would leave the pool without a price
+            liquidity_parameters.amounts.1,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+
     #[tokio::test]
     #[should_panic(expected = "DesiredAmountTooHigh")]
     async fn when_forwarding_insufficient_amount_of_a() {
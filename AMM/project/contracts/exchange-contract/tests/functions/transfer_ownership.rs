@@ -0,0 +1,96 @@
+use fuels::{tx::Address, types::Identity};
+use test_utils::interface::exchange::transfer_ownership;
+
+mod success {
+    use super::*;
+    use crate::utils::setup_and_construct;
+    use fuels::prelude::WalletUnlocked;
+    use test_utils::interface::{exchange::protocol_fee_info, TransferOwnershipEvent};
+
+    #[tokio::test]
+    async fn transfers_ownership() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+        let previous_owner = Identity::Address(Address::from(wallet.address()));
+        let new_owner =
+            Identity::Address(Address::from(WalletUnlocked::new_random(None).address()));
+
+        let response = transfer_ownership(&exchange.instance, new_owner.clone()).await;
+        let log = response
+            .get_logs_with_type::<TransferOwnershipEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        let protocol_fee_info = protocol_fee_info(&exchange.instance).await;
+
+        assert_eq!(
+            *event,
+            TransferOwnershipEvent {
+                previous_owner,
+                new_owner: new_owner.clone(),
+            }
+        );
+        assert_eq!(protocol_fee_info.owner, new_owner);
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::{setup, setup_and_construct};
+    use fuels::prelude::{TxParameters, WalletUnlocked, BASE_ASSET_ID};
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, wallet, _assets, _deadline) = setup().await;
+
+        transfer_ownership(
+            &exchange_instance,
+            Identity::Address(Address::from(wallet.address())),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_sender_is_not_owner() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        let other_wallet = WalletUnlocked::new_random(Some(wallet.get_provider().unwrap().clone()));
+        wallet
+            .transfer(
+                other_wallet.address(),
+                1_000_000,
+                BASE_ASSET_ID,
+                TxParameters::default(),
+            )
+            .await
+            .unwrap();
+
+        transfer_ownership(
+            &exchange.instance.with_wallet(other_wallet.clone()).unwrap(),
+            Identity::Address(Address::from(other_wallet.address())),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_ownership_was_transferred() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+        let new_owner =
+            Identity::Address(Address::from(WalletUnlocked::new_random(None).address()));
+
+        transfer_ownership(&exchange.instance, new_owner).await;
+
+        // the previous owner can no longer transfer ownership
+        transfer_ownership(
+            &exchange.instance,
+            Identity::Address(Address::from(wallet.address())),
+        )
+        .await;
+    }
+}
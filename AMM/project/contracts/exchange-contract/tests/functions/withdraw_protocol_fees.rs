@@ -0,0 +1,126 @@
+use test_utils::interface::exchange::withdraw_protocol_fees;
+
+mod success {
+    use super::*;
+    use crate::utils::setup_and_construct;
+    use fuels::{prelude::ContractId, tx::Address, types::Identity};
+    use test_utils::interface::{
+        exchange::{pool_info, protocol_fee_info, set_protocol_fee, swap_exact_input},
+        Asset, AssetPair, ProtocolFee, WithdrawProtocolFeesEvent,
+    };
+
+    #[tokio::test]
+    async fn withdraws_accrued_protocol_fees() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+        let recipient = Identity::Address(Address::from(wallet.address()));
+        let input_amount = 10_000;
+
+        // the protocol takes a quarter of the liquidity miner fee
+        set_protocol_fee(
+            &exchange.instance,
+            Some(ProtocolFee {
+                recipient: recipient.clone(),
+                share: 2_500,
+            }),
+        )
+        .await;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+
+        swap_exact_input(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            None,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await;
+
+        let protocol_fee = input_amount / 333 / 4;
+        let accrued = protocol_fee_info(&exchange.instance).await.accrued;
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let initial_wallet_balance = wallet.get_asset_balance(&exchange.pair.0).await.unwrap();
+
+        let response = withdraw_protocol_fees(&exchange.instance).await;
+        let log = response
+            .get_logs_with_type::<WithdrawProtocolFeesEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        let final_wallet_balance = wallet.get_asset_balance(&exchange.pair.0).await.unwrap();
+        let remaining = protocol_fee_info(&exchange.instance).await.accrued;
+
+        let withdrawn = AssetPair {
+            a: Asset {
+                id: ContractId::new(*exchange.pair.0),
+                amount: protocol_fee,
+            },
+            b: Asset {
+                id: ContractId::new(*exchange.pair.1),
+                amount: 0,
+            },
+        };
+
+        assert_eq!(accrued, withdrawn);
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + input_amount - protocol_fee
+        );
+        assert_eq!(response.value, withdrawn);
+        assert_eq!(
+            *event,
+            WithdrawProtocolFeesEvent {
+                recipient,
+                withdrawn,
+            }
+        );
+        assert_eq!(final_wallet_balance, initial_wallet_balance + protocol_fee);
+        assert_eq!(remaining.a.amount, 0);
+        assert_eq!(remaining.b.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn withdraws_nothing_without_swaps() {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        set_protocol_fee(
+            &exchange.instance,
+            Some(ProtocolFee {
+                recipient: Identity::Address(Address::from(wallet.address())),
+                share: 2_500,
+            }),
+        )
+        .await;
+
+        let withdrawn = withdraw_protocol_fees(&exchange.instance).await.value;
+
+        assert_eq!(withdrawn.a.amount, 0);
+        assert_eq!(withdrawn.b.amount, 0);
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::{setup, setup_and_construct};
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, _wallet, _assets, _deadline) = setup().await;
+
+        withdraw_protocol_fees(&exchange_instance).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProtocolFeeNotSet")]
+    async fn when_protocol_fee_not_set() {
+        let (exchange, _wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        withdraw_protocol_fees(&exchange.instance).await;
+    }
+}
@@ -0,0 +1,316 @@
+use crate::utils::setup_and_construct;
+use test_utils::interface::exchange::{preview_zap, zap};
+
+mod success {
+    use super::*;
+    use crate::utils::{
+        optimal_swap_amount, setup_and_construct_with_large_reserves, wallet_balances,
+    };
+    use fuels::prelude::ContractId;
+    use test_utils::{
+        data_structures::LIQUIDITY_MINER_FEE,
+        interface::{exchange::pool_info, Asset, AssetPair, ZapEvent},
+    };
+
+    #[tokio::test]
+    async fn zaps_a() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let input_amount = 1000;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+        let initial_wallet_balances = wallet_balances(&exchange, &wallet).await;
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        let response = zap(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            preview.liquidity_asset_to_receive.amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await;
+        let log = response.get_logs_with_type::<ZapEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        let added_liquidity = response.value;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let final_wallet_balances = wallet_balances(&exchange, &wallet).await;
+
+        // 488 of asset A buys 1857 of asset B, which is added along with 510 of asset A leaving 2 of asset A as dust
+        let swapped = 488;
+        let refunded = 2;
+
+        assert_eq!(
+            *event,
+            ZapEvent {
+                input: Asset {
+                    id: ContractId::new(*exchange.pair.0),
+                    amount: input_amount,
+                },
+                swapped: Asset {
+                    id: ContractId::new(*exchange.pair.0),
+                    amount: swapped,
+                },
+                added_assets: AssetPair {
+                    a: Asset {
+                        id: ContractId::new(*exchange.pair.0),
+                        amount: input_amount - swapped - refunded,
+                    },
+                    b: Asset {
+                        id: ContractId::new(*exchange.pair.1),
+                        amount: 1857,
+                    },
+                },
+                liquidity: Asset {
+                    id: exchange.id,
+                    amount: added_liquidity,
+                },
+            }
+        );
+        assert_eq!(
+            swapped,
+            optimal_swap_amount(
+                input_amount,
+                liquidity_parameters.amounts.0,
+                LIQUIDITY_MINER_FEE
+            )
+        );
+        assert_eq!(preview.swapped_asset.amount, swapped);
+        assert_eq!(preview.refund.a.amount, refunded);
+        assert_eq!(preview.refund.b.amount, 0);
+        assert_eq!(added_liquidity, preview.liquidity_asset_to_receive.amount);
+        assert_eq!(added_liquidity, 973);
+        assert_eq!(
+            final_wallet_balances.asset_a,
+            initial_wallet_balances.asset_a - input_amount + refunded
+        );
+        assert_eq!(
+            final_wallet_balances.asset_b,
+            initial_wallet_balances.asset_b
+        );
+        assert_eq!(
+            final_wallet_balances.liquidity_pool_asset,
+            initial_wallet_balances.liquidity_pool_asset + added_liquidity
+        );
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + input_amount - refunded
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount
+        );
+        assert_eq!(
+            final_pool_info.liquidity,
+            initial_pool_info.liquidity + added_liquidity
+        );
+    }
+
+    #[tokio::test]
+    async fn zaps_b() {
+        let (exchange, wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let input_amount = 5000;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+        let initial_wallet_balances = wallet_balances(&exchange, &wallet).await;
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.1, true).await;
+
+        let added_liquidity = zap(
+            &exchange.instance,
+            exchange.pair.1,
+            input_amount,
+            preview.liquidity_asset_to_receive.amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let final_wallet_balances = wallet_balances(&exchange, &wallet).await;
+
+        assert_eq!(added_liquidity, preview.liquidity_asset_to_receive.amount);
+        assert_eq!(
+            final_wallet_balances.asset_a,
+            initial_wallet_balances.asset_a + preview.refund.a.amount
+        );
+        assert_eq!(
+            final_wallet_balances.asset_b,
+            initial_wallet_balances.asset_b - input_amount + preview.refund.b.amount
+        );
+        assert_eq!(
+            final_wallet_balances.liquidity_pool_asset,
+            initial_wallet_balances.liquidity_pool_asset + added_liquidity
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount + input_amount - preview.refund.b.amount
+        );
+    }
+
+    #[tokio::test]
+    async fn zaps_with_large_reserves() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct_with_large_reserves().await;
+
+        let input_amount = 1 << 40;
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        let added_liquidity = zap(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            preview.liquidity_asset_to_receive.amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        // the split is solved for at a smaller scale since the unscaled discriminant exceeds 128 bits
+        assert_eq!(
+            preview.swapped_asset.amount,
+            optimal_swap_amount(
+                input_amount,
+                liquidity_parameters.amounts.0,
+                LIQUIDITY_MINER_FEE
+            )
+        );
+        assert_eq!(preview.swapped_asset.amount, 550_582_481_362);
+        assert!(added_liquidity > 0);
+        assert_eq!(added_liquidity, preview.liquidity_asset_to_receive.amount);
+    }
+}
+
+mod revert {
+    use super::*;
+    use crate::utils::setup;
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetPairNotSet")]
+    async fn when_uninitialized() {
+        // call setup instead of setup_and_construct
+        let (exchange_instance, _wallet, assets, deadline) = setup().await;
+
+        zap(&exchange_instance, assets.asset_1, 1, 0, deadline, false).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAsset")]
+    async fn when_msg_asset_id_is_invalid() {
+        let (exchange, _wallet, liquidity_parameters, asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        zap(
+            &exchange.instance,
+            asset_c_id,
+            1000,
+            0,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DeadlinePassed")]
+    async fn when_deadline_has_passed() {
+        let (exchange, _wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        zap(
+            &exchange.instance,
+            exchange.pair.0,
+            1000,
+            0,
+            0, // passing 0 deadline
+            false,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ExpectedNonZeroAmount")]
+    async fn when_msg_amount_is_zero() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        zap(
+            &exchange.instance,
+            exchange.pair.0,
+            0, // forwarding 0 as msg_amount
+            0,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientReserve")]
+    async fn when_pool_has_no_liquidity() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(false, false).await;
+
+        zap(
+            &exchange.instance,
+            exchange.pair.0,
+            1000,
+            0,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DesiredAmountTooLow")]
+    async fn when_input_amount_is_too_small() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        zap(
+            &exchange.instance,
+            exchange.pair.0,
+            1, // too little to swap for any of the other asset
+            0,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DesiredAmountTooHigh")]
+    async fn when_minimum_liquidity_is_too_high() {
+        let (exchange, _wallet, liquidity_parameters, _asset_c_id) =
+            setup_and_construct(true, true).await;
+
+        let input_amount = 1000;
+
+        let preview_liquidity =
+            preview_zap(&exchange.instance, input_amount, exchange.pair.0, true)
+                .await
+                .liquidity_asset_to_receive
+                .amount;
+
+        zap(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            preview_liquidity + 1, // setting min too high
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await;
+    }
+}
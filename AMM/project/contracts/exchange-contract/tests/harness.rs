@@ -1,2 +1,3 @@
 mod functions;
+mod properties;
 mod utils;
@@ -0,0 +1,407 @@
+use crate::utils::setup_and_construct_with_fee;
+use fuels::{
+    prelude::{AssetId, CallParameters},
+    tx::{Address, Receipt},
+    types::{errors::Error, Identity},
+};
+use test_utils::{
+    data_structures::{ExchangeContract, LIQUIDITY_MINER_FEE},
+    interface::{
+        exchange::{deposit, pool_info, protocol_fee_info, set_protocol_fee, withdraw},
+        ProtocolFee,
+    },
+    model::{ExchangeModel, ModelError, Side, MAX_PROTOCOL_FEE_SHARE, MINIMUM_LIQUIDITY},
+    random::Rng,
+};
+
+// Deadlines are not modelled so every operation is sent well before its deadline
+const DEADLINE: u64 = u64::MAX;
+// Largest amount of an asset used by a single operation, which keeps the wallet funded
+const MAXIMUM_AMOUNT: u64 = 1_000_000;
+const OPERATIONS: u64 = 30;
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    AddLiquidity {
+        deposits: (u64, u64),
+        desired_liquidity: u64,
+    },
+    RemoveLiquidity {
+        liquidity: u64,
+        min_amounts: (u64, u64),
+    },
+    SwapExactInput {
+        input: Side,
+        amount: u64,
+        min_output: Option<u64>,
+    },
+    SwapExactOutput {
+        input: Side,
+        output: u64,
+        forwarded: u64,
+    },
+}
+
+// Exchange contract driven alongside the model of its pool
+struct Harness {
+    exchange: ExchangeContract,
+    model: ExchangeModel,
+    rng: Rng,
+    seed: u64,
+}
+
+impl Harness {
+    async fn new(seed: u64, liquidity_miner_fee: u64, protocol_fee_share: Option<u64>) -> Self {
+        let (exchange, wallet, _liquidity_parameters, _asset_c_id) =
+            setup_and_construct_with_fee(false, false, liquidity_miner_fee).await;
+
+        if let Some(share) = protocol_fee_share {
+            set_protocol_fee(
+                &exchange.instance,
+                Some(ProtocolFee {
+                    recipient: Identity::Address(Address::from(wallet.address())),
+                    share,
+                }),
+            )
+            .await;
+        }
+
+        Self {
+            exchange,
+            model: ExchangeModel::new(liquidity_miner_fee, protocol_fee_share),
+            rng: Rng::new(seed),
+            seed,
+        }
+    }
+
+    fn asset(&self, side: Side) -> AssetId {
+        match side {
+            Side::A => self.exchange.pair.0,
+            Side::B => self.exchange.pair.1,
+        }
+    }
+
+    fn side(&mut self) -> Side {
+        match self.rng.between(0, 1) {
+            0 => Side::A,
+            _ => Side::B,
+        }
+    }
+
+    // Picks an operation with amounts around the boundaries where the operation is refused
+    fn next_operation(&mut self) -> Operation {
+        let model = self.model.clone();
+
+        // the wallet holds the whole supply of the liquidity pool asset, which may be empty
+        let operation = match self.rng.between(0, 3) {
+            1 if model.liquidity == 0 => 0,
+            operation => operation,
+        };
+
+        match operation {
+            0 => {
+                let deposits = (
+                    self.rng.amount(model.reserves.0, MAXIMUM_AMOUNT),
+                    self.rng.amount(model.reserves.1, MAXIMUM_AMOUNT),
+                );
+                let expected = model
+                    .clone()
+                    .add_liquidity(deposits, MINIMUM_LIQUIDITY)
+                    .map_or(MINIMUM_LIQUIDITY, |added| added.liquidity);
+                let desired_liquidity = match self.rng.between(0, 3) {
+                    0 => MINIMUM_LIQUIDITY - 1,
+                    1 => MINIMUM_LIQUIDITY,
+                    2 => expected,
+                    _ => expected + 1,
+                };
+
+                Operation::AddLiquidity {
+                    deposits,
+                    desired_liquidity,
+                }
+            }
+            1 => {
+                let liquidity = self.rng.between(1, model.liquidity);
+                let expected = model
+                    .clone()
+                    .remove_liquidity(liquidity, 1, 1)
+                    .unwrap_or((1, 1));
+                let min_amounts = match self.rng.between(0, 2) {
+                    0 => (1, 1),
+                    1 => expected,
+                    _ => (expected.0 + 1, expected.1),
+                };
+
+                Operation::RemoveLiquidity {
+                    liquidity,
+                    min_amounts,
+                }
+            }
+            2 => {
+                let input = self.side();
+                let amount = self.rng.amount(model.reserve(input), MAXIMUM_AMOUNT);
+                let expected = model.clone().swap_exact_input(input, amount, None);
+                let min_output = match (self.rng.between(0, 2), expected) {
+                    (0, _) | (_, Err(_)) => None,
+                    (1, Ok(bought)) => Some(bought),
+                    (_, Ok(bought)) => Some(bought + 1),
+                };
+
+                Operation::SwapExactInput {
+                    input,
+                    amount,
+                    min_output,
+                }
+            }
+            _ => {
+                let input = self.side();
+                let output_reserve = model.reserve(input.other());
+                let output = match self.rng.between(0, 4) {
+                    0 => output_reserve,
+                    _ => self.rng.amount(output_reserve, MAXIMUM_AMOUNT),
+                };
+                let expected = model.clone().swap_exact_output(input, output, u64::MAX);
+                let forwarded = match (self.rng.between(0, 2), expected) {
+                    (0, Ok(sold)) if sold <= MAXIMUM_AMOUNT => sold,
+                    (1, Ok(sold)) if sold > 1 && sold <= MAXIMUM_AMOUNT => sold - 1,
+                    _ => MAXIMUM_AMOUNT,
+                };
+
+                Operation::SwapExactOutput {
+                    input,
+                    output,
+                    forwarded,
+                }
+            }
+        }
+    }
+
+    // Runs the operation on the contract and in the model and checks that both agree
+    async fn run(&mut self, operation: Operation) {
+        let context = format!("seed {}, {operation:?}", self.seed);
+        let before = self.model.clone();
+
+        match operation {
+            Operation::AddLiquidity {
+                deposits,
+                desired_liquidity,
+            } => {
+                let expected = self.model.add_liquidity(deposits, desired_liquidity);
+                let actual = self.add_liquidity(deposits, desired_liquidity).await;
+                compare(actual, expected.map(|added| added.liquidity), &context);
+            }
+            Operation::RemoveLiquidity {
+                liquidity,
+                min_amounts,
+            } => {
+                let expected = self
+                    .model
+                    .remove_liquidity(liquidity, min_amounts.0, min_amounts.1);
+                let actual = self.remove_liquidity(liquidity, min_amounts).await;
+                compare(actual, expected, &context);
+            }
+            Operation::SwapExactInput {
+                input,
+                amount,
+                min_output,
+            } => {
+                let expected = self.model.swap_exact_input(input, amount, min_output);
+                let actual = self.swap_exact_input(input, amount, min_output).await;
+                compare(actual, expected, &context);
+
+                assert!(self.model.product() >= before.product(), "{context}");
+            }
+            Operation::SwapExactOutput {
+                input,
+                output,
+                forwarded,
+            } => {
+                let expected = self.model.swap_exact_output(input, output, forwarded);
+                let actual = self.swap_exact_output(input, output, forwarded).await;
+                compare(actual, expected, &context);
+
+                assert!(self.model.product() >= before.product(), "{context}");
+            }
+        }
+
+        let pool_info = pool_info(&self.exchange.instance).await;
+        let accrued = protocol_fee_info(&self.exchange.instance).await.accrued;
+
+        assert_eq!(
+            (pool_info.reserves.a.amount, pool_info.reserves.b.amount),
+            self.model.reserves,
+            "{context}"
+        );
+        assert_eq!(pool_info.liquidity, self.model.liquidity, "{context}");
+        assert_eq!(
+            (accrued.a.amount, accrued.b.amount),
+            self.model.protocol_fees,
+            "{context}"
+        );
+    }
+
+    async fn add_liquidity(
+        &self,
+        deposits: (u64, u64),
+        desired_liquidity: u64,
+    ) -> Result<u64, Error> {
+        let instance = &self.exchange.instance;
+        deposit(instance, deposits.0, self.exchange.pair.0).await;
+        deposit(instance, deposits.1, self.exchange.pair.1).await;
+
+        let response = instance
+            .methods()
+            .add_liquidity(desired_liquidity, DEADLINE)
+            .append_variable_outputs(2)
+            .call()
+            .await;
+
+        // deposits are kept by the contract when adding liquidity fails
+        if response.is_err() {
+            withdraw(instance, deposits.0, self.exchange.pair.0).await;
+            withdraw(instance, deposits.1, self.exchange.pair.1).await;
+        }
+
+        response.map(|response| response.value)
+    }
+
+    async fn remove_liquidity(
+        &self,
+        liquidity: u64,
+        min_amounts: (u64, u64),
+    ) -> Result<(u64, u64), Error> {
+        let liquidity_pool_asset = AssetId::new(*self.exchange.id);
+
+        let response = self
+            .exchange
+            .instance
+            .methods()
+            .remove_liquidity(min_amounts.0, min_amounts.1, DEADLINE)
+            .call_params(CallParameters::new(
+                Some(liquidity),
+                Some(liquidity_pool_asset),
+                None,
+            ))
+            .unwrap()
+            .append_variable_outputs(2)
+            .call()
+            .await;
+
+        response.map(|response| {
+            let removed = response.value.removed_amounts;
+            (removed.a.amount, removed.b.amount)
+        })
+    }
+
+    async fn swap_exact_input(
+        &self,
+        input: Side,
+        amount: u64,
+        min_output: Option<u64>,
+    ) -> Result<u64, Error> {
+        let response = self
+            .exchange
+            .instance
+            .methods()
+            .swap_exact_input(min_output, DEADLINE)
+            .call_params(CallParameters::new(
+                Some(amount),
+                Some(self.asset(input)),
+                None,
+            ))
+            .unwrap()
+            .append_variable_outputs(1)
+            .call()
+            .await;
+
+        response.map(|response| response.value)
+    }
+
+    async fn swap_exact_output(
+        &self,
+        input: Side,
+        output: u64,
+        forwarded: u64,
+    ) -> Result<u64, Error> {
+        let response = self
+            .exchange
+            .instance
+            .methods()
+            .swap_exact_output(output, DEADLINE)
+            .call_params(CallParameters::new(
+                Some(forwarded),
+                Some(self.asset(input)),
+                None,
+            ))
+            .unwrap()
+            .append_variable_outputs(2)
+            .call()
+            .await;
+
+        response.map(|response| response.value)
+    }
+}
+
+// Asserts that the contract returned what the model expected or reverted for the same reason
+fn compare<T>(actual: Result<T, Error>, expected: Result<T, ModelError>, context: &str)
+where
+    T: std::fmt::Debug + PartialEq,
+{
+    match (actual, expected) {
+        (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "{context}"),
+        (
+            Err(Error::RevertTransactionError {
+                reason, receipts, ..
+            }),
+            Err(ModelError::Panic),
+        ) => {
+            // a panic reverts without logging the reason that `require` logs
+            let logged = receipts
+                .iter()
+                .any(|receipt| matches!(receipt, Receipt::Log { .. } | Receipt::LogData { .. }));
+            assert!(
+                !logged,
+                "{context}: reverted with {reason} instead of panicking"
+            );
+        }
+        (Err(Error::RevertTransactionError { reason, .. }), Err(expected)) => {
+            let expected = format!("{expected:?}");
+            assert!(
+                reason.contains(&expected),
+                "{context}: reverted with {reason} instead of {expected}"
+            );
+        }
+        (actual, expected) => {
+            panic!("{context}: the contract returned {actual:?} but the model {expected:?}")
+        }
+    }
+}
+
+async fn run_sequence(seed: u64, liquidity_miner_fee: u64, protocol_fee_share: Option<u64>) {
+    let mut harness = Harness::new(seed, liquidity_miner_fee, protocol_fee_share).await;
+
+    for _ in 0..OPERATIONS {
+        let operation = harness.next_operation();
+        harness.run(operation).await;
+    }
+}
+
+#[tokio::test]
+async fn agrees_with_model() {
+    run_sequence(1, LIQUIDITY_MINER_FEE, None).await;
+}
+
+#[tokio::test]
+async fn agrees_with_model_with_protocol_fee() {
+    run_sequence(2, LIQUIDITY_MINER_FEE, Some(2_500)).await;
+}
+
+#[tokio::test]
+async fn agrees_with_model_in_high_fee_tier() {
+    run_sequence(3, 2, Some(MAX_PROTOCOL_FEE_SHARE)).await;
+}
+
+#[tokio::test]
+async fn agrees_with_model_in_low_fee_tier() {
+    run_sequence(4, 10_000, None).await;
+}
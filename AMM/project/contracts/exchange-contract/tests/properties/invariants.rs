@@ -0,0 +1,256 @@
+use test_utils::{
+    data_structures::LIQUIDITY_MINER_FEE,
+    model::{
+        amount_with_fee, maximum_input_for_exact_output, minimum_output_given_exact_input,
+        proportional_value, ExchangeModel, Side, MAX_PROTOCOL_FEE_SHARE, MINIMUM_LIQUIDITY,
+    },
+    random::Rng,
+};
+
+const FEE_TIERS: [u64; 4] = [2, 100, LIQUIDITY_MINER_FEE, 10_000];
+const ITERATIONS: u64 = 10_000;
+const OPERATIONS: u64 = 50;
+
+// Pool with random reserves, fee tier and protocol fee
+fn random_pool(rng: &mut Rng) -> ExchangeModel {
+    let liquidity_miner_fee = FEE_TIERS[rng.between(0, FEE_TIERS.len() as u64 - 1) as usize];
+    let protocol_fee_share = match rng.between(0, 1) {
+        0 => None,
+        _ => Some(rng.between(0, MAX_PROTOCOL_FEE_SHARE)),
+    };
+
+    let mut model = ExchangeModel::new(liquidity_miner_fee, protocol_fee_share);
+    // the product of the first deposits must fit into a u64
+    let deposits = (
+        rng.between(MINIMUM_LIQUIDITY, u32::MAX as u64),
+        rng.between(MINIMUM_LIQUIDITY, u32::MAX as u64),
+    );
+    model.add_liquidity(deposits, MINIMUM_LIQUIDITY).unwrap();
+    model
+}
+
+fn random_side(rng: &mut Rng) -> Side {
+    match rng.between(0, 1) {
+        0 => Side::A,
+        _ => Side::B,
+    }
+}
+
+#[test]
+fn proportional_value_rounds_down() {
+    let mut rng = Rng::new(1);
+
+    for _ in 0..ITERATIONS {
+        let (b, c) = (rng.next_u64() >> 32, rng.next_u64() >> 32);
+        let a = rng.between(1, u32::MAX as u64);
+
+        let d = match proportional_value(b, c, a) {
+            Ok(d) => d as u128,
+            Err(_) => continue,
+        };
+        let (a, product) = (a as u128, b as u128 * c as u128);
+
+        assert!(d * a <= product, "b: {b}, c: {c}, a: {a}");
+        assert!(product < (d + 1) * a, "b: {b}, c: {c}, a: {a}");
+    }
+}
+
+#[test]
+fn exact_input_never_overpays() {
+    let mut rng = Rng::new(2);
+
+    for _ in 0..ITERATIONS {
+        let model = random_pool(&mut rng);
+        let input = random_side(&mut rng);
+        let (input_reserve, output_reserve) = (model.reserve(input), model.reserve(input.other()));
+        let input_amount = rng.amount(input_reserve, u32::MAX as u64);
+
+        let bought = minimum_output_given_exact_input(
+            input_amount,
+            input_reserve,
+            output_reserve,
+            model.liquidity_miner_fee,
+        )
+        .unwrap() as u128;
+
+        // the output is the exact quotient rounded down
+        let with_fee = amount_with_fee(input_amount, model.liquidity_miner_fee) as u128;
+        let (numerator, denominator) = (
+            with_fee * output_reserve as u128,
+            input_reserve as u128 + with_fee,
+        );
+        assert!(
+            bought * denominator <= numerator,
+            "{model:?}, input: {input_amount}"
+        );
+        assert!(
+            numerator < (bought + 1) * denominator,
+            "{model:?}, input: {input_amount}"
+        );
+        assert!(
+            bought < output_reserve as u128,
+            "{model:?}, input: {input_amount}"
+        );
+    }
+}
+
+#[test]
+fn exact_output_never_undercharges() {
+    let mut rng = Rng::new(3);
+
+    for _ in 0..ITERATIONS {
+        let model = random_pool(&mut rng);
+        let input = random_side(&mut rng);
+        let (input_reserve, output_reserve) = (model.reserve(input), model.reserve(input.other()));
+        let output_amount = rng.between(1, output_reserve - 1);
+
+        let sold = maximum_input_for_exact_output(
+            output_amount,
+            input_reserve,
+            output_reserve,
+            model.liquidity_miner_fee,
+        )
+        .unwrap() as u128;
+
+        let numerator = input_reserve as u128 * output_amount as u128;
+        let denominator =
+            amount_with_fee(output_reserve - output_amount, model.liquidity_miner_fee) as u128;
+
+        if sold == 0 {
+            // a quotient below one rounds down and the swap is refused
+            assert!(
+                denominator > numerator,
+                "{model:?}, output: {output_amount}"
+            );
+        } else {
+            // one is added to the quotient rounded down, even when it is exact
+            assert!(
+                sold * denominator > numerator,
+                "{model:?}, output: {output_amount}"
+            );
+            assert!(
+                (sold - 1) * denominator <= numerator,
+                "{model:?}, output: {output_amount}"
+            );
+        }
+    }
+}
+
+#[test]
+fn swaps_never_decrease_product_of_reserves() {
+    let mut rng = Rng::new(4);
+
+    for _ in 0..ITERATIONS / OPERATIONS {
+        let mut model = random_pool(&mut rng);
+
+        for _ in 0..OPERATIONS {
+            let before = model.clone();
+            let input = random_side(&mut rng);
+
+            let swapped = match rng.between(0, 1) {
+                0 => {
+                    let amount = rng.amount(model.reserve(input), u32::MAX as u64);
+                    model.swap_exact_input(input, amount, None).is_ok()
+                }
+                _ => {
+                    let amount = rng.amount(model.reserve(input.other()), u32::MAX as u64);
+                    model.swap_exact_output(input, amount, u64::MAX).is_ok()
+                }
+            };
+
+            if swapped {
+                assert!(
+                    model.product() >= before.product(),
+                    "{before:?} -> {model:?}"
+                );
+            } else {
+                assert_eq!(model, before);
+            }
+        }
+    }
+}
+
+#[test]
+fn liquidity_changes_never_dilute_shares() {
+    let mut rng = Rng::new(5);
+
+    for _ in 0..ITERATIONS / OPERATIONS {
+        let mut model = random_pool(&mut rng);
+
+        for _ in 0..OPERATIONS {
+            let before = model.clone();
+
+            let changed = match rng.between(0, 1) {
+                0 => {
+                    let deposits = (
+                        rng.amount(model.reserves.0, u32::MAX as u64),
+                        rng.amount(model.reserves.1, u32::MAX as u64),
+                    );
+                    model.add_liquidity(deposits, MINIMUM_LIQUIDITY).is_ok()
+                }
+                _ => {
+                    let burned = rng.between(1, model.liquidity);
+                    model.remove_liquidity(burned, 1, 1).is_ok()
+                }
+            };
+
+            if !changed {
+                assert_eq!(model, before);
+                continue;
+            }
+            if model.liquidity == 0 {
+                break;
+            }
+
+            // the reserves backing each unit of liquidity never decrease
+            let (liquidity, before_liquidity) = (model.liquidity as u128, before.liquidity as u128);
+            assert!(
+                model.reserves.0 as u128 * before_liquidity
+                    >= before.reserves.0 as u128 * liquidity,
+                "{before:?} -> {model:?}"
+            );
+            assert!(
+                model.reserves.1 as u128 * before_liquidity
+                    >= before.reserves.1 as u128 * liquidity,
+                "{before:?} -> {model:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn round_trips_extract_no_value() {
+    let mut rng = Rng::new(6);
+
+    for _ in 0..ITERATIONS {
+        let mut model = random_pool(&mut rng);
+        let before = model.clone();
+
+        // adding and immediately removing liquidity returns at most what was added
+        let deposits = (
+            rng.amount(model.reserves.0, u32::MAX as u64),
+            rng.amount(model.reserves.1, u32::MAX as u64),
+        );
+        if let Ok(added) = model.add_liquidity(deposits, MINIMUM_LIQUIDITY) {
+            if let Ok(removed) = model.remove_liquidity(added.liquidity, 1, 1) {
+                assert!(
+                    removed.0 <= added.added.0,
+                    "{before:?}, deposits: {deposits:?}"
+                );
+                assert!(
+                    removed.1 <= added.added.1,
+                    "{before:?}, deposits: {deposits:?}"
+                );
+            }
+        }
+
+        // swapping there and back returns at most what was sold
+        let input = random_side(&mut rng);
+        let amount = rng.amount(model.reserve(input), u32::MAX as u64);
+        if let Ok(bought) = model.swap_exact_input(input, amount, None) {
+            if let Ok(returned) = model.swap_exact_input(input.other(), bought, None) {
+                assert!(returned <= amount, "{before:?}, input: {amount}");
+            }
+        }
+    }
+}
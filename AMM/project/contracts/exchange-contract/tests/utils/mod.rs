@@ -1,13 +1,14 @@
-use fuels::prelude::{AssetId, WalletUnlocked};
+use fuels::prelude::{AssetId, Bech32ContractId, ContractId, TxParameters, WalletUnlocked};
 use test_utils::{
     data_structures::{
         ExchangeContract, ExchangeContractConfiguration, LiquidityParameters,
-        WalletAssetConfiguration,
+        WalletAssetConfiguration, LIQUIDITY_MINER_FEE,
     },
     interface::{
         exchange::{balance, deposit},
         Exchange,
     },
+    model::integer_sqrt,
     setup::common::{
         deploy_and_construct_exchange, deploy_exchange, deposit_and_add_liquidity,
         setup_wallet_and_provider,
@@ -55,6 +56,46 @@ pub fn minimum_output_given_exact_input(
     numerator / denominator
 }
 
+pub fn optimal_swap_amount(input_amount: u64, input_reserve: u64, liquidity_miner_fee: u64) -> u64 {
+    // mirrors the scaling of `optimal_swap_amount` in `exchange_utils.sw`
+    let scale = input_amount.max(input_reserve) / ((1 << 62) / liquidity_miner_fee) + 1;
+    let (reserve, fee) = ((input_reserve / scale) as u128, liquidity_miner_fee as u128);
+    let b = reserve * (2 * fee - 1);
+    let discriminant = b * b + 4 * (fee - 1) * fee * reserve * (input_amount / scale) as u128;
+    ((integer_sqrt(discriminant) - b) / (2 * (fee - 1))) as u64 * scale
+}
+
+pub fn minimum_flash_swap_repayment(
+    output_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> u64 {
+    let numerator = input_reserve as u128 * output_amount as u128;
+    let denominator = (output_reserve - output_amount) as u128;
+    let required = ((numerator + denominator - 1) / denominator) as u64;
+    (required..)
+        .find(|amount| amount - amount / liquidity_miner_fee >= required)
+        .unwrap()
+}
+
+pub async fn fund_contract(
+    wallet: &WalletUnlocked,
+    contract: ContractId,
+    amount: u64,
+    asset: AssetId,
+) {
+    wallet
+        .force_transfer_to_contract(
+            &Bech32ContractId::from(contract),
+            amount,
+            asset,
+            TxParameters::default(),
+        )
+        .await
+        .unwrap();
+}
+
 pub async fn contract_balances(exchange: &ExchangeContract) -> ContractBalances {
     let asset_a = balance(&exchange.instance, exchange.pair.0).await;
     let asset_b = balance(&exchange.instance, exchange.pair.1).await;
@@ -109,17 +150,80 @@ pub async fn setup_and_construct(
     LiquidityParameters,
     AssetId,
 ) {
-    let (wallet, asset_ids, provider) =
-        setup_wallet_and_provider(&WalletAssetConfiguration::default()).await;
+    setup_and_construct_with_fee(deposit_both, add_liquidity, LIQUIDITY_MINER_FEE).await
+}
+
+pub async fn setup_and_construct_with_fee(
+    deposit_both: bool,
+    add_liquidity: bool,
+    liquidity_miner_fee: u64,
+) -> (
+    ExchangeContract,
+    WalletUnlocked,
+    LiquidityParameters,
+    AssetId,
+) {
+    setup_and_construct_with_reserves(
+        deposit_both,
+        add_liquidity,
+        liquidity_miner_fee,
+        &WalletAssetConfiguration::default(),
+        (10000, 40000),
+    )
+    .await
+}
+
+pub async fn setup_and_construct_with_large_reserves() -> (
+    ExchangeContract,
+    WalletUnlocked,
+    LiquidityParameters,
+    AssetId,
+) {
+    // reserves large enough that products of the reserves and the liquidity miner fee exceed 128 bits
+    setup_and_construct_with_reserves(
+        true,
+        true,
+        LIQUIDITY_MINER_FEE,
+        &WalletAssetConfiguration {
+            number_of_assets: 3,
+            coins_per_asset: 2,
+            amount_per_coin: 1 << 62,
+        },
+        (1 << 62, 1 << 62),
+    )
+    .await
+}
+
+async fn setup_and_construct_with_reserves(
+    deposit_both: bool,
+    add_liquidity: bool,
+    liquidity_miner_fee: u64,
+    wallet_assets: &WalletAssetConfiguration,
+    reserves: (u64, u64),
+) -> (
+    ExchangeContract,
+    WalletUnlocked,
+    LiquidityParameters,
+    AssetId,
+) {
+    let (wallet, asset_ids, provider) = setup_wallet_and_provider(wallet_assets).await;
 
     let exchange = deploy_and_construct_exchange(
         &wallet,
-        &ExchangeContractConfiguration::new(Some((asset_ids[0], asset_ids[1])), None, None, None),
+        &ExchangeContractConfiguration {
+            liquidity_miner_fee,
+            ..ExchangeContractConfiguration::new(
+                Some((asset_ids[0], asset_ids[1])),
+                None,
+                None,
+                None,
+            )
+        },
     )
     .await;
 
     let liquidity_parameters = LiquidityParameters::new(
-        Some((10000, 40000)),
+        Some(reserves),
         Some(provider.latest_block_height().await.unwrap() + 20),
         Some(20000),
     );
@@ -0,0 +1,315 @@
+contract;
+
+dep stable_swap;
+
+use stable_swap::StableSwap;
+use libraries::{
+    data_structures::{
+        Asset,
+        AssetPair,
+        PoolInfo,
+        PoolState,
+        PreviewAddLiquidityInfo,
+        PreviewSwapInfo,
+        PreviewZapInfo,
+        PriceObservation,
+        ProtocolFee,
+        ProtocolFeeInfo,
+        RemoveLiquidityInfo,
+    },
+    Exchange,
+    exchange_errors::{
+        InitError,
+        InputError,
+    },
+    exchange_functions::{
+        add_liquidity,
+        define_asset_pair,
+        deposit,
+        flash_borrow,
+        flash_repay,
+        pool_info,
+        price_observation,
+        protocol_fee_info,
+        remove_liquidity,
+        set_protocol_fee,
+        swap_exact_input,
+        swap_exact_output,
+        transfer_ownership,
+        withdraw,
+        withdraw_protocol_fees,
+        zap,
+    },
+    exchange_utils::{
+        preview_add_liquidity,
+        preview_swap_exact_input,
+        preview_swap_exact_output,
+        preview_zap,
+    },
+    FlashSwapCallee,
+    StableExchange,
+};
+use std::{
+    auth::msg_sender,
+    call_frames::msg_asset_id,
+    u128::U128,
+};
+
+storage {
+    /// Deposit amounts per (depositer, asset) that can be used to add liquidity or be withdrawn.
+    deposits: StorageMap<(Identity, ContractId), u64> = StorageMap {},
+    /// Total amount of the liquidity pool asset that has a unique identifier different from the identifiers of assets on either side of the pool.
+    liquidity_pool_supply: u64 = 0,
+    /// Fee tier of the pool that can be set only once using the `constructor`.
+    liquidity_miner_fee: u64 = 0,
+    /// The identity that controls the protocol fee, i.e., the identity that constructed the contract until it transfers ownership.
+    owner: Option<Identity> = Option::None,
+    /// The unique identifiers that make up the pool that can be set only once using the `constructor`.
+    pair: Option<AssetPair> = Option::None,
+    /// Share of the liquidity miner fee taken by the protocol and its recipient.
+    protocol_fee: Option<ProtocolFee> = Option::None,
+    /// Protocol fees per asset that have accrued since they were last withdrawn, which are not part of the reserves.
+    protocol_fees: StorageMap<ContractId, u64> = StorageMap {},
+    /// Cumulative prices of the pool assets as of the last time the reserves changed.
+    price_observation: PriceObservation = PriceObservation {
+        price_a_cumulative: U128 {
+            upper: 0,
+            lower: 0,
+        },
+        price_b_cumulative: U128 {
+            upper: 0,
+            lower: 0,
+        },
+        timestamp: 0,
+    },
+}
+
+impl Exchange for Contract {
+    #[storage(read, write)]
+    fn add_liquidity(desired_liquidity: u64, deadline: u64) -> u64 {
+        let pool = pool_state();
+        let sender = msg_sender().unwrap();
+
+        let (pool, added_liquidity) = add_liquidity(curve(), pool, deposits(sender, pool.reserves), desired_liquidity, MINIMUM_LIQUIDITY, deadline);
+
+        store_pool_state(pool);
+        store_deposits(sender, AssetPair::new(Asset::new(pool.reserves.a.id, 0), Asset::new(pool.reserves.b.id, 0)));
+
+        added_liquidity
+    }
+
+    #[storage(read, write)]
+    fn constructor(asset_a: ContractId, asset_b: ContractId, liquidity_miner_fee: u64) {
+        storage.pair = Option::Some(define_asset_pair(storage.pair, asset_a, asset_b, liquidity_miner_fee));
+        storage.liquidity_miner_fee = liquidity_miner_fee;
+        storage.owner = Option::Some(msg_sender().unwrap());
+    }
+
+    #[payable, storage(read, write)]
+    fn deposit() {
+        let reserves = reserves();
+        let sender = msg_sender().unwrap();
+        let deposit_asset = msg_asset_id();
+
+        let new_balance = deposit(reserves, storage.deposits.get((sender, deposit_asset)).unwrap_or(0));
+
+        storage.deposits.insert((sender, deposit_asset), new_balance);
+    }
+
+    #[storage(read, write)]
+    fn flash_swap(output: Asset, borrower: ContractId, deadline: u64) -> AssetPair {
+        let (borrowed, balances) = flash_borrow(pool_state(), output, borrower, deadline);
+
+        let callee = abi(FlashSwapCallee, borrower.into());
+        callee.on_flash_swap(msg_sender().unwrap(), output);
+
+        let (pool, repaid) = flash_repay(curve(), pool_state(), output, borrower, borrowed, balances);
+
+        store_pool_state(pool);
+
+        repaid
+    }
+
+    #[payable, storage(read, write)]
+    fn remove_liquidity(min_asset_a: u64, min_asset_b: u64, deadline: u64) -> RemoveLiquidityInfo {
+        let (pool, removed) = remove_liquidity(pool_state(), min_asset_a, min_asset_b, deadline);
+
+        store_pool_state(pool);
+
+        removed
+    }
+
+    #[payable, storage(read, write)]
+    fn swap_exact_input(min_output: Option<u64>, deadline: u64) -> u64 {
+        let (pool, bought) = swap_exact_input(curve(), pool_state(), min_output, deadline);
+
+        store_pool_state(pool);
+
+        bought
+    }
+
+    #[payable, storage(read, write)]
+    fn swap_exact_output(output: u64, deadline: u64) -> u64 {
+        let (pool, sold) = swap_exact_output(curve(), pool_state(), output, deadline);
+
+        store_pool_state(pool);
+
+        sold
+    }
+
+    #[storage(read, write)]
+    fn set_protocol_fee(protocol_fee: Option<ProtocolFee>) {
+        require(storage.pair.is_some(), InitError::AssetPairNotSet);
+
+        set_protocol_fee(storage.owner.unwrap(), protocol_fee);
+
+        storage.protocol_fee = protocol_fee;
+    }
+
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        require(storage.pair.is_some(), InitError::AssetPairNotSet);
+
+        transfer_ownership(storage.owner.unwrap(), new_owner);
+
+        storage.owner = Option::Some(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn withdraw_protocol_fees() -> AssetPair {
+        let (pool, withdrawn) = withdraw_protocol_fees(pool_state());
+
+        store_pool_state(pool);
+
+        withdrawn
+    }
+
+    #[storage(read, write)]
+    fn withdraw(asset: Asset) {
+        let reserves = reserves();
+        let sender = msg_sender().unwrap();
+
+        let remaining = withdraw(reserves, asset, storage.deposits.get((sender, asset.id)).unwrap_or(0));
+
+        storage.deposits.insert((sender, asset.id), remaining);
+    }
+
+    #[payable, storage(read, write)]
+    fn zap(min_liquidity: u64, deadline: u64) -> u64 {
+        let (pool, liquidity) = zap(curve(), pool_state(), min_liquidity, deadline);
+
+        store_pool_state(pool);
+
+        liquidity
+    }
+
+    #[storage(read)]
+    fn balance(asset_id: ContractId) -> u64 {
+        let reserves = reserves();
+        require(asset_id == reserves.a.id || asset_id == reserves.b.id, InputError::InvalidAsset);
+
+        storage.deposits.get((msg_sender().unwrap(), asset_id)).unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn pool_info() -> PoolInfo {
+        pool_info(pool_state())
+    }
+
+    #[storage(read)]
+    fn protocol_fee_info() -> ProtocolFeeInfo {
+        protocol_fee_info(pool_state(), storage.owner.unwrap())
+    }
+
+    #[storage(read)]
+    fn price_observation() -> PriceObservation {
+        price_observation(pool_state())
+    }
+
+    #[storage(read)]
+    fn preview_add_liquidity(asset: Asset) -> PreviewAddLiquidityInfo {
+        let pool = pool_state();
+
+        preview_add_liquidity(curve(), asset, deposits(msg_sender().unwrap(), pool.reserves), pool.reserves, pool.liquidity)
+    }
+
+    #[storage(read)]
+    fn preview_swap_exact_input(exact_input_asset: Asset) -> PreviewSwapInfo {
+        preview_swap_exact_input(curve(), exact_input_asset, storage.pair, storage.liquidity_miner_fee)
+    }
+
+    #[storage(read)]
+    fn preview_swap_exact_output(exact_output_asset: Asset) -> PreviewSwapInfo {
+        preview_swap_exact_output(curve(), exact_output_asset, storage.pair, storage.liquidity_miner_fee)
+    }
+
+    #[storage(read)]
+    fn preview_zap(asset: Asset) -> PreviewZapInfo {
+        preview_zap(curve(), asset, storage.pair, storage.liquidity_pool_supply, storage.liquidity_miner_fee, storage.protocol_fee)
+    }
+}
+
+impl StableExchange for Contract {
+    fn amplification_coefficient() -> u64 {
+        AMPLIFICATION_COEFFICIENT
+    }
+}
+
+/// Returns the curve along which the reserves of the pool trade
+fn curve() -> StableSwap {
+    StableSwap {
+        amplification: AMPLIFICATION_COEFFICIENT,
+    }
+}
+
+/// Returns the amounts of the pool assets deposited by `depositer`
+#[storage(read)]
+fn deposits(depositer: Identity, reserves: AssetPair) -> AssetPair {
+    AssetPair::new(
+        Asset::new(reserves.a.id, storage.deposits.get((depositer, reserves.a.id)).unwrap_or(0)),
+        Asset::new(reserves.b.id, storage.deposits.get((depositer, reserves.b.id)).unwrap_or(0)),
+    )
+}
+
+/// Returns the values of the pool held in storage
+#[storage(read)]
+fn pool_state() -> PoolState {
+    let reserves = reserves();
+
+    PoolState {
+        reserves,
+        liquidity: storage.liquidity_pool_supply,
+        liquidity_miner_fee: storage.liquidity_miner_fee,
+        protocol_fee: storage.protocol_fee,
+        accrued_protocol_fees: AssetPair::new(
+            Asset::new(reserves.a.id, storage.protocol_fees.get(reserves.a.id).unwrap_or(0)),
+            Asset::new(reserves.b.id, storage.protocol_fees.get(reserves.b.id).unwrap_or(0)),
+        ),
+        price_observation: storage.price_observation,
+    }
+}
+
+/// Returns the reserves of the pool once the asset pair is set
+#[storage(read)]
+fn reserves() -> AssetPair {
+    require(storage.pair.is_some(), InitError::AssetPairNotSet);
+
+    storage.pair.unwrap()
+}
+
+/// Sets the amounts of the pool assets deposited by `depositer` to the `deposits`
+#[storage(write)]
+fn store_deposits(depositer: Identity, deposits: AssetPair) {
+    storage.deposits.insert((depositer, deposits.a.id), deposits.a.amount);
+    storage.deposits.insert((depositer, deposits.b.id), deposits.b.amount);
+}
+
+/// Writes the values of the `pool` that the exchange functions update back to storage
+#[storage(write)]
+fn store_pool_state(pool: PoolState) {
+    storage.pair = Option::Some(pool.reserves);
+    storage.liquidity_pool_supply = pool.liquidity;
+    storage.protocol_fees.insert(pool.reserves.a.id, pool.accrued_protocol_fees.a.amount);
+    storage.protocol_fees.insert(pool.reserves.b.id, pool.accrued_protocol_fees.b.amount);
+    storage.price_observation = pool.price_observation;
+}
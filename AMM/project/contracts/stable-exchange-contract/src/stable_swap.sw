@@ -0,0 +1,238 @@
+library stable_swap;
+
+use core::primitives::*;
+use libraries::{
+    curve::Curve,
+    data_structures::AssetPair,
+    exchange_utils::{
+        calculate_amount_with_fee,
+        proportional_value,
+    },
+};
+use std::u128::U128;
+
+/// Upper bound on the iterations of the approximations of the invariant and of a reserve
+const MAX_ITERATIONS: u64 = 255;
+
+/// Largest reserve the invariant is approximated on, larger reserves are scaled down so that the approximations do not overflow
+const MAX_SCALED_RESERVE: u64 = 281_474_976_710_656;
+
+/// The StableSwap invariant `4A(x + y) + D = 4AD + D^3 / 4xy` for the `amplification` coefficient A
+pub struct StableSwap {
+    amplification: u64,
+}
+
+impl Curve for StableSwap {
+    fn initial_liquidity(self, amount_a: u64, amount_b: u64) -> u64 {
+        let scale = scale(larger(amount_a, amount_b));
+
+        invariant(amount_a / scale, amount_b / scale, self.amplification).as_u64().unwrap() * scale
+    }
+
+    fn invariant_holds(
+        self,
+        reserves: AssetPair,
+        new_reserves: AssetPair,
+        repaid: AssetPair,
+        liquidity_miner_fee: u64,
+    ) -> bool {
+        let adjusted_a = new_reserves.a.amount - repaid.a.amount / liquidity_miner_fee;
+        let adjusted_b = new_reserves.b.amount - repaid.b.amount / liquidity_miner_fee;
+
+        // a common scale keeps the comparison meaningful, rounding in favour of the pool
+        let scale = scale(larger(larger(adjusted_a, adjusted_b), larger(reserves.a.amount, reserves.b.amount)));
+
+        invariant(adjusted_a / scale, adjusted_b / scale, self.amplification) >= invariant(divide_up(reserves.a.amount, scale), divide_up(reserves.b.amount, scale), self.amplification)
+    }
+
+    fn maximum_input_for_exact_output(
+        self,
+        output_amount: u64,
+        input_reserve: u64,
+        output_reserve: u64,
+        liquidity_miner_fee: u64,
+    ) -> u64 {
+        assert(input_reserve > 0 && output_reserve > 0);
+        let scale = scale(larger(input_reserve, output_reserve));
+        let d = invariant(divide_up(input_reserve, scale), divide_up(output_reserve, scale), self.amplification);
+
+        // round up in favour of the pool
+        let new_input_reserve = (other_reserve((output_reserve - output_amount) / scale, d, self.amplification).as_u64().unwrap() + 1) * scale;
+        let input_amount_with_fee = if new_input_reserve > input_reserve {
+            new_input_reserve - input_reserve
+        } else {
+            1
+        };
+
+        // the smallest amount from which `calculate_amount_with_fee` leaves at least `input_amount_with_fee`
+        proportional_value(input_amount_with_fee, liquidity_miner_fee, liquidity_miner_fee - 1) + 1
+    }
+
+    fn minimum_output_given_exact_input(
+        self,
+        input_amount: u64,
+        input_reserve: u64,
+        output_reserve: u64,
+        liquidity_miner_fee: u64,
+    ) -> u64 {
+        assert(input_reserve > 0 && output_reserve > 0);
+        let new_input_reserve = input_reserve + calculate_amount_with_fee(input_amount, liquidity_miner_fee);
+        let scale = scale(larger(new_input_reserve, output_reserve));
+        let d = invariant(divide_up(input_reserve, scale), divide_up(output_reserve, scale), self.amplification);
+
+        // round down in favour of the pool
+        let new_output_reserve = (other_reserve(new_input_reserve / scale, d, self.amplification).as_u64().unwrap() + 1) * scale;
+        if new_output_reserve >= output_reserve {
+            0
+        } else {
+            output_reserve - new_output_reserve
+        }
+    }
+
+    fn zap_swap_amount(
+        self,
+        input_amount: u64,
+        input_reserve: u64,
+        output_reserve: u64,
+        liquidity_miner_fee: u64,
+    ) -> u64 {
+        // bisect for the largest swap after which the rest of the deposit does not exceed the bought amount at the ratio of the new reserves
+        let mut low = 0;
+        let mut high = input_amount;
+        while low < high {
+            let swapped = low + (high - low + 1) / 2;
+            let bought = self.minimum_output_given_exact_input(swapped, input_reserve, output_reserve, liquidity_miner_fee);
+
+            let rest = U128::from((0, input_amount - swapped)) * U128::from((0, output_reserve - bought));
+            let added = U128::from((0, bought)) * (U128::from((0, input_reserve)) + U128::from((0, swapped)));
+
+            if rest >= added {
+                low = swapped;
+            } else {
+                high = swapped - 1;
+            }
+        }
+
+        low
+    }
+}
+
+// Number of bits needed to represent `value`
+fn bit_length(value: U128) -> u64 {
+    let (mut word, offset) = if value.upper > 0 {
+        (value.upper, 64)
+    } else {
+        (value.lower, 0)
+    };
+
+    let mut length = 0;
+    while word > 0 {
+        word = word >> 1;
+        length += 1;
+    }
+
+    offset + length
+}
+
+// Whether two successive approximations are at most 1 apart
+fn converged(current: U128, previous: U128) -> bool {
+    let one = U128::from((0, 1));
+    if current > previous {
+        current - previous <= one
+    } else {
+        previous - current <= one
+    }
+}
+
+// Divides `amount` by `divisor` rounding up
+fn divide_up(amount: u64, divisor: u64) -> u64 {
+    let quotient = amount / divisor;
+    if amount % divisor > 0 {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+// Approximates the invariant D of the reserves with Newton's method
+fn invariant(reserve_a: u64, reserve_b: u64, amplification: u64) -> U128 {
+    if reserve_a == 0 || reserve_b == 0 {
+        return U128::new();
+    }
+
+    let (x, y) = (U128::from((0, reserve_a)), U128::from((0, reserve_b)));
+    let (two, three) = (U128::from((0, 2)), U128::from((0, 3)));
+    // A * n^n where n = 2 is the number of assets in the pool
+    let ann = U128::from((0, amplification * 4));
+    let sum = x + y;
+
+    let mut d = sum;
+    let mut iteration = 0;
+    while iteration < MAX_ITERATIONS {
+        // D^3 / 4xy one reserve at a time as the cube of D exceeds U128 for imbalanced reserves
+        let mut d_p = d * d / (x * two);
+        d_p = multiply_divide(d_p, d, y * two);
+        let previous = d;
+        d = multiply_divide(ann * sum + d_p * two, d, (ann - U128::from((0, 1))) * d + d_p * three);
+
+        if converged(d, previous) {
+            return d;
+        }
+        iteration += 1;
+    }
+
+    d
+}
+
+// Returns the larger of the amounts
+fn larger(a: u64, b: u64) -> u64 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+// Computes `a * b / c` through the quotient and remainder of `a / c` as `a * b` may not fit in U128
+//
+// The result is exact whenever `a * b` fits, otherwise the remainder and `c` are shifted right until
+// their product fits, which only drops bits far below the result
+fn multiply_divide(a: U128, b: U128, c: U128) -> U128 {
+    let quotient = a / c;
+    let mut remainder = a - quotient * c;
+    let mut divisor = c;
+    while bit_length(remainder) + bit_length(b) > 128 {
+        remainder = remainder >> 1;
+        divisor = divisor >> 1;
+    }
+
+    quotient * b + remainder * b / divisor
+}
+
+// Approximates the reserve of the other asset that keeps the invariant `d` given the `reserve` of one asset
+fn other_reserve(reserve: u64, d: U128, amplification: u64) -> U128 {
+    let x = U128::from((0, reserve));
+    let two = U128::from((0, 2));
+    let ann = U128::from((0, amplification * 4));
+    let c = multiply_divide(d * d / (x * two), d, ann * two);
+    let b = x + d / ann;
+
+    let mut y = d;
+    let mut iteration = 0;
+    while iteration < MAX_ITERATIONS {
+        let previous = y;
+        y = (y * y + c) / (y * two + b - d);
+
+        if converged(y, previous) {
+            return y;
+        }
+        iteration += 1;
+    }
+
+    y
+}
+
+// Returns the divisor that brings the `largest` amount within `MAX_SCALED_RESERVE`, i.e., 1 for amounts that need no scaling
+fn scale(largest: u64) -> u64 {
+    largest / MAX_SCALED_RESERVE + 1
+}
@@ -0,0 +1,100 @@
+use crate::utils::setup_and_construct;
+use test_utils::{interface::exchange::add_liquidity, setup::common::deposit_and_add_liquidity};
+
+mod success {
+    use super::*;
+    use crate::utils::{invariant, AMPLIFICATION_COEFFICIENT};
+    use test_utils::interface::exchange::pool_info;
+
+    #[tokio::test]
+    async fn adds_invariant_when_liquidity_is_zero() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(false).await;
+
+        let added_liquidity =
+            deposit_and_add_liquidity(&liquidity_parameters, &exchange, false).await;
+
+        let pool_info = pool_info(&exchange.instance).await;
+
+        // the invariant of a balanced pool is the sum of its reserves
+        assert_eq!(
+            added_liquidity,
+            liquidity_parameters.amounts.0 + liquidity_parameters.amounts.1
+        );
+        assert_eq!(
+            added_liquidity as u128,
+            invariant(
+                liquidity_parameters.amounts.0,
+                liquidity_parameters.amounts.1,
+                AMPLIFICATION_COEFFICIENT
+            )
+        );
+        assert_eq!(pool_info.liquidity, added_liquidity);
+    }
+
+    #[tokio::test]
+    async fn adds_invariant_of_unbalanced_deposits() {
+        let (exchange, _wallet, mut liquidity_parameters) = setup_and_construct(false).await;
+        liquidity_parameters.amounts = (50_000, 150_000);
+
+        let added_liquidity =
+            deposit_and_add_liquidity(&liquidity_parameters, &exchange, false).await;
+
+        let expected_liquidity = invariant(
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            AMPLIFICATION_COEFFICIENT,
+        );
+
+        assert_eq!(added_liquidity as u128, expected_liquidity);
+        // close to the sum of the reserves thanks to the amplification
+        assert!(added_liquidity < 200_000);
+        assert!(added_liquidity > 199_000);
+    }
+
+    #[tokio::test]
+    async fn adds_further_liquidity_proportionally() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+
+        let added_liquidity =
+            deposit_and_add_liquidity(&liquidity_parameters, &exchange, false).await;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+
+        assert_eq!(added_liquidity, initial_pool_info.liquidity);
+        assert_eq!(final_pool_info.liquidity, initial_pool_info.liquidity * 2);
+    }
+}
+
+mod revert {
+    use super::*;
+    use test_utils::interface::exchange::deposit;
+
+    #[tokio::test]
+    #[should_panic(expected = "DesiredAmountTooHigh")]
+    async fn when_desired_liquidity_is_more_than_invariant() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(false).await;
+
+        deposit(
+            &exchange.instance,
+            liquidity_parameters.amounts.0,
+            exchange.pair.0,
+        )
+        .await;
+        deposit(
+            &exchange.instance,
+            liquidity_parameters.amounts.1,
+            exchange.pair.1,
+        )
+        .await;
+
+        add_liquidity(
+            &exchange.instance,
+            liquidity_parameters.amounts.0 + liquidity_parameters.amounts.1 + 1,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+}
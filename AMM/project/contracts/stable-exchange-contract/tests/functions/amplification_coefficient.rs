@@ -0,0 +1,24 @@
+use crate::utils::{setup_and_construct, AMPLIFICATION_COEFFICIENT};
+use test_utils::interface::StableExchange;
+
+mod success {
+    use super::*;
+    use fuels::prelude::Bech32ContractId;
+
+    #[tokio::test]
+    async fn returns_amplification_coefficient() {
+        let (exchange, wallet, _liquidity_parameters) = setup_and_construct(false).await;
+
+        let stable_exchange = StableExchange::new(Bech32ContractId::from(exchange.id), wallet);
+
+        let amplification_coefficient = stable_exchange
+            .methods()
+            .amplification_coefficient()
+            .call()
+            .await
+            .unwrap()
+            .value;
+
+        assert_eq!(amplification_coefficient, AMPLIFICATION_COEFFICIENT);
+    }
+}
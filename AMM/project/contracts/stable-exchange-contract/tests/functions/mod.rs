@@ -0,0 +1,7 @@
+mod add_liquidity;
+mod amplification_coefficient;
+mod preview_swap_exact_input;
+mod preview_swap_exact_output;
+mod swap_exact_input;
+mod swap_exact_output;
+mod zap;
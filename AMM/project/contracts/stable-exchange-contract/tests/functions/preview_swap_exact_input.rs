@@ -0,0 +1,54 @@
+use crate::utils::setup_and_construct;
+use test_utils::interface::exchange::preview_swap_exact_input;
+
+mod success {
+    use super::*;
+    use crate::utils::minimum_output_given_exact_input;
+    use fuels::prelude::AssetId;
+    use test_utils::data_structures::LIQUIDITY_MINER_FEE;
+
+    #[tokio::test]
+    async fn previews_swap_of_a() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = 1000;
+
+        let expected_output = minimum_output_given_exact_input(
+            input_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let preview_swap_info =
+            preview_swap_exact_input(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        assert_eq!(
+            AssetId::new(*preview_swap_info.other_asset.id),
+            exchange.pair.1
+        );
+        assert_eq!(preview_swap_info.other_asset.amount, expected_output);
+        assert!(preview_swap_info.sufficient_reserve);
+    }
+
+    #[tokio::test]
+    async fn previews_swap_of_large_input() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = liquidity_parameters.amounts.0 / 2;
+
+        let expected_output = minimum_output_given_exact_input(
+            input_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let preview_swap_info =
+            preview_swap_exact_input(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        assert_eq!(preview_swap_info.other_asset.amount, expected_output);
+        assert!(preview_swap_info.other_asset.amount < liquidity_parameters.amounts.1);
+        assert!(preview_swap_info.sufficient_reserve);
+    }
+}
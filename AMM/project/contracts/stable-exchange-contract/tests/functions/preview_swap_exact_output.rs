@@ -0,0 +1,52 @@
+use crate::utils::setup_and_construct;
+use test_utils::interface::exchange::preview_swap_exact_output;
+
+mod success {
+    use super::*;
+    use crate::utils::maximum_input_for_exact_output;
+    use fuels::prelude::AssetId;
+    use test_utils::data_structures::LIQUIDITY_MINER_FEE;
+
+    #[tokio::test]
+    async fn previews_swap_of_a() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let output_amount = 1000;
+
+        let expected_input = maximum_input_for_exact_output(
+            output_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let preview_swap_info =
+            preview_swap_exact_output(&exchange.instance, output_amount, exchange.pair.1, true)
+                .await;
+
+        assert_eq!(
+            AssetId::new(*preview_swap_info.other_asset.id),
+            exchange.pair.0
+        );
+        assert_eq!(preview_swap_info.other_asset.amount, expected_input);
+        assert!(preview_swap_info.sufficient_reserve);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "DesiredAmountTooHigh")]
+    async fn when_output_amount_is_entire_reserve() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        preview_swap_exact_output(
+            &exchange.instance,
+            liquidity_parameters.amounts.1,
+            exchange.pair.1,
+            true,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,193 @@
+use crate::utils::setup_and_construct;
+use test_utils::interface::exchange::{preview_swap_exact_input, swap_exact_input};
+
+mod success {
+    use super::*;
+    use crate::utils::{
+        minimum_output_given_exact_input, setup_and_construct_with_imbalanced_reserves,
+        setup_and_construct_with_large_reserves,
+    };
+    use test_utils::{data_structures::LIQUIDITY_MINER_FEE, interface::exchange::pool_info};
+
+    #[tokio::test]
+    async fn swaps_a_for_b() {
+        let (exchange, wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = 1000;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+        let initial_balance = wallet.get_asset_balance(&exchange.pair.1).await.unwrap();
+
+        let expected_output = minimum_output_given_exact_input(
+            input_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let output_amount = swap_exact_input(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            Some(expected_output),
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let final_balance = wallet.get_asset_balance(&exchange.pair.1).await.unwrap();
+
+        assert_eq!(output_amount, expected_output);
+        assert_eq!(final_balance, initial_balance + output_amount);
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + input_amount
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount - output_amount
+        );
+    }
+
+    #[tokio::test]
+    async fn swaps_b_for_a() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = 1000;
+
+        let expected_output = minimum_output_given_exact_input(
+            input_amount,
+            liquidity_parameters.amounts.1,
+            liquidity_parameters.amounts.0,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let output_amount = swap_exact_input(
+            &exchange.instance,
+            exchange.pair.1,
+            input_amount,
+            Some(expected_output),
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        assert_eq!(output_amount, expected_output);
+    }
+
+    #[tokio::test]
+    async fn swaps_with_less_slippage_than_constant_product() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = 1000;
+
+        let input_amount_with_fee = input_amount - input_amount / LIQUIDITY_MINER_FEE;
+        let constant_product_output = input_amount_with_fee * liquidity_parameters.amounts.1
+            / (liquidity_parameters.amounts.0 + input_amount_with_fee);
+
+        let output_amount = swap_exact_input(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            None,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        assert_eq!(constant_product_output, 987);
+        assert_eq!(output_amount, 996);
+    }
+
+    #[tokio::test]
+    async fn swaps_with_large_reserves() {
+        let (exchange, _wallet, liquidity_parameters) =
+            setup_and_construct_with_large_reserves().await;
+
+        let input_amount = 1 << 40;
+
+        let expected_output = minimum_output_given_exact_input(
+            input_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let output_amount = swap_exact_input(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            None,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        assert_eq!(output_amount, expected_output);
+        // the pool keeps trading close to 1:1 rather than overflowing
+        assert!(output_amount > input_amount - input_amount / 100);
+    }
+
+    #[tokio::test]
+    async fn swaps_with_imbalanced_reserves() {
+        let (exchange, _wallet, liquidity_parameters) =
+            setup_and_construct_with_imbalanced_reserves().await;
+
+        let input_amount = 1 << 40;
+
+        // sell the abundant asset for the scarce one
+        let expected_output = minimum_output_given_exact_input(
+            input_amount,
+            liquidity_parameters.amounts.1,
+            liquidity_parameters.amounts.0,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let output_amount = swap_exact_input(
+            &exchange.instance,
+            exchange.pair.1,
+            input_amount,
+            None,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        assert_eq!(output_amount, expected_output);
+        assert_eq!(output_amount, 6);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "DesiredAmountTooHigh")]
+    async fn when_minimum_output_is_not_satisfied() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = 1000;
+
+        let preview_amount =
+            preview_swap_exact_input(&exchange.instance, input_amount, exchange.pair.0, true)
+                .await
+                .other_asset
+                .amount;
+
+        swap_exact_input(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            Some(preview_amount + 1),
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,124 @@
+use crate::utils::setup_and_construct;
+use test_utils::interface::exchange::{preview_swap_exact_output, swap_exact_output};
+
+mod success {
+    use super::*;
+    use crate::utils::maximum_input_for_exact_output;
+    use test_utils::{data_structures::LIQUIDITY_MINER_FEE, interface::exchange::pool_info};
+
+    #[tokio::test]
+    async fn swaps_a_for_b() {
+        let (exchange, wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let output_amount = 1000;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+        let initial_balance = wallet.get_asset_balance(&exchange.pair.0).await.unwrap();
+
+        let expected_input = maximum_input_for_exact_output(
+            output_amount,
+            liquidity_parameters.amounts.0,
+            liquidity_parameters.amounts.1,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        // forwarding more than required so that the excess is refunded
+        let input_amount = swap_exact_output(
+            &exchange.instance,
+            exchange.pair.0,
+            expected_input + 100,
+            output_amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let final_balance = wallet.get_asset_balance(&exchange.pair.0).await.unwrap();
+
+        assert_eq!(input_amount, expected_input);
+        assert_eq!(input_amount, 1005);
+        assert_eq!(final_balance, initial_balance - input_amount);
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + input_amount
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount - output_amount
+        );
+    }
+
+    #[tokio::test]
+    async fn swaps_b_for_a() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let output_amount = 1000;
+
+        let expected_input = maximum_input_for_exact_output(
+            output_amount,
+            liquidity_parameters.amounts.1,
+            liquidity_parameters.amounts.0,
+            LIQUIDITY_MINER_FEE,
+        );
+
+        let input_amount = swap_exact_output(
+            &exchange.instance,
+            exchange.pair.1,
+            expected_input,
+            output_amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        assert_eq!(input_amount, expected_input);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientReserve")]
+    async fn when_output_amount_is_entire_reserve() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        swap_exact_output(
+            &exchange.instance,
+            exchange.pair.0,
+            liquidity_parameters.amounts.0,
+            // the invariant cannot be maintained once a reserve is drained
+            liquidity_parameters.amounts.1,
+            liquidity_parameters.deadline,
+            false,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DesiredAmountTooHigh")]
+    async fn when_forwarding_insufficient_amount() {
+        let (exchange, _wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let output_amount = 1000;
+
+        let preview_amount =
+            preview_swap_exact_output(&exchange.instance, output_amount, exchange.pair.1, true)
+                .await
+                .other_asset
+                .amount;
+
+        swap_exact_output(
+            &exchange.instance,
+            exchange.pair.0,
+            preview_amount - 1,
+            output_amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await;
+    }
+}
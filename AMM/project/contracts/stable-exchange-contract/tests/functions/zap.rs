@@ -0,0 +1,75 @@
+use crate::utils::{setup_and_construct, setup_and_construct_with_large_reserves};
+use test_utils::interface::exchange::{preview_zap, zap};
+
+mod success {
+    use super::*;
+    use test_utils::interface::exchange::pool_info;
+
+    #[tokio::test]
+    async fn zaps_a() {
+        let (exchange, wallet, liquidity_parameters) = setup_and_construct(true).await;
+
+        let input_amount = 1000;
+
+        let initial_pool_info = pool_info(&exchange.instance).await;
+        let initial_balance = wallet.get_asset_balance(&exchange.pair.1).await.unwrap();
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        let added_liquidity = zap(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            preview.liquidity_asset_to_receive.amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        let final_pool_info = pool_info(&exchange.instance).await;
+        let final_balance = wallet.get_asset_balance(&exchange.pair.1).await.unwrap();
+
+        // the split is bisected along the StableSwap invariant so only dust of the deposited asset is refunded
+        assert_eq!(preview.swapped_asset.amount, 498);
+        assert_eq!(preview.refund.a.amount, 2);
+        assert_eq!(preview.refund.b.amount, 0);
+        assert_eq!(added_liquidity, preview.liquidity_asset_to_receive.amount);
+        assert_eq!(added_liquidity, 996);
+        assert_eq!(final_balance, initial_balance + preview.refund.b.amount);
+        assert_eq!(
+            final_pool_info.reserves.a.amount,
+            initial_pool_info.reserves.a.amount + input_amount - preview.refund.a.amount
+        );
+        assert_eq!(
+            final_pool_info.reserves.b.amount,
+            initial_pool_info.reserves.b.amount
+        );
+    }
+
+    #[tokio::test]
+    async fn zaps_with_large_reserves() {
+        let (exchange, _wallet, liquidity_parameters) =
+            setup_and_construct_with_large_reserves().await;
+
+        let input_amount = 1 << 40;
+
+        let preview = preview_zap(&exchange.instance, input_amount, exchange.pair.0, true).await;
+
+        let added_liquidity = zap(
+            &exchange.instance,
+            exchange.pair.0,
+            input_amount,
+            preview.liquidity_asset_to_receive.amount,
+            liquidity_parameters.deadline,
+            true,
+        )
+        .await
+        .value;
+
+        assert_eq!(preview.swapped_asset.amount, 550_582_474_654);
+        assert_eq!(preview.refund.b.amount, 0);
+        assert_eq!(added_liquidity, preview.liquidity_asset_to_receive.amount);
+        assert_eq!(added_liquidity, 1_097_858_147_516);
+    }
+}
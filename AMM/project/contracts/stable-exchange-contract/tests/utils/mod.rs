@@ -0,0 +1,200 @@
+use fuels::prelude::WalletUnlocked;
+use test_utils::{
+    data_structures::{
+        ExchangeContract, ExchangeContractConfiguration, LiquidityParameters,
+        WalletAssetConfiguration,
+    },
+    setup::common::{
+        deploy_and_construct_exchange, deposit_and_add_liquidity, setup_wallet_and_provider,
+    },
+};
+
+/// `AMPLIFICATION_COEFFICIENT` in the `Forc.toml` of the stable exchange contract
+pub const AMPLIFICATION_COEFFICIENT: u64 = 100;
+
+// mirrors `MAX_SCALED_RESERVE` in `stable_swap.sw`
+const MAX_SCALED_RESERVE: u64 = 1 << 48;
+
+// mirrors `divide_up` in `stable_swap.sw`
+fn divide_up(amount: u64, divisor: u64) -> u64 {
+    amount / divisor + u64::from(amount % divisor > 0)
+}
+
+// mirrors `invariant` in `stable_swap.sw`
+pub fn invariant(reserve_a: u64, reserve_b: u64, amplification: u64) -> u128 {
+    if reserve_a == 0 || reserve_b == 0 {
+        return 0;
+    }
+
+    let (x, y) = (reserve_a as u128, reserve_b as u128);
+    let ann = amplification as u128 * 4;
+    let sum = x + y;
+
+    let mut d = sum;
+    for _ in 0..255 {
+        let d_p = multiply_divide(d * d / (x * 2), d, y * 2);
+        let previous = d;
+        d = multiply_divide(ann * sum + d_p * 2, d, (ann - 1) * d + d_p * 3);
+
+        if d.abs_diff(previous) <= 1 {
+            return d;
+        }
+    }
+    d
+}
+
+// mirrors `multiply_divide` in `stable_swap.sw`
+fn multiply_divide(a: u128, b: u128, c: u128) -> u128 {
+    let quotient = a / c;
+    let (mut remainder, mut divisor) = (a - quotient * c, c);
+    while (128 - remainder.leading_zeros()) + (128 - b.leading_zeros()) > 128 {
+        remainder >>= 1;
+        divisor >>= 1;
+    }
+    quotient * b + remainder * b / divisor
+}
+
+// mirrors `other_reserve` in `stable_swap.sw`
+fn other_reserve(reserve: u64, d: u128, amplification: u64) -> u128 {
+    let x = reserve as u128;
+    let ann = amplification as u128 * 4;
+    let c = multiply_divide(d * d / (x * 2), d, ann * 2);
+    let b = x + d / ann;
+
+    let mut y = d;
+    for _ in 0..255 {
+        let previous = y;
+        y = (y * y + c) / (y * 2 + b - d);
+
+        if y.abs_diff(previous) <= 1 {
+            return y;
+        }
+    }
+    y
+}
+
+// mirrors `scale` in `stable_swap.sw`
+fn scale(largest: u64) -> u64 {
+    largest / MAX_SCALED_RESERVE + 1
+}
+
+pub fn maximum_input_for_exact_output(
+    output_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> u64 {
+    let scale = scale(input_reserve.max(output_reserve));
+    let d = invariant(
+        divide_up(input_reserve, scale),
+        divide_up(output_reserve, scale),
+        AMPLIFICATION_COEFFICIENT,
+    );
+    let new_input_reserve = (other_reserve(
+        (output_reserve - output_amount) / scale,
+        d,
+        AMPLIFICATION_COEFFICIENT,
+    ) as u64
+        + 1)
+        * scale;
+    let input_amount_with_fee = if new_input_reserve > input_reserve {
+        new_input_reserve - input_reserve
+    } else {
+        1
+    };
+    input_amount_with_fee * liquidity_miner_fee / (liquidity_miner_fee - 1) + 1
+}
+
+pub fn minimum_output_given_exact_input(
+    input_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> u64 {
+    let new_input_reserve = input_reserve + input_amount - input_amount / liquidity_miner_fee;
+    let scale = scale(new_input_reserve.max(output_reserve));
+    let d = invariant(
+        divide_up(input_reserve, scale),
+        divide_up(output_reserve, scale),
+        AMPLIFICATION_COEFFICIENT,
+    );
+    let new_output_reserve =
+        (other_reserve(new_input_reserve / scale, d, AMPLIFICATION_COEFFICIENT) as u64 + 1) * scale;
+    output_reserve.saturating_sub(new_output_reserve)
+}
+
+pub async fn setup_and_construct(
+    add_liquidity: bool,
+) -> (ExchangeContract, WalletUnlocked, LiquidityParameters) {
+    // a balanced pool of two assets that are meant to trade close to 1:1
+    setup_and_construct_with_reserves(
+        add_liquidity,
+        &WalletAssetConfiguration::default(),
+        (100_000, 100_000),
+    )
+    .await
+}
+
+pub async fn setup_and_construct_with_large_reserves(
+) -> (ExchangeContract, WalletUnlocked, LiquidityParameters) {
+    // reserves large enough that the invariant has to be approximated on scaled down reserves
+    setup_and_construct_with_reserves(
+        true,
+        &WalletAssetConfiguration {
+            number_of_assets: 2,
+            coins_per_asset: 2,
+            amount_per_coin: 1 << 62,
+        },
+        (1 << 62, 1 << 62),
+    )
+    .await
+}
+
+pub async fn setup_and_construct_with_imbalanced_reserves(
+) -> (ExchangeContract, WalletUnlocked, LiquidityParameters) {
+    // reserves so far apart that the cube of the invariant exceeds u128
+    setup_and_construct_with_reserves(
+        true,
+        &WalletAssetConfiguration {
+            number_of_assets: 2,
+            coins_per_asset: 2,
+            amount_per_coin: 1 << 48,
+        },
+        (1_000, 1 << 48),
+    )
+    .await
+}
+
+async fn setup_and_construct_with_reserves(
+    add_liquidity: bool,
+    wallet_assets: &WalletAssetConfiguration,
+    reserves: (u64, u64),
+) -> (ExchangeContract, WalletUnlocked, LiquidityParameters) {
+    let (wallet, asset_ids, provider) = setup_wallet_and_provider(wallet_assets).await;
+
+    let exchange = deploy_and_construct_exchange(
+        &wallet,
+        &ExchangeContractConfiguration {
+            stable: true,
+            ..ExchangeContractConfiguration::new(
+                Some((asset_ids[0], asset_ids[1])),
+                None,
+                None,
+                None,
+            )
+        },
+    )
+    .await;
+
+    let liquidity_parameters = LiquidityParameters::new(
+        Some(reserves),
+        Some(provider.latest_block_height().await.unwrap() + 20),
+        Some(20_000),
+    );
+
+    if add_liquidity {
+        deposit_and_add_liquidity(&liquidity_parameters, &exchange, false).await;
+    }
+
+    (exchange, wallet, liquidity_parameters)
+}
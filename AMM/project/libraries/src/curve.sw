@@ -0,0 +1,27 @@
+library curve;
+
+dep data_structures;
+
+use data_structures::AssetPair;
+
+/// The curve along which the reserves of a pool trade, implemented by each exchange contract
+///
+/// Every amount is rounded in favour of the pool
+pub trait Curve {
+    /// Returns the amount of liquidity pool asset minted for the first deposits of `amount_a` and `amount_b`
+    fn initial_liquidity(self, amount_a: u64, amount_b: u64) -> u64;
+
+    /// Whether the `new_reserves`, excluding the liquidity miner fee charged on the `repaid` amounts,
+    /// are on or above the curve through the `reserves`
+    fn invariant_holds(self, reserves: AssetPair, new_reserves: AssetPair, repaid: AssetPair, liquidity_miner_fee: u64) -> bool;
+
+    /// Returns the maximum required amount of the input asset to get exactly `output_amount` of the output asset
+    fn maximum_input_for_exact_output(self, output_amount: u64, input_reserve: u64, output_reserve: u64, liquidity_miner_fee: u64) -> u64;
+
+    /// Given exactly `input_amount` of the input asset, returns the minimum resulting amount of the output asset
+    fn minimum_output_given_exact_input(self, input_amount: u64, input_reserve: u64, output_reserve: u64, liquidity_miner_fee: u64) -> u64;
+
+    /// Returns the portion of a single-sided deposit of `input_amount` to swap so that the rest of the deposit and the
+    /// bought amount match the ratio of the reserves after the swap
+    fn zap_swap_amount(self, input_amount: u64, input_reserve: u64, output_reserve: u64, liquidity_miner_fee: u64) -> u64;
+}
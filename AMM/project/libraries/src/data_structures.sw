@@ -1,5 +1,7 @@
 library data_structures;
 
+use std::u128::U128;
+
 pub struct Asset {
     /// Identifier of asset
     id: ContractId,
@@ -92,6 +94,48 @@ pub struct PoolInfo {
     reserves: AssetPair,
     /// The amount of liquidity pool asset supply in the exchange contract
     liquidity: u64,
+    /// The fee tier of the pool, i.e., one in `liquidity_miner_fee` of every input amount is charged as a fee
+    liquidity_miner_fee: u64,
+}
+
+pub struct PoolState {
+    /// Unique identifiers and reserve amounts of the assets that make up the pool
+    reserves: AssetPair,
+    /// Total amount of the liquidity pool asset
+    liquidity: u64,
+    /// The fee tier of the pool, i.e., one in `liquidity_miner_fee` of every input amount is charged as a fee
+    liquidity_miner_fee: u64,
+    /// Share of the liquidity miner fee taken by the protocol and its recipient
+    protocol_fee: Option<ProtocolFee>,
+    /// Protocol fees that have accrued since they were last withdrawn, which are not part of the reserves
+    accrued_protocol_fees: AssetPair,
+    /// Cumulative prices of the pool assets as of the last time the reserves changed
+    price_observation: PriceObservation,
+}
+
+pub struct ProtocolFee {
+    /// The recipient of the protocol fees when they are withdrawn
+    recipient: Identity,
+    /// The share of the liquidity miner fee that is taken by the protocol, in basis points
+    share: u64,
+}
+
+pub struct ProtocolFeeInfo {
+    /// Protocol fees that have accrued and not been withdrawn yet
+    accrued: AssetPair,
+    /// The identity that controls the protocol fee, i.e., the identity that constructed the exchange contract until it transfers ownership
+    owner: Identity,
+    /// The current protocol fee, if the protocol takes a share of the liquidity miner fee
+    protocol_fee: Option<ProtocolFee>,
+}
+
+pub struct PriceObservation {
+    /// Sum of the prices of asset A in asset B, scaled by 10^9, multiplied by the number of seconds each price was in effect
+    price_a_cumulative: U128,
+    /// Sum of the prices of asset B in asset A, scaled by 10^9, multiplied by the number of seconds each price was in effect
+    price_b_cumulative: U128,
+    /// Time up to which the prices have been accumulated
+    timestamp: u64,
 }
 
 pub struct PreviewAddLiquidityInfo {
@@ -110,6 +154,24 @@ pub struct PreviewSwapInfo {
     sufficient_reserve: bool,
 }
 
+pub struct PreviewZapInfo {
+    /// The portion of the zapped asset that is swapped for the other asset
+    swapped_asset: Asset,
+    /// The liquidity pool asset to be minted and transferred to the sender
+    liquidity_asset_to_receive: Asset,
+    /// Amounts of both assets that cannot be added at the ratio of the reserves and are transferred back to the sender
+    refund: AssetPair,
+}
+
+pub struct RegisteredPool {
+    /// The ordered pair of asset identifiers that make up the pool
+    asset_pair: (ContractId, ContractId),
+    /// The fee tier of the pool, i.e., the liquidity miner fee of the exchange contract
+    liquidity_miner_fee: u64,
+    /// The exchange contract that manages the pool
+    pool: ContractId,
+}
+
 pub struct RemoveLiquidityInfo {
     /// Pool assets that are removed from the reserves and transferred to the sender
     removed_amounts: AssetPair,
@@ -1,9 +1,14 @@
-library errors;
+library exchange_errors;
+
+pub enum AccessError {
+    NotOwner: (),
+}
 
 pub enum InitError {
     AssetPairAlreadySet: (),
     AssetPairNotSet: (),
     IdenticalAssets: (),
+    InvalidLiquidityMinerFee: u64,
 }
 
 pub enum InputError {
@@ -12,12 +17,15 @@ pub enum InputError {
     ExpectedNonZeroAmount: ContractId,
     ExpectedNonZeroParameter: ContractId,
     InvalidAsset: (),
+    ProtocolFeeShareTooHigh: u64,
 }
 
 pub enum TransactionError {
     DesiredAmountTooHigh: u64,
     DesiredAmountTooLow: u64,
     ExpectedNonZeroDeposit: ContractId,
+    FlashSwapNotRepaid: (),
     InsufficientReserve: ContractId,
     NoLiquidityToRemove: (),
+    ProtocolFeeNotSet: (),
 }
@@ -0,0 +1,88 @@
+library exchange_events;
+
+dep data_structures;
+
+use data_structures::{Asset, AssetPair, ProtocolFee};
+
+pub struct AddLiquidityEvent {
+    /// Identifiers and amounts of assets added to reserves
+    added_assets: AssetPair,
+    /// Identifier and amount of liquidity pool assets minted and transferred to sender
+    liquidity: Asset,
+}
+
+pub struct DefineAssetPairEvent {
+    /// Identifier of one of the assets that make up the pool
+    asset_a_id: ContractId,
+    /// Identifier of the other asset
+    asset_b_id: ContractId,
+    /// Fee tier of the pool
+    liquidity_miner_fee: u64,
+}
+
+pub struct DepositEvent {
+    /// Deposited asset that may be withdrawn or used to add liquidity
+    deposited_asset: Asset,
+    /// New deposit balance of asset in contract
+    new_balance: u64,
+}
+
+pub struct FlashSwapEvent {
+    /// Contract the borrowed asset was transferred to and called back
+    borrower: ContractId,
+    /// Identifier and amount of the borrowed asset
+    borrowed: Asset,
+    /// Identifiers and amounts of assets transferred to the contract during the callback
+    repaid: AssetPair,
+}
+
+pub struct RemoveLiquidityEvent {
+    /// Identifiers and amounts of assets removed from reserves and transferred to sender
+    removed_reserve: AssetPair,
+    /// Identifier and amount of burned liquidity pool assets
+    burned_liquidity: Asset,
+}
+
+pub struct SetProtocolFeeEvent {
+    /// The new protocol fee, `None` when the protocol no longer takes a share of the liquidity miner fee
+    protocol_fee: Option<ProtocolFee>,
+}
+
+pub struct SwapEvent {
+    /// Identifier and amount of sold asset
+    input: Asset,
+    /// Identifier and amount of bought asset
+    output: Asset,
+}
+
+pub struct TransferOwnershipEvent {
+    /// The identity that controlled the protocol fee
+    previous_owner: Identity,
+    /// The identity that controls the protocol fee from now on
+    new_owner: Identity,
+}
+
+pub struct WithdrawProtocolFeesEvent {
+    /// Identity the accrued protocol fees are transferred to
+    recipient: Identity,
+    /// Identifiers and amounts of the withdrawn protocol fees
+    withdrawn: AssetPair,
+}
+
+pub struct WithdrawEvent {
+    /// Identifier and amount of withdrawn asset
+    withdrawn_asset: Asset,
+    /// Remaining deposit balance of asset in contract
+    remaining_balance: u64,
+}
+
+pub struct ZapEvent {
+    /// Identifier and amount of the single asset forwarded by the sender
+    input: Asset,
+    /// Identifier and amount of the portion of the input asset that was swapped for the other asset
+    swapped: Asset,
+    /// Identifiers and amounts of assets added to reserves
+    added_assets: AssetPair,
+    /// Identifier and amount of liquidity pool assets minted and transferred to sender
+    liquidity: Asset,
+}
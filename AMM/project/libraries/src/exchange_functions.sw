@@ -0,0 +1,540 @@
+library exchange_functions;
+
+dep curve;
+dep data_structures;
+dep exchange_errors;
+dep exchange_events;
+dep exchange_utils;
+
+use curve::Curve;
+use data_structures::{
+    Asset,
+    AssetPair,
+    PoolInfo,
+    PoolState,
+    PriceObservation,
+    ProtocolFee,
+    ProtocolFeeInfo,
+    RemoveLiquidityInfo,
+};
+use exchange_errors::{AccessError, InitError, InputError, TransactionError};
+use exchange_events::{
+    AddLiquidityEvent,
+    DefineAssetPairEvent,
+    DepositEvent,
+    FlashSwapEvent,
+    RemoveLiquidityEvent,
+    SetProtocolFeeEvent,
+    SwapEvent,
+    TransferOwnershipEvent,
+    WithdrawEvent,
+    WithdrawProtocolFeesEvent,
+    ZapEvent,
+};
+use exchange_utils::{
+    accumulate_prices,
+    determine_assets,
+    liquidity_to_add,
+    proportional_value,
+    protocol_fee_amount,
+    valid_protocol_fee_share,
+    zap_amounts,
+};
+use std::{
+    auth::msg_sender,
+    block::{
+        height,
+        timestamp,
+    },
+    call_frames::{
+        contract_id,
+        msg_asset_id,
+    },
+    context::{
+        msg_amount,
+        this_balance,
+    },
+    reentrancy::reentrancy_guard,
+    token::{
+        burn,
+        mint,
+        transfer,
+    },
+};
+
+/// Adds the `deposits` of the sender to the reserves of the pool along the `curve` and returns the updated pool and the minted liquidity
+///
+/// The deposits are used up entirely, i.e., whatever is not added is transferred back to the sender
+pub fn add_liquidity<C>(
+    curve: C,
+    pool: PoolState,
+    deposits: AssetPair,
+    desired_liquidity: u64,
+    minimum_liquidity: u64,
+    deadline: u64,
+) -> (PoolState, u64) where C: Curve {
+    reentrancy_guard();
+
+    require(deadline > height(), InputError::DeadlinePassed(deadline));
+    require(minimum_liquidity <= desired_liquidity, InputError::CannotAddLessThanMinimumLiquidity(desired_liquidity));
+
+    // checking this because this will either result in a math error or adding no liquidity at all
+    require(deposits.a.amount != 0, TransactionError::ExpectedNonZeroDeposit(deposits.a.id));
+    require(deposits.b.amount != 0, TransactionError::ExpectedNonZeroDeposit(deposits.b.id));
+
+    let mut pool = pool;
+
+    // the first deposits are used up entirely to determine the ratio, further deposits are added at the current ratio
+    let (added_assets, added_liquidity) = liquidity_to_add(curve, deposits, pool.reserves, pool.liquidity);
+    require(desired_liquidity <= added_liquidity, TransactionError::DesiredAmountTooHigh(desired_liquidity));
+
+    // accumulate the prices in effect until the reserves change
+    pool.price_observation = accumulate_prices(pool.price_observation, pool.reserves, timestamp());
+
+    // add new asset amounts to reserves
+    pool.reserves = pool.reserves + added_assets;
+
+    // mint liquidity pool asset and transfer to sender
+    let sender = msg_sender().unwrap();
+    mint(added_liquidity);
+    pool.liquidity = pool.liquidity + added_liquidity;
+    transfer(added_liquidity, contract_id(), sender);
+
+    // transfer remaining deposit amounts back to the sender
+    let refund = deposits - added_assets;
+
+    if refund.a.amount > 0 {
+        transfer(refund.a.amount, refund.a.id, sender);
+    }
+
+    if refund.b.amount > 0 {
+        transfer(refund.b.amount, refund.b.id, sender);
+    }
+
+    log(AddLiquidityEvent {
+        added_assets,
+        liquidity: Asset::new(contract_id(), added_liquidity),
+    });
+
+    (pool, added_liquidity)
+}
+
+/// Returns the empty reserves of a pool of `asset_a` and `asset_b` unless the contract already defines the `pair`
+pub fn define_asset_pair(
+    pair: Option<AssetPair>,
+    asset_a: ContractId,
+    asset_b: ContractId,
+    liquidity_miner_fee: u64,
+) -> AssetPair {
+    require(pair.is_none(), InitError::AssetPairAlreadySet);
+    require(asset_a != asset_b, InitError::IdenticalAssets);
+    // a fee of 1 would charge the entire input amount
+    require(liquidity_miner_fee > 1, InitError::InvalidLiquidityMinerFee(liquidity_miner_fee));
+
+    log(DefineAssetPairEvent {
+        asset_a_id: asset_a,
+        asset_b_id: asset_b,
+        liquidity_miner_fee,
+    });
+
+    AssetPair::new(Asset::new(asset_a, 0), Asset::new(asset_b, 0))
+}
+
+/// Returns the deposit balance of the sender after adding the forwarded amount of a pool asset to their `balance`
+pub fn deposit(reserves: AssetPair, balance: u64) -> u64 {
+    reentrancy_guard();
+
+    let deposit_asset = msg_asset_id();
+
+    require(deposit_asset == reserves.a.id || deposit_asset == reserves.b.id, InputError::InvalidAsset);
+
+    let amount = msg_amount();
+    let new_balance = balance + amount;
+
+    log(DepositEvent {
+        deposited_asset: Asset::new(deposit_asset, amount),
+        new_balance,
+    });
+
+    new_balance
+}
+
+/// Lends `output` to the `borrower` and returns the borrowed amounts along with the balances of the contract before lending
+///
+/// The contract calls the borrower between `flash_borrow` and `flash_repay`
+pub fn flash_borrow(
+    pool: PoolState,
+    output: Asset,
+    borrower: ContractId,
+    deadline: u64,
+) -> (AssetPair, AssetPair) {
+    reentrancy_guard();
+
+    let (output_reserve, _) = determine_assets(output.id, Option::Some(pool.reserves));
+
+    require(deadline > height(), InputError::DeadlinePassed(deadline));
+    require(output.amount > 0, InputError::ExpectedNonZeroParameter(output.id));
+    require(output.amount < output_reserve.amount, TransactionError::InsufficientReserve(output.id));
+
+    let reserves = pool.reserves;
+    let mut borrowed = AssetPair::new(Asset::new(reserves.a.id, 0), Asset::new(reserves.b.id, 0));
+    if output.id == reserves.a.id {
+        borrowed.a.amount = output.amount;
+    } else {
+        borrowed.b.amount = output.amount;
+    }
+
+    // balances include deposits and protocol fees which must not count towards the repayment
+    let balances = AssetPair::new(
+        Asset::new(reserves.a.id, this_balance(reserves.a.id)),
+        Asset::new(reserves.b.id, this_balance(reserves.b.id)),
+    );
+
+    transfer(output.amount, output.id, Identity::ContractId(borrower));
+
+    (borrowed, balances)
+}
+
+/// Checks that the `borrower` repaid the `borrowed` amounts along the `curve` and returns the updated pool and the repaid amounts
+pub fn flash_repay<C>(
+    curve: C,
+    pool: PoolState,
+    output: Asset,
+    borrower: ContractId,
+    borrowed: AssetPair,
+    balances: AssetPair,
+) -> (PoolState, AssetPair) where C: Curve {
+    let mut pool = pool;
+    let reserves = pool.reserves;
+
+    // the guard keeps assets from leaving the contract during the callback so the balances cannot decrease
+    let repaid = AssetPair::new(
+        Asset::new(reserves.a.id, this_balance(reserves.a.id) + borrowed.a.amount - balances.a.amount),
+        Asset::new(reserves.b.id, this_balance(reserves.b.id) + borrowed.b.amount - balances.b.amount),
+    );
+    let new_reserves = reserves + repaid - borrowed;
+
+    require(curve.invariant_holds(reserves, new_reserves, repaid, pool.liquidity_miner_fee), TransactionError::FlashSwapNotRepaid);
+
+    pool.price_observation = accumulate_prices(pool.price_observation, reserves, timestamp());
+
+    let protocol_fees = AssetPair::new(
+        Asset::new(reserves.a.id, protocol_fee_amount(repaid.a.amount, pool.liquidity_miner_fee, pool.protocol_fee)),
+        Asset::new(reserves.b.id, protocol_fee_amount(repaid.b.amount, pool.liquidity_miner_fee, pool.protocol_fee)),
+    );
+    pool.accrued_protocol_fees = pool.accrued_protocol_fees + protocol_fees;
+    pool.reserves = new_reserves - protocol_fees;
+
+    log(FlashSwapEvent {
+        borrower,
+        borrowed: output,
+        repaid,
+    });
+
+    (pool, repaid)
+}
+
+/// Returns the reserves, liquidity and fee tier of the pool
+pub fn pool_info(pool: PoolState) -> PoolInfo {
+    PoolInfo {
+        reserves: pool.reserves,
+        liquidity: pool.liquidity,
+        liquidity_miner_fee: pool.liquidity_miner_fee,
+    }
+}
+
+/// Returns the cumulative prices of the pool as of the current block
+pub fn price_observation(pool: PoolState) -> PriceObservation {
+    accumulate_prices(pool.price_observation, pool.reserves, timestamp())
+}
+
+/// Returns the accrued protocol fees, the `owner` and the protocol fee of the pool
+pub fn protocol_fee_info(pool: PoolState, owner: Identity) -> ProtocolFeeInfo {
+    ProtocolFeeInfo {
+        accrued: pool.accrued_protocol_fees,
+        owner,
+        protocol_fee: pool.protocol_fee,
+    }
+}
+
+/// Burns the forwarded liquidity pool asset and returns the updated pool along with the amounts transferred to the sender
+pub fn remove_liquidity(
+    pool: PoolState,
+    min_asset_a: u64,
+    min_asset_b: u64,
+    deadline: u64,
+) -> (PoolState, RemoveLiquidityInfo) {
+    reentrancy_guard();
+
+    require(pool.liquidity > 0, TransactionError::NoLiquidityToRemove);
+
+    let mut pool = pool;
+    let reserves = pool.reserves;
+
+    require(min_asset_a > 0, InputError::ExpectedNonZeroParameter(reserves.a.id));
+    require(min_asset_b > 0, InputError::ExpectedNonZeroParameter(reserves.b.id));
+    require(deadline > height(), InputError::DeadlinePassed(deadline));
+
+    let burned_liquidity = Asset::new(contract_id(), msg_amount());
+
+    require(burned_liquidity.id == msg_asset_id(), InputError::InvalidAsset);
+    require(burned_liquidity.amount > 0, InputError::ExpectedNonZeroAmount(burned_liquidity.id));
+
+    let mut removed_assets = AssetPair::new(Asset::new(reserves.a.id, 0), Asset::new(reserves.b.id, 0));
+    removed_assets.a.amount = proportional_value(burned_liquidity.amount, reserves.a.amount, pool.liquidity);
+    removed_assets.b.amount = proportional_value(burned_liquidity.amount, reserves.b.amount, pool.liquidity);
+
+    require(removed_assets.a.amount >= min_asset_a, TransactionError::DesiredAmountTooHigh(min_asset_a));
+    require(removed_assets.b.amount >= min_asset_b, TransactionError::DesiredAmountTooHigh(min_asset_b));
+
+    pool.price_observation = accumulate_prices(pool.price_observation, reserves, timestamp());
+
+    burn(burned_liquidity.amount);
+    pool.liquidity = pool.liquidity - burned_liquidity.amount;
+    pool.reserves = reserves - removed_assets;
+
+    let sender = msg_sender().unwrap();
+    transfer(removed_assets.a.amount, removed_assets.a.id, sender);
+    transfer(removed_assets.b.amount, removed_assets.b.id, sender);
+
+    log(RemoveLiquidityEvent {
+        removed_reserve: removed_assets,
+        burned_liquidity,
+    });
+
+    (pool, RemoveLiquidityInfo {
+        removed_amounts: removed_assets,
+        burned_liquidity,
+    })
+}
+
+/// Checks that the sender is the current `owner` before the `protocol_fee` replaces the protocol fee of the pool
+pub fn set_protocol_fee(owner: Identity, protocol_fee: Option<ProtocolFee>) {
+    require(owner == msg_sender().unwrap(), AccessError::NotOwner);
+
+    if protocol_fee.is_some() {
+        let share = protocol_fee.unwrap().share;
+        require(valid_protocol_fee_share(share), InputError::ProtocolFeeShareTooHigh(share));
+    }
+
+    log(SetProtocolFeeEvent { protocol_fee });
+}
+
+/// Sells the forwarded amount along the `curve` and returns the updated pool and the amount bought by the sender
+pub fn swap_exact_input<C>(
+    curve: C,
+    pool: PoolState,
+    min_output: Option<u64>,
+    deadline: u64,
+) -> (PoolState, u64) where C: Curve {
+    reentrancy_guard();
+
+    require(deadline >= height(), InputError::DeadlinePassed(deadline));
+
+    let mut pool = pool;
+    let reserves = pool.reserves;
+    let (mut input_asset, mut output_asset) = determine_assets(msg_asset_id(), Option::Some(reserves));
+
+    let exact_input = msg_amount();
+    require(exact_input > 0, InputError::ExpectedNonZeroAmount(input_asset.id));
+
+    let bought = curve.minimum_output_given_exact_input(exact_input, input_asset.amount, output_asset.amount, pool.liquidity_miner_fee);
+
+    if min_output.is_some() {
+        require(bought >= min_output.unwrap(), TransactionError::DesiredAmountTooHigh(min_output.unwrap()));
+    }
+
+    transfer(bought, output_asset.id, msg_sender().unwrap());
+
+    pool.price_observation = accumulate_prices(pool.price_observation, reserves, timestamp());
+
+    let protocol_fee = protocol_fee_amount(exact_input, pool.liquidity_miner_fee, pool.protocol_fee);
+    pool.accrued_protocol_fees = pool.accrued_protocol_fees + only(Asset::new(input_asset.id, protocol_fee), reserves);
+
+    input_asset.amount = input_asset.amount + exact_input - protocol_fee;
+    output_asset.amount = output_asset.amount - bought;
+    pool.reserves = AssetPair::new(input_asset, output_asset).sort(reserves);
+
+    log(SwapEvent {
+        input: input_asset,
+        output: output_asset,
+    });
+
+    (pool, bought)
+}
+
+/// Buys exactly `output` along the `curve` with the forwarded amount and returns the updated pool and the amount sold by the sender
+///
+/// Whatever is not sold is transferred back to the sender
+pub fn swap_exact_output<C>(
+    curve: C,
+    pool: PoolState,
+    output: u64,
+    deadline: u64,
+) -> (PoolState, u64) where C: Curve {
+    reentrancy_guard();
+
+    let mut pool = pool;
+    let reserves = pool.reserves;
+    let (mut input_asset, mut output_asset) = determine_assets(msg_asset_id(), Option::Some(reserves));
+
+    require(deadline > height(), InputError::DeadlinePassed(deadline));
+    require(output > 0, InputError::ExpectedNonZeroParameter(output_asset.id));
+    require(output < output_asset.amount, TransactionError::InsufficientReserve(output_asset.id));
+
+    let input_amount = msg_amount();
+    require(input_amount > 0, InputError::ExpectedNonZeroAmount(input_asset.id));
+
+    let sold = curve.maximum_input_for_exact_output(output, input_asset.amount, output_asset.amount, pool.liquidity_miner_fee);
+
+    require(sold > 0, TransactionError::DesiredAmountTooLow(output));
+    require(input_amount >= sold, TransactionError::DesiredAmountTooHigh(input_amount));
+
+    let sender = msg_sender().unwrap();
+
+    let refund = input_amount - sold;
+    if refund > 0 {
+        transfer(refund, input_asset.id, sender);
+    };
+
+    transfer(output, output_asset.id, sender);
+
+    pool.price_observation = accumulate_prices(pool.price_observation, reserves, timestamp());
+
+    let protocol_fee = protocol_fee_amount(sold, pool.liquidity_miner_fee, pool.protocol_fee);
+    pool.accrued_protocol_fees = pool.accrued_protocol_fees + only(Asset::new(input_asset.id, protocol_fee), reserves);
+
+    input_asset.amount = input_asset.amount + sold - protocol_fee;
+    output_asset.amount = output_asset.amount - output;
+    pool.reserves = AssetPair::new(input_asset, output_asset).sort(reserves);
+
+    log(SwapEvent {
+        input: input_asset,
+        output: output_asset,
+    });
+
+    (pool, sold)
+}
+
+/// Checks that the sender is the `previous_owner` before the `new_owner` takes control of the protocol fee
+pub fn transfer_ownership(previous_owner: Identity, new_owner: Identity) {
+    require(previous_owner == msg_sender().unwrap(), AccessError::NotOwner);
+
+    log(TransferOwnershipEvent {
+        previous_owner,
+        new_owner,
+    });
+}
+
+/// Transfers `asset` out of the `deposited` amount of the sender and returns what remains of the deposit
+pub fn withdraw(reserves: AssetPair, asset: Asset, deposited: u64) -> u64 {
+    reentrancy_guard();
+
+    require(asset.id == reserves.a.id || asset.id == reserves.b.id, InputError::InvalidAsset);
+    require(deposited >= asset.amount, TransactionError::DesiredAmountTooHigh(asset.amount));
+
+    let remaining = deposited - asset.amount;
+    transfer(asset.amount, asset.id, msg_sender().unwrap());
+
+    log(WithdrawEvent {
+        withdrawn_asset: asset,
+        remaining_balance: remaining,
+    });
+
+    remaining
+}
+
+/// Transfers the accrued protocol fees to the recipient of the protocol fee and returns the updated pool and the withdrawn amounts
+pub fn withdraw_protocol_fees(pool: PoolState) -> (PoolState, AssetPair) {
+    reentrancy_guard();
+
+    require(pool.protocol_fee.is_some(), TransactionError::ProtocolFeeNotSet);
+
+    let mut pool = pool;
+    let recipient = pool.protocol_fee.unwrap().recipient;
+    let withdrawn = pool.accrued_protocol_fees;
+
+    pool.accrued_protocol_fees = AssetPair::new(Asset::new(withdrawn.a.id, 0), Asset::new(withdrawn.b.id, 0));
+
+    if withdrawn.a.amount > 0 {
+        transfer(withdrawn.a.amount, withdrawn.a.id, recipient);
+    }
+
+    if withdrawn.b.amount > 0 {
+        transfer(withdrawn.b.amount, withdrawn.b.id, recipient);
+    }
+
+    log(WithdrawProtocolFeesEvent {
+        recipient,
+        withdrawn,
+    });
+
+    (pool, withdrawn)
+}
+
+/// Adds the forwarded amount to the pool along the `curve` after swapping part of it for the other asset
+/// and returns the updated pool and the minted liquidity
+pub fn zap<C>(
+    curve: C,
+    pool: PoolState,
+    min_liquidity: u64,
+    deadline: u64,
+) -> (PoolState, u64) where C: Curve {
+    reentrancy_guard();
+
+    require(deadline > height(), InputError::DeadlinePassed(deadline));
+
+    let mut pool = pool;
+    let reserves = pool.reserves;
+    let (mut input_asset, mut output_asset) = determine_assets(msg_asset_id(), Option::Some(reserves));
+
+    let input_amount = msg_amount();
+    require(input_amount > 0, InputError::ExpectedNonZeroAmount(input_asset.id));
+    // the ratio to add liquidity at is only known once the pool has liquidity
+    require(pool.liquidity > 0, TransactionError::InsufficientReserve(output_asset.id));
+
+    let zap = zap_amounts(curve, input_amount, input_asset.amount, output_asset.amount, pool.liquidity, pool.liquidity_miner_fee, pool.protocol_fee);
+
+    require(zap.liquidity > 0, TransactionError::DesiredAmountTooLow(input_amount));
+    require(min_liquidity <= zap.liquidity, TransactionError::DesiredAmountTooHigh(min_liquidity));
+
+    // accumulate the prices in effect until the reserves change
+    pool.price_observation = accumulate_prices(pool.price_observation, reserves, timestamp());
+
+    let protocol_fee = protocol_fee_amount(zap.swapped, pool.liquidity_miner_fee, pool.protocol_fee);
+    pool.accrued_protocol_fees = pool.accrued_protocol_fees + only(Asset::new(input_asset.id, protocol_fee), reserves);
+
+    input_asset.amount = input_asset.amount + zap.swapped - protocol_fee + zap.added_input;
+    output_asset.amount = output_asset.amount - zap.bought + zap.added_output;
+    pool.reserves = AssetPair::new(input_asset, output_asset).sort(reserves);
+
+    // mint liquidity pool asset and transfer to sender
+    let sender = msg_sender().unwrap();
+    mint(zap.liquidity);
+    pool.liquidity = pool.liquidity + zap.liquidity;
+    transfer(zap.liquidity, contract_id(), sender);
+
+    // transfer the amounts that could not be added at the ratio of the reserves back to the sender
+    let remaining = input_amount - zap.swapped;
+    if remaining > zap.added_input {
+        transfer(remaining - zap.added_input, input_asset.id, sender);
+    }
+
+    if zap.bought > zap.added_output {
+        transfer(zap.bought - zap.added_output, output_asset.id, sender);
+    }
+
+    log(ZapEvent {
+        input: Asset::new(input_asset.id, input_amount),
+        swapped: Asset::new(input_asset.id, zap.swapped),
+        added_assets: AssetPair::new(Asset::new(input_asset.id, zap.added_input), Asset::new(output_asset.id, zap.added_output)).sort(reserves),
+        liquidity: Asset::new(contract_id(), zap.liquidity),
+    });
+
+    (pool, zap.liquidity)
+}
+
+// Pairs `asset` with none of the other asset of the `reserves`
+fn only(asset: Asset, reserves: AssetPair) -> AssetPair {
+    AssetPair::new(asset, Asset::new(reserves.other_asset(asset.id).id, 0)).sort(reserves)
+}
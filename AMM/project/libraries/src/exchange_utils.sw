@@ -0,0 +1,302 @@
+library exchange_utils;
+
+dep curve;
+dep data_structures;
+dep exchange_errors;
+
+use core::primitives::*;
+use curve::Curve;
+use data_structures::{
+    Asset,
+    AssetPair,
+    PreviewAddLiquidityInfo,
+    PreviewSwapInfo,
+    PreviewZapInfo,
+    PriceObservation,
+    ProtocolFee,
+};
+use exchange_errors::{InitError, InputError, TransactionError};
+use std::{call_frames::contract_id, math::*, u128::U128};
+
+/// Denominator of the protocol fee share
+const BASIS_POINTS: u64 = 10_000;
+
+/// Largest share of the liquidity miner fee that the protocol may take, i.e., a third of it in basis points
+const MAX_PROTOCOL_FEE_SHARE: u64 = 3_333;
+
+/// Scale of the prices accumulated in a `PriceObservation`
+const PRICE_PRECISION: u64 = 1_000_000_000;
+
+/// Bound on the product of the liquidity miner fee and the amounts a zap is solved for, which keeps the discriminant within 128 bits
+const MAX_SCALED_ZAP_AMOUNT: u64 = 4_611_686_018_427_387_904;
+
+/// Amounts of a zap, i.e., a single-sided deposit part of which is swapped for the other asset before the rest is added as liquidity
+pub struct ZapAmounts {
+    /// Portion of the deposit that is swapped for the other asset
+    swapped: u64,
+    /// Amount of the other asset bought with the swapped portion
+    bought: u64,
+    /// Amount of the deposited asset added to the reserves after the swap
+    added_input: u64,
+    /// Amount of the bought asset added to the reserves after the swap
+    added_output: u64,
+    /// Amount of liquidity pool asset minted for the added amounts
+    liquidity: u64,
+}
+
+/// Returns `amount` less the liquidity miner fee, i.e., one in `liquidity_miner_fee` of the amount rounded down
+pub fn calculate_amount_with_fee(amount: u64, liquidity_miner_fee: u64) -> u64 {
+    let fee = (amount / liquidity_miner_fee);
+    amount - fee
+}
+
+/// Returns the observation with the prices implied by `reserves` accumulated from the time of the observation up to `timestamp`
+///
+/// Nothing is accumulated while either reserve is empty since there is no price
+pub fn accumulate_prices(observation: PriceObservation, reserves: AssetPair, timestamp: u64) -> PriceObservation {
+    let elapsed = timestamp - observation.timestamp;
+
+    if elapsed == 0 || reserves.a.amount == 0 || reserves.b.amount == 0 {
+        return PriceObservation {
+            price_a_cumulative: observation.price_a_cumulative,
+            price_b_cumulative: observation.price_b_cumulative,
+            timestamp,
+        };
+    }
+
+    let elapsed = U128::from((0, elapsed));
+    PriceObservation {
+        price_a_cumulative: observation.price_a_cumulative + price(reserves.a.amount, reserves.b.amount) * elapsed,
+        price_b_cumulative: observation.price_b_cumulative + price(reserves.b.amount, reserves.a.amount) * elapsed,
+        timestamp,
+    }
+}
+
+// Calculates the price of the base asset in the quote asset scaled by `PRICE_PRECISION`
+fn price(base_reserve: u64, quote_reserve: u64) -> U128 {
+    (U128::from((0, quote_reserve)) * U128::from((0, PRICE_PRECISION))) / U128::from((0, base_reserve))
+}
+
+/// Returns the amounts of the `deposits` that are added to the `reserves` and the amount of liquidity pool asset minted for them
+///
+/// The first deposits are used up entirely to set the ratio of the reserves, further deposits are added at the ratio of the
+/// reserves using up all of one of the deposits
+pub fn liquidity_to_add<C>(curve: C, deposits: AssetPair, reserves: AssetPair, total_liquidity: u64) -> (AssetPair, u64) where C: Curve {
+    if reserves.a.amount == 0 && reserves.b.amount == 0 {
+        return (deposits, curve.initial_liquidity(deposits.a.amount, deposits.b.amount));
+    }
+
+    // attempt to add liquidity by using up the deposited asset A amount
+    let b_to_attempt = proportional_value(deposits.a.amount, reserves.b.amount, reserves.a.amount);
+
+    // continue adding based on asset A if deposited asset B amount is sufficient
+    if b_to_attempt <= deposits.b.amount {
+        let added_liquidity = proportional_value(b_to_attempt, total_liquidity, reserves.b.amount);
+        (AssetPair::new(deposits.a, Asset::new(reserves.b.id, b_to_attempt)), added_liquidity)
+    } else { // attempt to add liquidity by using up the deposited asset B amount
+        let a_to_attempt = proportional_value(deposits.b.amount, reserves.a.amount, reserves.b.amount);
+        let added_liquidity = proportional_value(a_to_attempt, total_liquidity, reserves.a.amount);
+        (AssetPair::new(Asset::new(reserves.a.id, a_to_attempt), deposits.b), added_liquidity)
+    }
+}
+
+/// Returns the preview of adding `asset` along with the `deposits` of the sender as liquidity
+pub fn preview_add_liquidity<C>(
+    curve: C,
+    asset: Asset,
+    deposits: AssetPair,
+    reserves: AssetPair,
+    total_liquidity: u64,
+) -> PreviewAddLiquidityInfo where C: Curve {
+    let mut added_assets = AssetPair::new(Asset::new(reserves.a.id, 0), Asset::new(reserves.b.id, 0));
+    let mut added_liquidity = 0;
+
+    if total_liquidity == 0 {
+        added_assets.a.amount = if deposits.a.amount == 0 {
+            asset.amount
+        } else {
+            deposits.a.amount
+        };
+        added_assets.b.amount = if deposits.b.amount == 0 {
+            asset.amount
+        } else {
+            deposits.b.amount
+        };
+        added_liquidity = curve.initial_liquidity(added_assets.a.amount, added_assets.b.amount);
+    } else {
+        if asset.id == reserves.a.id {
+            added_assets.a.amount = asset.amount;
+            added_assets.b.amount = proportional_value(asset.amount, reserves.b.amount, reserves.a.amount);
+        } else {
+            added_assets.a.amount = proportional_value(asset.amount, reserves.a.amount, reserves.b.amount);
+            added_assets.b.amount = asset.amount;
+        }
+        added_liquidity = proportional_value(added_assets.b.amount, total_liquidity, reserves.b.amount);
+    }
+
+    PreviewAddLiquidityInfo {
+        other_asset_to_add: if asset.id == reserves.a.id {
+            added_assets.b
+        } else {
+            added_assets.a
+        },
+        liquidity_asset_to_receive: Asset::new(contract_id(), added_liquidity),
+    }
+}
+
+/// Returns the preview of selling exactly `exact_input_asset` to the pool of `pair`
+pub fn preview_swap_exact_input<C>(
+    curve: C,
+    exact_input_asset: Asset,
+    pair: Option<AssetPair>,
+    liquidity_miner_fee: u64,
+) -> PreviewSwapInfo where C: Curve {
+    let (input_reserve, output_reserve) = determine_assets(exact_input_asset.id, pair);
+
+    let output = curve.minimum_output_given_exact_input(exact_input_asset.amount, input_reserve.amount, output_reserve.amount, liquidity_miner_fee);
+
+    PreviewSwapInfo {
+        other_asset: Asset::new(output_reserve.id, output),
+        sufficient_reserve: output < output_reserve.amount,
+    }
+}
+
+/// Returns the preview of buying exactly `exact_output_asset` from the pool of `pair`
+pub fn preview_swap_exact_output<C>(
+    curve: C,
+    exact_output_asset: Asset,
+    pair: Option<AssetPair>,
+    liquidity_miner_fee: u64,
+) -> PreviewSwapInfo where C: Curve {
+    let (output_reserve, input_reserve) = determine_assets(exact_output_asset.id, pair);
+
+    require(exact_output_asset.amount < output_reserve.amount, TransactionError::DesiredAmountTooHigh(exact_output_asset.amount));
+
+    let input = curve.maximum_input_for_exact_output(exact_output_asset.amount, input_reserve.amount, output_reserve.amount, liquidity_miner_fee);
+    require(input > 0, TransactionError::DesiredAmountTooLow(exact_output_asset.amount));
+
+    PreviewSwapInfo {
+        other_asset: Asset::new(input_reserve.id, input),
+        sufficient_reserve: exact_output_asset.amount < output_reserve.amount,
+    }
+}
+
+/// Returns the preview of zapping `asset` into the pool of `pair`
+pub fn preview_zap<C>(
+    curve: C,
+    asset: Asset,
+    pair: Option<AssetPair>,
+    total_liquidity: u64,
+    liquidity_miner_fee: u64,
+    protocol_fee: Option<ProtocolFee>,
+) -> PreviewZapInfo where C: Curve {
+    let (input_reserve, output_reserve) = determine_assets(asset.id, pair);
+
+    require(total_liquidity > 0, TransactionError::InsufficientReserve(output_reserve.id));
+
+    let zap = zap_amounts(curve, asset.amount, input_reserve.amount, output_reserve.amount, total_liquidity, liquidity_miner_fee, protocol_fee);
+
+    PreviewZapInfo {
+        swapped_asset: Asset::new(asset.id, zap.swapped),
+        liquidity_asset_to_receive: Asset::new(contract_id(), zap.liquidity),
+        refund: AssetPair::new(Asset::new(input_reserve.id, asset.amount - zap.swapped - zap.added_input), Asset::new(output_reserve.id, zap.bought - zap.added_output)).sort(pair.unwrap()),
+    }
+}
+
+/// Returns the amounts of a zap of `input_amount` into a pool with the `input_reserve` and `output_reserve`
+///
+/// The protocol share of the liquidity miner fee charged on the swapped portion does not count towards the reserves the rest is added at
+pub fn zap_amounts<C>(
+    curve: C,
+    input_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    total_liquidity: u64,
+    liquidity_miner_fee: u64,
+    protocol_fee: Option<ProtocolFee>,
+) -> ZapAmounts where C: Curve {
+    let swapped = curve.zap_swap_amount(input_amount, input_reserve, output_reserve, liquidity_miner_fee);
+    let bought = curve.minimum_output_given_exact_input(swapped, input_reserve, output_reserve, liquidity_miner_fee);
+
+    let input_reserve = input_reserve + swapped - protocol_fee_amount(swapped, liquidity_miner_fee, protocol_fee);
+    let output_reserve = output_reserve - bought;
+
+    let (added_input, added_output) = amounts_at_ratio(input_amount - swapped, bought, input_reserve, output_reserve);
+
+    ZapAmounts {
+        swapped,
+        bought,
+        added_input,
+        added_output,
+        liquidity: proportional_value(added_output, total_liquidity, output_reserve),
+    }
+}
+
+/// Returns the portion of a single-sided deposit of `input_amount` to swap so that the rest of the deposit and the bought amount
+/// match the ratio of the reserves after the swap along the constant product curve
+///
+/// This is the closed-form solution of `(lmf - 1) * s^2 + (2 * lmf - 1) * r * s - lmf * r * a = 0` for the swapped amount `s`
+/// where `a` is the deposit, `r` is the input reserve and `lmf` is the liquidity miner fee
+///
+/// Every term is quadratic in the amounts, so large amounts are solved for at a common scale and the result is scaled back up
+pub fn optimal_swap_amount(input_amount: u64, input_reserve: u64, liquidity_miner_fee: u64) -> u64 {
+    let largest = if input_amount > input_reserve { input_amount } else { input_reserve };
+    let scale = largest / (MAX_SCALED_ZAP_AMOUNT / liquidity_miner_fee) + 1;
+
+    let one = U128::from((0, 1));
+    let two = U128::from((0, 2));
+    let fee = U128::from((0, liquidity_miner_fee));
+    let reserve = U128::from((0, input_reserve / scale));
+
+    let b = reserve * (fee * two - one);
+    let discriminant = b * b + two * two * (fee - one) * fee * reserve * U128::from((0, input_amount / scale));
+
+    ((discriminant.sqrt() - b) / (two * (fee - one))).as_u64().unwrap() * scale
+}
+
+/// Returns the amounts of `input_amount` and `output_amount` that can be added to the `input_reserve` and `output_reserve`
+/// without changing their ratio, using up all of one of the amounts
+pub fn amounts_at_ratio(
+    input_amount: u64,
+    output_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+) -> (u64, u64) {
+    let output_to_attempt = proportional_value(input_amount, output_reserve, input_reserve);
+
+    if output_to_attempt <= output_amount {
+        (input_amount, output_to_attempt)
+    } else {
+        (proportional_value(output_amount, input_reserve, output_reserve), output_amount)
+    }
+}
+
+/// Returns the share of the liquidity miner fee charged on `amount` that is taken by the `protocol_fee`, if any
+pub fn protocol_fee_amount(amount: u64, liquidity_miner_fee: u64, protocol_fee: Option<ProtocolFee>) -> u64 {
+    match protocol_fee {
+        Option::Some(protocol_fee) => proportional_value(amount / liquidity_miner_fee, protocol_fee.share, BASIS_POINTS),
+        Option::None => 0,
+    }
+}
+
+/// Whether the protocol fee share, in basis points, leaves at least two thirds of the liquidity miner fee to the liquidity providers
+pub fn valid_protocol_fee_share(protocol_fee_share: u64) -> bool {
+    protocol_fee_share <= MAX_PROTOCOL_FEE_SHARE
+}
+
+// Calculates d in the proportion a / b = c / d
+pub fn proportional_value(b: u64, c: u64, a: u64) -> u64 {
+    let calculation = (U128::from((0, b)) * U128::from((0, c)));
+    let result_wrapped = (calculation / U128::from((0, a))).as_u64();
+    result_wrapped.unwrap()
+}
+
+pub fn determine_assets(input_asset_id: ContractId, pair: Option<AssetPair>) -> (Asset, Asset) {
+    require(pair.is_some(), InitError::AssetPairNotSet);
+    let pair = pair.unwrap();
+    require(input_asset_id == pair.a.id || input_asset_id == pair.b.id, InputError::InvalidAsset);
+    (
+        pair.this_asset(input_asset_id),
+        pair.other_asset(input_asset_id),
+    )
+}
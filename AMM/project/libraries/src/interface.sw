@@ -1,18 +1,31 @@
 library interface;
 
+dep curve;
 dep data_structures;
+dep exchange_errors;
+dep exchange_events;
+dep exchange_functions;
+dep exchange_utils;
 
 use data_structures::{
     Asset,
+    AssetPair,
     PoolInfo,
     PreviewAddLiquidityInfo,
     PreviewSwapInfo,
+    PreviewZapInfo,
+    PriceObservation,
+    ProtocolFee,
+    ProtocolFeeInfo,
+    RegisteredPool,
     RemoveLiquidityInfo,
 };
 
 abi AMM {
     /// Initialize the AMM by specifying the exchange contract bytecode root, for security.
     ///
+    /// The sender becomes the owner of the AMM who may approve further exchange contract implementations.
+    ///
     /// # Arguments
     ///
     /// - `exchange_bytecode_root` - bytecode root of the intended implementation of the exchange ABI
@@ -23,7 +36,39 @@ abi AMM {
     #[storage(read, write)]
     fn initialize(exchange_bytecode_root: ContractId);
 
-    /// Add an (asset pair, exchange contract ID) mapping to the storage.
+    /// Approve another implementation of the exchange ABI, e.g., a pool with a different curve.
+    ///
+    /// # Arguments
+    ///
+    /// - `exchange_bytecode_root` - bytecode root of the additional implementation of the exchange ABI
+    ///
+    /// # Reverts
+    ///
+    /// * When the AMM contract has not been initialized
+    /// * When the sender is not the owner
+    #[storage(read, write)]
+    fn add_exchange_bytecode_root(exchange_bytecode_root: ContractId);
+
+    /// Revoke the approval of an implementation of the exchange ABI, e.g., once it has been superseded by an upgrade.
+    ///
+    /// Pools that have already been added with the implementation remain registered.
+    ///
+    /// # Arguments
+    ///
+    /// - `exchange_bytecode_root` - bytecode root of the approved implementation of the exchange ABI
+    ///
+    /// # Reverts
+    ///
+    /// * When the AMM contract has not been initialized
+    /// * When the sender is not the owner
+    /// * When `exchange_bytecode_root` is not approved
+    #[storage(read, write)]
+    fn remove_exchange_bytecode_root(exchange_bytecode_root: ContractId);
+
+    /// Add an ((asset pair, fee tier), exchange contract ID) mapping to the storage.
+    ///
+    /// The fee tier is the liquidity miner fee of the exchange contract.
+    /// A pool that is added for an asset pair and fee tier which already has a pool replaces it at the same index.
     ///
     /// # Arguments
     ///
@@ -33,18 +78,57 @@ abi AMM {
     /// # Reverts
     ///
     /// * When the AMM contract has not been initialized
-    /// * When the bytecode root of `pool` does not match the bytecode root of the intended exchange contract
+    /// * When the bytecode root of `pool` does not match the bytecode root of an approved exchange contract
     /// * When the pool info of the exchange contract with the given address does not consist of the given asset pair
+    /// * When the AMM contract is not the owner of the exchange contract, see `transfer_ownership` of the exchange ABI
+    /// * When a pool of another approved implementation is already registered for the asset pair and fee tier
     #[storage(read, write)]
     fn add_pool(asset_pair: (ContractId, ContractId), pool: ContractId);
 
-    /// For the given asset pair, get the exchange contract, i.e., the pool that consists of the asset pair.
+    /// Set the protocol fee of a pool owned by the AMM contract, so that the owner of the AMM controls the protocol fees of all of its pools.
+    ///
+    /// # Arguments
+    ///
+    /// - `pool` - exchange contract whose protocol fee to set
+    /// - `protocol_fee` - the new protocol fee, or `None` to stop taking a share of the liquidity miner fee
+    ///
+    /// # Reverts
+    ///
+    /// * When the AMM contract has not been initialized
+    /// * When the sender is not the owner
+    /// * When the AMM contract is not the owner of `pool`
+    /// * When the share is more than 3,333 basis points, i.e., a third of the liquidity miner fee
+    #[storage(read)]
+    fn set_protocol_fee(pool: ContractId, protocol_fee: Option<ProtocolFee>);
+
+    /// For the given asset pair and fee tier, get the exchange contract, i.e., the pool that consists of the asset pair.
     ///
     /// # Arguments
     ///
     /// - `asset_pair` - pair of assets that make up the pool
+    /// - `liquidity_miner_fee` - fee tier of the pool
+    #[storage(read)]
+    fn pool(asset_pair: (ContractId, ContractId), liquidity_miner_fee: u64) -> Option<ContractId>;
+
+    /// Get the pool at `index` in the order that asset pairs and fee tiers were first added.
+    ///
+    /// # Arguments
+    ///
+    /// - `index` - position of the pool, less than the pool count
+    #[storage(read)]
+    fn pool_at(index: u64) -> Option<RegisteredPool>;
+
+    /// Get the number of asset pair and fee tier combinations that have a pool.
+    #[storage(read)]
+    fn pool_count() -> u64;
+
+    /// Whether pools may be added with the implementation of the exchange ABI with the given bytecode root.
+    ///
+    /// # Arguments
+    ///
+    /// - `exchange_bytecode_root` - bytecode root of an implementation of the exchange ABI
     #[storage(read)]
-    fn pool(asset_pair: (ContractId, ContractId)) -> Option<ContractId>;
+    fn is_approved_exchange_bytecode_root(exchange_bytecode_root: ContractId) -> bool;
 }
 
 abi Exchange {
@@ -69,19 +153,23 @@ abi Exchange {
     #[storage(read, write)]
     fn add_liquidity(desired_liquidity: u64, deadline: u64) -> u64;
 
-    /// Initialize contract by specifying the asset pair that makes up the pool.
+    /// Initialize contract by specifying the asset pair that makes up the pool and the fee tier of the pool.
+    ///
+    /// The sender becomes the owner that controls the protocol fee until it transfers ownership, e.g., to the AMM contract to register the pool.
     ///
     /// # Arguments
     ///
     /// - `asset_a` - unique identifier of one asset
     /// - `asset_b` - unique identifier of the other asset
+    /// - `liquidity_miner_fee` - one in `liquidity_miner_fee` of every swapped input amount is charged as a fee, e.g., 333 for ~0.3%
     ///
     /// # Reverts
     ///
     /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
     /// * When the passed pair describes identical assets
+    /// * When `liquidity_miner_fee` is less than 2
     #[storage(read, write)]
-    fn constructor(asset_a: ContractId, asset_b: ContractId);
+    fn constructor(asset_a: ContractId, asset_b: ContractId, liquidity_miner_fee: u64);
 
     /// Deposit asset to later add to the liquidity pool or withdraw.
     ///
@@ -92,6 +180,29 @@ abi Exchange {
     #[payable, storage(read, write)]
     fn deposit();
 
+    /// Lend `output` to the `borrower` contract and call it back, requiring the pool to be repaid in either asset by the end of the call.
+    ///
+    /// The borrower repays by transferring assets to the exchange contract in `on_flash_swap` of the `FlashSwapCallee` ABI.
+    /// The product of the reserves after repayment, excluding the liquidity miner fee charged on the repaid amounts, must not decrease.
+    /// Functions that move assets in or out of the contract cannot be called while the borrower is called back.
+    ///
+    /// # Arguments
+    ///
+    /// - `output` - identifier and amount of the asset to lend
+    /// - `borrower` - contract that receives `output` and implements the `FlashSwapCallee` ABI
+    /// - `deadline` - limit on block height for operation
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    /// * When `output` does not identify asset A or asset B
+    /// * When the current block height is not less than `deadline`
+    /// * When the amount of `output` is 0
+    /// * When the amount of `output` is not less than its reserve
+    /// * When the repaid amounts do not preserve the product of the reserves after the liquidity miner fee
+    #[storage(read, write)]
+    fn flash_swap(output: Asset, borrower: ContractId, deadline: u64) -> AssetPair;
+
     /// Burn liquidity pool asset at current ratio and transfer asset A and asset B to the sender.
     ///
     /// # Arguments
@@ -112,8 +223,26 @@ abi Exchange {
     #[payable, storage(read, write)]
     fn remove_liquidity(min_asset_a: u64, min_asset_b: u64, deadline: u64) -> RemoveLiquidityInfo;
 
+    /// Set the share of the liquidity miner fee that is taken by the protocol and the recipient of the protocol fees.
+    ///
+    /// Fees that have already accrued are not affected and are withdrawn to the latest recipient.
+    ///
+    /// # Arguments
+    ///
+    /// - `protocol_fee` - the new protocol fee, or `None` to stop taking a share of the liquidity miner fee
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    /// * When the sender is not the owner
+    /// * When the share is more than 3,333 basis points, i.e., a third of the liquidity miner fee
+    #[storage(read, write)]
+    fn set_protocol_fee(protocol_fee: Option<ProtocolFee>);
+
     /// Swap forwarded amount of forwarded asset for other asset and transfer to sender.
     ///
+    /// When there is a protocol fee, its share of the liquidity miner fee is set aside instead of being added to the reserves.
+    ///
     /// # Arguments
     ///
     /// - `min_output` - minimum output required (to protect against excessive slippage)
@@ -132,6 +261,7 @@ abi Exchange {
     /// Swap forwarded asset for `exact_output_amount` of other asset and transfer to sender.
     ///
     /// Refund any extra input amount.
+    /// When there is a protocol fee, its share of the liquidity miner fee is set aside instead of being added to the reserves.
     ///
     /// # Arguments
     ///
@@ -149,6 +279,28 @@ abi Exchange {
     #[payable, storage(read, write)]
     fn swap_exact_output(output: u64, deadline: u64) -> u64;
 
+    /// Hand control of the protocol fee to `new_owner`.
+    ///
+    /// # Arguments
+    ///
+    /// - `new_owner` - the identity that controls the protocol fee from now on
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    /// * When the sender is not the owner
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity);
+
+    /// Transfer the accrued protocol fees to the recipient of the protocol fee.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    /// * When there is no protocol fee, i.e., there is no recipient
+    #[storage(read, write)]
+    fn withdraw_protocol_fees() -> AssetPair;
+
     /// Withdraw coins that have not been added to a liquidity pool yet.
     ///
     /// # Arguments
@@ -163,6 +315,29 @@ abi Exchange {
     #[storage(read, write)]
     fn withdraw(asset: Asset);
 
+    /// Add liquidity with a single asset by swapping part of it for the other asset of the pool.
+    ///
+    /// The swapped portion is chosen so that the rest of the forwarded asset and the bought asset match the ratio of the reserves after the swap.
+    /// The liquidity pool asset is minted and transferred to the sender along with any amounts that could not be added.
+    /// When there is a protocol fee, its share of the liquidity miner fee charged on the swap is set aside instead of being added to the reserves.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_liquidity` - minimum amount of liquidity pool asset to receive
+    /// - `deadline` - limit on block height for operation
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    /// * When the `msg_asset_id` does not identify asset A or asset B
+    /// * When the current block height is not less than `deadline`
+    /// * When the `msg_amount` with function call is 0
+    /// * When the pool has no liquidity to determine the ratio with
+    /// * When the forwarded amount is too small to add any liquidity
+    /// * When the liquidity to receive is less than `min_liquidity`
+    #[payable, storage(read, write)]
+    fn zap(min_liquidity: u64, deadline: u64) -> u64;
+
     /// Get current balance of the sender for a given asset on the contract.
     ///
     /// # Arguments
@@ -182,7 +357,8 @@ abi Exchange {
     /// - Identifier of asset B,
     /// - Asset A amount in reserves,
     /// - Asset B amount in reserves,
-    /// - Liquidity pool asset supply amount.
+    /// - Liquidity pool asset supply amount,
+    /// - Liquidity miner fee of the pool.
     ///
     /// # Reverts
     ///
@@ -190,6 +366,25 @@ abi Exchange {
     #[storage(read)]
     fn pool_info() -> PoolInfo;
 
+    /// Get the accrued protocol fees, the owner and the current protocol fee.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    #[storage(read)]
+    fn protocol_fee_info() -> ProtocolFeeInfo;
+
+    /// Get the cumulative prices of the pool assets as of the current block.
+    ///
+    /// The time-weighted average price over a window is the difference between the cumulative prices of two observations
+    /// divided by the difference between their timestamps. Prices are scaled by 10^9.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    #[storage(read)]
+    fn price_observation() -> PriceObservation;
+
     /// Get the preview info of adding liquidity.
     ///
     /// The preview info consists of:
@@ -238,4 +433,41 @@ abi Exchange {
     /// * When the `exact_output` is less than the reserve amount of the output asset
     #[storage(read)]
     fn preview_swap_exact_output(exact_output_asset: Asset) -> PreviewSwapInfo;
+
+    /// Get information about a `zap` without adding liquidity.
+    ///
+    /// The preview info while zapping `asset` consists of:
+    /// - The portion of the asset that is swapped for the other asset,
+    /// - Liquidity pool asset amount to be received,
+    /// - The amounts of both assets that are refunded.
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - id and amount of asset to add
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract has not been initialized, i.e., asset pair in storage is `None`
+    /// * When `asset` does not identify asset A or asset B
+    /// * When the pool has no liquidity to determine the ratio with
+    #[storage(read)]
+    fn preview_zap(asset: Asset) -> PreviewZapInfo;
+}
+
+abi FlashSwapCallee {
+    /// Called by an exchange contract after it transferred the borrowed asset in a flash swap.
+    ///
+    /// The exchange contract is the `msg_sender` of the call and must be repaid before the call returns.
+    ///
+    /// # Arguments
+    ///
+    /// - `sender` - the identity that called `flash_swap`
+    /// - `borrowed` - identifier and amount of the asset that was transferred to the borrower
+    #[storage(read, write)]
+    fn on_flash_swap(sender: Identity, borrowed: Asset);
+}
+
+abi StableExchange {
+    /// Get the amplification coefficient of the StableSwap invariant of the pool.
+    fn amplification_coefficient() -> u64;
 }
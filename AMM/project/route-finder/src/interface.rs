@@ -0,0 +1,16 @@
+use fuels::prelude::abigen;
+
+abigen!(
+    Contract(
+        name = "AMM",
+        abi = "./contracts/AMM-contract/out/debug/AMM-contract-abi.json"
+    ),
+    Contract(
+        name = "Exchange",
+        abi = "./contracts/exchange-contract/out/debug/exchange-contract-abi.json"
+    ),
+    Contract(
+        name = "StableExchange",
+        abi = "./contracts/stable-exchange-contract/out/debug/stable-exchange-contract-abi.json"
+    )
+);
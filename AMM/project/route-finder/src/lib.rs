@@ -0,0 +1,4 @@
+pub mod interface;
+pub mod math;
+pub mod pools;
+pub mod routes;
@@ -0,0 +1,199 @@
+/// Largest reserve the StableSwap invariant is approximated on, `MAX_SCALED_RESERVE` in `stable_swap.sw`
+const MAX_SCALED_RESERVE: u64 = 1 << 48;
+/// Upper bound on the iterations of the approximations, `MAX_ITERATIONS` in `stable_swap.sw`
+const MAX_ITERATIONS: usize = 255;
+
+fn calculate_amount_with_fee(amount: u64, liquidity_miner_fee: u64) -> u64 {
+    let fee = amount / liquidity_miner_fee;
+    amount - fee
+}
+
+/// Returns the maximum required amount of the input asset to get exactly `output_amount` of the output asset
+///
+/// Mirrors the exchange contract and returns `None` where the contract would revert
+pub fn maximum_input_for_exact_output(
+    output_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> Option<u64> {
+    if input_reserve == 0 || output_reserve == 0 || output_amount > output_reserve {
+        return None;
+    }
+
+    let numerator = input_reserve as u128 * output_amount as u128;
+    let denominator =
+        calculate_amount_with_fee(output_reserve - output_amount, liquidity_miner_fee) as u128;
+    if denominator == 0 {
+        return None;
+    }
+
+    if denominator > numerator {
+        // 0 < result < 1, round the result down since there are no floating points
+        Some(0)
+    } else {
+        u64::try_from(numerator / denominator).ok()?.checked_add(1)
+    }
+}
+
+/// Given exactly `input_amount` of the input asset, returns the minimum resulting amount of the output asset
+///
+/// Mirrors the exchange contract and returns `None` where the contract would revert
+pub fn minimum_output_given_exact_input(
+    input_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> Option<u64> {
+    if input_reserve == 0 || output_reserve == 0 {
+        return None;
+    }
+
+    let input_amount_with_fee =
+        calculate_amount_with_fee(input_amount, liquidity_miner_fee) as u128;
+    let numerator = input_amount_with_fee * output_reserve as u128;
+    let denominator = input_reserve as u128 + input_amount_with_fee;
+    u64::try_from(numerator / denominator).ok()
+}
+
+/// Returns the maximum required amount of the input asset to get exactly `output_amount` of the output asset
+/// from a pool along the StableSwap invariant with the `amplification` coefficient
+///
+/// Mirrors the stable exchange contract and returns `None` where the contract would revert
+pub fn stable_maximum_input_for_exact_output(
+    output_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+    amplification: u64,
+) -> Option<u64> {
+    if input_reserve == 0 || output_reserve == 0 || output_amount >= output_reserve {
+        return None;
+    }
+
+    let scale = scale(input_reserve.max(output_reserve));
+    let d = invariant(
+        divide_up(input_reserve, scale),
+        divide_up(output_reserve, scale),
+        amplification,
+    )?;
+
+    // round up in favour of the pool
+    let new_input_reserve =
+        other_reserve((output_reserve - output_amount) / scale, d, amplification)?
+            .checked_add(1)?
+            .checked_mul(scale)?;
+    let input_amount_with_fee = if new_input_reserve > input_reserve {
+        new_input_reserve - input_reserve
+    } else {
+        1
+    };
+
+    let input_amount = input_amount_with_fee as u128 * liquidity_miner_fee as u128
+        / (liquidity_miner_fee as u128 - 1);
+    u64::try_from(input_amount).ok()?.checked_add(1)
+}
+
+/// Given exactly `input_amount` of the input asset, returns the minimum resulting amount of the output asset
+/// from a pool along the StableSwap invariant with the `amplification` coefficient
+///
+/// Mirrors the stable exchange contract and returns `None` where the contract would revert
+pub fn stable_minimum_output_given_exact_input(
+    input_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+    amplification: u64,
+) -> Option<u64> {
+    if input_reserve == 0 || output_reserve == 0 {
+        return None;
+    }
+
+    let new_input_reserve =
+        input_reserve.checked_add(calculate_amount_with_fee(input_amount, liquidity_miner_fee))?;
+    let scale = scale(new_input_reserve.max(output_reserve));
+    let d = invariant(
+        divide_up(input_reserve, scale),
+        divide_up(output_reserve, scale),
+        amplification,
+    )?;
+
+    // round down in favour of the pool
+    let new_output_reserve = other_reserve(new_input_reserve / scale, d, amplification)?
+        .checked_add(1)?
+        .checked_mul(scale)?;
+    Some(output_reserve.saturating_sub(new_output_reserve))
+}
+
+fn divide_up(amount: u64, divisor: u64) -> u64 {
+    amount / divisor + u64::from(amount % divisor > 0)
+}
+
+// Approximates the invariant D of the reserves with Newton's method
+fn invariant(reserve_a: u64, reserve_b: u64, amplification: u64) -> Option<u128> {
+    if reserve_a == 0 || reserve_b == 0 {
+        return Some(0);
+    }
+
+    let (x, y) = (reserve_a as u128, reserve_b as u128);
+    let ann = amplification as u128 * 4;
+    let sum = x + y;
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        let d_p = multiply_divide(d.checked_mul(d)? / (x * 2), d, y * 2)?;
+        let previous = d;
+        d = multiply_divide(
+            ann.checked_mul(sum)?.checked_add(d_p.checked_mul(2)?)?,
+            d,
+            (ann - 1).checked_mul(d)?.checked_add(d_p.checked_mul(3)?)?,
+        )?;
+
+        if d.abs_diff(previous) <= 1 {
+            break;
+        }
+    }
+    Some(d)
+}
+
+// Computes `a * b / c` like the stable exchange contract, which shifts the remainder of `a / c` and
+// `c` right until their product fits
+fn multiply_divide(a: u128, b: u128, c: u128) -> Option<u128> {
+    let quotient = a / c;
+    let (mut remainder, mut divisor) = (a - quotient * c, c);
+    while (128 - remainder.leading_zeros()) + (128 - b.leading_zeros()) > 128 {
+        remainder >>= 1;
+        divisor >>= 1;
+    }
+    quotient
+        .checked_mul(b)?
+        .checked_add(remainder * b / divisor)
+}
+
+// Approximates the reserve of the other asset that keeps the invariant `d` given the `reserve` of one asset
+fn other_reserve(reserve: u64, d: u128, amplification: u64) -> Option<u64> {
+    if reserve == 0 {
+        return None;
+    }
+
+    let x = reserve as u128;
+    let ann = amplification as u128 * 4;
+    let c = multiply_divide(d.checked_mul(d)? / (x * 2), d, ann * 2)?;
+    let b = x + d / ann;
+
+    let mut y = d;
+    for _ in 0..MAX_ITERATIONS {
+        let previous = y;
+        y = y.checked_mul(y)?.checked_add(c)? / (y * 2 + b).checked_sub(d)?;
+
+        if y.abs_diff(previous) <= 1 {
+            break;
+        }
+    }
+    u64::try_from(y).ok()
+}
+
+// Returns the divisor that brings the `largest` amount within `MAX_SCALED_RESERVE`
+fn scale(largest: u64) -> u64 {
+    largest / MAX_SCALED_RESERVE + 1
+}
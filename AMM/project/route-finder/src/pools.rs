@@ -0,0 +1,185 @@
+use crate::{
+    interface::{Exchange, StableExchange, AMM},
+    math::{
+        maximum_input_for_exact_output, minimum_output_given_exact_input,
+        stable_maximum_input_for_exact_output, stable_minimum_output_given_exact_input,
+    },
+};
+use fuels::{
+    prelude::{Bech32ContractId, ContractId, WalletUnlocked},
+    types::errors::Error,
+};
+
+/// Curve along which an exchange contract prices swaps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Curve {
+    /// The constant product `x * y = k` of the exchange contract
+    ConstantProduct,
+    /// The StableSwap invariant of the stable exchange contract with its amplification coefficient
+    StableSwap { amplification: u64 },
+}
+
+/// Liquidity pool of an exchange contract and its reserves
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pool {
+    /// Exchange contract that manages the pool
+    pub id: ContractId,
+    /// Fee tier of the pool, one in `liquidity_miner_fee` of the input amount is charged as a fee
+    pub liquidity_miner_fee: u64,
+    /// Curve along which the exchange contract prices swaps
+    pub curve: Curve,
+    /// Identifiers and reserve amounts of the assets that make up the pool
+    pub reserves: ((ContractId, u64), (ContractId, u64)),
+}
+
+impl Pool {
+    pub fn new(
+        id: ContractId,
+        liquidity_miner_fee: u64,
+        reserves: ((ContractId, u64), (ContractId, u64)),
+    ) -> Self {
+        Self {
+            id,
+            liquidity_miner_fee,
+            curve: Curve::ConstantProduct,
+            reserves,
+        }
+    }
+
+    /// Prices swaps along `curve` rather than the constant product
+    pub fn with_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Reads the fee tier, curve and reserves of the pool managed by the exchange contract `id`
+    pub async fn load(wallet: &WalletUnlocked, id: ContractId) -> Result<Self, Error> {
+        let exchange = Exchange::new(Bech32ContractId::from(id), wallet.clone());
+        let pool_info = exchange.methods().pool_info().simulate().await?.value;
+        let reserves = pool_info.reserves;
+
+        let pool = Self::new(
+            id,
+            pool_info.liquidity_miner_fee,
+            (
+                (reserves.a.id, reserves.a.amount),
+                (reserves.b.id, reserves.b.amount),
+            ),
+        );
+
+        // only the stable exchange contract has an amplification coefficient, other exchange contracts revert
+        let stable_exchange = StableExchange::new(Bech32ContractId::from(id), wallet.clone());
+        match stable_exchange
+            .methods()
+            .amplification_coefficient()
+            .simulate()
+            .await
+        {
+            Ok(response) => Ok(pool.with_curve(Curve::StableSwap {
+                amplification: response.value,
+            })),
+            Err(Error::RevertTransactionError { .. }) => Ok(pool),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Identifiers of the assets that make up the pool
+    pub fn assets(&self) -> (ContractId, ContractId) {
+        (self.reserves.0 .0, self.reserves.1 .0)
+    }
+
+    /// Returns the other asset of the pool, or `None` when `asset` is not part of the pool
+    pub fn other_asset(&self, asset: ContractId) -> Option<ContractId> {
+        self.input_output_reserves(asset)
+            .map(|((_, _), (output_asset, _))| output_asset)
+    }
+
+    /// Amount of the other asset received when swapping exactly `input_amount` of `input_asset`
+    pub fn output_for_exact_input(
+        &self,
+        input_asset: ContractId,
+        input_amount: u64,
+    ) -> Option<u64> {
+        let ((_, input_reserve), (_, output_reserve)) = self.input_output_reserves(input_asset)?;
+        match self.curve {
+            Curve::ConstantProduct => minimum_output_given_exact_input(
+                input_amount,
+                input_reserve,
+                output_reserve,
+                self.liquidity_miner_fee,
+            ),
+            Curve::StableSwap { amplification } => stable_minimum_output_given_exact_input(
+                input_amount,
+                input_reserve,
+                output_reserve,
+                self.liquidity_miner_fee,
+                amplification,
+            ),
+        }
+    }
+
+    /// Amount of the other asset sold when swapping for exactly `output_amount` of `output_asset`
+    ///
+    /// Returns `None` when the reserves are insufficient or the exchange would refuse the swap
+    pub fn input_for_exact_output(
+        &self,
+        output_asset: ContractId,
+        output_amount: u64,
+    ) -> Option<u64> {
+        let ((_, output_reserve), (_, input_reserve)) = self.input_output_reserves(output_asset)?;
+        match self.curve {
+            Curve::ConstantProduct => maximum_input_for_exact_output(
+                output_amount,
+                input_reserve,
+                output_reserve,
+                self.liquidity_miner_fee,
+            ),
+            Curve::StableSwap { amplification } => stable_maximum_input_for_exact_output(
+                output_amount,
+                input_reserve,
+                output_reserve,
+                self.liquidity_miner_fee,
+                amplification,
+            ),
+        }
+        .filter(|input_amount| *input_amount > 0)
+    }
+
+    // Orders the reserves so that the reserve of `asset` comes first
+    fn input_output_reserves(
+        &self,
+        asset: ContractId,
+    ) -> Option<((ContractId, u64), (ContractId, u64))> {
+        let (a, b) = self.reserves;
+        if asset == a.0 {
+            Some((a, b))
+        } else if asset == b.0 {
+            Some((b, a))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the exchange contract of every asset pair and fee tier registered in the AMM, in the
+/// order they were first added
+pub async fn registered_pools(amm: &AMM) -> Result<Vec<ContractId>, Error> {
+    let pool_count = amm.methods().pool_count().simulate().await?.value;
+
+    let mut pools = Vec::with_capacity(pool_count as usize);
+    for index in 0..pool_count {
+        if let Some(registered_pool) = amm.methods().pool_at(index).simulate().await?.value {
+            pools.push(registered_pool.pool);
+        }
+    }
+    Ok(pools)
+}
+
+/// Reads the reserves of every pool in `ids`
+pub async fn load_pools(wallet: &WalletUnlocked, ids: &[ContractId]) -> Result<Vec<Pool>, Error> {
+    let mut pools = Vec::with_capacity(ids.len());
+    for id in ids {
+        pools.push(Pool::load(wallet, *id).await?);
+    }
+    Ok(pools)
+}
@@ -0,0 +1,163 @@
+use crate::pools::{Curve, Pool};
+use fuels::prelude::ContractId;
+
+/// Default maximum number of pools a route may swap through
+pub const DEFAULT_MAX_HOPS: usize = 3;
+
+/// Sequence of swaps from the first asset of the route to its last asset
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// Assets of the route in the order they are swapped, as expected by the swap scripts
+    pub assets: Vec<ContractId>,
+    /// Fee tiers of the pools swapped through, as expected by the swap scripts
+    pub fee_tiers: Vec<u64>,
+    /// Curves of the pools swapped through
+    pub curves: Vec<Curve>,
+    /// Amount of the first asset sold
+    pub input_amount: u64,
+    /// Amount of the last asset bought
+    pub output_amount: u64,
+    /// Exchange contracts swapped through, one for every consecutive pair of assets
+    pub pools: Vec<ContractId>,
+}
+
+/// Finds the most favourable route between two assets over a set of pools
+///
+/// Every route without repeated assets of at most `max_hops` pools is simulated with the math of
+/// the curve of each pool.
+#[derive(Clone, Debug)]
+pub struct RouteFinder {
+    // Maximum number of pools a route may swap through
+    max_hops: usize,
+    // Pools which routes may swap through
+    pools: Vec<Pool>,
+}
+
+impl RouteFinder {
+    pub fn new(pools: Vec<Pool>) -> Self {
+        Self {
+            max_hops: DEFAULT_MAX_HOPS,
+            pools,
+        }
+    }
+
+    /// Limits routes to at most `max_hops` pools
+    pub fn with_max_hops(mut self, max_hops: usize) -> Self {
+        self.max_hops = max_hops;
+        self
+    }
+
+    /// Returns every route from `input_asset` to `output_asset` as the pools swapped through
+    pub fn paths(&self, input_asset: ContractId, output_asset: ContractId) -> Vec<Vec<&Pool>> {
+        let mut paths = vec![];
+        if input_asset != output_asset {
+            self.visit(
+                &mut vec![input_asset],
+                &mut vec![],
+                output_asset,
+                &mut paths,
+            );
+        }
+        paths
+    }
+
+    /// Returns the route that buys the most `output_asset` for exactly `input_amount` of `input_asset`
+    ///
+    /// Returns `None` when no route results in a positive output amount
+    pub fn best_exact_input(
+        &self,
+        input_asset: ContractId,
+        output_asset: ContractId,
+        input_amount: u64,
+    ) -> Option<Route> {
+        self.paths(input_asset, output_asset)
+            .into_iter()
+            .filter_map(|path| {
+                let mut assets = vec![input_asset];
+                let mut amount = input_amount;
+                for pool in &path {
+                    let asset = *assets.last().unwrap();
+                    amount = pool.output_for_exact_input(asset, amount)?;
+                    assets.push(pool.other_asset(asset)?);
+                }
+                (amount > 0).then(|| route(assets, input_amount, amount, &path))
+            })
+            .max_by(|a, b| {
+                a.output_amount
+                    .cmp(&b.output_amount)
+                    .then(b.pools.len().cmp(&a.pools.len()))
+            })
+    }
+
+    /// Returns the route that sells the least `input_asset` for exactly `output_amount` of `output_asset`
+    ///
+    /// Returns `None` when the reserves of every route are insufficient
+    pub fn best_exact_output(
+        &self,
+        input_asset: ContractId,
+        output_asset: ContractId,
+        output_amount: u64,
+    ) -> Option<Route> {
+        self.paths(input_asset, output_asset)
+            .into_iter()
+            .filter_map(|path| {
+                // Walk the route backwards like the `swap-exact-output` script
+                let mut assets = vec![output_asset];
+                let mut amount = output_amount;
+                for pool in path.iter().rev() {
+                    let asset = *assets.last().unwrap();
+                    amount = pool.input_for_exact_output(asset, amount)?;
+                    assets.push(pool.other_asset(asset)?);
+                }
+                assets.reverse();
+                Some(route(assets, amount, output_amount, &path))
+            })
+            .min_by(|a, b| {
+                a.input_amount
+                    .cmp(&b.input_amount)
+                    .then(a.pools.len().cmp(&b.pools.len()))
+            })
+    }
+
+    // Depth-first search of the paths which do not visit an asset twice
+    fn visit<'a>(
+        &'a self,
+        assets: &mut Vec<ContractId>,
+        path: &mut Vec<&'a Pool>,
+        output_asset: ContractId,
+        paths: &mut Vec<Vec<&'a Pool>>,
+    ) {
+        if path.len() == self.max_hops {
+            return;
+        }
+
+        let asset = *assets.last().unwrap();
+        for pool in &self.pools {
+            let next_asset = match pool.other_asset(asset) {
+                Some(next_asset) if !assets.contains(&next_asset) => next_asset,
+                _ => continue,
+            };
+
+            path.push(pool);
+            if next_asset == output_asset {
+                paths.push(path.clone());
+            } else {
+                assets.push(next_asset);
+                self.visit(assets, path, output_asset, paths);
+                assets.pop();
+            }
+            path.pop();
+        }
+    }
+}
+
+fn route(assets: Vec<ContractId>, input_amount: u64, output_amount: u64, path: &[&Pool]) -> Route {
+    Route {
+        assets,
+        fee_tiers: path.iter().map(|pool| pool.liquidity_miner_fee).collect(),
+        curves: path.iter().map(|pool| pool.curve).collect(),
+        input_amount,
+        output_amount,
+        pools: path.iter().map(|pool| pool.id).collect(),
+    }
+}
@@ -0,0 +1,150 @@
+use route_finder::math::{
+    maximum_input_for_exact_output, minimum_output_given_exact_input,
+    stable_maximum_input_for_exact_output, stable_minimum_output_given_exact_input,
+};
+use test_utils::data_structures::LIQUIDITY_MINER_FEE;
+
+mod success {
+    use super::*;
+
+    #[test]
+    fn calculates_output_for_exact_input() {
+        assert_eq!(
+            minimum_output_given_exact_input(1_000, 100_000, 100_000, LIQUIDITY_MINER_FEE),
+            Some(987)
+        );
+        assert_eq!(
+            minimum_output_given_exact_input(1_000, 100_000, 200_000, LIQUIDITY_MINER_FEE),
+            Some(1_974)
+        );
+    }
+
+    #[test]
+    fn calculates_input_for_exact_output() {
+        assert_eq!(
+            maximum_input_for_exact_output(987, 100_000, 100_000, LIQUIDITY_MINER_FEE),
+            Some(1_000)
+        );
+    }
+
+    #[test]
+    fn rounds_fractional_input_down() {
+        assert_eq!(
+            maximum_input_for_exact_output(1, 1, 1_000_000, LIQUIDITY_MINER_FEE),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn does_not_overflow_with_large_reserves() {
+        assert_eq!(
+            minimum_output_given_exact_input(u64::MAX, u64::MAX, u64::MAX, LIQUIDITY_MINER_FEE),
+            Some(9_209_502_304_468_528_024)
+        );
+    }
+}
+
+mod stable {
+    use super::*;
+
+    // `AMPLIFICATION_COEFFICIENT` in the `Forc.toml` of the stable exchange contract
+    const AMPLIFICATION: u64 = 100;
+
+    #[test]
+    fn calculates_output_for_exact_input() {
+        assert_eq!(
+            stable_minimum_output_given_exact_input(
+                1_000,
+                100_000,
+                100_000,
+                LIQUIDITY_MINER_FEE,
+                AMPLIFICATION
+            ),
+            Some(996)
+        );
+    }
+
+    #[test]
+    fn calculates_input_for_exact_output() {
+        assert_eq!(
+            stable_maximum_input_for_exact_output(
+                996,
+                100_000,
+                100_000,
+                LIQUIDITY_MINER_FEE,
+                AMPLIFICATION
+            ),
+            Some(1_001)
+        );
+    }
+
+    #[test]
+    fn scales_large_reserves() {
+        assert_eq!(
+            stable_minimum_output_given_exact_input(
+                1 << 40,
+                1 << 62,
+                1 << 62,
+                LIQUIDITY_MINER_FEE,
+                AMPLIFICATION
+            ),
+            Some(1_096_209_734_929)
+        );
+    }
+
+    #[test]
+    fn handles_imbalanced_reserves() {
+        assert_eq!(
+            stable_minimum_output_given_exact_input(
+                1 << 40,
+                1 << 48,
+                1_000,
+                LIQUIDITY_MINER_FEE,
+                AMPLIFICATION
+            ),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn when_output_is_entire_reserve() {
+        assert_eq!(
+            stable_maximum_input_for_exact_output(
+                100_000,
+                100_000,
+                100_000,
+                LIQUIDITY_MINER_FEE,
+                AMPLIFICATION
+            ),
+            None
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[test]
+    fn when_reserves_are_empty() {
+        assert_eq!(
+            minimum_output_given_exact_input(1_000, 0, 100_000, LIQUIDITY_MINER_FEE),
+            None
+        );
+        assert_eq!(
+            maximum_input_for_exact_output(1_000, 100_000, 0, LIQUIDITY_MINER_FEE),
+            None
+        );
+    }
+
+    #[test]
+    fn when_output_exceeds_reserve() {
+        assert_eq!(
+            maximum_input_for_exact_output(100_001, 100_000, 100_000, LIQUIDITY_MINER_FEE),
+            None
+        );
+        assert_eq!(
+            maximum_input_for_exact_output(100_000, 100_000, 100_000, LIQUIDITY_MINER_FEE),
+            None
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod math;
+mod pools;
+mod routes;
@@ -0,0 +1,149 @@
+use fuels::prelude::{AssetId, ContractId, WalletUnlocked};
+use route_finder::{
+    interface::AMM,
+    pools::{load_pools, registered_pools, Curve, Pool},
+    routes::RouteFinder,
+};
+use test_utils::{
+    data_structures::{
+        AMMContract, ExchangeContractConfiguration, LiquidityParameters, WalletAssetConfiguration,
+        LIQUIDITY_MINER_FEE,
+    },
+    interface::exchange::preview_swap_exact_input,
+    setup::{
+        common::{
+            deploy_and_construct_exchange, deploy_and_initialize_amm, deposit_and_add_liquidity,
+            register_pool, setup_wallet_and_provider,
+        },
+        scripts::setup_exchange_contracts,
+    },
+};
+
+async fn setup() -> (WalletUnlocked, AMMContract, Vec<AssetId>) {
+    let (wallet, asset_ids, provider) =
+        setup_wallet_and_provider(&WalletAssetConfiguration::default()).await;
+
+    let mut amm = deploy_and_initialize_amm(&wallet).await;
+
+    setup_exchange_contracts(&wallet, &provider, &mut amm, &asset_ids).await;
+
+    (wallet, amm, asset_ids)
+}
+
+fn asset(asset_id: AssetId) -> ContractId {
+    ContractId::new(*asset_id)
+}
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn enumerates_registered_pools() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[2]);
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration::new(Some(pair), None, None, Some([9u8; 32])),
+        )
+        .await;
+        register_pool(&amm.instance, pair, &exchange).await;
+
+        let instance = AMM::new(amm.id.into(), wallet.clone());
+        let pools = registered_pools(&instance).await.unwrap();
+
+        // pools are listed in the order they were added, after the pools added by the setup
+        assert_eq!(pools.len(), amm.pools.len() + 1);
+        assert_eq!(pools.last(), Some(&exchange.id));
+        assert!(amm
+            .pools
+            .values()
+            .all(|registered| pools.contains(&registered.id)));
+    }
+
+    #[tokio::test]
+    async fn loads_reserves() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let exchange = amm.pools.get(&(asset_ids[1], asset_ids[2])).unwrap();
+
+        let pools = load_pools(&wallet, &[exchange.id]).await.unwrap();
+
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[0].id, exchange.id);
+        assert_eq!(pools[0].liquidity_miner_fee, LIQUIDITY_MINER_FEE);
+        assert_eq!(pools[0].curve, Curve::ConstantProduct);
+        assert_eq!(
+            pools[0].output_for_exact_input(asset(asset_ids[1]), 1_000),
+            Some(1_974)
+        );
+        assert_eq!(
+            pools[0].other_asset(asset(asset_ids[2])),
+            Some(asset(asset_ids[1]))
+        );
+    }
+
+    #[tokio::test]
+    async fn loads_stable_pool() {
+        let (wallet, _amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+
+        let exchange = deploy_and_construct_exchange(
+            &wallet,
+            &ExchangeContractConfiguration {
+                stable: true,
+                ..ExchangeContractConfiguration::new(Some(pair), None, None, None)
+            },
+        )
+        .await;
+        deposit_and_add_liquidity(
+            &LiquidityParameters::new(Some((100_000, 100_000)), None, Some(20_000)),
+            &exchange,
+            false,
+        )
+        .await;
+
+        let pools = load_pools(&wallet, &[exchange.id]).await.unwrap();
+        let expected = preview_swap_exact_input(&exchange.instance, 1_000, pair.0, true)
+            .await
+            .other_asset
+            .amount;
+
+        assert_eq!(pools[0].curve, Curve::StableSwap { amplification: 100 });
+        assert_eq!(
+            pools[0].output_for_exact_input(asset(pair.0), 1_000),
+            Some(expected)
+        );
+        assert_eq!(expected, 996);
+    }
+
+    #[tokio::test]
+    async fn finds_route_matching_preview() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let ids: Vec<ContractId> = amm.pools.values().map(|exchange| exchange.id).collect();
+        let pools: Vec<Pool> = load_pools(&wallet, &ids).await.unwrap();
+
+        let route = RouteFinder::new(pools)
+            .best_exact_input(asset(asset_ids[0]), asset(asset_ids[3]), 1_000)
+            .unwrap();
+
+        let mut expected = 1_000;
+        for index in 0..3 {
+            let pair = (asset_ids[index], asset_ids[index + 1]);
+            let exchange = &amm.pools.get(&pair).unwrap().instance;
+            expected = preview_swap_exact_input(exchange, expected, pair.0, true)
+                .await
+                .other_asset
+                .amount;
+        }
+
+        assert_eq!(
+            route.assets,
+            asset_ids[..4]
+                .iter()
+                .copied()
+                .map(asset)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(route.output_amount, expected);
+    }
+}
@@ -0,0 +1,148 @@
+use fuels::prelude::ContractId;
+use route_finder::{
+    pools::{Curve, Pool},
+    routes::RouteFinder,
+};
+use test_utils::data_structures::LIQUIDITY_MINER_FEE;
+
+const A: ContractId = ContractId::new([1u8; 32]);
+const B: ContractId = ContractId::new([2u8; 32]);
+const C: ContractId = ContractId::new([3u8; 32]);
+const D: ContractId = ContractId::new([4u8; 32]);
+
+fn pool(id: u8, a: (ContractId, u64), b: (ContractId, u64)) -> Pool {
+    Pool::new(ContractId::new([id; 32]), LIQUIDITY_MINER_FEE, (a, b))
+}
+
+// A deep route from A to C through B next to a shallow pool of A and C
+fn route_finder() -> RouteFinder {
+    RouteFinder::new(vec![
+        pool(10, (A, 100_000), (B, 100_000)),
+        pool(11, (B, 100_000), (C, 100_000)),
+        pool(12, (A, 1_000), (C, 1_000)),
+    ])
+}
+
+mod success {
+    use super::*;
+
+    #[test]
+    fn finds_every_path() {
+        let paths = route_finder().paths(A, C);
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().any(|path| path.len() == 1));
+        assert!(paths.iter().any(|path| path.len() == 2));
+    }
+
+    #[test]
+    fn finds_best_route_for_exact_input() {
+        let route = route_finder().best_exact_input(A, C, 1_000).unwrap();
+
+        assert_eq!(route.assets, vec![A, B, C]);
+        assert_eq!(
+            route.pools,
+            vec![ContractId::new([10u8; 32]), ContractId::new([11u8; 32])]
+        );
+        assert_eq!(
+            route.fee_tiers,
+            vec![LIQUIDITY_MINER_FEE, LIQUIDITY_MINER_FEE]
+        );
+        assert_eq!(
+            route.curves,
+            vec![Curve::ConstantProduct, Curve::ConstantProduct]
+        );
+        assert_eq!(route.input_amount, 1_000);
+        assert_eq!(route.output_amount, 975);
+    }
+
+    #[test]
+    fn finds_best_route_for_exact_output() {
+        let route = route_finder().best_exact_output(A, C, 500).unwrap();
+
+        assert_eq!(route.assets, vec![A, B, C]);
+        assert_eq!(route.input_amount, 510);
+        assert_eq!(route.output_amount, 500);
+    }
+
+    #[test]
+    fn finds_route_in_reverse_direction() {
+        let route = route_finder().best_exact_input(C, A, 1_000).unwrap();
+
+        assert_eq!(route.assets, vec![C, B, A]);
+        assert_eq!(route.output_amount, 975);
+    }
+
+    #[test]
+    fn simulates_stable_pool_with_its_curve() {
+        let stable = Curve::StableSwap { amplification: 100 };
+        let route_finder = RouteFinder::new(vec![
+            pool(10, (A, 100_000), (B, 100_000)),
+            pool(11, (B, 100_000), (C, 100_000)),
+            pool(12, (A, 100_000), (C, 100_000)).with_curve(stable),
+        ]);
+
+        let route = route_finder.best_exact_input(A, C, 1_000).unwrap();
+        assert_eq!(route.assets, vec![A, C]);
+        assert_eq!(route.curves, vec![stable]);
+        assert_eq!(route.output_amount, 996);
+
+        let route = route_finder.best_exact_output(A, C, 996).unwrap();
+        assert_eq!(route.assets, vec![A, C]);
+        assert_eq!(route.input_amount, 1_001);
+    }
+
+    #[test]
+    fn respects_max_hops() {
+        let route_finder = route_finder().with_max_hops(1);
+
+        let route = route_finder.best_exact_input(A, C, 1_000).unwrap();
+        assert_eq!(route.assets, vec![A, C]);
+        assert_eq!(route.output_amount, 499);
+
+        let route = route_finder.best_exact_output(A, C, 500).unwrap();
+        assert_eq!(route.assets, vec![A, C]);
+        assert_eq!(route.input_amount, 1_003);
+    }
+
+    #[test]
+    fn prefers_cheaper_fee_tier() {
+        // the same reserves where one in 10_000 rather than one in 333 is charged as a fee
+        let cheap_pool = Pool::new(
+            ContractId::new([21u8; 32]),
+            10_000,
+            ((A, 100_000), (B, 100_000)),
+        );
+        let route_finder = RouteFinder::new(vec![pool(20, (A, 100_000), (B, 100_000)), cheap_pool]);
+
+        let route = route_finder.best_exact_input(A, B, 1_000).unwrap();
+        assert_eq!(route.pools, vec![ContractId::new([21u8; 32])]);
+        assert_eq!(route.fee_tiers, vec![10_000]);
+        assert_eq!(route.output_amount, 990);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[test]
+    fn when_assets_are_not_connected() {
+        assert_eq!(route_finder().best_exact_input(A, D, 1_000), None);
+        assert_eq!(route_finder().best_exact_output(A, D, 500), None);
+    }
+
+    #[test]
+    fn when_assets_are_identical() {
+        assert!(route_finder().paths(A, A).is_empty());
+    }
+
+    #[test]
+    fn when_reserves_are_insufficient() {
+        assert_eq!(route_finder().best_exact_output(A, C, 100_000), None);
+    }
+
+    #[test]
+    fn when_output_rounds_to_zero() {
+        assert_eq!(route_finder().best_exact_input(A, C, 1), None);
+    }
+}
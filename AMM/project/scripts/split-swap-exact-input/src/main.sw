@@ -0,0 +1,92 @@
+script;
+
+use libraries::{AMM, Exchange};
+
+enum InputError {
+    FeeTiersMismatch: (),
+    NoRoutes: (),
+    RouteMismatch: (),
+    RouteTooShort: (),
+}
+
+enum SwapError {
+    ExcessiveSlippage: u64,
+    PairExchangeNotRegistered: (ContractId, ContractId),
+}
+
+/// One of the routes an input amount is split across
+struct Route {
+    /// Assets to swap along, starting with the asset to sell
+    assets: Vec<ContractId>,
+    /// Fee tiers of the pools to swap through, one for every consecutive pair of assets
+    fee_tiers: Vec<u64>,
+    /// Exact amount of the first asset to sell along the route
+    input_amount: u64,
+}
+
+fn main(
+    routes: Vec<Route>,
+    minimum_output_amount: Option<u64>,
+    deadline: u64,
+) -> u64 {
+    require(routes.len() > 0, InputError::NoRoutes);
+
+    let amm_contract = abi(AMM, AMM_ID);
+
+    // every route sells the same asset and buys the same asset so that the outputs can be combined
+    let first_route = routes.get(0).unwrap().assets;
+    require(first_route.len() >= 2, InputError::RouteTooShort);
+    let input_asset = first_route.get(0).unwrap();
+    let output_asset = first_route.get(first_route.len() - 1).unwrap();
+
+    let mut total_bought = 0;
+
+    let mut route_index = 0;
+    while route_index < routes.len() {
+        let route = routes.get(route_index).unwrap();
+        let assets = route.assets;
+        let fee_tiers = route.fee_tiers;
+
+        require(assets.len() >= 2, InputError::RouteTooShort);
+        require(fee_tiers.len() == assets.len() - 1, InputError::FeeTiersMismatch);
+        require(assets.get(0).unwrap() == input_asset && assets.get(assets.len() - 1).unwrap() == output_asset, InputError::RouteMismatch);
+
+        let mut latest_bought = route.input_amount;
+
+        // start swapping by selling the first asset in the route
+        let mut sold_asset_index = 0;
+
+        // swap subsequent asset pairs along route
+        while sold_asset_index < assets.len() - 1 {
+            let asset_pair = (
+                assets.get(sold_asset_index).unwrap(),
+                assets.get(sold_asset_index + 1).unwrap(),
+            );
+
+            // get the exchange contract id of asset pair in the fee tier of the hop
+            let exchange_contract_id = amm_contract.pool { gas: 100_000 }(asset_pair, fee_tiers.get(sold_asset_index).unwrap());
+
+            require(exchange_contract_id.is_some(), SwapError::PairExchangeNotRegistered(asset_pair));
+
+            let exchange_contract = abi(Exchange, exchange_contract_id.unwrap().into());
+
+            // swap by specifying the exact amount to sell
+            latest_bought = exchange_contract.swap_exact_input {
+                gas: 10_000_000,
+                coins: latest_bought, // forwarding coins of asset to sell
+                asset_id: asset_pair.0.into(), // identifier of asset to sell
+            }(Option::None, deadline);
+
+            sold_asset_index += 1;
+        }
+
+        total_bought += latest_bought;
+        route_index += 1;
+    }
+
+    if minimum_output_amount.is_some() {
+        require(total_bought >= minimum_output_amount.unwrap(), SwapError::ExcessiveSlippage(total_bought));
+    }
+
+    total_bought
+}
@@ -0,0 +1,2 @@
+mod revert;
+mod success;
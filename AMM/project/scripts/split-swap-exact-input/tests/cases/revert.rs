@@ -0,0 +1,153 @@
+use crate::utils::{expected_and_actual_output, expected_swap_output, route, setup, swap};
+use fuels::prelude::AssetId;
+
+#[tokio::test]
+#[should_panic(expected = "NoRoutes")]
+async fn when_there_are_no_routes() {
+    expected_and_actual_output(vec![]).await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "RouteTooShort")]
+async fn when_first_route_is_too_short() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![route(&asset_ids[..1], 10)],
+        None,
+        deadline,
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "RouteTooShort")]
+async fn when_another_route_is_too_short() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![route(&asset_ids[..2], 10), route(&asset_ids[..1], 10)],
+        None,
+        deadline,
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "FeeTiersMismatch")]
+async fn when_fee_tiers_do_not_match_route() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    // one fee tier short of a fee tier for every pair of consecutive assets
+    let mut short_route = route(&asset_ids[..3], 10);
+    short_route.fee_tiers.pop();
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![short_route],
+        None,
+        deadline,
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "RouteMismatch")]
+async fn when_routes_buy_different_assets() {
+    expected_and_actual_output(vec![(vec![0, 1], 10), (vec![0, 2], 10)]).await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "RouteMismatch")]
+async fn when_routes_sell_different_assets() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![
+            route(&asset_ids[1..3], 10),
+            route(&[asset_ids[0], asset_ids[2]], 10),
+        ],
+        None,
+        deadline,
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "PairExchangeNotRegistered")]
+async fn when_pair_exchange_not_registered() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    // make sure that the first asset in the second route does not have a pool
+    let not_registered_asset_id = AssetId::from([1u8; 32]);
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![
+            route(&[not_registered_asset_id, asset_ids[2]], 10),
+            route(&[not_registered_asset_id, asset_ids[1], asset_ids[2]], 10),
+        ],
+        None,
+        deadline,
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "DeadlinePassed")]
+async fn when_deadline_passed() {
+    let (script_instance, amm, asset_ids, transaction_parameters, _deadline) = setup().await;
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![
+            route(&[asset_ids[0], asset_ids[2]], 60),
+            route(&asset_ids[..3], 60),
+        ],
+        None,
+        0, // deadline is 0
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "ExcessiveSlippage")]
+async fn when_combined_minimum_output_not_satisfied() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    let direct_route = vec![asset_ids[0], asset_ids[2]];
+    let indirect_route = asset_ids[..3].to_vec();
+    let expected_result = expected_swap_output(&amm, 60, &direct_route).await
+        + expected_swap_output(&amm, 60, &indirect_route).await;
+
+    swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![route(&direct_route, 60), route(&indirect_route, 60)],
+        Some(expected_result + 1), // setting the minimum to be higher than what it can be
+        deadline,
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "ExpectedNonZeroAmount")]
+async fn when_input_of_a_route_is_zero() {
+    expected_and_actual_output(vec![(vec![0, 2], 60), (vec![0, 1, 2], 0)]).await;
+}
@@ -0,0 +1,69 @@
+use crate::utils::{expected_and_actual_output, expected_swap_output, route, setup, swap};
+
+#[tokio::test]
+async fn can_swap_along_single_route() {
+    let swap_result = expected_and_actual_output(vec![(vec![0, 1, 2, 3, 4], 10_000)]).await;
+
+    assert_eq!(swap_result.expected.unwrap(), swap_result.actual);
+}
+
+#[tokio::test]
+async fn can_swap_along_split_routes_small_input() {
+    let swap_result = expected_and_actual_output(vec![(vec![0, 2], 2), (vec![0, 1, 2], 2)]).await;
+
+    assert_eq!(swap_result.expected.unwrap(), swap_result.actual);
+}
+
+#[tokio::test]
+async fn can_swap_along_split_routes_large_input() {
+    let swap_result =
+        expected_and_actual_output(vec![(vec![0, 2], 10_000), (vec![0, 1, 2], 5_000)]).await;
+
+    assert_eq!(swap_result.expected.unwrap(), swap_result.actual);
+}
+
+#[tokio::test]
+async fn can_swap_along_same_route_twice() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    let assets = vec![asset_ids[0], asset_ids[1]];
+    let independent_output = 2 * expected_swap_output(&amm, 1_000, &assets).await;
+
+    let actual = swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![route(&assets, 1_000), route(&assets, 1_000)],
+        None,
+        deadline,
+    )
+    .await;
+
+    // the second swap sells into the reserves the first swap left behind
+    assert!(actual > 0);
+    assert!(actual < independent_output);
+}
+
+#[tokio::test]
+async fn split_routes_reduce_slippage() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    let direct_route = vec![asset_ids[0], asset_ids[2]];
+    let indirect_route = vec![asset_ids[0], asset_ids[1], asset_ids[2]];
+    let single_route_output = expected_swap_output(&amm, 200_000, &direct_route).await;
+
+    let actual = swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        vec![
+            route(&direct_route, 100_000),
+            route(&indirect_route, 100_000),
+        ],
+        Some(single_route_output),
+        deadline,
+    )
+    .await;
+
+    assert!(actual > single_route_output);
+}
@@ -0,0 +1,2 @@
+mod cases;
+mod utils;
@@ -0,0 +1,153 @@
+use fuels::prelude::{AssetId, ContractId, TxParameters};
+use test_utils::{
+    data_structures::{
+        AMMContract, ExchangeContractConfiguration, LiquidityParameters, SwapResult,
+        TransactionParameters, WalletAssetConfiguration, NUMBER_OF_ASSETS,
+    },
+    interface::{
+        exchange::preview_swap_exact_input, Route, SplitSwapExactInputScript, SCRIPT_GAS_LIMIT,
+    },
+    paths::SPLIT_SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH,
+    setup::{
+        common::{deploy_and_initialize_amm, register_pool, setup_wallet_and_provider},
+        scripts::{
+            contract_instances, fee_tiers, setup_exchange_contract, setup_exchange_contracts,
+            transaction_inputs_outputs,
+        },
+    },
+};
+
+pub async fn expected_swap_output(
+    amm: &AMMContract,
+    input_amount: u64,
+    route: &Vec<AssetId>,
+) -> u64 {
+    assert!(route.len() >= 2);
+    let (mut i, mut latest_output) = (0, input_amount);
+
+    while i < route.len() - 1 {
+        let pair = (*route.get(i).unwrap(), *route.get(i + 1).unwrap());
+        let exchange = &amm.pools.get(&pair).unwrap().instance;
+        latest_output = preview_swap_exact_input(exchange, latest_output, pair.0, true)
+            .await
+            .other_asset
+            .amount;
+        i += 1;
+    }
+    latest_output
+}
+
+// routes are given as the indexes of their assets along with their input amounts and must not
+// share any pools for the previews to be accurate
+pub async fn expected_and_actual_output(routes: Vec<(Vec<usize>, u64)>) -> SwapResult {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    let mut expected = 0;
+    let mut script_routes = Vec::with_capacity(routes.len());
+    for (asset_indexes, input_amount) in routes {
+        let assets: Vec<AssetId> = asset_indexes
+            .into_iter()
+            .map(|asset_index| *asset_ids.get(asset_index).unwrap())
+            .collect();
+
+        expected += expected_swap_output(&amm, input_amount, &assets).await;
+        script_routes.push(route(&assets, input_amount));
+    }
+
+    let actual = swap(
+        &script_instance,
+        &amm,
+        transaction_parameters,
+        script_routes,
+        Some(expected),
+        deadline,
+    )
+    .await;
+
+    SwapResult {
+        actual,
+        expected: Some(expected),
+    }
+}
+
+pub fn route(assets: &[AssetId], input_amount: u64) -> Route {
+    Route {
+        assets: assets
+            .iter()
+            .map(|asset_id| ContractId::new(**asset_id))
+            .collect(),
+        fee_tiers: fee_tiers(assets),
+        input_amount,
+    }
+}
+
+pub async fn setup() -> (
+    SplitSwapExactInputScript,
+    AMMContract,
+    Vec<AssetId>,
+    TransactionParameters,
+    u64,
+) {
+    let (wallet, asset_ids, provider) =
+        setup_wallet_and_provider(&WalletAssetConfiguration::default()).await;
+
+    let mut amm = deploy_and_initialize_amm(&wallet).await;
+
+    setup_exchange_contracts(&wallet, &provider, &mut amm, &asset_ids).await;
+
+    // add a pool for (asset 1, asset 3) so that there are two routes between them
+    let direct_pair = (asset_ids[0], asset_ids[2]);
+    let direct_exchange = setup_exchange_contract(
+        &wallet,
+        &ExchangeContractConfiguration::new(
+            Some(direct_pair),
+            None,
+            None,
+            Some([NUMBER_OF_ASSETS as u8; 32]),
+        ),
+        &LiquidityParameters::new(
+            Some((100_000, 300_000)),
+            Some(provider.latest_block_height().await.unwrap() + 10),
+            Some(100_000),
+        ),
+    )
+    .await;
+    register_pool(&amm.instance, direct_pair, &direct_exchange).await;
+    amm.pools.insert(direct_pair, direct_exchange);
+
+    let transaction_parameters =
+        transaction_inputs_outputs(&wallet, &provider, &asset_ids, None).await;
+
+    let deadline = provider.latest_block_height().await.unwrap() + 10;
+
+    let script_instance =
+        SplitSwapExactInputScript::new(wallet, SPLIT_SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH);
+
+    (
+        script_instance,
+        amm,
+        asset_ids,
+        transaction_parameters,
+        deadline,
+    )
+}
+
+pub async fn swap(
+    script_instance: &SplitSwapExactInputScript,
+    amm: &AMMContract,
+    transaction_parameters: TransactionParameters,
+    routes: Vec<Route>,
+    minimum_output_amount: Option<u64>,
+    deadline: u64,
+) -> u64 {
+    script_instance
+        .main(routes, minimum_output_amount, deadline)
+        .set_contracts(&contract_instances(amm))
+        .with_inputs(transaction_parameters.inputs)
+        .with_outputs(transaction_parameters.outputs)
+        .tx_params(TxParameters::new(None, Some(SCRIPT_GAS_LIMIT), None))
+        .call()
+        .await
+        .unwrap()
+        .value
+}
@@ -3,6 +3,7 @@ script;
 use libraries::{AMM, Exchange};
 
 enum InputError {
+    FeeTiersMismatch: (),
     RouteTooShort: (),
 }
 
@@ -13,11 +14,13 @@ enum SwapError {
 
 fn main(
     assets: Vec<ContractId>,
+    fee_tiers: Vec<u64>,
     input_amount: u64,
     minimum_output_amount: Option<u64>,
     deadline: u64,
 ) -> u64 {
     require(assets.len() >= 2, InputError::RouteTooShort);
+    require(fee_tiers.len() == assets.len() - 1, InputError::FeeTiersMismatch);
 
     let amm_contract = abi(AMM, AMM_ID);
 
@@ -33,8 +36,8 @@ fn main(
             assets.get(sold_asset_index + 1).unwrap(),
         );
 
-        // get the exchange contract id of asset pair
-        let exchange_contract_id = amm_contract.pool { gas: 100_000 }(asset_pair);
+        // get the exchange contract id of asset pair in the fee tier of the hop
+        let exchange_contract_id = amm_contract.pool { gas: 100_000 }(asset_pair, fee_tiers.get(sold_asset_index).unwrap());
 
         require(exchange_contract_id.is_some(), SwapError::PairExchangeNotRegistered(asset_pair));
 
@@ -3,7 +3,7 @@ use fuels::prelude::{AssetId, ContractId, TxParameters};
 use test_utils::{
     data_structures::{SwapParameters, NUMBER_OF_ASSETS},
     interface::SCRIPT_GAS_LIMIT,
-    setup::scripts::contract_instances,
+    setup::scripts::{contract_instances, fee_tiers},
 };
 
 #[tokio::test]
@@ -26,6 +26,37 @@ async fn when_route_length_is_one() {
     .await;
 }
 
+#[tokio::test]
+#[should_panic(expected = "FeeTiersMismatch")]
+async fn when_fee_tiers_do_not_match_route() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    let route = asset_ids;
+    let input_amount = 60;
+
+    // one fee tier short of a fee tier for every pair of consecutive assets
+    let mut fee_tiers = fee_tiers(&route);
+    fee_tiers.pop();
+
+    script_instance
+        .main(
+            route
+                .into_iter()
+                .map(|asset_id| ContractId::new(*asset_id))
+                .collect(),
+            fee_tiers,
+            input_amount,
+            None,
+            deadline,
+        )
+        .set_contracts(&contract_instances(&amm))
+        .with_inputs(transaction_parameters.inputs)
+        .with_outputs(transaction_parameters.outputs)
+        .call()
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 #[should_panic(expected = "PairExchangeNotRegistered")]
 async fn when_pair_exchange_not_registered() {
@@ -39,12 +70,15 @@ async fn when_pair_exchange_not_registered() {
     route.remove(0);
     route.insert(0, not_registered_asset_id);
 
+    let fee_tiers = fee_tiers(&route);
+
     script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             input_amount,
             None,
             deadline,
@@ -67,12 +101,15 @@ async fn when_deadline_passed() {
 
     let expected_result = expected_swap_output(&amm, input_amount, &route).await;
 
+    let fee_tiers = fee_tiers(&route);
+
     script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             input_amount,
             Some(expected_result),
             0, // deadline is 0
@@ -96,12 +133,15 @@ async fn when_minimum_output_not_satisfied() {
 
     let expected_result = expected_swap_output(&amm, input_amount, &route).await;
 
+    let fee_tiers = fee_tiers(&route);
+
     script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             input_amount,
             Some(expected_result + 1), // setting the minimum to be higher than what it can be
             deadline,
@@ -7,7 +7,9 @@ use test_utils::{
     paths::SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH,
     setup::{
         common::{deploy_and_initialize_amm, setup_wallet_and_provider},
-        scripts::{contract_instances, setup_exchange_contracts, transaction_inputs_outputs},
+        scripts::{
+            contract_instances, fee_tiers, setup_exchange_contracts, transaction_inputs_outputs,
+        },
     },
 };
 
@@ -47,12 +49,15 @@ pub async fn expected_and_actual_output(swap_parameters: SwapParameters) -> Swap
         None
     };
 
+    let fee_tiers = fee_tiers(&route);
+
     let actual = script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             swap_parameters.amount,
             expected,
             deadline,
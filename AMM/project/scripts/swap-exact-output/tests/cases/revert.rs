@@ -3,7 +3,7 @@ use fuels::prelude::{AssetId, ContractId, TxParameters};
 use test_utils::{
     data_structures::{SwapParameters, NUMBER_OF_ASSETS},
     interface::SCRIPT_GAS_LIMIT,
-    setup::scripts::contract_instances,
+    setup::scripts::{contract_instances, fee_tiers},
 };
 
 #[tokio::test]
@@ -26,6 +26,38 @@ async fn when_route_length_is_one() {
     .await;
 }
 
+#[tokio::test]
+#[should_panic(expected = "FeeTiersMismatch")]
+async fn when_fee_tiers_do_not_match_route() {
+    let (script_instance, amm, asset_ids, transaction_parameters, deadline) = setup().await;
+
+    let route = asset_ids;
+    let output_amount = 10_000;
+    let maximum_input_amount = 0;
+
+    // one fee tier short of a fee tier for every pair of consecutive assets
+    let mut fee_tiers = fee_tiers(&route);
+    fee_tiers.pop();
+
+    script_instance
+        .main(
+            route
+                .into_iter()
+                .map(|asset_id| ContractId::new(*asset_id))
+                .collect(),
+            fee_tiers,
+            output_amount,
+            maximum_input_amount,
+            deadline,
+        )
+        .set_contracts(&contract_instances(&amm))
+        .with_inputs(transaction_parameters.inputs)
+        .with_outputs(transaction_parameters.outputs)
+        .call()
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 #[should_panic(expected = "PairExchangeNotRegistered")]
 async fn when_pair_exchange_not_registered() {
@@ -40,12 +72,15 @@ async fn when_pair_exchange_not_registered() {
     route.remove(route.len() - 1);
     route.push(not_registered_asset_id);
 
+    let fee_tiers = fee_tiers(&route);
+
     script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             output_amount,
             maximum_input_amount,
             deadline,
@@ -67,12 +102,15 @@ async fn when_deadline_passed() {
     let output_amount = 10_000;
     let maximum_input_amount = expected_swap_input(&amm, output_amount, &route).await;
 
+    let fee_tiers = fee_tiers(&route);
+
     script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             output_amount,
             maximum_input_amount,
             0, // deadline is 0
@@ -95,12 +133,15 @@ async fn when_maximum_input_not_satisfied() {
     let output_amount = 10_000;
     let maximum_input_amount = expected_swap_input(&amm, output_amount, &route).await;
 
+    let fee_tiers = fee_tiers(&route);
+
     script_instance
         .main(
             route
                 .into_iter()
                 .map(|asset_id| ContractId::new(*asset_id))
                 .collect(),
+            fee_tiers,
             output_amount,
             maximum_input_amount - 1, // setting the maximum to be lower than what it can be
             deadline,
@@ -0,0 +1,100 @@
+use crate::{
+    errors::Error,
+    exchange::ExchangeClient,
+    interface::amm::{RegisteredPool, AMM},
+    options::TxOptions,
+};
+use fuels::prelude::{AssetId, Bech32ContractId, ContractId, WalletUnlocked};
+
+/// Client of the AMM contract that looks up the exchange contracts of registered pools
+pub struct AmmClient {
+    // Identifier of the AMM contract
+    id: ContractId,
+    // Bindings of the AMM contract called by the wallet
+    instance: AMM,
+    // Deadline and slippage tolerance handed to the exchange clients
+    options: TxOptions,
+    // Wallet that sends the transactions of the exchange clients
+    wallet: WalletUnlocked,
+}
+
+impl AmmClient {
+    pub fn new(id: ContractId, wallet: WalletUnlocked) -> Self {
+        Self {
+            id,
+            instance: AMM::new(Bech32ContractId::from(id), wallet.clone()),
+            options: TxOptions::default(),
+            wallet,
+        }
+    }
+
+    pub fn with_options(mut self, options: TxOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn id(&self) -> ContractId {
+        self.id
+    }
+
+    pub fn instance(&self) -> &AMM {
+        &self.instance
+    }
+
+    /// Exchange contract of the pool for `asset_pair` in the `liquidity_miner_fee` fee tier
+    pub async fn pool(
+        &self,
+        asset_pair: (AssetId, AssetId),
+        liquidity_miner_fee: u64,
+    ) -> Result<Option<ContractId>, Error> {
+        let asset_pair = (
+            ContractId::new(*asset_pair.0),
+            ContractId::new(*asset_pair.1),
+        );
+
+        Ok(self
+            .instance
+            .methods()
+            .pool(asset_pair, liquidity_miner_fee)
+            .simulate()
+            .await?
+            .value)
+    }
+
+    /// Every pool registered with the AMM contract in the order they were first registered
+    pub async fn pools(&self) -> Result<Vec<RegisteredPool>, Error> {
+        let methods = self.instance.methods();
+        let pool_count = methods.pool_count().simulate().await?.value;
+
+        let mut pools = Vec::with_capacity(pool_count as usize);
+        for index in 0..pool_count {
+            if let Some(pool) = methods.pool_at(index).simulate().await?.value {
+                pools.push(pool);
+            }
+        }
+
+        Ok(pools)
+    }
+
+    /// Client of the exchange contract of the pool for `asset_pair` in the `liquidity_miner_fee`
+    /// fee tier
+    pub async fn exchange(
+        &self,
+        asset_pair: (AssetId, AssetId),
+        liquidity_miner_fee: u64,
+    ) -> Result<ExchangeClient, Error> {
+        let not_registered = Error::PoolNotRegistered {
+            asset_pair: (
+                ContractId::new(*asset_pair.0),
+                ContractId::new(*asset_pair.1),
+            ),
+            liquidity_miner_fee,
+        };
+        let pool = self
+            .pool(asset_pair, liquidity_miner_fee)
+            .await?
+            .ok_or(not_registered)?;
+
+        Ok(ExchangeClient::new(pool, self.wallet.clone()).with_options(self.options))
+    }
+}
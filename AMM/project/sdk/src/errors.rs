@@ -0,0 +1,127 @@
+use crate::interface::{
+    exchange::{AccessError, InitError, InputError, TransactionError},
+    swap_exact_input::{InputError as RouteError, SwapError},
+};
+use fuels::{
+    prelude::{AssetId, ContractId},
+    programs::logs::LogDecoder,
+    tx::Receipt,
+    types::{
+        errors::Error as SdkError,
+        traits::{Parameterize, Tokenizable},
+    },
+};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The sender is not the owner of the exchange contract
+    Access(AccessError),
+    /// A swap route must have one fee tier for every consecutive pair of assets
+    FeeTiersMismatch,
+    /// The exchange contract has not been constructed
+    Init(InitError),
+    /// The exchange contract rejected the arguments of the call
+    Input(InputError),
+    /// The slippage tolerance, in basis points, exceeds 100%
+    InvalidSlippage(u64),
+    /// The AMM contract has no pool for the asset pair in the fee tier
+    PoolNotRegistered {
+        asset_pair: (ContractId, ContractId),
+        liquidity_miner_fee: u64,
+    },
+    /// The swap script rejected the route
+    Route(RouteError),
+    /// A swap route must consist of at least two assets
+    RouteTooShort,
+    /// Any other failure such as a revert without a decodable reason or an unreachable node
+    Sdk(SdkError),
+    /// The swap script found no pool for a hop of the route or exceeded the slippage tolerance
+    Swap(SwapError),
+    /// The exchange contract could not fulfill the call with its reserves or deposits
+    Transaction(TransactionError),
+    /// The asset is not part of the pool of the exchange contract
+    UnknownAsset(AssetId),
+}
+
+impl Error {
+    /// Decodes the revert reason logged by an exchange contract or a swap script out of `error`
+    ///
+    /// Errors that are not reverts, or reverts without an error log, are kept as is.
+    pub fn from_revert(error: SdkError, log_decoder: &LogDecoder) -> Self {
+        let receipts = match &error {
+            SdkError::RevertTransactionError { receipts, .. } => receipts,
+            _ => return Self::Sdk(error),
+        };
+
+        // The revert reason is the last error logged before the revert
+        let reason = receipts
+            .iter()
+            .rev()
+            .find_map(|receipt| decode_reason(log_decoder, receipt));
+
+        reason.unwrap_or(Self::Sdk(error))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Access(reason) => write!(f, "the exchange denied access: {reason:?}"),
+            Self::FeeTiersMismatch => write!(
+                f,
+                "a route must have one fee tier for every consecutive pair of assets"
+            ),
+            Self::Init(reason) => write!(f, "the exchange is not initialized: {reason:?}"),
+            Self::Input(reason) => write!(f, "the exchange rejected the input: {reason:?}"),
+            Self::InvalidSlippage(slippage_bps) => write!(
+                f,
+                "the slippage tolerance of {slippage_bps} basis points exceeds 100%"
+            ),
+            Self::PoolNotRegistered {
+                asset_pair,
+                liquidity_miner_fee,
+            } => write!(
+                f,
+                "no pool of {} and {} is registered in fee tier {liquidity_miner_fee}",
+                asset_pair.0, asset_pair.1
+            ),
+            Self::Route(reason) => write!(f, "the swap script rejected the route: {reason:?}"),
+            Self::RouteTooShort => write!(f, "a route must consist of at least two assets"),
+            Self::Sdk(error) => write!(f, "{error}"),
+            Self::Swap(reason) => write!(f, "the swap script reverted: {reason:?}"),
+            Self::Transaction(reason) => write!(f, "the exchange reverted: {reason:?}"),
+            Self::UnknownAsset(asset) => write!(f, "the asset {asset} is not part of the pool"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SdkError> for Error {
+    fn from(error: SdkError) -> Self {
+        Self::Sdk(error)
+    }
+}
+
+// Decodes `receipt` as any of the errors logged by an exchange contract or a swap script
+fn decode_reason(log_decoder: &LogDecoder, receipt: &Receipt) -> Option<Error> {
+    // Both swap scripts declare the same errors, which decode into the types of either
+    decode_log(log_decoder, receipt)
+        .map(Error::Transaction)
+        .or_else(|| decode_log(log_decoder, receipt).map(Error::Input))
+        .or_else(|| decode_log(log_decoder, receipt).map(Error::Init))
+        .or_else(|| decode_log(log_decoder, receipt).map(Error::Access))
+        .or_else(|| decode_log(log_decoder, receipt).map(Error::Swap))
+        .or_else(|| decode_log(log_decoder, receipt).map(Error::Route))
+}
+
+fn decode_log<T>(log_decoder: &LogDecoder, receipt: &Receipt) -> Option<T>
+where
+    T: Parameterize + Tokenizable + 'static,
+{
+    log_decoder
+        .get_logs_with_type::<T>(std::slice::from_ref(receipt))
+        .ok()?
+        .pop()
+}
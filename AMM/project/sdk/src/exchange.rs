@@ -0,0 +1,292 @@
+use crate::{
+    errors::Error,
+    interface::exchange::{
+        Asset, Exchange, PoolInfo, PreviewAddLiquidityInfo, PreviewZapInfo, RemoveLiquidityInfo,
+    },
+    options::TxOptions,
+};
+use fuels::{
+    prelude::{AssetId, Bech32ContractId, CallParameters, ContractId, Provider, WalletUnlocked},
+    programs::{call_response::FuelCallResponse, logs::LogDecoder},
+    types::errors::Error as SdkError,
+};
+
+// Number of variable outputs each call transfers coins into
+const ADD_LIQUIDITY_OUTPUTS: u64 = 2; // minted liquidity and the unused deposit
+const REMOVE_LIQUIDITY_OUTPUTS: u64 = 2; // both assets of the pool
+const SWAP_EXACT_INPUT_OUTPUTS: u64 = 1; // bought asset
+const SWAP_EXACT_OUTPUT_OUTPUTS: u64 = 2; // bought asset and refund of the sold asset
+const WITHDRAW_OUTPUTS: u64 = 1; // withdrawn asset
+const ZAP_OUTPUTS: u64 = 3; // minted liquidity and the refunds of both assets
+
+/// Client of a single exchange contract
+pub struct ExchangeClient {
+    // Identifier of the exchange contract
+    id: ContractId,
+    // Bindings of the exchange contract called by the wallet
+    instance: Exchange,
+    // Deadline and slippage tolerance applied to every transaction
+    options: TxOptions,
+    // Wallet that sends the transactions and receives the transferred coins
+    wallet: WalletUnlocked,
+}
+
+impl ExchangeClient {
+    pub fn new(id: ContractId, wallet: WalletUnlocked) -> Self {
+        Self {
+            id,
+            instance: Exchange::new(Bech32ContractId::from(id), wallet.clone()),
+            options: TxOptions::default(),
+            wallet,
+        }
+    }
+
+    pub fn with_options(mut self, options: TxOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn id(&self) -> ContractId {
+        self.id
+    }
+
+    pub fn instance(&self) -> &Exchange {
+        &self.instance
+    }
+
+    pub fn log_decoder(&self) -> LogDecoder {
+        self.instance.log_decoder()
+    }
+
+    pub fn options(&self) -> TxOptions {
+        self.options
+    }
+
+    /// Reserves, liquidity and fee tier of the pool
+    pub async fn pool_info(&self) -> Result<PoolInfo, Error> {
+        let call_handler = self.instance.methods().pool_info();
+        self.call(call_handler.simulate().await)
+    }
+
+    /// Amount of the other asset received when swapping exactly `input_amount` of `input_asset`
+    pub async fn quote_exact_input(
+        &self,
+        input_asset: AssetId,
+        input_amount: u64,
+    ) -> Result<u64, Error> {
+        let call_handler = self
+            .instance
+            .methods()
+            .preview_swap_exact_input(asset(input_asset, input_amount));
+        let preview = self.call(call_handler.simulate().await)?;
+
+        Ok(preview.other_asset.amount)
+    }
+
+    /// Amount of the other asset sold when swapping for exactly `output_amount` of `output_asset`
+    pub async fn quote_exact_output(
+        &self,
+        output_asset: AssetId,
+        output_amount: u64,
+    ) -> Result<u64, Error> {
+        let call_handler = self
+            .instance
+            .methods()
+            .preview_swap_exact_output(asset(output_asset, output_amount));
+        let preview = self.call(call_handler.simulate().await)?;
+
+        Ok(preview.other_asset.amount)
+    }
+
+    /// Amount of the other asset to deposit and liquidity received when adding `amount` of `asset`
+    pub async fn quote_add_liquidity(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<PreviewAddLiquidityInfo, Error> {
+        let call_handler = self
+            .instance
+            .methods()
+            .preview_add_liquidity(asset(asset_id, amount));
+        self.call(call_handler.simulate().await)
+    }
+
+    /// Swapped amounts, liquidity and refunds of zapping `amount` of `asset`
+    pub async fn quote_zap(&self, asset_id: AssetId, amount: u64) -> Result<PreviewZapInfo, Error> {
+        let call_handler = self.instance.methods().preview_zap(asset(asset_id, amount));
+        self.call(call_handler.simulate().await)
+    }
+
+    /// Sells exactly `input_amount` of `input_asset` for at least the quoted output less slippage
+    pub async fn swap_exact_input(
+        &self,
+        input_asset: AssetId,
+        input_amount: u64,
+    ) -> Result<u64, Error> {
+        let quoted = self.quote_exact_input(input_asset, input_amount).await?;
+        let min_output = self.options.minimum_output(quoted);
+        let deadline = self.deadline().await?;
+
+        let call_handler = self
+            .instance
+            .methods()
+            .swap_exact_input(Some(min_output), deadline)
+            .call_params(CallParameters::new(
+                Some(input_amount),
+                Some(input_asset),
+                None,
+            ))?
+            .append_variable_outputs(SWAP_EXACT_INPUT_OUTPUTS);
+
+        self.call(call_handler.call().await)
+    }
+
+    /// Buys exactly `output_amount` of `output_asset` for at most the quoted input plus slippage
+    ///
+    /// The part of the forwarded input that is not sold is refunded.
+    pub async fn swap_exact_output(
+        &self,
+        output_asset: AssetId,
+        output_amount: u64,
+    ) -> Result<u64, Error> {
+        let input_asset = self.other_asset(output_asset).await?;
+        let quoted = self.quote_exact_output(output_asset, output_amount).await?;
+        let max_input = self.options.maximum_input(quoted);
+        let deadline = self.deadline().await?;
+
+        let call_handler = self
+            .instance
+            .methods()
+            .swap_exact_output(output_amount, deadline)
+            .call_params(CallParameters::new(
+                Some(max_input),
+                Some(input_asset),
+                None,
+            ))?
+            .append_variable_outputs(SWAP_EXACT_OUTPUT_OUTPUTS);
+
+        self.call(call_handler.call().await)
+    }
+
+    /// Deposits `amount` of `asset` to be used by `add_liquidity`
+    pub async fn deposit(&self, asset_id: AssetId, amount: u64) -> Result<(), Error> {
+        let call_handler = self
+            .instance
+            .methods()
+            .deposit()
+            .call_params(CallParameters::new(Some(amount), Some(asset_id), None))?;
+
+        self.call(call_handler.call().await)
+    }
+
+    /// Adds the deposited assets as liquidity, minting at least `desired_liquidity` less slippage
+    pub async fn add_liquidity(&self, desired_liquidity: u64) -> Result<u64, Error> {
+        let min_liquidity = self.options.minimum_output(desired_liquidity);
+        let deadline = self.deadline().await?;
+
+        let call_handler = self
+            .instance
+            .methods()
+            .add_liquidity(min_liquidity, deadline)
+            .append_variable_outputs(ADD_LIQUIDITY_OUTPUTS);
+
+        self.call(call_handler.call().await)
+    }
+
+    /// Burns `liquidity`, receiving at least the current share of the reserves less slippage
+    pub async fn remove_liquidity(&self, liquidity: u64) -> Result<RemoveLiquidityInfo, Error> {
+        let pool_info = self.pool_info().await?;
+        let share = |reserve: u64| {
+            (liquidity as u128 * reserve as u128 / pool_info.liquidity.max(1) as u128) as u64
+        };
+        let min_asset_a = self
+            .options
+            .minimum_output(share(pool_info.reserves.a.amount));
+        let min_asset_b = self
+            .options
+            .minimum_output(share(pool_info.reserves.b.amount));
+        let deadline = self.deadline().await?;
+
+        let call_handler = self
+            .instance
+            .methods()
+            .remove_liquidity(min_asset_a, min_asset_b, deadline)
+            .call_params(CallParameters::new(
+                Some(liquidity),
+                Some(AssetId::new(*self.id)),
+                None,
+            ))?
+            .append_variable_outputs(REMOVE_LIQUIDITY_OUTPUTS);
+
+        self.call(call_handler.call().await)
+    }
+
+    /// Withdraws `amount` of the deposited `asset`
+    pub async fn withdraw(&self, asset_id: AssetId, amount: u64) -> Result<(), Error> {
+        let call_handler = self
+            .instance
+            .methods()
+            .withdraw(asset(asset_id, amount))
+            .append_variable_outputs(WITHDRAW_OUTPUTS);
+
+        self.call(call_handler.call().await)
+    }
+
+    /// Adds `amount` of a single asset as liquidity, minting at least the quoted liquidity less
+    /// slippage
+    pub async fn zap(&self, asset_id: AssetId, amount: u64) -> Result<u64, Error> {
+        let quoted = self.quote_zap(asset_id, amount).await?;
+        let min_liquidity = self
+            .options
+            .minimum_output(quoted.liquidity_asset_to_receive.amount);
+        let deadline = self.deadline().await?;
+
+        let call_handler = self
+            .instance
+            .methods()
+            .zap(min_liquidity, deadline)
+            .call_params(CallParameters::new(Some(amount), Some(asset_id), None))?
+            .append_variable_outputs(ZAP_OUTPUTS);
+
+        self.call(call_handler.call().await)
+    }
+
+    /// The asset of the pool that is not `asset`
+    pub async fn other_asset(&self, asset_id: AssetId) -> Result<AssetId, Error> {
+        let reserves = self.pool_info().await?.reserves;
+        let asset = ContractId::new(*asset_id);
+
+        if asset == reserves.a.id {
+            Ok(AssetId::new(*reserves.b.id))
+        } else if asset == reserves.b.id {
+            Ok(AssetId::new(*reserves.a.id))
+        } else {
+            Err(Error::UnknownAsset(asset_id))
+        }
+    }
+
+    async fn deadline(&self) -> Result<u64, Error> {
+        self.options.deadline_height(&self.provider()?).await
+    }
+
+    fn provider(&self) -> Result<Provider, Error> {
+        self.wallet
+            .get_provider()
+            .cloned()
+            .map_err(|error| Error::Sdk(error.into()))
+    }
+
+    // Returns the value of the call or decodes the reason it reverted
+    fn call<T>(&self, response: Result<FuelCallResponse<T>, SdkError>) -> Result<T, Error> {
+        response
+            .map(|response| response.value)
+            .map_err(|error| Error::from_revert(error, &self.log_decoder()))
+    }
+}
+
+fn asset(asset_id: AssetId, amount: u64) -> Asset {
+    Asset {
+        id: ContractId::new(*asset_id),
+        amount,
+    }
+}
@@ -0,0 +1,49 @@
+use fuels::prelude::abigen;
+
+// Each program is generated in its own module as they declare types of the same name
+// The ABIs are vendored in `abi` so that the crate builds without the output of `forc build`
+
+pub mod amm {
+    use super::*;
+
+    abigen!(Contract(
+        name = "AMM",
+        abi = "./sdk/abi/AMM-contract-abi.json"
+    ));
+}
+
+pub mod exchange {
+    use super::*;
+
+    abigen!(Contract(
+        name = "Exchange",
+        abi = "./sdk/abi/exchange-contract-abi.json"
+    ));
+}
+
+pub mod atomic_add_liquidity {
+    use super::*;
+
+    abigen!(Script(
+        name = "AtomicAddLiquidityScript",
+        abi = "./sdk/abi/atomic-add-liquidity-abi.json"
+    ));
+}
+
+pub mod swap_exact_input {
+    use super::*;
+
+    abigen!(Script(
+        name = "SwapExactInputScript",
+        abi = "./sdk/abi/swap-exact-input-abi.json"
+    ));
+}
+
+pub mod swap_exact_output {
+    use super::*;
+
+    abigen!(Script(
+        name = "SwapExactOutputScript",
+        abi = "./sdk/abi/swap-exact-output-abi.json"
+    ));
+}
@@ -0,0 +1,13 @@
+//! Client for integrating with the AMM contract, its exchange contracts and the swap and liquidity
+//! scripts
+//!
+//! Calls are built with the variable outputs the contracts transfer into, quoted before they are
+//! sent so that the slippage tolerance and deadline of [`options::TxOptions`] apply, and their
+//! reverts are decoded into [`errors::Error`].
+
+pub mod amm;
+pub mod errors;
+pub mod exchange;
+pub mod interface;
+pub mod options;
+pub mod scripts;
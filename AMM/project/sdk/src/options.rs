@@ -0,0 +1,110 @@
+use crate::errors::Error;
+use fuels::prelude::Provider;
+
+/// Denominator of slippage tolerances expressed in basis points
+pub const BASIS_POINTS: u64 = 10_000;
+/// Number of blocks a transaction remains valid for unless a deadline is given
+pub const DEFAULT_DEADLINE_BLOCKS: u64 = 20;
+/// Slippage tolerance of 0.5% applied unless another tolerance is given
+pub const DEFAULT_SLIPPAGE_BPS: u64 = 50;
+
+/// Block height from which the exchange contracts refuse a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deadline {
+    /// The transaction is refused from this block height onwards
+    AtHeight(u64),
+    /// The transaction expires this many blocks after the latest block at the time it is built
+    InBlocks(u64),
+}
+
+/// Deadline and slippage tolerance of the transactions built by the clients
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TxOptions {
+    // Block height from which the transaction is refused
+    deadline: Deadline,
+    // Maximum movement of a quoted amount against the sender, in basis points
+    slippage_bps: u64,
+}
+
+impl TxOptions {
+    pub fn builder() -> TxOptionsBuilder {
+        TxOptionsBuilder::default()
+    }
+
+    pub fn deadline(&self) -> Deadline {
+        self.deadline
+    }
+
+    pub fn slippage_bps(&self) -> u64 {
+        self.slippage_bps
+    }
+
+    /// Resolves the deadline into the block height passed to the contracts
+    pub async fn deadline_height(&self, provider: &Provider) -> Result<u64, Error> {
+        match self.deadline {
+            Deadline::AtHeight(height) => Ok(height),
+            Deadline::InBlocks(blocks) => {
+                let latest_height = provider
+                    .latest_block_height()
+                    .await
+                    .map_err(|error| Error::Sdk(error.into()))?;
+                Ok(latest_height.saturating_add(blocks))
+            }
+        }
+    }
+
+    /// Smallest amount accepted in exchange for a `quoted` output, rounding down
+    pub fn minimum_output(&self, quoted: u64) -> u64 {
+        let minimum = quoted as u128 * (BASIS_POINTS - self.slippage_bps) as u128;
+        (minimum / BASIS_POINTS as u128) as u64
+    }
+
+    /// Largest amount paid in exchange for a `quoted` input, rounding up
+    pub fn maximum_input(&self, quoted: u64) -> u64 {
+        let maximum = quoted as u128 * (BASIS_POINTS + self.slippage_bps) as u128;
+        let basis_points = BASIS_POINTS as u128;
+        u64::try_from((maximum + basis_points - 1) / basis_points).unwrap_or(u64::MAX)
+    }
+}
+
+impl Default for TxOptions {
+    fn default() -> Self {
+        Self {
+            deadline: Deadline::InBlocks(DEFAULT_DEADLINE_BLOCKS),
+            slippage_bps: DEFAULT_SLIPPAGE_BPS,
+        }
+    }
+}
+
+/// Builds [`TxOptions`], rejecting slippage tolerances above 100%
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TxOptionsBuilder {
+    deadline: Option<Deadline>,
+    slippage_bps: Option<u64>,
+}
+
+impl TxOptionsBuilder {
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn with_slippage_bps(mut self, slippage_bps: u64) -> Self {
+        self.slippage_bps = Some(slippage_bps);
+        self
+    }
+
+    pub fn build(self) -> Result<TxOptions, Error> {
+        let defaults = TxOptions::default();
+        let slippage_bps = self.slippage_bps.unwrap_or(defaults.slippage_bps);
+
+        if slippage_bps > BASIS_POINTS {
+            return Err(Error::InvalidSlippage(slippage_bps));
+        }
+
+        Ok(TxOptions {
+            deadline: self.deadline.unwrap_or(defaults.deadline),
+            slippage_bps,
+        })
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{
+    amm::AmmClient,
+    errors::Error,
+    exchange::ExchangeClient,
+    interface::{
+        atomic_add_liquidity::{Asset, AssetPair, AtomicAddLiquidityScript, LiquidityParameters},
+        swap_exact_input::SwapExactInputScript,
+        swap_exact_output::SwapExactOutputScript,
+    },
+    options::TxOptions,
+};
+use fuels::{
+    prelude::{Address, AssetId, ContractId, SettableContract, TxParameters, WalletUnlocked},
+    programs::{call_response::FuelCallResponse, logs::LogDecoder},
+    tx::{Input, Output},
+    types::errors::Error as SdkError,
+};
+
+/// Gas limit of script transactions, which cannot be estimated
+pub const SCRIPT_GAS_LIMIT: u64 = 100_000_000;
+
+// Number of variable outputs each script transfers coins into
+const ATOMIC_ADD_LIQUIDITY_OUTPUTS: usize = 2; // minted liquidity and the unused deposit
+const SWAP_EXACT_INPUT_OUTPUTS_PER_SWAP: usize = 1; // bought asset
+const SWAP_EXACT_OUTPUT_OUTPUTS_PER_SWAP: usize = 2; // bought asset and refund of the sold asset
+
+/// Paths of the compiled script binaries
+#[derive(Clone, Debug)]
+pub struct ScriptBinaries {
+    pub atomic_add_liquidity: String,
+    pub swap_exact_input: String,
+    pub swap_exact_output: String,
+}
+
+/// Client of the scripts that add liquidity atomically and swap along routes of pools
+pub struct ScriptClient {
+    // Client of the AMM contract the swap scripts look up pools from
+    amm: AmmClient,
+    // Paths of the script binaries that are run
+    binaries: ScriptBinaries,
+    // Deadline and slippage tolerance applied to every transaction
+    options: TxOptions,
+    // Wallet that funds the transactions and receives the transferred coins
+    wallet: WalletUnlocked,
+}
+
+impl ScriptClient {
+    pub fn new(amm: ContractId, binaries: ScriptBinaries, wallet: WalletUnlocked) -> Self {
+        Self {
+            amm: AmmClient::new(amm, wallet.clone()),
+            binaries,
+            options: TxOptions::default(),
+            wallet,
+        }
+    }
+
+    pub fn with_options(mut self, options: TxOptions) -> Self {
+        self.amm = self.amm.with_options(options);
+        self.options = options;
+        self
+    }
+
+    /// Amount of the last asset of `route` received when selling exactly `input_amount` of the
+    /// first asset through the pools in `fee_tiers`
+    pub async fn quote_exact_input(
+        &self,
+        route: &[AssetId],
+        fee_tiers: &[u64],
+        input_amount: u64,
+    ) -> Result<u64, Error> {
+        let exchanges = self.route_exchanges(route, fee_tiers).await?;
+        quote_exact_input(&exchanges, route, input_amount).await
+    }
+
+    /// Amount of the first asset of `route` sold when buying exactly `output_amount` of the last
+    /// asset through the pools in `fee_tiers`
+    pub async fn quote_exact_output(
+        &self,
+        route: &[AssetId],
+        fee_tiers: &[u64],
+        output_amount: u64,
+    ) -> Result<u64, Error> {
+        let exchanges = self.route_exchanges(route, fee_tiers).await?;
+        quote_exact_output(&exchanges, route, output_amount).await
+    }
+
+    /// Deposits both assets and adds them as liquidity in a single transaction, minting at least
+    /// `desired_liquidity` less slippage
+    pub async fn add_liquidity(
+        &self,
+        exchange: ContractId,
+        deposits: ((AssetId, u64), (AssetId, u64)),
+        desired_liquidity: u64,
+    ) -> Result<u64, Error> {
+        let exchange = ExchangeClient::new(exchange, self.wallet.clone());
+        let liquidity_parameters = LiquidityParameters {
+            deposits: AssetPair {
+                a: asset(deposits.0),
+                b: asset(deposits.1),
+            },
+            liquidity: self.options.minimum_output(desired_liquidity),
+            deadline: self.deadline().await?,
+        };
+
+        let mut inputs = self.coin_inputs(deposits.0).await?;
+        inputs.extend(self.coin_inputs(deposits.1).await?);
+
+        let script =
+            AtomicAddLiquidityScript::new(self.wallet.clone(), &self.binaries.atomic_add_liquidity);
+        let response = script
+            .main(exchange.id(), liquidity_parameters)
+            .set_contracts(&[exchange.instance() as &dyn SettableContract])
+            .with_inputs(inputs)
+            .with_outputs(variable_outputs(ATOMIC_ADD_LIQUIDITY_OUTPUTS))
+            .tx_params(TxParameters::new(None, Some(SCRIPT_GAS_LIMIT), None))
+            .call()
+            .await;
+
+        value(response, &exchange.log_decoder())
+    }
+
+    /// Sells exactly `input_amount` of the first asset of `route` for at least the quoted amount of
+    /// the last asset less slippage
+    pub async fn swap_exact_input(
+        &self,
+        route: &[AssetId],
+        fee_tiers: &[u64],
+        input_amount: u64,
+    ) -> Result<u64, Error> {
+        let exchanges = self.route_exchanges(route, fee_tiers).await?;
+        let quoted = quote_exact_input(&exchanges, route, input_amount).await?;
+        let minimum_output_amount = self.options.minimum_output(quoted);
+
+        let script =
+            SwapExactInputScript::new(self.wallet.clone(), &self.binaries.swap_exact_input);
+        let call_handler = script
+            .main(
+                contract_ids(route),
+                fee_tiers.to_vec(),
+                input_amount,
+                Some(minimum_output_amount),
+                self.deadline().await?,
+            )
+            .set_contracts(&self.contracts(&exchanges))
+            .with_inputs(self.coin_inputs((route[0], input_amount)).await?)
+            .with_outputs(variable_outputs(
+                exchanges.len() * SWAP_EXACT_INPUT_OUTPUTS_PER_SWAP,
+            ))
+            .tx_params(TxParameters::new(None, Some(SCRIPT_GAS_LIMIT), None));
+        let log_decoder = log_decoder(&call_handler.log_decoder, &exchanges);
+        let response = call_handler.call().await;
+
+        value(response, &log_decoder)
+    }
+
+    /// Buys exactly `output_amount` of the last asset of `route` for at most the quoted amount of
+    /// the first asset plus slippage
+    pub async fn swap_exact_output(
+        &self,
+        route: &[AssetId],
+        fee_tiers: &[u64],
+        output_amount: u64,
+    ) -> Result<u64, Error> {
+        let exchanges = self.route_exchanges(route, fee_tiers).await?;
+        let quoted = quote_exact_output(&exchanges, route, output_amount).await?;
+        let maximum_input_amount = self.options.maximum_input(quoted);
+
+        let script =
+            SwapExactOutputScript::new(self.wallet.clone(), &self.binaries.swap_exact_output);
+        let call_handler = script
+            .main(
+                contract_ids(route),
+                fee_tiers.to_vec(),
+                output_amount,
+                maximum_input_amount,
+                self.deadline().await?,
+            )
+            .set_contracts(&self.contracts(&exchanges))
+            .with_inputs(self.coin_inputs((route[0], maximum_input_amount)).await?)
+            .with_outputs(variable_outputs(
+                exchanges.len() * SWAP_EXACT_OUTPUT_OUTPUTS_PER_SWAP,
+            ))
+            .tx_params(TxParameters::new(None, Some(SCRIPT_GAS_LIMIT), None));
+        let log_decoder = log_decoder(&call_handler.log_decoder, &exchanges);
+        let response = call_handler.call().await;
+
+        value(response, &log_decoder)
+    }
+
+    // Exchange clients of every consecutive asset pair of `route` in the matching fee tier
+    async fn route_exchanges(
+        &self,
+        route: &[AssetId],
+        fee_tiers: &[u64],
+    ) -> Result<Vec<ExchangeClient>, Error> {
+        if route.len() < 2 {
+            return Err(Error::RouteTooShort);
+        }
+        if fee_tiers.len() != route.len() - 1 {
+            return Err(Error::FeeTiersMismatch);
+        }
+
+        let mut exchanges = Vec::with_capacity(route.len() - 1);
+        for (pair, liquidity_miner_fee) in route.windows(2).zip(fee_tiers) {
+            exchanges.push(
+                self.amm
+                    .exchange((pair[0], pair[1]), *liquidity_miner_fee)
+                    .await?,
+            );
+        }
+
+        Ok(exchanges)
+    }
+
+    fn contracts<'a>(&'a self, exchanges: &'a [ExchangeClient]) -> Vec<&'a dyn SettableContract> {
+        exchanges
+            .iter()
+            .map(|exchange| exchange.instance() as &dyn SettableContract)
+            .chain(std::iter::once(self.amm.instance() as &dyn SettableContract))
+            .collect()
+    }
+
+    async fn coin_inputs(&self, (asset_id, amount): (AssetId, u64)) -> Result<Vec<Input>, Error> {
+        Ok(self
+            .wallet
+            .get_asset_inputs_for_amount(asset_id, amount, 0)
+            .await?)
+    }
+
+    async fn deadline(&self) -> Result<u64, Error> {
+        let provider = self
+            .wallet
+            .get_provider()
+            .map_err(|error| Error::Sdk(error.into()))?;
+        self.options.deadline_height(provider).await
+    }
+}
+
+async fn quote_exact_input(
+    exchanges: &[ExchangeClient],
+    route: &[AssetId],
+    input_amount: u64,
+) -> Result<u64, Error> {
+    let mut amount = input_amount;
+    for (exchange, input_asset) in exchanges.iter().zip(route) {
+        amount = exchange.quote_exact_input(*input_asset, amount).await?;
+    }
+    Ok(amount)
+}
+
+async fn quote_exact_output(
+    exchanges: &[ExchangeClient],
+    route: &[AssetId],
+    output_amount: u64,
+) -> Result<u64, Error> {
+    // Walk the route backwards as each swap must buy the input of the next one
+    let mut amount = output_amount;
+    for (exchange, output_asset) in exchanges.iter().zip(&route[1..]).rev() {
+        amount = exchange.quote_exact_output(*output_asset, amount).await?;
+    }
+    Ok(amount)
+}
+
+fn asset((asset_id, amount): (AssetId, u64)) -> Asset {
+    Asset {
+        id: ContractId::new(*asset_id),
+        amount,
+    }
+}
+
+fn contract_ids(route: &[AssetId]) -> Vec<ContractId> {
+    route
+        .iter()
+        .map(|asset_id| ContractId::new(**asset_id))
+        .collect()
+}
+
+// Decodes the logs of the swap script and of every exchange contract along the route
+fn log_decoder(script: &LogDecoder, exchanges: &[ExchangeClient]) -> LogDecoder {
+    let mut log_decoder = script.clone();
+    for exchange in exchanges {
+        log_decoder.merge(exchange.log_decoder());
+    }
+    log_decoder
+}
+
+fn value<T>(
+    response: Result<FuelCallResponse<T>, SdkError>,
+    log_decoder: &LogDecoder,
+) -> Result<T, Error> {
+    response
+        .map(|response| response.value)
+        .map_err(|error| Error::from_revert(error, log_decoder))
+}
+
+fn variable_outputs(count: usize) -> Vec<Output> {
+    vec![
+        Output::Variable {
+            amount: 0,
+            to: Address::zeroed(),
+            asset_id: AssetId::default(),
+        };
+        count
+    ]
+}
@@ -0,0 +1,67 @@
+use crate::utils::setup;
+use amm_sdk::{amm::AmmClient, errors::Error};
+use test_utils::data_structures::LIQUIDITY_MINER_FEE;
+
+mod success {
+    use super::*;
+    use fuels::prelude::ContractId;
+
+    #[tokio::test]
+    async fn finds_pool() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let client = AmmClient::new(amm.id, wallet);
+
+        let pool = client.pool(pair, LIQUIDITY_MINER_FEE).await.unwrap();
+
+        assert_eq!(pool, Some(amm.pools.get(&pair).unwrap().id));
+    }
+
+    #[tokio::test]
+    async fn lists_pools_in_registration_order() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let client = AmmClient::new(amm.id, wallet);
+
+        let pools = client.pools().await.unwrap();
+
+        assert_eq!(pools.len(), asset_ids.len() - 1);
+        for (pool, pair) in pools.iter().zip(asset_ids.windows(2)) {
+            let asset_pair = (ContractId::new(*pair[0]), ContractId::new(*pair[1]));
+            assert_eq!(pool.asset_pair, asset_pair);
+            assert_eq!(pool.liquidity_miner_fee, LIQUIDITY_MINER_FEE);
+            assert_eq!(pool.pool, amm.pools.get(&(pair[0], pair[1])).unwrap().id);
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_exchange_client() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let client = AmmClient::new(amm.id, wallet);
+
+        let exchange = client.exchange(pair, LIQUIDITY_MINER_FEE).await.unwrap();
+
+        assert_eq!(exchange.id(), amm.pools.get(&pair).unwrap().id);
+        assert_eq!(
+            exchange.quote_exact_input(pair.0, 1_000).await.unwrap(),
+            987
+        );
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    async fn when_pool_is_not_registered() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let client = AmmClient::new(amm.id, wallet);
+
+        let error = client
+            .exchange((asset_ids[0], asset_ids[2]), LIQUIDITY_MINER_FEE)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::PoolNotRegistered { .. }));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::utils::{script_binaries, setup};
+use amm_sdk::{
+    errors::Error,
+    exchange::ExchangeClient,
+    interface::{
+        exchange::AccessError,
+        swap_exact_input::{InputError as RouteError, SwapError, SwapExactInputScript},
+    },
+    scripts::SCRIPT_GAS_LIMIT,
+};
+use fuels::prelude::{AssetId, ContractId, SettableContract, TxParameters, WalletUnlocked};
+use test_utils::data_structures::{AMMContract, LIQUIDITY_MINER_FEE};
+
+// Runs the swap exact input script directly, without the checks of the script client
+async fn swap_exact_input(
+    wallet: &WalletUnlocked,
+    amm: &AMMContract,
+    route: &[AssetId],
+    fee_tiers: &[u64],
+) -> Error {
+    let script = SwapExactInputScript::new(wallet.clone(), &script_binaries().swap_exact_input);
+    let call_handler = script
+        .main(
+            route
+                .iter()
+                .map(|asset_id| ContractId::new(**asset_id))
+                .collect(),
+            fee_tiers.to_vec(),
+            1_000,
+            None,
+            1_000,
+        )
+        .set_contracts(&[&amm.instance as &dyn SettableContract])
+        .tx_params(TxParameters::new(None, Some(SCRIPT_GAS_LIMIT), None));
+    let log_decoder = call_handler.log_decoder.clone();
+
+    let error = call_handler.call().await.unwrap_err();
+
+    Error::from_revert(error, &log_decoder)
+}
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn decodes_access_error() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        // registered pools are owned by the AMM contract rather than the wallet
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet);
+
+        let error = exchange
+            .instance()
+            .methods()
+            .set_protocol_fee(None)
+            .call()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            Error::from_revert(error, &exchange.log_decoder()),
+            Error::Access(AccessError::NotOwner)
+        ));
+    }
+
+    #[tokio::test]
+    async fn decodes_route_error_of_swap_script() {
+        let (wallet, amm, asset_ids) = setup().await;
+
+        let error = swap_exact_input(&wallet, &amm, &asset_ids[0..2], &[]).await;
+
+        assert!(matches!(error, Error::Route(RouteError::FeeTiersMismatch)));
+    }
+
+    #[tokio::test]
+    async fn decodes_swap_error_of_swap_script() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let route = [asset_ids[0], asset_ids[2]];
+
+        let error = swap_exact_input(&wallet, &amm, &route, &[LIQUIDITY_MINER_FEE]).await;
+
+        assert!(matches!(
+            error,
+            Error::Swap(SwapError::PairExchangeNotRegistered(pair))
+                if pair == (ContractId::new(*route[0]), ContractId::new(*route[1]))
+        ));
+    }
+}
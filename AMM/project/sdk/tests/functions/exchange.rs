@@ -0,0 +1,156 @@
+use crate::utils::setup;
+use amm_sdk::{
+    errors::Error,
+    exchange::ExchangeClient,
+    interface::exchange::InputError,
+    options::{Deadline, TxOptions},
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn quotes_swaps() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet);
+
+        assert_eq!(
+            exchange.quote_exact_input(pair.0, 1_000).await.unwrap(),
+            987
+        );
+        assert_eq!(
+            exchange.quote_exact_output(pair.1, 987).await.unwrap(),
+            1_000
+        );
+    }
+
+    #[tokio::test]
+    async fn swaps_exact_input() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet.clone());
+
+        let initial_balance = wallet.get_asset_balance(&pair.1).await.unwrap();
+
+        let output = exchange.swap_exact_input(pair.0, 1_000).await.unwrap();
+
+        let final_balance = wallet.get_asset_balance(&pair.1).await.unwrap();
+
+        assert_eq!(output, 987);
+        assert_eq!(final_balance, initial_balance + output);
+    }
+
+    #[tokio::test]
+    async fn swaps_exact_output() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet.clone());
+
+        let initial_balances = (
+            wallet.get_asset_balance(&pair.0).await.unwrap(),
+            wallet.get_asset_balance(&pair.1).await.unwrap(),
+        );
+
+        let input = exchange.swap_exact_output(pair.1, 987).await.unwrap();
+
+        let final_balances = (
+            wallet.get_asset_balance(&pair.0).await.unwrap(),
+            wallet.get_asset_balance(&pair.1).await.unwrap(),
+        );
+
+        // the part of the forwarded input that covered the slippage tolerance is refunded
+        assert_eq!(input, 1_000);
+        assert_eq!(final_balances.0, initial_balances.0 - input);
+        assert_eq!(final_balances.1, initial_balances.1 + 987);
+    }
+
+    #[tokio::test]
+    async fn adds_and_removes_liquidity() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet);
+
+        let preview = exchange.quote_add_liquidity(pair.0, 1_000).await.unwrap();
+
+        exchange.deposit(pair.0, 1_000).await.unwrap();
+        exchange
+            .deposit(pair.1, preview.other_asset_to_add.amount)
+            .await
+            .unwrap();
+        let liquidity = exchange
+            .add_liquidity(preview.liquidity_asset_to_receive.amount)
+            .await
+            .unwrap();
+
+        let removed = exchange.remove_liquidity(liquidity).await.unwrap();
+
+        assert_eq!(liquidity, 1_000);
+        assert_eq!(removed.burned_liquidity.amount, liquidity);
+        assert_eq!(removed.removed_amounts.a.amount, 1_000);
+        assert_eq!(removed.removed_amounts.b.amount, 1_000);
+    }
+
+    #[tokio::test]
+    async fn zaps() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet);
+
+        let preview = exchange.quote_zap(pair.0, 1_000).await.unwrap();
+        let liquidity = exchange.zap(pair.0, 1_000).await.unwrap();
+
+        assert_eq!(liquidity, preview.liquidity_asset_to_receive.amount);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    async fn when_deadline_has_passed() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let options = TxOptions::builder()
+            .with_deadline(Deadline::AtHeight(1))
+            .build()
+            .unwrap();
+        let exchange =
+            ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet).with_options(options);
+
+        let error = exchange.swap_exact_input(pair.0, 1_000).await.unwrap_err();
+
+        assert!(matches!(error, Error::Input(InputError::DeadlinePassed(1))));
+    }
+
+    #[tokio::test]
+    async fn when_asset_is_not_in_pool() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet);
+
+        let error = exchange
+            .quote_exact_input(asset_ids[2], 1_000)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Input(InputError::InvalidAsset { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn when_output_asset_is_not_in_pool() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = ExchangeClient::new(amm.pools.get(&pair).unwrap().id, wallet);
+
+        let error = exchange
+            .swap_exact_output(asset_ids[2], 1_000)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::UnknownAsset(asset) if asset == asset_ids[2]));
+    }
+}
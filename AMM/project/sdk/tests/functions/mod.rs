@@ -0,0 +1,5 @@
+mod amm;
+mod errors;
+mod exchange;
+mod options;
+mod scripts;
@@ -0,0 +1,82 @@
+use amm_sdk::{
+    errors::Error,
+    options::{Deadline, TxOptions, DEFAULT_DEADLINE_BLOCKS, DEFAULT_SLIPPAGE_BPS},
+};
+
+mod success {
+    use super::*;
+
+    #[test]
+    fn builds_default_options() {
+        let options = TxOptions::builder().build().unwrap();
+
+        assert_eq!(options, TxOptions::default());
+        assert_eq!(options.slippage_bps(), DEFAULT_SLIPPAGE_BPS);
+        assert_eq!(
+            options.deadline(),
+            Deadline::InBlocks(DEFAULT_DEADLINE_BLOCKS)
+        );
+    }
+
+    #[test]
+    fn builds_options() {
+        let options = TxOptions::builder()
+            .with_deadline(Deadline::AtHeight(100))
+            .with_slippage_bps(100)
+            .build()
+            .unwrap();
+
+        assert_eq!(options.slippage_bps(), 100);
+        assert_eq!(options.deadline(), Deadline::AtHeight(100));
+    }
+
+    #[test]
+    fn applies_slippage_to_output() {
+        let options = TxOptions::default();
+
+        assert_eq!(options.minimum_output(987), 982);
+        assert_eq!(options.minimum_output(1), 0);
+    }
+
+    #[test]
+    fn applies_slippage_to_input() {
+        let options = TxOptions::default();
+
+        assert_eq!(options.maximum_input(1_000), 1_005);
+        assert_eq!(options.maximum_input(1), 2);
+        assert_eq!(options.maximum_input(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn applies_no_slippage() {
+        let options = TxOptions::builder().with_slippage_bps(0).build().unwrap();
+
+        assert_eq!(options.minimum_output(987), 987);
+        assert_eq!(options.maximum_input(1_000), 1_000);
+    }
+
+    #[test]
+    fn applies_full_slippage() {
+        let options = TxOptions::builder()
+            .with_slippage_bps(10_000)
+            .build()
+            .unwrap();
+
+        assert_eq!(options.minimum_output(987), 0);
+        assert_eq!(options.maximum_input(1_000), 2_000);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[test]
+    fn when_slippage_exceeds_100_percent() {
+        let error = TxOptions::builder()
+            .with_slippage_bps(10_001)
+            .build()
+            .unwrap_err();
+
+        assert!(matches!(error, Error::InvalidSlippage(10_001)));
+    }
+}
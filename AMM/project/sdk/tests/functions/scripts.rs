@@ -0,0 +1,114 @@
+use crate::utils::{script_binaries, setup};
+use amm_sdk::{errors::Error, scripts::ScriptClient};
+use test_utils::data_structures::LIQUIDITY_MINER_FEE;
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn swaps_exact_input_along_route() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let route = &asset_ids[0..3];
+        let fee_tiers = [LIQUIDITY_MINER_FEE; 2];
+        let client = ScriptClient::new(amm.id, script_binaries(), wallet.clone());
+
+        let initial_balance = wallet.get_asset_balance(&route[2]).await.unwrap();
+
+        let quoted = client
+            .quote_exact_input(route, &fee_tiers, 1_000)
+            .await
+            .unwrap();
+        let output = client
+            .swap_exact_input(route, &fee_tiers, 1_000)
+            .await
+            .unwrap();
+
+        let final_balance = wallet.get_asset_balance(&route[2]).await.unwrap();
+
+        assert_eq!(output, quoted);
+        assert_eq!(final_balance, initial_balance + output);
+    }
+
+    #[tokio::test]
+    async fn swaps_exact_output_along_route() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let route = &asset_ids[0..3];
+        let fee_tiers = [LIQUIDITY_MINER_FEE; 2];
+        let client = ScriptClient::new(amm.id, script_binaries(), wallet.clone());
+
+        let initial_balance = wallet.get_asset_balance(&route[2]).await.unwrap();
+
+        let quoted = client
+            .quote_exact_output(route, &fee_tiers, 1_000)
+            .await
+            .unwrap();
+        let input = client
+            .swap_exact_output(route, &fee_tiers, 1_000)
+            .await
+            .unwrap();
+
+        let final_balance = wallet.get_asset_balance(&route[2]).await.unwrap();
+
+        assert_eq!(input, quoted);
+        assert_eq!(final_balance, initial_balance + 1_000);
+    }
+
+    #[tokio::test]
+    async fn adds_liquidity_atomically() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let pair = (asset_ids[0], asset_ids[1]);
+        let exchange = amm.pools.get(&pair).unwrap().id;
+        let client = ScriptClient::new(amm.id, script_binaries(), wallet);
+
+        let liquidity = client
+            .add_liquidity(exchange, ((pair.0, 1_000), (pair.1, 1_000)), 1_000)
+            .await
+            .unwrap();
+
+        assert_eq!(liquidity, 1_000);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    async fn when_route_is_too_short() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let client = ScriptClient::new(amm.id, script_binaries(), wallet);
+
+        let error = client
+            .swap_exact_input(&asset_ids[0..1], &[], 1_000)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::RouteTooShort));
+    }
+
+    #[tokio::test]
+    async fn when_fee_tiers_do_not_match_route() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let client = ScriptClient::new(amm.id, script_binaries(), wallet);
+
+        let error = client
+            .swap_exact_input(&asset_ids[0..3], &[LIQUIDITY_MINER_FEE], 1_000)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::FeeTiersMismatch));
+    }
+
+    #[tokio::test]
+    async fn when_route_has_unregistered_pool() {
+        let (wallet, amm, asset_ids) = setup().await;
+        let route = [asset_ids[0], asset_ids[2]];
+        let client = ScriptClient::new(amm.id, script_binaries(), wallet);
+
+        let error = client
+            .swap_exact_input(&route, &[LIQUIDITY_MINER_FEE], 1_000)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::PoolNotRegistered { .. }));
+    }
+}
@@ -0,0 +1,31 @@
+use amm_sdk::scripts::ScriptBinaries;
+use fuels::prelude::{AssetId, WalletUnlocked};
+use test_utils::{
+    data_structures::{AMMContract, WalletAssetConfiguration},
+    setup::{
+        common::{deploy_and_initialize_amm, setup_wallet_and_provider},
+        scripts::setup_exchange_contracts,
+    },
+};
+
+pub async fn setup() -> (WalletUnlocked, AMMContract, Vec<AssetId>) {
+    let (wallet, asset_ids, provider) =
+        setup_wallet_and_provider(&WalletAssetConfiguration::default()).await;
+
+    let mut amm = deploy_and_initialize_amm(&wallet).await;
+
+    // pools of (asset 1, asset 2), (asset 2, asset 3) and so on with ratios of 1:1, 1:2 and so on
+    setup_exchange_contracts(&wallet, &provider, &mut amm, &asset_ids).await;
+
+    (wallet, amm, asset_ids)
+}
+
+pub fn script_binaries() -> ScriptBinaries {
+    ScriptBinaries {
+        atomic_add_liquidity: "../scripts/atomic-add-liquidity/out/debug/atomic-add-liquidity.bin"
+            .to_string(),
+        swap_exact_input: "../scripts/swap-exact-input/out/debug/swap-exact-input.bin".to_string(),
+        swap_exact_output: "../scripts/swap-exact-output/out/debug/swap-exact-output.bin"
+            .to_string(),
+    }
+}
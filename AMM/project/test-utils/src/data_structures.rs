@@ -10,6 +10,7 @@ const COINS_PER_ASSET: u64 = 100;
 const DEADLINE: u64 = 1000;
 const DEPOSIT_AMOUNTS: (u64, u64) = (10000, 40000);
 const LIQUIDITY: u64 = 20000;
+pub const LIQUIDITY_MINER_FEE: u64 = 333;
 pub const NUMBER_OF_ASSETS: u64 = 5;
 
 pub struct AMMContract {
@@ -30,6 +31,8 @@ pub struct ExchangeContractConfiguration {
     pub compute_bytecode_root: bool,
     pub malicious: bool,
     pub salt: [u8; 32],
+    pub liquidity_miner_fee: u64,
+    pub stable: bool,
 }
 
 pub struct LiquidityParameters {
@@ -61,6 +64,8 @@ impl ExchangeContractConfiguration {
             compute_bytecode_root: compute_bytecode_root.unwrap_or_default(),
             malicious: malicious.unwrap_or_default(),
             salt: salt.unwrap_or_default(),
+            liquidity_miner_fee: LIQUIDITY_MINER_FEE,
+            stable: false,
         }
     }
 }
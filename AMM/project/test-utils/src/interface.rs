@@ -1,6 +1,7 @@
 use fuels::{
     prelude::{abigen, AssetId, CallParameters, ContractId, TxParameters},
     programs::call_response::FuelCallResponse,
+    types::Identity,
 };
 
 abigen!(
@@ -12,10 +13,26 @@ abigen!(
         name = "Exchange",
         abi = "./contracts/exchange-contract/out/debug/exchange-contract-abi.json"
     ),
+    Contract(
+        name = "FlashBorrower",
+        abi = "./contracts/exchange-contract/tests/artifacts/flash-borrower/out/debug/flash-borrower-abi.json"
+    ),
+    Contract(
+        name = "MaliciousBorrower",
+        abi = "./contracts/exchange-contract/tests/artifacts/malicious-borrower/out/debug/malicious-borrower-abi.json"
+    ),
+    Contract(
+        name = "StableExchange",
+        abi = "./contracts/stable-exchange-contract/out/debug/stable-exchange-contract-abi.json"
+    ),
     Script(
         name = "AtomicAddLiquidityScript",
         abi = "./scripts/atomic-add-liquidity/out/debug/atomic-add-liquidity-abi.json"
     ),
+    Script(
+        name = "SplitSwapExactInputScript",
+        abi = "./scripts/split-swap-exact-input/out/debug/split-swap-exact-input-abi.json"
+    ),
     Script(
         name = "SwapExactInputScript",
         abi = "./scripts/swap-exact-input/out/debug/swap-exact-input-abi.json"
@@ -44,6 +61,30 @@ pub mod amm {
             .unwrap()
     }
 
+    pub async fn add_exchange_bytecode_root(
+        contract: &AMM,
+        exchange_bytecode_root: ContractId,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .add_exchange_bytecode_root(exchange_bytecode_root)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn remove_exchange_bytecode_root(
+        contract: &AMM,
+        exchange_bytecode_root: ContractId,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .remove_exchange_bytecode_root(exchange_bytecode_root)
+            .call()
+            .await
+            .unwrap()
+    }
+
     pub async fn add_pool(
         contract: &AMM,
         asset_pair: (AssetId, AssetId),
@@ -64,13 +105,61 @@ pub mod amm {
             .unwrap()
     }
 
-    pub async fn pool(contract: &AMM, asset_pair: (AssetId, AssetId)) -> Option<ContractId> {
+    pub async fn set_protocol_fee(
+        contract: &AMM,
+        pool: ContractId,
+        protocol_fee: Option<ProtocolFee>,
+    ) -> FuelCallResponse<()> {
         contract
             .methods()
-            .pool((
-                ContractId::new(*asset_pair.0),
-                ContractId::new(*asset_pair.1),
-            ))
+            .set_protocol_fee(pool, protocol_fee)
+            .set_contract_ids(&[pool.into()])
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn pool(
+        contract: &AMM,
+        asset_pair: (AssetId, AssetId),
+        liquidity_miner_fee: u64,
+    ) -> Option<ContractId> {
+        contract
+            .methods()
+            .pool(
+                (
+                    ContractId::new(*asset_pair.0),
+                    ContractId::new(*asset_pair.1),
+                ),
+                liquidity_miner_fee,
+            )
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn pool_at(contract: &AMM, index: u64) -> Option<RegisteredPool> {
+        contract
+            .methods()
+            .pool_at(index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn pool_count(contract: &AMM) -> u64 {
+        contract.methods().pool_count().call().await.unwrap().value
+    }
+
+    pub async fn is_approved_exchange_bytecode_root(
+        contract: &AMM,
+        exchange_bytecode_root: ContractId,
+    ) -> bool {
+        contract
+            .methods()
+            .is_approved_exchange_bytecode_root(exchange_bytecode_root)
             .call()
             .await
             .unwrap()
@@ -112,12 +201,14 @@ pub mod exchange {
     pub async fn constructor(
         contract: &Exchange,
         asset_pair: (AssetId, AssetId),
+        liquidity_miner_fee: u64,
     ) -> FuelCallResponse<()> {
         contract
             .methods()
             .constructor(
                 ContractId::new(*asset_pair.0),
                 ContractId::new(*asset_pair.1),
+                liquidity_miner_fee,
             )
             .call()
             .await
@@ -135,6 +226,29 @@ pub mod exchange {
             .unwrap()
     }
 
+    pub async fn flash_swap(
+        contract: &Exchange,
+        output_asset: AssetId,
+        output_amount: u64,
+        borrower: ContractId,
+        deadline: u64,
+    ) -> FuelCallResponse<AssetPair> {
+        contract
+            .methods()
+            .flash_swap(
+                Asset {
+                    id: ContractId::new(*output_asset),
+                    amount: output_amount,
+                },
+                borrower,
+                deadline,
+            )
+            .set_contract_ids(&[borrower.into()])
+            .call()
+            .await
+            .unwrap()
+    }
+
     pub async fn remove_liquidity(
         contract: &Exchange,
         exchange_id: ContractId,
@@ -252,6 +366,73 @@ pub mod exchange {
             .unwrap()
     }
 
+    pub async fn zap(
+        contract: &Exchange,
+        input_asset: AssetId,
+        input_amount: u64,
+        min_liquidity: u64,
+        deadline: u64,
+        override_gas_limit: bool,
+    ) -> FuelCallResponse<u64> {
+        let mut call_handler = contract
+            .methods()
+            .zap(min_liquidity, deadline)
+            .call_params(CallParameters::new(
+                Some(input_amount),
+                Some(input_asset),
+                None,
+            ))
+            .unwrap()
+            .append_variable_outputs(3);
+
+        if override_gas_limit {
+            let estimated_gas = call_handler
+                .estimate_transaction_cost(Some(GAS_TOLERANCE))
+                .await
+                .unwrap()
+                .gas_used;
+
+            call_handler =
+                call_handler.tx_params(TxParameters::new(None, Some(estimated_gas), None));
+        }
+
+        call_handler.call().await.unwrap()
+    }
+
+    pub async fn set_protocol_fee(
+        contract: &Exchange,
+        protocol_fee: Option<ProtocolFee>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_protocol_fee(protocol_fee)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn transfer_ownership(
+        contract: &Exchange,
+        new_owner: Identity,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .transfer_ownership(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn withdraw_protocol_fees(contract: &Exchange) -> FuelCallResponse<AssetPair> {
+        contract
+            .methods()
+            .withdraw_protocol_fees()
+            .append_variable_outputs(2)
+            .call()
+            .await
+            .unwrap()
+    }
+
     pub async fn balance(contract: &Exchange, asset: AssetId) -> u64 {
         contract
             .methods()
@@ -266,6 +447,26 @@ pub mod exchange {
         contract.methods().pool_info().call().await.unwrap().value
     }
 
+    pub async fn protocol_fee_info(contract: &Exchange) -> ProtocolFeeInfo {
+        contract
+            .methods()
+            .protocol_fee_info()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn price_observation(contract: &Exchange) -> PriceObservation {
+        contract
+            .methods()
+            .price_observation()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
     pub async fn preview_add_liquidity(
         contract: &Exchange,
         amount: u64,
@@ -340,4 +541,48 @@ pub mod exchange {
 
         call_handler.call().await.unwrap().value
     }
+
+    pub async fn preview_zap(
+        contract: &Exchange,
+        amount: u64,
+        asset: AssetId,
+        override_gas_limit: bool,
+    ) -> PreviewZapInfo {
+        let mut call_handler = contract.methods().preview_zap(Asset {
+            id: ContractId::new(*asset),
+            amount,
+        });
+
+        if override_gas_limit {
+            let estimated_gas = call_handler
+                .estimate_transaction_cost(Some(GAS_TOLERANCE))
+                .await
+                .unwrap()
+                .gas_used;
+
+            call_handler =
+                call_handler.tx_params(TxParameters::new(None, Some(estimated_gas), None));
+        }
+
+        call_handler.call().await.unwrap().value
+    }
+}
+
+pub mod flash_borrower {
+    use super::*;
+
+    pub async fn set_repayment(
+        contract: &FlashBorrower,
+        repayment: Option<(AssetId, u64)>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_repayment(repayment.map(|(asset, amount)| Asset {
+                id: ContractId::new(*asset),
+                amount,
+            }))
+            .call()
+            .await
+            .unwrap()
+    }
 }
@@ -1,4 +1,7 @@
 pub mod data_structures;
 pub mod interface;
+pub mod model;
 pub mod paths;
+pub mod random;
 pub mod setup;
+pub mod twap;
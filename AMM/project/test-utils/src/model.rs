@@ -0,0 +1,357 @@
+/// Smallest amount of liquidity that may be added, as configured in the manifest of the exchange contract
+pub const MINIMUM_LIQUIDITY: u64 = 100;
+/// Largest share of the liquidity miner fee the protocol may take, in basis points
+pub const MAX_PROTOCOL_FEE_SHARE: u64 = 3_333;
+
+/// Denominator of the protocol fee share
+const BASIS_POINTS: u64 = 10_000;
+
+/// Reason the model refuses an operation, named after the error the exchange contract reverts with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModelError {
+    CannotAddLessThanMinimumLiquidity,
+    DesiredAmountTooHigh,
+    DesiredAmountTooLow,
+    ExpectedNonZeroAmount,
+    ExpectedNonZeroDeposit,
+    ExpectedNonZeroParameter,
+    InsufficientReserve,
+    NoLiquidityToRemove,
+    /// The contract reverts without logging an error, e.g. on an overflow, a division by zero or a
+    /// failed assertion
+    Panic,
+}
+
+/// Side of the pool an asset is on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    A,
+    B,
+}
+
+impl Side {
+    pub fn other(self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+}
+
+/// Outcome of adding liquidity
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddedLiquidity {
+    /// Amounts of the deposits added to the reserves
+    pub added: (u64, u64),
+    /// Amount of the liquidity pool asset minted
+    pub liquidity: u64,
+    /// Amounts of the deposits returned to the sender
+    pub refund: (u64, u64),
+}
+
+/// Pure reference model of a constant product exchange contract
+///
+/// Every operation mirrors the arithmetic, rounding and checks of the contract and leaves the model
+/// untouched when it is refused. Deadlines, deposits and identities are not modelled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExchangeModel {
+    /// Total supply of the liquidity pool asset
+    pub liquidity: u64,
+    /// Fee tier of the pool, one in `liquidity_miner_fee` of the input amount is charged as a fee
+    pub liquidity_miner_fee: u64,
+    /// Share of the liquidity miner fee taken by the protocol, in basis points
+    pub protocol_fee_share: Option<u64>,
+    /// Protocol fees accrued on either side, which are not part of the reserves
+    pub protocol_fees: (u64, u64),
+    /// Reserves of asset A and asset B
+    pub reserves: (u64, u64),
+}
+
+impl ExchangeModel {
+    pub fn new(liquidity_miner_fee: u64, protocol_fee_share: Option<u64>) -> Self {
+        Self {
+            liquidity: 0,
+            liquidity_miner_fee,
+            protocol_fee_share,
+            protocol_fees: (0, 0),
+            reserves: (0, 0),
+        }
+    }
+
+    /// Reserve of the asset on `side`
+    pub fn reserve(&self, side: Side) -> u64 {
+        match side {
+            Side::A => self.reserves.0,
+            Side::B => self.reserves.1,
+        }
+    }
+
+    /// Product of the reserves, which swaps must never decrease
+    pub fn product(&self) -> u128 {
+        self.reserves.0 as u128 * self.reserves.1 as u128
+    }
+
+    /// Adds `deposits` as liquidity, minting at least `desired_liquidity`
+    pub fn add_liquidity(
+        &mut self,
+        deposits: (u64, u64),
+        desired_liquidity: u64,
+    ) -> Result<AddedLiquidity, ModelError> {
+        require(
+            MINIMUM_LIQUIDITY <= desired_liquidity,
+            ModelError::CannotAddLessThanMinimumLiquidity,
+        )?;
+        require(deposits.0 != 0, ModelError::ExpectedNonZeroDeposit)?;
+        require(deposits.1 != 0, ModelError::ExpectedNonZeroDeposit)?;
+
+        let (added, liquidity) = if self.reserves == (0, 0) {
+            // the first deposits are used up entirely to set the ratio of the reserves
+            let product = deposits
+                .0
+                .checked_mul(deposits.1)
+                .ok_or(ModelError::Panic)?;
+            (deposits, integer_sqrt(product as u128) as u64)
+        } else {
+            let (reserve_a, reserve_b) = self.reserves;
+            let b_to_attempt = proportional_value(deposits.0, reserve_b, reserve_a)?;
+
+            if b_to_attempt <= deposits.1 {
+                let liquidity = proportional_value(b_to_attempt, self.liquidity, reserve_b)?;
+                ((deposits.0, b_to_attempt), liquidity)
+            } else {
+                let a_to_attempt = proportional_value(deposits.1, reserve_a, reserve_b)?;
+                let liquidity = proportional_value(a_to_attempt, self.liquidity, reserve_a)?;
+                ((a_to_attempt, deposits.1), liquidity)
+            }
+        };
+
+        require(
+            desired_liquidity <= liquidity,
+            ModelError::DesiredAmountTooHigh,
+        )?;
+
+        let reserves = (
+            checked_add(self.reserves.0, added.0)?,
+            checked_add(self.reserves.1, added.1)?,
+        );
+        self.liquidity = checked_add(self.liquidity, liquidity)?;
+        self.reserves = reserves;
+
+        Ok(AddedLiquidity {
+            added,
+            liquidity,
+            refund: (deposits.0 - added.0, deposits.1 - added.1),
+        })
+    }
+
+    /// Burns `burned_liquidity` for the proportional share of both reserves
+    pub fn remove_liquidity(
+        &mut self,
+        burned_liquidity: u64,
+        min_asset_a: u64,
+        min_asset_b: u64,
+    ) -> Result<(u64, u64), ModelError> {
+        require(self.liquidity > 0, ModelError::NoLiquidityToRemove)?;
+        require(min_asset_a > 0, ModelError::ExpectedNonZeroParameter)?;
+        require(min_asset_b > 0, ModelError::ExpectedNonZeroParameter)?;
+        require(burned_liquidity > 0, ModelError::ExpectedNonZeroAmount)?;
+
+        let removed = (
+            proportional_value(burned_liquidity, self.reserves.0, self.liquidity)?,
+            proportional_value(burned_liquidity, self.reserves.1, self.liquidity)?,
+        );
+
+        require(removed.0 >= min_asset_a, ModelError::DesiredAmountTooHigh)?;
+        require(removed.1 >= min_asset_b, ModelError::DesiredAmountTooHigh)?;
+
+        let reserves = (
+            checked_sub(self.reserves.0, removed.0)?,
+            checked_sub(self.reserves.1, removed.1)?,
+        );
+        self.liquidity = checked_sub(self.liquidity, burned_liquidity)?;
+        self.reserves = reserves;
+
+        Ok(removed)
+    }
+
+    /// Sells exactly `input_amount` of the asset on `input` for at least `min_output`
+    pub fn swap_exact_input(
+        &mut self,
+        input: Side,
+        input_amount: u64,
+        min_output: Option<u64>,
+    ) -> Result<u64, ModelError> {
+        require(input_amount > 0, ModelError::ExpectedNonZeroAmount)?;
+
+        let (input_reserve, output_reserve) = (self.reserve(input), self.reserve(input.other()));
+        let bought = minimum_output_given_exact_input(
+            input_amount,
+            input_reserve,
+            output_reserve,
+            self.liquidity_miner_fee,
+        )?;
+
+        if let Some(min_output) = min_output {
+            require(bought >= min_output, ModelError::DesiredAmountTooHigh)?;
+        }
+
+        self.settle_swap(input, input_amount, bought)?;
+
+        Ok(bought)
+    }
+
+    /// Buys exactly `output_amount` of the asset on the other side of `input`, forwarding
+    /// `input_amount` of which the unsold part is refunded
+    pub fn swap_exact_output(
+        &mut self,
+        input: Side,
+        output_amount: u64,
+        input_amount: u64,
+    ) -> Result<u64, ModelError> {
+        let (input_reserve, output_reserve) = (self.reserve(input), self.reserve(input.other()));
+
+        require(output_amount > 0, ModelError::ExpectedNonZeroParameter)?;
+        require(
+            output_amount < output_reserve,
+            ModelError::InsufficientReserve,
+        )?;
+        require(input_amount > 0, ModelError::ExpectedNonZeroAmount)?;
+
+        let sold = maximum_input_for_exact_output(
+            output_amount,
+            input_reserve,
+            output_reserve,
+            self.liquidity_miner_fee,
+        )?;
+
+        require(sold > 0, ModelError::DesiredAmountTooLow)?;
+        require(input_amount >= sold, ModelError::DesiredAmountTooHigh)?;
+
+        self.settle_swap(input, sold, output_amount)?;
+
+        Ok(sold)
+    }
+
+    // Moves the sold amount, less the protocol fee, into the reserves and the bought amount out
+    fn settle_swap(&mut self, input: Side, sold: u64, bought: u64) -> Result<(), ModelError> {
+        let protocol_fee = match self.protocol_fee_share {
+            Some(share) => protocol_fee_amount(sold, self.liquidity_miner_fee, share)?,
+            None => 0,
+        };
+
+        let (input_reserve, output_reserve) = (self.reserve(input), self.reserve(input.other()));
+        let input_reserve = checked_sub(checked_add(input_reserve, sold)?, protocol_fee)?;
+        let output_reserve = checked_sub(output_reserve, bought)?;
+
+        match input {
+            Side::A => {
+                self.protocol_fees.0 = checked_add(self.protocol_fees.0, protocol_fee)?;
+                self.reserves = (input_reserve, output_reserve);
+            }
+            Side::B => {
+                self.protocol_fees.1 = checked_add(self.protocol_fees.1, protocol_fee)?;
+                self.reserves = (output_reserve, input_reserve);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the input amount less the liquidity miner fee, rounding the fee down
+pub fn amount_with_fee(amount: u64, liquidity_miner_fee: u64) -> u64 {
+    amount - amount / liquidity_miner_fee
+}
+
+/// Largest integer whose square does not exceed `value`, matching the rounding of the contract
+pub fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method converges from above onto the floor of the square root
+    let mut root = value;
+    let mut next = value / 2 + value % 2;
+    while next < root {
+        root = next;
+        next = (root + value / root) / 2;
+    }
+    root
+}
+
+/// Amount of the input asset required to buy exactly `output_amount`, mirroring the contract
+///
+/// The quotient is rounded up by adding one, even when it is exact, unless it is less than one in
+/// which case it is rounded down to zero. Fails wherever the contract panics.
+pub fn maximum_input_for_exact_output(
+    output_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> Result<u64, ModelError> {
+    require(input_reserve > 0 && output_reserve > 0, ModelError::Panic)?;
+
+    let numerator = input_reserve as u128 * output_amount as u128;
+    let denominator = amount_with_fee(output_reserve - output_amount, liquidity_miner_fee) as u128;
+    require(denominator > 0, ModelError::Panic)?;
+
+    if denominator > numerator {
+        return Ok(0);
+    }
+
+    u64::try_from(numerator / denominator)
+        .ok()
+        .and_then(|quotient| quotient.checked_add(1))
+        .ok_or(ModelError::Panic)
+}
+
+/// Amount of the output asset bought with exactly `input_amount`, mirroring the contract
+pub fn minimum_output_given_exact_input(
+    input_amount: u64,
+    input_reserve: u64,
+    output_reserve: u64,
+    liquidity_miner_fee: u64,
+) -> Result<u64, ModelError> {
+    require(input_reserve > 0 && output_reserve > 0, ModelError::Panic)?;
+
+    let input_amount_with_fee = amount_with_fee(input_amount, liquidity_miner_fee) as u128;
+    let numerator = input_amount_with_fee * output_reserve as u128;
+    let denominator = input_reserve as u128 + input_amount_with_fee;
+
+    u64::try_from(numerator / denominator).map_err(|_| ModelError::Panic)
+}
+
+/// Share of the liquidity miner fee charged on `amount` that is taken by the protocol
+pub fn protocol_fee_amount(
+    amount: u64,
+    liquidity_miner_fee: u64,
+    protocol_fee_share: u64,
+) -> Result<u64, ModelError> {
+    proportional_value(
+        amount / liquidity_miner_fee,
+        protocol_fee_share,
+        BASIS_POINTS,
+    )
+}
+
+/// Calculates d in the proportion a / b = c / d, rounding down
+pub fn proportional_value(b: u64, c: u64, a: u64) -> Result<u64, ModelError> {
+    require(a > 0, ModelError::Panic)?;
+    u64::try_from(b as u128 * c as u128 / a as u128).map_err(|_| ModelError::Panic)
+}
+
+fn checked_add(a: u64, b: u64) -> Result<u64, ModelError> {
+    a.checked_add(b).ok_or(ModelError::Panic)
+}
+
+fn checked_sub(a: u64, b: u64) -> Result<u64, ModelError> {
+    a.checked_sub(b).ok_or(ModelError::Panic)
+}
+
+fn require(condition: bool, error: ModelError) -> Result<(), ModelError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
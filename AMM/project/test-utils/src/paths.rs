@@ -7,9 +7,23 @@ pub const EXCHANGE_CONTRACT_BINARY_PATH: &str =
     "../../contracts/exchange-contract/out/debug/exchange-contract.bin";
 pub const EXCHANGE_CONTRACT_STORAGE_PATH: &str =
     "../../contracts/exchange-contract/out/debug/exchange-contract-storage_slots.json";
+pub const FLASH_BORROWER_CONTRACT_BINARY_PATH: &str =
+    "../exchange-contract/tests/artifacts/flash-borrower/out/debug/flash-borrower.bin";
+pub const FLASH_BORROWER_CONTRACT_STORAGE_PATH: &str =
+    "../exchange-contract/tests/artifacts/flash-borrower/out/debug/flash-borrower-storage_slots.json";
+pub const MALICIOUS_BORROWER_CONTRACT_BINARY_PATH: &str =
+    "../exchange-contract/tests/artifacts/malicious-borrower/out/debug/malicious-borrower.bin";
+pub const MALICIOUS_BORROWER_CONTRACT_STORAGE_PATH: &str =
+    "../exchange-contract/tests/artifacts/malicious-borrower/out/debug/malicious-borrower-storage_slots.json";
 pub const MALICIOUS_EXCHANGE_CONTRACT_BINARY_PATH: &str =
     "../exchange-contract/tests/artifacts/malicious-implementation/out/debug/malicious-implementation.bin";
 pub const MALICIOUS_EXCHANGE_CONTRACT_STORAGE_PATH: &str =
     "../exchange-contract/tests/artifacts/malicious-implementation/out/debug/malicious-implementation-storage_slots.json";
+pub const SPLIT_SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH: &str =
+    "./out/debug/split-swap-exact-input.bin";
+pub const STABLE_EXCHANGE_CONTRACT_BINARY_PATH: &str =
+    "../../contracts/stable-exchange-contract/out/debug/stable-exchange-contract.bin";
+pub const STABLE_EXCHANGE_CONTRACT_STORAGE_PATH: &str =
+    "../../contracts/stable-exchange-contract/out/debug/stable-exchange-contract-storage_slots.json";
 pub const SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH: &str = "./out/debug/swap-exact-input.bin";
 pub const SWAP_EXACT_OUTPUT_SCRIPT_BINARY_PATH: &str = "./out/debug/swap-exact-output.bin";
@@ -0,0 +1,42 @@
+/// Deterministic pseudo-random number generator (SplitMix64) so that failing sequences can be
+/// replayed from their seed
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `low..=high`
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high);
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Returns an amount that is tiny, a small share, or a large share of `reference`, up to `cap`
+    ///
+    /// Amounts close to the boundaries are where rounding differences show up.
+    pub fn amount(&mut self, reference: u64, cap: u64) -> u64 {
+        let high = match self.between(0, 3) {
+            0 => 10,
+            1 => reference / 100,
+            2 => reference,
+            _ => cap,
+        };
+        self.between(1, high.clamp(1, cap))
+    }
+}
@@ -14,19 +14,23 @@ pub mod common {
     use fuels::{
         programs::call_response::FuelCallResponse,
         test_helpers::{setup_multiple_assets_coins, setup_test_provider},
+        types::Identity,
     };
 
     use crate::{
         data_structures::WalletAssetConfiguration,
         interface::{
-            amm::initialize,
-            exchange::{add_liquidity, constructor, deposit},
-            Exchange, AMM,
+            amm::{add_pool, initialize},
+            exchange::{add_liquidity, constructor, deposit, transfer_ownership},
+            Exchange, FlashBorrower, MaliciousBorrower, AMM,
         },
         paths::{
             AMM_CONTRACT_BINARY_PATH, AMM_CONTRACT_STORAGE_PATH, EXCHANGE_CONTRACT_BINARY_PATH,
-            EXCHANGE_CONTRACT_STORAGE_PATH, MALICIOUS_EXCHANGE_CONTRACT_BINARY_PATH,
-            MALICIOUS_EXCHANGE_CONTRACT_STORAGE_PATH,
+            EXCHANGE_CONTRACT_STORAGE_PATH, FLASH_BORROWER_CONTRACT_BINARY_PATH,
+            FLASH_BORROWER_CONTRACT_STORAGE_PATH, MALICIOUS_BORROWER_CONTRACT_BINARY_PATH,
+            MALICIOUS_BORROWER_CONTRACT_STORAGE_PATH, MALICIOUS_EXCHANGE_CONTRACT_BINARY_PATH,
+            MALICIOUS_EXCHANGE_CONTRACT_STORAGE_PATH, STABLE_EXCHANGE_CONTRACT_BINARY_PATH,
+            STABLE_EXCHANGE_CONTRACT_STORAGE_PATH,
         },
     };
     use std::collections::HashMap;
@@ -59,13 +63,15 @@ pub mod common {
     ) -> ExchangeContract {
         let (id, instance) = deploy_exchange(wallet, config).await;
 
-        constructor(&instance, config.pair).await;
+        constructor(&instance, config.pair, config.liquidity_miner_fee).await;
 
         ExchangeContract {
-            bytecode_root: if config.compute_bytecode_root {
-                Some(exchange_bytecode_root().await)
-            } else {
+            bytecode_root: if !config.compute_bytecode_root {
                 None
+            } else if config.stable {
+                Some(stable_exchange_bytecode_root().await)
+            } else {
+                Some(exchange_bytecode_root().await)
             },
             id,
             instance,
@@ -83,24 +89,29 @@ pub mod common {
         wallet: &WalletUnlocked,
         config: &ExchangeContractConfiguration,
     ) -> (ContractId, Exchange) {
-        let binary_path = if config.malicious {
-            MALICIOUS_EXCHANGE_CONTRACT_BINARY_PATH
+        let (binary_path, storage_path) = if config.malicious {
+            (
+                MALICIOUS_EXCHANGE_CONTRACT_BINARY_PATH,
+                MALICIOUS_EXCHANGE_CONTRACT_STORAGE_PATH,
+            )
+        } else if config.stable {
+            (
+                STABLE_EXCHANGE_CONTRACT_BINARY_PATH,
+                STABLE_EXCHANGE_CONTRACT_STORAGE_PATH,
+            )
         } else {
-            EXCHANGE_CONTRACT_BINARY_PATH
+            (
+                EXCHANGE_CONTRACT_BINARY_PATH,
+                EXCHANGE_CONTRACT_STORAGE_PATH,
+            )
         };
-        let storage_path = if config.malicious {
-            MALICIOUS_EXCHANGE_CONTRACT_STORAGE_PATH
-        } else {
-            EXCHANGE_CONTRACT_STORAGE_PATH
-        }
-        .to_string();
 
         let contract_id = Contract::deploy_with_parameters(
             binary_path,
             wallet,
             TxParameters::default(),
             StorageConfiguration {
-                storage_path: Some(storage_path),
+                storage_path: Some(storage_path.to_string()),
                 manual_storage_vec: None,
             },
             Salt::from(config.salt),
@@ -114,6 +125,46 @@ pub mod common {
         (id, instance)
     }
 
+    pub async fn deploy_flash_borrower(wallet: &WalletUnlocked) -> (ContractId, FlashBorrower) {
+        let contract_id = Contract::deploy(
+            FLASH_BORROWER_CONTRACT_BINARY_PATH,
+            wallet,
+            TxParameters::default(),
+            StorageConfiguration {
+                storage_path: Some(FLASH_BORROWER_CONTRACT_STORAGE_PATH.to_string()),
+                manual_storage_vec: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let id = ContractId::from(contract_id.clone());
+        let instance = FlashBorrower::new(contract_id, wallet.clone());
+
+        (id, instance)
+    }
+
+    pub async fn deploy_malicious_borrower(
+        wallet: &WalletUnlocked,
+    ) -> (ContractId, MaliciousBorrower) {
+        let contract_id = Contract::deploy(
+            MALICIOUS_BORROWER_CONTRACT_BINARY_PATH,
+            wallet,
+            TxParameters::default(),
+            StorageConfiguration {
+                storage_path: Some(MALICIOUS_BORROWER_CONTRACT_STORAGE_PATH.to_string()),
+                manual_storage_vec: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let id = ContractId::from(contract_id.clone());
+        let instance = MaliciousBorrower::new(contract_id, wallet.clone());
+
+        (id, instance)
+    }
+
     pub async fn deposit_and_add_liquidity_with_response(
         liquidity_parameters: &LiquidityParameters,
         exchange: &ExchangeContract,
@@ -154,13 +205,33 @@ pub mod common {
     }
 
     pub async fn exchange_bytecode_root() -> ContractId {
-        let exchange_raw_code = Contract::load_contract(
-            EXCHANGE_CONTRACT_BINARY_PATH,
-            &StorageConfiguration::default().storage_path,
+        bytecode_root(EXCHANGE_CONTRACT_BINARY_PATH)
+    }
+
+    /// Transfers ownership of the exchange contract to the AMM, which is required to add the pool
+    pub async fn register_pool(
+        amm: &AMM,
+        asset_pair: (AssetId, AssetId),
+        exchange: &ExchangeContract,
+    ) -> FuelCallResponse<()> {
+        transfer_ownership(
+            &exchange.instance,
+            Identity::ContractId(amm.contract_id().into()),
         )
-        .unwrap()
-        .raw;
-        (*TxContract::root_from_code(exchange_raw_code)).into()
+        .await;
+        add_pool(amm, asset_pair, exchange.id).await
+    }
+
+    pub async fn stable_exchange_bytecode_root() -> ContractId {
+        bytecode_root(STABLE_EXCHANGE_CONTRACT_BINARY_PATH)
+    }
+
+    fn bytecode_root(binary_path: &str) -> ContractId {
+        let raw_code =
+            Contract::load_contract(binary_path, &StorageConfiguration::default().storage_path)
+                .unwrap()
+                .raw;
+        (*TxContract::root_from_code(raw_code)).into()
     }
 
     pub async fn setup_wallet_and_provider(
@@ -185,8 +256,8 @@ pub mod common {
 
 pub mod scripts {
     use super::*;
-    use crate::{data_structures::TransactionParameters, interface::amm::add_pool};
-    use common::{deploy_and_construct_exchange, deposit_and_add_liquidity};
+    use crate::data_structures::{TransactionParameters, LIQUIDITY_MINER_FEE};
+    use common::{deploy_and_construct_exchange, deposit_and_add_liquidity, register_pool};
     use fuels::{
         tx::{Input, Output, TxPointer},
         types::resource::Resource,
@@ -202,6 +273,11 @@ pub mod scripts {
             .collect()
     }
 
+    /// Fee tier of every pool along `route`, as added by `setup_exchange_contracts`
+    pub fn fee_tiers(route: &[AssetId]) -> Vec<u64> {
+        vec![LIQUIDITY_MINER_FEE; route.len().saturating_sub(1)]
+    }
+
     pub async fn setup_exchange_contract(
         wallet: &WalletUnlocked,
         exchange_config: &ExchangeContractConfiguration,
@@ -251,7 +327,7 @@ pub mod scripts {
             )
             .await;
 
-            add_pool(&amm.instance, asset_pair, exchange.id).await;
+            register_pool(&amm.instance, asset_pair, &exchange).await;
 
             amm.pools.insert(asset_pair, exchange);
             exchange_index += 1;
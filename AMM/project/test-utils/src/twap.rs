@@ -0,0 +1,38 @@
+use super::interface::{PriceObservation, U128};
+
+/// Scale of the prices accumulated by the exchange contract
+pub const PRICE_PRECISION: u128 = 1_000_000_000;
+
+/// Time-weighted average prices of the assets of a pool, scaled by `PRICE_PRECISION`
+#[derive(Debug, PartialEq, Eq)]
+pub struct AveragePrices {
+    /// Average price of asset A in asset B
+    pub price_a: u128,
+    /// Average price of asset B in asset A
+    pub price_b: u128,
+}
+
+/// Returns the time-weighted average prices over the window between two observations of the same pool
+///
+/// Returns `None` when `end` was not observed after `start`
+pub fn time_weighted_average_prices(
+    start: &PriceObservation,
+    end: &PriceObservation,
+) -> Option<AveragePrices> {
+    let window = end.timestamp.checked_sub(start.timestamp)?;
+    if window == 0 {
+        return None;
+    }
+
+    let average =
+        |start: &U128, end: &U128| to_u128(end).wrapping_sub(to_u128(start)) / window as u128;
+
+    Some(AveragePrices {
+        price_a: average(&start.price_a_cumulative, &end.price_a_cumulative),
+        price_b: average(&start.price_b_cumulative, &end.price_b_cumulative),
+    })
+}
+
+fn to_u128(value: &U128) -> u128 {
+    ((value.upper as u128) << 64) | value.lower as u128
+}
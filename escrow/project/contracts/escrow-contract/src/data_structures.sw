@@ -27,29 +27,45 @@ pub struct Asset {
 pub struct Buyer {
     /// Address identifying the buyer
     address: Identity,
-    /// The asset that the user has currently deposited in the contract
-    asset: Option<ContractId>,
-    // Minor data duplication allows us to forego validating unique assets upon escrow creation
-    // otherwise the same asset with different values can be added which, if handled incorrectly,
-    // may allow the user to drain the contract
-    /// The amount of asset that has been deposited
+    /// The amount of asset that the buyer has deposited, which sets their share of any refund
     deposited_amount: u64,
 }
 
 pub struct EscrowInfo {
-    /// Trusted 3rd party who handles the resolution of a dispute
-    arbiter: Arbiter,
+    /// The asset that the buyers have deposited in the contract
+    asset: Option<ContractId>,
     /// Total number of assets the escrow accepts
     asset_count: u64,
-    /// The authorized user who is able to make a payment into the escrow
-    buyer: Buyer,
-    /// End height after which the buyer can no longer deposit and the seller can take payment
+    /// Total number of buyers who are able to make a payment into the escrow
+    buyer_count: u64,
+    /// Position of the milestone that is currently being worked on, equal to `milestone_count`
+    /// once every milestone has been settled
+    current_milestone: u64,
+    /// End height after which the buyers can no longer deposit and the seller can take payment
     deadline: u64,
-    /// Marker set by the buyer to lock the escrow and prevent the seller from taking payment
+    // Minor data duplication allows us to forego validating unique assets upon escrow creation
+    // otherwise the same asset with different values can be added which, if handled incorrectly,
+    // may allow the user to drain the contract
+    /// The amount of asset that has been deposited by all of the buyers
+    deposited_amount: u64,
+    /// Marker set by a buyer to lock the current milestone and prevent the seller from taking
+    /// payment
     disputed: bool,
     /// Index of the first asset in storage vec `assets`
     first_asset_index: u64,
-    /// The authorized user who is the recipient of payments made by the buyer
+    /// Index of the first buyer in storage vec `buyers`
+    first_buyer_index: u64,
+    /// Index of the first milestone in storage vec `milestones`
+    first_milestone_index: u64,
+    /// Total number of milestones the deposit is released in
+    milestone_count: u64,
+    /// Trusted 3rd parties who handle the resolution of a dispute
+    panel: Panel,
+    /// Height after which either party can settle the current dispute with the rulings so far
+    resolution_deadline: u64,
+    /// Number of rulings submitted by the panel on the current dispute
+    ruling_count: u64,
+    /// The authorized user who is the recipient of payments made by the buyers
     seller: Seller,
     /// Mechanism used to manage the control flow of the escrow
     state: State,
@@ -57,24 +73,31 @@ pub struct EscrowInfo {
 
 impl EscrowInfo {
     pub fn new(
-        arbiter: Arbiter,
         asset_count: u64,
-        buyer: Identity,
+        buyer_count: u64,
         deadline: u64,
         first_asset_index: u64,
+        first_buyer_index: u64,
+        first_milestone_index: u64,
+        milestone_count: u64,
+        panel: Panel,
         seller: Identity,
     ) -> Self {
         Self {
-            arbiter,
+            asset: Option::None,
             asset_count,
-            buyer: Buyer {
-                address: buyer,
-                asset: Option::None,
-                deposited_amount: 0,
-            },
+            buyer_count,
+            current_milestone: 0,
             deadline,
+            deposited_amount: 0,
             disputed: false,
             first_asset_index,
+            first_buyer_index,
+            first_milestone_index,
+            milestone_count,
+            panel,
+            resolution_deadline: 0,
+            ruling_count: 0,
             seller: Seller {
                 address: seller,
             },
@@ -83,6 +106,105 @@ impl EscrowInfo {
     }
 }
 
+pub struct Milestone {
+    /// Amount of the deposited asset released to the seller upon completion of the milestone
+    amount: u64,
+    /// Height after which the seller can take payment for the milestone, the deadline of the
+    /// escrow applies when it is not set
+    deadline: Option<u64>,
+}
+
+pub struct MilestoneInfo {
+    /// Amount of the deposited asset released to the seller upon completion of the milestone
+    amount: u64,
+    /// Height after which the seller can take payment for the milestone
+    deadline: Option<u64>,
+    /// Whether the amount of the milestone is still held or who it has been sent to
+    state: MilestoneState,
+}
+
+impl MilestoneInfo {
+    pub fn new(milestone: Milestone) -> Self {
+        Self {
+            amount: milestone.amount,
+            deadline: milestone.deadline,
+            state: MilestoneState::Pending,
+        }
+    }
+}
+
+pub enum MilestoneState {
+    /// The amount of the milestone is held by the escrow
+    Pending: (),
+    /// The amount of the milestone has been sent to the seller
+    Released: (),
+    /// The amount of the milestone has been sent back to the buyers
+    Returned: (),
+}
+
+impl Eq for MilestoneState {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (MilestoneState::Pending, MilestoneState::Pending) => {
+                true
+            },
+            (MilestoneState::Released, MilestoneState::Released) => {
+                true
+            },
+            (MilestoneState::Returned, MilestoneState::Returned) => {
+                true
+            },
+            _ => {
+                false
+            },
+        }
+    }
+}
+
+pub struct Panel {
+    /// Number of arbiters on the panel
+    arbiter_count: u64,
+    /// The party that receives the disputed milestone when no arbiter has ruled by the resolution
+    /// deadline
+    default_party: Party,
+    /// Index of the first arbiter in storage vec `arbiters`
+    first_arbiter_index: u64,
+    /// Number of rulings after which a dispute is resolved with the median ruling
+    quorum: u64,
+    /// Number of blocks the panel has to rule on a dispute before either party can settle it
+    resolution_window: u64,
+}
+
+pub enum Party {
+    /// The disputed milestone is split between the buyers in proportion to their deposits
+    Buyers: (),
+    /// The disputed milestone is released to the seller
+    Seller: (),
+}
+
+pub enum Role {
+    /// User who has been on the panel of an escrow
+    Arbiter: (),
+    /// User who deposits into an escrow
+    Buyer: (),
+    /// User who created an escrow
+    Seller: (),
+}
+
+pub struct Ruling {
+    /// The amount of the arbiter fees the panel will take as a payment for their work
+    payment_amount: u64,
+    /// The user who the disputed milestone will be sent to (either a buyer or the seller), a
+    /// milestone sent to any buyer is split between all of the buyers
+    user: Identity,
+}
+
+impl Eq for Ruling {
+    fn eq(self, other: Self) -> bool {
+        self.payment_amount == other.payment_amount && self.user == other.user
+    }
+}
+
 pub struct Seller {
     /// Address identifying the seller
     address: Identity,
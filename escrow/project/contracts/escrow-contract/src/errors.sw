@@ -1,6 +1,7 @@
 library errors;
 
 pub enum ArbiterInputError {
+    AlreadyOnPanel: (),
     AssetDoesNotMatch: (),
     CannotBeBuyer: (),
     CannotBeSeller: (),
@@ -14,6 +15,11 @@ pub enum AssetInputError {
     AssetAmountCannotBeZero: (),
 }
 
+pub enum BuyerInputError {
+    AlreadyBuyer: (),
+    UnspecifiedBuyers: (),
+}
+
 pub enum DeadlineInputError {
     MustBeInTheFuture: (),
 }
@@ -23,12 +29,30 @@ pub enum DepositError {
     IncorrectAssetSent: (),
 }
 
+pub enum MilestoneInputError {
+    AmountCannotBeZero: (),
+    AmountsDoNotMatchAssets: (),
+    DeadlinesMustIncrease: (),
+    RequireSingleAsset: (),
+}
+
+pub enum PanelInputError {
+    InvalidQuorum: (),
+    PositionOutOfBounds: (),
+    ResolutionWindowCannotBeZero: (),
+    ResolutionWindowTooLong: (),
+    UnspecifiedArbiters: (),
+}
+
 pub enum StateError {
+    AlreadyApproved: (),
     AlreadyDeposited: (),
     AlreadyDisputed: (),
+    AlreadyRuled: (),
     ArbiterHasNotBeenProposed: (),
     CannotDisputeBeforeDesposit: (),
     CannotResolveBeforeDesposit: (),
+    CannotSettleBeforeResolutionDeadline: (),
     CannotTakePaymentBeforeDeadline: (),
     CannotTakePaymentDuringDispute: (),
     CannotTransferBeforeDesposit: (),
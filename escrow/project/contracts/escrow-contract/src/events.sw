@@ -2,11 +2,31 @@ library events;
 
 dep data_structures;
 
-use data_structures::{Arbiter, EscrowInfo};
+use data_structures::{Arbiter, EscrowInfo, Ruling};
 
 pub struct AcceptedArbiterEvent {
     /// Unique escrow identifier
     identifier: u64,
+    /// Position of the arbiter on the panel
+    position: u64,
+}
+
+pub struct ApprovedArbiterEvent {
+    /// The buyer who approved the proposal
+    buyer: Identity,
+    /// Unique escrow identifier
+    identifier: u64,
+    /// Position of the arbiter on the panel
+    position: u64,
+}
+
+pub struct ApprovedTransferEvent {
+    /// The buyer who approved the transfer
+    buyer: Identity,
+    /// Unique escrow identifier
+    identifier: u64,
+    /// Position of the milestone in the escrow
+    milestone: u64,
 }
 
 pub struct CreatedEscrowEvent {
@@ -17,8 +37,12 @@ pub struct CreatedEscrowEvent {
 }
 
 pub struct DepositEvent {
-    /// The asset that the user deposited
+    /// The amount of asset that the buyer deposited
+    amount: u64,
+    /// The asset that the buyer deposited
     asset: ContractId,
+    /// The buyer who made the deposit
+    buyer: Identity,
     /// Unique escrow identifier
     identifier: u64,
 }
@@ -26,11 +50,20 @@ pub struct DepositEvent {
 pub struct DisputeEvent {
     /// Unique escrow identifier
     identifier: u64,
+    /// Position of the milestone in the escrow
+    milestone: u64,
+}
+
+pub struct ExpiredEscrowEvent {
+    /// Unique escrow identifier
+    identifier: u64,
 }
 
 pub struct PaymentTakenEvent {
     /// Unique escrow identifier
     identifier: u64,
+    /// Position of the milestone in the escrow
+    milestone: u64,
 }
 
 pub struct ProposedArbiterEvent {
@@ -38,12 +71,17 @@ pub struct ProposedArbiterEvent {
     arbiter: Arbiter,
     /// Unique escrow identifier
     identifier: u64,
+    /// Position on the panel of the arbiter that is proposed to be replaced
+    position: u64,
 }
 
 pub struct ResolvedDisputeEvent {
     /// Unique escrow identifier
     identifier: u64,
-    /// The user that has been chosen by the arbiter to receive the disputed funds (buyer / seller)
+    /// Position of the disputed milestone in the escrow
+    milestone: u64,
+    /// The user that has been chosen by the panel to receive the disputed funds (buyer / seller),
+    /// funds sent to a buyer are split between all of the buyers
     user: Identity,
 }
 
@@ -52,9 +90,22 @@ pub struct ReturnedDepositEvent {
     identifier: u64,
 }
 
+pub struct SubmittedRulingEvent {
+    /// The arbiter who submitted the ruling
+    arbiter: Identity,
+    /// Unique escrow identifier
+    identifier: u64,
+    /// Position of the disputed milestone in the escrow
+    milestone: u64,
+    /// The recipient of the disputed milestone and the payment chosen by the arbiter
+    ruling: Ruling,
+}
+
 pub struct TransferredToSellerEvent {
     /// Unique escrow identifier
     identifier: u64,
+    /// Position of the milestone in the escrow
+    milestone: u64,
 }
 
 pub struct WithdrawnCollateralEvent {
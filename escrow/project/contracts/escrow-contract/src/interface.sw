@@ -2,49 +2,96 @@ library interface;
 
 dep data_structures;
 
-use data_structures::{Arbiter, Asset, EscrowInfo};
+use data_structures::{
+    Arbiter,
+    Asset,
+    Buyer,
+    EscrowInfo,
+    Milestone,
+    MilestoneInfo,
+    Party,
+    Role,
+    Ruling,
+};
 
 abi Escrow {
-    /// Buyer accepts proposal to change arbiter details
+    /// A buyer approves the proposal to change the details of an arbiter on the panel
+    ///
+    /// The proposal is accepted once every buyer has approved it, approvals are discarded when the
+    /// seller makes a new proposal for the position. A ruling already submitted by the replaced
+    /// arbiter on the current dispute is discarded
     ///
     /// # Arguments
     ///
     /// * `identifier`: Identifier used to find a specific escrow
+    /// * `position`: Position of the arbiter on the panel
     ///
     /// # Reverts
     ///
     /// * When the escrow is not in the State::Pending state
-    /// * When the caller is not the buyer
+    /// * When the caller is not a buyer
     /// * When the arbiter has not been proposed by the seller
+    /// * When the caller has already approved the proposal
+    /// * When the proposed arbiter already sits at another position on the panel
     #[storage(read, write)]
-    fn accept_arbiter(identifier: u64);
+    fn accept_arbiter(identifier: u64, position: u64);
 
     /// Creates an internal representation of an escrow instead of deploying a contract per escrow
     ///
-    /// The escrow allows the buyer to deposit any asset from the specified assets
+    /// The escrow allows the buyers to deposit any asset from the specified assets, every buyer
+    /// contributes part of the amount of that asset
+    /// The deposit is released to the seller in the order of the milestones, an escrow without
+    /// milestones releases the whole deposit at once
+    /// An escrow with milestones accepts a single asset as the milestones are amounts of that asset
     ///
     /// # Arguments
     ///
-    /// * `arbiter`: A third party which decides how a dispute is resolved
+    /// * `arbiters`: The panel of third parties which decides how a dispute is resolved
     /// * `assets`: The assets, with the required deposit amounts, that the campaign accepts
-    /// * `buyer`: User who deposits funds into the escrow
-    /// * `deadline`: End height after which the buyer can no longer deposit and the seller can take payment
+    /// * `buyers`: Users who deposit funds into the escrow
+    /// * `deadline`: End height after which the buyers can no longer deposit and the seller can take payment
+    /// * `default_party`: The party that receives a disputed milestone when the panel does not rule in time
+    /// * `milestones`: The amounts, with optional deadlines, the deposit is released in
+    /// * `quorum`: The number of rulings after which a dispute is resolved with the median ruling
+    /// * `resolution_window`: The number of blocks the panel has to rule on a dispute
     ///
     /// # Reverts
     ///
     /// * When the caller does not specify any assets
     /// * When the deadline is not in the future
-    /// * When the arbiter fee is set to 0
-    /// * When the caller does not deposit the amount specified for the arbiter fee
-    /// * When the caller does not deposit the specified asset for the arbiter fee
-    /// * When the caller is setting the buyer or themselves as the arbiter
+    /// * When the caller does not specify any buyers
+    /// * When the same buyer is set more than once
+    /// * When the caller does not specify any arbiters
+    /// * When the quorum is 0 or larger than the panel
+    /// * When the resolution window is set to 0
+    /// * When the resolution window is longer than 1_000_000 blocks
+    /// * When any arbiter fee is set to 0
+    /// * When the caller does not deposit the specified asset for every arbiter fee
+    /// * When the caller is setting a buyer or themselves as an arbiter
+    /// * When the same arbiter is set more than once
+    /// * When the caller does not deposit the total amount specified for the arbiter fees
     /// * When the amount of any asset required for deposit is set to 0
+    /// * When milestones are set and the caller specifies more than one asset
+    /// * When the amount of any milestone is set to 0
+    /// * When the deadlines of the milestones are not after the deadline and each other
+    /// * When the amount of the asset is not the total amount of the milestones
     #[payable, storage(read, write)]
-    fn create_escrow(arbiter: Arbiter, assets: Vec<Asset>, buyer: Identity, deadline: u64);
+    fn create_escrow(
+        arbiters: Vec<Arbiter>,
+        assets: Vec<Asset>,
+        buyers: Vec<Identity>,
+        deadline: u64,
+        default_party: Party,
+        milestones: Vec<Milestone>,
+        quorum: u64,
+        resolution_window: u64,
+    );
 
-    /// Accepts a deposit from the buyer for any of the assets specified in the escrow
+    /// Accepts a deposit from a buyer for any of the assets specified in the escrow
     ///
-    /// A successful deposit unlocks functionality for the rest of the escrow
+    /// Every buyer may deposit any part of the amount of the asset, as many times as they like,
+    /// and all of the deposits must be in the same asset
+    /// Depositing the whole amount of the asset unlocks functionality for the rest of the escrow
     ///
     /// # Arguments
     ///
@@ -54,10 +101,11 @@ abi Escrow {
     ///
     /// * When the deposit is made during / after the deadline
     /// * When the escrow is not in the State::Pending state
-    /// * When the caller is not the buyer
-    /// * When the caller deposits more than once
-    /// * When the caller sends an incorrect amount of an asset for the specified asset in the escrow
-    /// * When the caller deposits an asset that has not been specified in the escrow
+    /// * When the caller is not a buyer
+    /// * When the buyers have already deposited the whole amount
+    /// * When the caller deposits an asset that has not been specified in the escrow or differs
+    ///   from the asset deposited by the other buyers
+    /// * When the caller sends nothing or more than the amount left for the asset in the escrow
     #[payable, storage(read, write)]
     fn deposit(identifier: u64);
 
@@ -65,6 +113,8 @@ abi Escrow {
     ///
     /// Once the escrow is locked the seller cannot take the payment given that the conditions for
     /// taking a payment have been otherwise met
+    /// The dispute only applies to the current milestone and ends once that milestone is settled
+    /// The panel has the resolution window of the escrow to rule on the dispute
     ///
     /// # Arguments
     ///
@@ -74,54 +124,88 @@ abi Escrow {
     ///
     /// * When the escrow is not in the State::Pending state
     /// * When the escrow is already in a dispute
-    /// * When the caller is not the buyer
-    /// * When the caller does not currently have a deposit in the escrow
+    /// * When the caller is not a buyer
+    /// * When the buyers have not deposited the whole amount
     #[storage(read, write)]
     fn dispute(identifier: u64);
 
-    /// Allows the seller to propose a new arbiter and/or change the arbiter fee
+    /// Completes an escrow that the buyers have not funded by its deadline
+    ///
+    /// Anyone can expire the escrow so that the buyers do not have to wait on the seller for their
+    /// partial deposits, which are returned to the buyers who made them, while the collateral is
+    /// returned to the seller
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier`: Identifier used to find a specific escrow
+    ///
+    /// # Reverts
+    ///
+    /// * When the escrow is not in the State::Pending state
+    /// * When the caller attempts to expire the escrow before / during the deadline
+    /// * When the buyers deposited the whole amount
+    #[storage(read, write)]
+    fn expire_escrow(identifier: u64);
+
+    /// Allows the seller to propose a new arbiter and/or change the arbiter fee for a position on
+    /// the panel
     ///
     /// If a dispute has been initiated and the arbiter is taking too long then the seller can change
     /// the arbiter, the asset for payment and the fee amount
     /// Seller can also set the same arbiter but with a different fee
+    /// The asset for payment must be the same across a panel of more than one arbiter
     ///
     /// # Arguments
     ///
     /// * `arbiter`: A third party which decides how a dispute is resolved
     /// * `identifier`: Identifier used to find a specific escrow
+    /// * `position`: Position of the arbiter on the panel that is replaced
     ///
     /// # Reverts
     ///
     /// * When the escrow is not in the State::Pending state
     /// * When the caller is not the seller
-    /// * When the caller is setting the buyer or seller as the new arbiter
+    /// * When the position is not on the panel
+    /// * When the caller is setting a buyer or the seller as the new arbiter
     /// * When the arbiter fee is set to 0
     /// * When the caller does not deposit the amount specified for the arbiter fee
     /// * When the caller does not deposit the specified asset for the arbiter fee
+    /// * When the new arbiter is already at another position on the panel
+    /// * When the asset for payment differs from the rest of the panel
     #[payable, storage(read, write)]
-    fn propose_arbiter(arbiter: Arbiter, identifier: u64);
+    fn propose_arbiter(arbiter: Arbiter, identifier: u64, position: u64);
 
-    /// The arbiter decides who the deposit is sent to and how much of the designated payment they
-    /// will take
+    /// An arbiter on the panel submits a ruling on who the amount of the current milestone is sent
+    /// to and how much of the designated payment the panel will take
+    ///
+    /// The dispute is resolved as soon as a majority of the panel submits matching rulings or,
+    /// failing that, with the median ruling once the quorum of rulings has been submitted
+    /// The payment is shared between the arbiters who ruled in proportion to their fees, deducted
+    /// from those fees and the rest of the fees are returned to the seller once the last milestone
+    /// is settled
     ///
     /// # Arguments
     ///
     /// * `identifier`: Identifier used to find a specific escrow
-    /// * `payment_amount`: The amount the arbiter will take as a payment for their work
-    /// * `user`: The user who the deposit from the buyer will be sent to (either buyer or seller)
+    /// * `payment_amount`: The amount of the arbiter fees the panel will take as a payment
+    /// * `user`: The user who the milestone amount will be sent to (either a buyer or the seller),
+    ///           an amount sent to a buyer is split between the buyers in proportion to their
+    ///           deposits
     ///
     /// # Reverts
     ///
     /// * When the escrow is not in the State::Pending state
     /// * When the escrow is not in dispute
-    /// * When the caller is not the arbiter
-    /// * When the `user` is not the buyer or seller
-    /// * When the buyer does not currently have a deposit in the escrow
-    /// * When the `payment_amount` is greater than the deposit by the seller
+    /// * When the caller is not an arbiter on the panel
+    /// * When the `user` is not a buyer or the seller
+    /// * When the buyers have not deposited the whole amount
+    /// * When the `payment_amount` is greater than the remaining deposit by the seller
+    /// * When the caller has already ruled on the dispute
     #[storage(read, write)]
     fn resolve_dispute(identifier: u64, payment_amount: u64, user: Identity);
 
-    /// The seller transfers the funds from the escrow to the buyer
+    /// The seller transfers the funds of every milestone that has not been settled from the escrow
+    /// to the buyers, split in proportion to their deposits
     ///
     /// # Arguments
     ///
@@ -131,12 +215,32 @@ abi Escrow {
     ///
     /// * When the escrow is not in the State::Pending state
     /// * When the caller is not the seller
-    /// * When the buyer does not currently have a deposit in the escrow
+    /// * When the buyers have not deposited the whole amount
     #[storage(read, write)]
     fn return_deposit(identifier: u64);
 
-    /// If a user has deposited but not transferred in time & they have not disputed then the seller
-    /// can take the payment themselves
+    /// Falls back to the rulings submitted so far when the panel has not resolved a dispute before
+    /// the resolution deadline
+    ///
+    /// The dispute is resolved with the median ruling of the arbiters who ruled. If no arbiter
+    /// ruled then the disputed milestone is sent to the default party of the panel without a
+    /// payment
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier`: Identifier used to find a specific escrow
+    ///
+    /// # Reverts
+    ///
+    /// * When the escrow is not in the State::Pending state
+    /// * When the escrow is not in dispute
+    /// * When the caller is not a buyer or the seller
+    /// * When the caller attempts to settle before / during the resolution deadline
+    #[storage(read, write)]
+    fn settle_dispute(identifier: u64);
+
+    /// If the buyers have deposited but not transferred the current milestone in time & they have
+    /// not disputed it then the seller can take the payment for that milestone themselves
     ///
     /// # Arguments
     ///
@@ -145,14 +249,18 @@ abi Escrow {
     /// # Reverts
     ///
     /// * When the escrow is not in the State::Pending state
-    /// * When the caller attempts to take payment before / during the deadline
+    /// * When the caller attempts to take payment before / during the deadline of the milestone
     /// * When the caller attempts to take payment during a dispute
     /// * When the caller is not the seller
-    /// * When the buyer does not currently have a deposit in the escrow
+    /// * When the buyers have not deposited the whole amount
     #[storage(read, write)]
     fn take_payment(identifier: u64);
 
-    /// After a buyer deposits they can transfer the deposit to the seller
+    /// After the buyers deposit each of them approves the transfer of the amount of the current
+    /// milestone to the seller
+    ///
+    /// The milestone is transferred once every buyer has approved it and the escrow is completed
+    /// once the last milestone has been transferred
     ///
     /// # Arguments
     ///
@@ -161,13 +269,16 @@ abi Escrow {
     /// # Reverts
     ///
     /// * When the escrow is not in the State::Pending state
-    /// * When the buyer does not currently have a deposit in the escrow
-    /// * When the caller is not the buyer
+    /// * When the buyers have not deposited the whole amount
+    /// * When the caller is not a buyer
+    /// * When the caller has already approved the transfer of the current milestone
     #[storage(read, write)]
     fn transfer_to_seller(identifier: u64);
 
-    /// If a buyer has not deposited and the deadline has been surpassed then the seller can withdraw
-    /// their collateral
+    /// If the buyers have not deposited the whole amount and the deadline has been surpassed then
+    /// the seller can withdraw their collateral
+    ///
+    /// Any partial deposits are returned to the buyers who made them
     ///
     /// # Arguments
     ///
@@ -179,27 +290,46 @@ abi Escrow {
     /// * When the caller attempts to withdraw before / during the deadline
     /// * When the caller attempts to withdraw during a dispute
     /// * When the caller is not the seller
-    /// * When the buyer deposited
+    /// * When the buyers deposited the whole amount
     #[storage(read, write)]
     fn withdraw_collateral(identifier: u64);
 }
 
 abi Info {
-    /// Returns the proposed arbiter for resolution
+    /// Returns the proposed arbiter for a position on the panel
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier`: Identifier used to find a specific escrow
+    /// * `position`: Position of the arbiter on the panel
+    #[storage(read)]
+    fn arbiter_proposal(identifier: u64, position: u64) -> Option<Arbiter>;
+
+    /// Returns an arbiter on the panel of an escrow
     ///
     /// # Arguments
     ///
     /// * `identifier`: Identifier used to find a specific escrow
+    /// * `position`: Position of the arbiter on the panel
     #[storage(read)]
-    fn arbiter_proposal(identifier: u64) -> Option<Arbiter>;
+    fn arbiters(identifier: u64, position: u64) -> Option<Arbiter>;
 
-    /// Returns the information about the asset used in an escrow
+    /// Returns every asset that an escrow accepts, in the order they were specified
     ///
     /// # Arguments
     ///
     /// * `identifier`: Identifier used to find a specific escrow
     #[storage(read)]
-    fn assets(identifier: u64) -> Option<Asset>;
+    fn assets(identifier: u64) -> Vec<Asset>;
+
+    /// Returns a buyer of an escrow along with the amount they have deposited
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier`: Identifier used to find a specific escrow
+    /// * `position`: Position of the buyer in the escrow
+    #[storage(read)]
+    fn buyers(identifier: u64, position: u64) -> Option<Buyer>;
 
     /// Returns information about an escrow
     ///
@@ -212,4 +342,45 @@ abi Info {
     /// Returns the total number of escrows created in the contract
     #[storage(read)]
     fn escrow_count() -> u64;
+
+    /// Returns information about a milestone of an escrow
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier`: Identifier used to find a specific escrow
+    /// * `milestone`: Position of the milestone in the escrow
+    #[storage(read)]
+    fn milestones(identifier: u64, milestone: u64) -> Option<MilestoneInfo>;
+
+    /// Returns the ruling an arbiter submitted on the current dispute of an escrow
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier`: Identifier used to find a specific escrow
+    /// * `position`: Position of the arbiter on the panel
+    #[storage(read)]
+    fn rulings(identifier: u64, position: u64) -> Option<Ruling>;
+
+    /// Returns the number of escrows in which a user has had a role
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: The role of the user in the escrows
+    /// * `user`: The user whose escrows are counted
+    #[storage(read)]
+    fn user_escrow_count(role: Role, user: Identity) -> u64;
+
+    /// Returns a page of the identifiers of the escrows in which a user has had a role
+    ///
+    /// Escrows are listed in the order the user took on the role, an arbiter is listed again each
+    /// time they are accepted onto the panel of an escrow
+    ///
+    /// # Arguments
+    ///
+    /// * `count`: The maximum number of identifiers returned
+    /// * `role`: The role of the user in the escrows
+    /// * `start`: Position of the first identifier returned, starting from 0
+    /// * `user`: The user whose escrows are listed
+    #[storage(read)]
+    fn user_escrows(count: u64, role: Role, start: u64, user: Identity) -> Vec<u64>;
 }
@@ -5,13 +5,31 @@ dep data_structures;
 dep errors;
 dep events;
 dep interface;
-
-use data_structures::{Arbiter, Asset, Buyer, EscrowInfo, Seller, State};
+dep utils;
+
+use data_structures::{
+    Arbiter,
+    Asset,
+    Buyer,
+    EscrowInfo,
+    Milestone,
+    MilestoneInfo,
+    MilestoneState,
+    Panel,
+    Party,
+    Role,
+    Ruling,
+    Seller,
+    State,
+};
 use errors::{
     ArbiterInputError,
     AssetInputError,
+    BuyerInputError,
     DeadlineInputError,
     DepositError,
+    MilestoneInputError,
+    PanelInputError,
     StateError,
     UserError,
     UserInputError,
@@ -19,13 +37,17 @@ use errors::{
 
 use events::{
     AcceptedArbiterEvent,
+    ApprovedArbiterEvent,
+    ApprovedTransferEvent,
     CreatedEscrowEvent,
     DepositEvent,
     DisputeEvent,
+    ExpiredEscrowEvent,
     PaymentTakenEvent,
     ProposedArbiterEvent,
     ResolvedDisputeEvent,
     ReturnedDepositEvent,
+    SubmittedRulingEvent,
     TransferredToSellerEvent,
     WithdrawnCollateralEvent,
 };
@@ -38,78 +60,241 @@ use std::{
     context::msg_amount,
     storage::StorageVec,
     token::transfer,
+    u128::U128,
 };
+use utils::median;
+
+/// Longest resolution window, in blocks, so that the resolution deadline of a dispute cannot overflow
+const MAX_RESOLUTION_WINDOW: u64 = 1_000_000;
 
 storage {
-    /// Used as a temporary variable for containing a change, proposed by the seller, to the arbiter
-    /// Map(ID => Info)
-    arbiter_proposal: StorageMap<u64, Arbiter> = StorageMap {},
-    /// Contains all assets approved for escrow deposits by the buyer
+    /// Buyers who have approved the proposal for a position on the panel of an escrow
+    /// Map((ID, position on the panel, buyer) => approved)
+    arbiter_approvals: StorageMap<(u64, u64, Identity), bool> = StorageMap {},
+    /// Used as a temporary variable for containing a change, proposed by the seller, to an arbiter
+    /// Map((ID, position on the panel) => Info)
+    arbiter_proposal: StorageMap<(u64, u64), Arbiter> = StorageMap {},
+    /// Contains the arbiters on the panel of every escrow
+    /// The indexing logic for each escrow is stored in the corresponding `EscrowInfo`
+    arbiters: StorageVec<Arbiter> = StorageVec {},
+    /// Contains all assets approved for escrow deposits by the buyers
     /// The indexing logic for each escrow is stored in the corresponding `EscrowInfo`
     /// TODO move this into `EscrowInfo` once https://github.com/FuelLabs/sway/issues/2465 is fixed
     assets: StorageVec<Asset> = StorageVec {},
+    /// Contains the buyers of every escrow along with their deposits
+    /// The indexing logic for each escrow is stored in the corresponding `EscrowInfo`
+    buyers: StorageVec<Buyer> = StorageVec {},
     /// Information describing an escrow created via create_escrow()
     /// Map(ID => Info)
     escrows: StorageMap<u64, EscrowInfo> = StorageMap {},
     /// Number of created escrows
     /// Used as an identifier for O(1) look-up in mappings
     escrow_count: u64 = 0,
+    /// Contains the milestones of every escrow
+    /// The indexing logic for each escrow is stored in the corresponding `EscrowInfo`
+    milestones: StorageVec<MilestoneInfo> = StorageVec {},
+    /// Rulings submitted by the arbiters on the current dispute of an escrow
+    /// Map((ID, position on the panel) => Ruling)
+    rulings: StorageMap<(u64, u64), Ruling> = StorageMap {},
+    /// Buyers who have approved the transfer of a milestone of an escrow to the seller
+    /// Map((ID, position of the milestone, buyer) => approved)
+    transfer_approvals: StorageMap<(u64, u64, Identity), bool> = StorageMap {},
+    /// The number of escrows in which a user has had a role
+    /// This is only incremented, completing an escrow does not affect it
+    /// Map((user, role) => count)
+    user_escrow_count: StorageMap<(Identity, Role), u64> = StorageMap {},
+    /// Escrows in which a user has had a role
+    /// Map((user, role, 0...user_escrow_count) => ID)
+    user_escrows: StorageMap<(Identity, Role, u64), u64> = StorageMap {},
 }
 
 impl Escrow for Contract {
     #[storage(read, write)]
-    fn accept_arbiter(identifier: u64) {
-        // The assertions ensure that only the buyer can accept a proposal if the escrow has not
-        // been completed and the seller has proposed a new arbiter
+    fn accept_arbiter(identifier: u64, position: u64) {
+        // The assertions ensure that only a buyer can approve a proposal, once, if the escrow has
+        // not been completed, the seller has proposed a new arbiter for the position on the panel
+        // and the arbiter has not taken another position on the panel since it was proposed
         let mut escrow = storage.escrows.get(identifier).unwrap();
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
-        require(msg_sender().unwrap() == escrow.buyer.address, UserError::Unauthorized);
 
-        let arbiter = storage.arbiter_proposal.get(identifier);
+        let user = msg_sender().unwrap();
+
+        require(buyer_position(escrow, user).is_some(), UserError::Unauthorized);
+
+        let arbiter = storage.arbiter_proposal.get((identifier, position));
 
         require(arbiter.is_some(), StateError::ArbiterHasNotBeenProposed);
+        require(storage.arbiter_approvals.get((identifier, position, user)).is_none(), StateError::AlreadyApproved);
+
+        // The panel may have changed since the proposal so the arbiter must still have a single seat
+        let mut other = 0;
+        while other < escrow.panel.arbiter_count {
+            if other != position {
+                let panel_arbiter = storage.arbiters.get(escrow.panel.first_arbiter_index + other).unwrap();
+                require(arbiter.unwrap().address != panel_arbiter.address, ArbiterInputError::AlreadyOnPanel);
+            }
+
+            other += 1;
+        }
+
+        storage.arbiter_approvals.insert((identifier, position, user), true);
+
+        log(ApprovedArbiterEvent {
+            buyer: user,
+            identifier,
+            position,
+        });
+
+        // The panel decides the disputes over the deposits of every buyer so they must all agree
+        if !arbiter_approved(escrow, identifier, position) {
+            return;
+        }
 
         // Upon acceptance we must transfer back the previous fee the seller deposited
-        transfer(escrow.arbiter.fee_amount, escrow.arbiter.asset, escrow.seller.address);
+        let index = escrow.panel.first_arbiter_index + position;
+        let previous_arbiter = storage.arbiters.get(index).unwrap();
+        if previous_arbiter.fee_amount != 0 {
+            transfer(previous_arbiter.fee_amount, previous_arbiter.asset, escrow.seller.address);
+        }
+
+        storage.arbiters.set(index, arbiter.unwrap());
 
-        escrow.arbiter = arbiter.unwrap();
+        // Re-proposing the arbiter of the position only changes their fee so they are indexed once
+        if previous_arbiter.address != arbiter.unwrap().address {
+            index_escrow(identifier, Role::Arbiter, arbiter.unwrap().address);
+        }
+
+        // The ruling of the previous arbiter no longer counts towards the current dispute
+        if storage.rulings.get((identifier, position)).is_some() {
+            storage.rulings.remove((identifier, position));
+            escrow.ruling_count -= 1;
+        }
 
         // We must reset the proposal or the escrow contract will be drained
-        storage.arbiter_proposal.remove(identifier);
+        storage.arbiter_proposal.remove((identifier, position));
+        clear_arbiter_approvals(escrow, identifier, position);
         storage.escrows.insert(identifier, escrow);
 
-        log(AcceptedArbiterEvent { identifier });
+        log(AcceptedArbiterEvent {
+            identifier,
+            position,
+        });
     }
 
     #[payable, storage(read, write)]
     fn create_escrow(
-        arbiter: Arbiter,
+        arbiters: Vec<Arbiter>,
         assets: Vec<Asset>,
-        buyer: Identity,
+        buyers: Vec<Identity>,
         deadline: u64,
+        default_party: Party,
+        milestones: Vec<Milestone>,
+        quorum: u64,
+        resolution_window: u64,
     ) {
-        // The assertions ensure that assets are specified with a none-zero amount, the arbiter is
-        // not the buyer / seller, the arbiter has a fee that they can take upon resolving a dispute
-        // and the escrow deadline is set in the future
+        // The assertions ensure that assets are specified with a none-zero amount, the buyers are
+        // distinct, the arbiters are distinct and not a buyer / the seller, every arbiter has a
+        // fee that they can take upon resolving a dispute, the quorum can be reached by the panel
+        // within a bounded resolution window and the escrow deadline is set in the future. Milestones must add up to the amount of
+        // the single accepted asset and their deadlines must follow the escrow deadline in order
         require(0 < assets.len(), AssetInputError::UnspecifiedAssets);
         require(height() < deadline, DeadlineInputError::MustBeInTheFuture);
-        require(0 < arbiter.fee_amount, ArbiterInputError::FeeCannotBeZero);
-        require(arbiter.fee_amount == msg_amount(), ArbiterInputError::FeeDoesNotMatchAmountSent);
-        require(arbiter.asset == msg_asset_id(), ArbiterInputError::AssetDoesNotMatch);
-        require(arbiter.address != buyer, ArbiterInputError::CannotBeBuyer);
-        require(arbiter.address != msg_sender().unwrap(), ArbiterInputError::CannotBeSeller);
+        require(0 < buyers.len(), BuyerInputError::UnspecifiedBuyers);
+        require(0 < arbiters.len(), PanelInputError::UnspecifiedArbiters);
+        require(0 < quorum && quorum <= arbiters.len(), PanelInputError::InvalidQuorum);
+        require(0 < resolution_window, PanelInputError::ResolutionWindowCannotBeZero);
+        require(resolution_window <= MAX_RESOLUTION_WINDOW, PanelInputError::ResolutionWindowTooLong);
+
+        let seller = msg_sender().unwrap();
+
+        let mut index = 0;
+        while index < buyers.len() {
+            let buyer = buyers.get(index).unwrap();
+
+            let mut other = 0;
+            while other < index {
+                require(buyer != buyers.get(other).unwrap(), BuyerInputError::AlreadyBuyer);
+                other += 1;
+            }
+
+            storage.buyers.push(Buyer {
+                address: buyer,
+                deposited_amount: 0,
+            });
+            index_escrow(storage.escrow_count, Role::Buyer, buyer);
+            index += 1;
+        }
+
+        let mut fee_total = 0;
+        let mut index = 0;
+        while index < arbiters.len() {
+            let arbiter = arbiters.get(index).unwrap();
+            require(0 < arbiter.fee_amount, ArbiterInputError::FeeCannotBeZero);
+            require(arbiter.asset == msg_asset_id(), ArbiterInputError::AssetDoesNotMatch);
+            require(arbiter.address != seller, ArbiterInputError::CannotBeSeller);
+
+            let mut buyer = 0;
+            while buyer < buyers.len() {
+                require(arbiter.address != buyers.get(buyer).unwrap(), ArbiterInputError::CannotBeBuyer);
+                buyer += 1;
+            }
+
+            let mut other = 0;
+            while other < index {
+                require(arbiter.address != arbiters.get(other).unwrap().address, ArbiterInputError::AlreadyOnPanel);
+                other += 1;
+            }
+
+            fee_total += arbiter.fee_amount;
+            storage.arbiters.push(arbiter);
+            index_escrow(storage.escrow_count, Role::Arbiter, arbiter.address);
+            index += 1;
+        }
+
+        // The seller deposits the fees of the whole panel at once
+        require(fee_total == msg_amount(), ArbiterInputError::FeeDoesNotMatchAmountSent);
+
+        // The amounts of the milestones are denominated in the one asset the buyers deposit
+        require(milestones.len() == 0 || assets.len() == 1, MilestoneInputError::RequireSingleAsset);
+
+        let mut milestone_total = 0;
+        let mut previous_deadline = deadline;
+        let mut index = 0;
+        while index < milestones.len() {
+            let milestone = milestones.get(index).unwrap();
+            require(0 < milestone.amount, MilestoneInputError::AmountCannotBeZero);
+
+            if milestone.deadline.is_some() {
+                require(previous_deadline < milestone.deadline.unwrap(), MilestoneInputError::DeadlinesMustIncrease);
+                previous_deadline = milestone.deadline.unwrap();
+            }
+
+            milestone_total += milestone.amount;
+            storage.milestones.push(MilestoneInfo::new(milestone));
+            index += 1;
+        }
 
         let mut index = 0;
         while index < assets.len() {
             require(0 < assets.get(index).unwrap().amount, AssetInputError::AssetAmountCannotBeZero);
+            require(milestones.len() == 0 || assets.get(index).unwrap().amount == milestone_total, MilestoneInputError::AmountsDoNotMatchAssets);
             storage.assets.push(assets.get(index).unwrap());
             index += 1;
         }
 
-        let escrow = EscrowInfo::new(arbiter, assets.len(), buyer, deadline, storage.assets.len() - assets.len(), msg_sender().unwrap());
+        let panel = Panel {
+            arbiter_count: arbiters.len(),
+            default_party,
+            first_arbiter_index: storage.arbiters.len() - arbiters.len(),
+            quorum,
+            resolution_window,
+        };
+
+        let escrow = EscrowInfo::new(assets.len(), buyers.len(), deadline, storage.assets.len() - assets.len(), storage.buyers.len() - buyers.len(), storage.milestones.len() - milestones.len(), milestones.len(), panel, seller);
 
         storage.escrows.insert(storage.escrow_count, escrow);
+        index_escrow(storage.escrow_count, Role::Seller, seller);
 
         storage.escrow_count += 1;
 
@@ -121,63 +306,110 @@ impl Escrow for Contract {
 
     #[payable, storage(read, write)]
     fn deposit(identifier: u64) {
-        // The assertions ensure that only the buyer can deposit (only once) prior to the deadline
-        // and escrow completion
+        // The assertions ensure that only a buyer can deposit prior to the deadline and escrow
+        // completion, as long as the buyers have not deposited the whole amount yet
         let mut escrow = storage.escrows.get(identifier).unwrap();
 
         require(height() < escrow.deadline, StateError::EscrowExpired);
         require(escrow.state == State::Pending, StateError::StateNotPending);
-        require(msg_sender().unwrap() == escrow.buyer.address, UserError::Unauthorized);
-        require(escrow.buyer.asset.is_none(), StateError::AlreadyDeposited);
+
+        let user = msg_sender().unwrap();
+        let position = buyer_position(escrow, user);
+
+        require(position.is_some(), UserError::Unauthorized);
+        require(!funded(escrow), StateError::AlreadyDeposited);
+
+        // Every buyer must deposit the asset of the first deposit
+        require(escrow.asset.is_none() || escrow.asset.unwrap() == msg_asset_id(), DepositError::IncorrectAssetSent);
 
         // TODO: https://github.com/FuelLabs/sway/issues/2014
         //       `.contains() -> bool / .position() -> u64` would clean up the loop
+        let mut required_amount = 0;
         let mut index = 0;
         while index < escrow.asset_count {
             let asset = storage.assets.get(escrow.first_asset_index + index).unwrap();
             if asset.id == msg_asset_id() {
-                require(asset.amount == msg_amount(), DepositError::IncorrectAssetAmount);
-                escrow.buyer.asset = Option::Some(msg_asset_id());
-                escrow.buyer.deposited_amount = msg_amount();
+                required_amount = asset.amount;
                 break;
             }
 
             index += 1;
         }
 
-        // User must deposit one of the specified assets in the correct amount
-        require(escrow.buyer.asset.is_some() && 0 < escrow.buyer.deposited_amount, DepositError::IncorrectAssetSent);
+        // User must deposit one of the specified assets without exceeding its amount
+        require(0 < required_amount, DepositError::IncorrectAssetSent);
+        require(0 < msg_amount() && escrow.deposited_amount + msg_amount() <= required_amount, DepositError::IncorrectAssetAmount);
+
+        let buyer_index = escrow.first_buyer_index + position.unwrap();
+        let mut buyer = storage.buyers.get(buyer_index).unwrap();
+        buyer.deposited_amount += msg_amount();
+        storage.buyers.set(buyer_index, buyer);
+
+        escrow.asset = Option::Some(msg_asset_id());
+        escrow.deposited_amount += msg_amount();
+
+        // An escrow without milestones releases the whole deposit as a single milestone
+        if escrow.deposited_amount == required_amount && escrow.milestone_count == 0 {
+            storage.milestones.push(MilestoneInfo::new(Milestone {
+                amount: escrow.deposited_amount,
+                deadline: Option::None,
+            }));
+            escrow.first_milestone_index = storage.milestones.len() - 1;
+            escrow.milestone_count = 1;
+        }
 
         storage.escrows.insert(identifier, escrow);
 
         log(DepositEvent {
-            asset: escrow.buyer.asset.unwrap(),
+            amount: msg_amount(),
+            asset: msg_asset_id(),
+            buyer: user,
             identifier,
         });
     }
 
     #[storage(read, write)]
     fn dispute(identifier: u64) {
-        // The assertions ensure that a dispute can only be raised once by the buyer as long as the
-        // escrow is not completed and the buyer has deposited
+        // The assertions ensure that a dispute can only be raised once by a buyer as long as the
+        // escrow is not completed and the buyers have deposited
         let mut escrow = storage.escrows.get(identifier).unwrap();
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
         require(!escrow.disputed, StateError::AlreadyDisputed);
-        require(msg_sender().unwrap() == escrow.buyer.address, UserError::Unauthorized);
-        require(escrow.buyer.asset.is_some(), StateError::CannotDisputeBeforeDesposit);
+        require(buyer_position(escrow, msg_sender().unwrap()).is_some(), UserError::Unauthorized);
+        require(funded(escrow), StateError::CannotDisputeBeforeDesposit);
 
-        // Lock the escrow
+        // Lock the current milestone of the escrow until the panel rules on it
         escrow.disputed = true;
+        escrow.resolution_deadline = height() + escrow.panel.resolution_window;
         storage.escrows.insert(identifier, escrow);
 
-        log(DisputeEvent { identifier });
+        log(DisputeEvent {
+            identifier,
+            milestone: escrow.current_milestone,
+        });
+    }
+
+    #[storage(read, write)]
+    fn expire_escrow(identifier: u64) {
+        // The assertions ensure that anyone can complete an escrow once its deadline has passed
+        // without the buyers depositing the whole amount
+        let mut escrow = storage.escrows.get(identifier).unwrap();
+
+        require(escrow.state == State::Pending, StateError::StateNotPending);
+        require(escrow.deadline < height(), StateError::CannotWithdrawBeforeDeadline);
+        require(!funded(escrow), StateError::CannotWithdrawAfterDesposit);
+
+        close_unfunded(escrow, identifier);
+
+        log(ExpiredEscrowEvent { identifier });
     }
 
     #[payable, storage(read, write)]
-    fn propose_arbiter(arbiter: Arbiter, identifier: u64) {
-        // The assertions ensure that only the seller can propose a new arbiter and the arbiter
-        // cannot be the buyer / seller, the arbiter will be able to take a none-zero payment
+    fn propose_arbiter(arbiter: Arbiter, identifier: u64, position: u64) {
+        // The assertions ensure that only the seller can propose a new arbiter for a position on
+        // the panel and the arbiter cannot be a buyer / the seller / another arbiter on the panel,
+        // the arbiter will be able to take a none-zero payment
         let escrow = storage.escrows.get(identifier).unwrap();
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
@@ -185,63 +417,87 @@ impl Escrow for Contract {
         let user = msg_sender().unwrap();
 
         require(user == escrow.seller.address, UserError::Unauthorized);
-        require(arbiter.address != escrow.buyer.address, ArbiterInputError::CannotBeBuyer);
+        require(position < escrow.panel.arbiter_count, PanelInputError::PositionOutOfBounds);
+        require(buyer_position(escrow, arbiter.address).is_none(), ArbiterInputError::CannotBeBuyer);
         require(arbiter.address != escrow.seller.address, ArbiterInputError::CannotBeSeller);
         require(0 < arbiter.fee_amount, ArbiterInputError::FeeCannotBeZero);
         require(arbiter.fee_amount == msg_amount(), ArbiterInputError::FeeDoesNotMatchAmountSent);
         require(arbiter.asset == msg_asset_id(), ArbiterInputError::AssetDoesNotMatch);
 
+        // The fees of a panel are paid in a single asset and every arbiter has a single ruling
+        let mut other = 0;
+        while other < escrow.panel.arbiter_count {
+            if other != position {
+                let panel_arbiter = storage.arbiters.get(escrow.panel.first_arbiter_index + other).unwrap();
+                require(arbiter.address != panel_arbiter.address, ArbiterInputError::AlreadyOnPanel);
+                require(arbiter.asset == panel_arbiter.asset, ArbiterInputError::AssetDoesNotMatch);
+            }
+
+            other += 1;
+        }
+
         // If there is a previous proposal then we must transfer those funds back to the seller
-        let proposal = storage.arbiter_proposal.get(identifier);
+        let proposal = storage.arbiter_proposal.get((identifier, position));
         if proposal.is_some() {
             transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
         }
 
-        storage.arbiter_proposal.insert(identifier, arbiter);
+        // Approvals of the previous proposal do not carry over to the new arbiter
+        clear_arbiter_approvals(escrow, identifier, position);
+
+        storage.arbiter_proposal.insert((identifier, position), arbiter);
 
         log(ProposedArbiterEvent {
             arbiter,
             identifier,
+            position,
         });
     }
 
     #[storage(read, write)]
     fn resolve_dispute(identifier: u64, payment_amount: u64, user: Identity) {
-        // The assertions ensure that a resolution can only occur during a dispute and only once
-        // by the specified arbiter. The deposit will be sent to either the buyer or seller and the
-        // arbiter can choose their payment amount up to the deposit from the seller
+        // The assertions ensure that a ruling can only be submitted during a dispute and only once
+        // per dispute by each arbiter on the panel. The milestone will be sent to either the buyers
+        // or seller and the panel can choose their payment amount up to the remaining deposit from
+        // the seller
         let mut escrow = storage.escrows.get(identifier).unwrap();
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
         require(escrow.disputed, StateError::NotDisputed);
-        require(msg_sender().unwrap() == escrow.arbiter.address, UserError::Unauthorized);
-        require(user == escrow.buyer.address || user == escrow.seller.address, UserInputError::InvalidRecipient);
-        require(escrow.buyer.asset.is_some() && 0 < escrow.buyer.deposited_amount, StateError::CannotResolveBeforeDesposit);
-        require(payment_amount <= escrow.arbiter.fee_amount, ArbiterInputError::PaymentTooLarge);
 
-        escrow.state = State::Completed;
-        storage.escrows.insert(identifier, escrow);
+        let arbiter = msg_sender().unwrap();
+        let position = panel_position(escrow, arbiter);
 
-        transfer(escrow.buyer.deposited_amount, escrow.buyer.asset.unwrap(), user);
-        transfer(payment_amount, escrow.arbiter.asset, escrow.arbiter.address);
+        require(position.is_some(), UserError::Unauthorized);
+        require(user == escrow.seller.address || buyer_position(escrow, user).is_some(), UserInputError::InvalidRecipient);
+        require(funded(escrow), StateError::CannotResolveBeforeDesposit);
+        require(payment_amount <= panel_fees(escrow), ArbiterInputError::PaymentTooLarge);
+        require(storage.rulings.get((identifier, position.unwrap())).is_none(), StateError::AlreadyRuled);
 
-        if payment_amount != escrow.arbiter.fee_amount {
-            transfer(escrow.arbiter.fee_amount - payment_amount, escrow.arbiter.asset, escrow.seller.address);
-        }
+        let ruling = Ruling {
+            payment_amount,
+            user,
+        };
 
-        // If there is a previous proposal then we must transfer those funds back to the seller
-        let proposal = storage.arbiter_proposal.get(identifier);
-        if proposal.is_some() {
-            transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
-            // Not needed as long as the entire contract handles state correctly but leaving it in
-            // for conceptual closure at the slight expense of users
-            storage.arbiter_proposal.remove(identifier);
-        }
+        storage.rulings.insert((identifier, position.unwrap()), ruling);
+        escrow.ruling_count += 1;
 
-        log(ResolvedDisputeEvent {
+        log(SubmittedRulingEvent {
+            arbiter,
             identifier,
-            user,
+            milestone: escrow.current_milestone,
+            ruling,
         });
+
+        // A majority of the panel agreeing resolves the dispute right away otherwise the median
+        // ruling applies once the quorum has been reached
+        if escrow.panel.arbiter_count < matching_rulings(escrow, identifier, ruling) * 2 {
+            resolve(escrow, identifier, ruling);
+        } else if escrow.panel.quorum <= escrow.ruling_count {
+            resolve(escrow, identifier, median_ruling(escrow, identifier));
+        } else {
+            storage.escrows.insert(identifier, escrow);
+        }
     }
 
     #[storage(read, write)]
@@ -252,81 +508,117 @@ impl Escrow for Contract {
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
         require(msg_sender().unwrap() == escrow.seller.address, UserError::Unauthorized);
-        require(escrow.buyer.asset.is_some(), StateError::CannotTransferBeforeDesposit);
+        require(funded(escrow), StateError::CannotTransferBeforeDesposit);
 
-        escrow.state = State::Completed;
+        // Every milestone that has not been settled yet is returned which completes the escrow
+        let mut amount = 0;
+        while escrow.current_milestone < escrow.milestone_count {
+            amount += settle_milestone(escrow, identifier, MilestoneState::Returned);
+        }
         storage.escrows.insert(identifier, escrow);
 
-        transfer(escrow.buyer.deposited_amount, escrow.buyer.asset.unwrap(), escrow.buyer.address);
-        transfer(escrow.arbiter.fee_amount, escrow.arbiter.asset, escrow.seller.address);
-
-        // If there is a previous proposal then we must transfer those funds back to the seller
-        let proposal = storage.arbiter_proposal.get(identifier);
-        if proposal.is_some() {
-            transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
-            // Not needed as long as the entire contract handles state correctly but leaving it in
-            // for conceptual closure at the slight expense of users
-            storage.arbiter_proposal.remove(identifier);
-        }
+        refund_buyers(escrow, amount);
+        return_collateral(escrow, identifier);
 
         log(ReturnedDepositEvent { identifier });
     }
 
+    #[storage(read, write)]
+    fn settle_dispute(identifier: u64) {
+        // The assertions ensure that either party can fall back to the rulings submitted so far, or
+        // to the default party when there are none, once the panel has failed to resolve the
+        // dispute in time
+        let mut escrow = storage.escrows.get(identifier).unwrap();
+
+        require(escrow.state == State::Pending, StateError::StateNotPending);
+        require(escrow.disputed, StateError::NotDisputed);
+
+        let user = msg_sender().unwrap();
+
+        require(user == escrow.seller.address || buyer_position(escrow, user).is_some(), UserError::Unauthorized);
+        require(escrow.resolution_deadline < height(), StateError::CannotSettleBeforeResolutionDeadline);
+
+        let ruling = if escrow.ruling_count == 0 {
+            default_ruling(escrow)
+        } else {
+            median_ruling(escrow, identifier)
+        };
+
+        resolve(escrow, identifier, ruling);
+    }
+
     #[storage(read, write)]
     fn take_payment(identifier: u64) {
-        // The assertions ensure that only the seller can take payment before the escrow has been
-        // completed and after the deadline as long as there is no disupte and it contains a deposit
+        // The assertions ensure that only the seller can take payment for the current milestone
+        // before the escrow has been completed and after the deadline of the milestone as long as
+        // there is no disupte and it contains a deposit
         let mut escrow = storage.escrows.get(identifier).unwrap();
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
-        require(escrow.deadline < height(), StateError::CannotTakePaymentBeforeDeadline);
+        require(payment_deadline(escrow) < height(), StateError::CannotTakePaymentBeforeDeadline);
         require(!escrow.disputed, StateError::CannotTakePaymentDuringDispute);
         require(msg_sender().unwrap() == escrow.seller.address, UserError::Unauthorized);
-        require(escrow.buyer.asset.is_some(), StateError::CannotTransferBeforeDesposit);
+        require(funded(escrow), StateError::CannotTransferBeforeDesposit);
 
-        escrow.state = State::Completed;
+        let milestone = escrow.current_milestone;
+        let amount = settle_milestone(escrow, identifier, MilestoneState::Released);
         storage.escrows.insert(identifier, escrow);
 
-        transfer(escrow.buyer.deposited_amount, escrow.buyer.asset.unwrap(), escrow.seller.address);
-        transfer(escrow.arbiter.fee_amount, escrow.arbiter.asset, escrow.seller.address);
+        transfer(amount, escrow.asset.unwrap(), escrow.seller.address);
 
-        // If there is a previous proposal then we must transfer those funds back to the seller
-        let proposal = storage.arbiter_proposal.get(identifier);
-        if proposal.is_some() {
-            transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
-            // Not needed as long as the entire contract handles state correctly but leaving it in
-            // for conceptual closure at the slight expense of users
-            storage.arbiter_proposal.remove(identifier);
+        if escrow.state == State::Completed {
+            return_collateral(escrow, identifier);
         }
 
-        log(PaymentTakenEvent { identifier });
+        log(PaymentTakenEvent {
+            identifier,
+            milestone,
+        });
     }
 
     #[storage(read, write)]
     fn transfer_to_seller(identifier: u64) {
-        // The assertions ensure that only the buyer can transfer their deposit once
+        // The assertions ensure that only a buyer can approve the transfer of each milestone of the
+        // deposit once
         let mut escrow = storage.escrows.get(identifier).unwrap();
 
         require(escrow.state == State::Pending, StateError::StateNotPending);
-        require(escrow.buyer.asset.is_some() && 0 < escrow.buyer.deposited_amount, StateError::CannotTransferBeforeDesposit);
-        require(msg_sender().unwrap() == escrow.buyer.address, UserError::Unauthorized);
+        require(funded(escrow), StateError::CannotTransferBeforeDesposit);
 
-        escrow.state = State::Completed;
+        let user = msg_sender().unwrap();
+
+        require(buyer_position(escrow, user).is_some(), UserError::Unauthorized);
+
+        let milestone = escrow.current_milestone;
+
+        require(storage.transfer_approvals.get((identifier, milestone, user)).is_none(), StateError::AlreadyApproved);
+
+        storage.transfer_approvals.insert((identifier, milestone, user), true);
+
+        log(ApprovedTransferEvent {
+            buyer: user,
+            identifier,
+            milestone,
+        });
+
+        // The milestone is paid out of the deposits of every buyer so they must all agree
+        if !transfer_approved(escrow, identifier, milestone) {
+            return;
+        }
+
+        let amount = settle_milestone(escrow, identifier, MilestoneState::Released);
         storage.escrows.insert(identifier, escrow);
 
-        transfer(escrow.buyer.deposited_amount, escrow.buyer.asset.unwrap(), escrow.seller.address);
-        transfer(escrow.arbiter.fee_amount, escrow.arbiter.asset, escrow.seller.address);
+        transfer(amount, escrow.asset.unwrap(), escrow.seller.address);
 
-        // If there is a previous proposal then we must transfer those funds back to the seller
-        let proposal = storage.arbiter_proposal.get(identifier);
-        if proposal.is_some() {
-            transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
-            // Not needed as long as the entire contract handles state correctly but leaving it in
-            // for conceptual closure at the slight expense of users
-            storage.arbiter_proposal.remove(identifier);
+        if escrow.state == State::Completed {
+            return_collateral(escrow, identifier);
         }
 
-        log(TransferredToSellerEvent { identifier });
+        log(TransferredToSellerEvent {
+            identifier,
+            milestone,
+        });
     }
 
     #[storage(read, write)]
@@ -338,21 +630,9 @@ impl Escrow for Contract {
         require(escrow.state == State::Pending, StateError::StateNotPending);
         require(escrow.deadline < height(), StateError::CannotWithdrawBeforeDeadline);
         require(msg_sender().unwrap() == escrow.seller.address, UserError::Unauthorized);
-        require(escrow.buyer.asset.is_none(), StateError::CannotWithdrawAfterDesposit);
-
-        escrow.state = State::Completed;
-        storage.escrows.insert(identifier, escrow);
-
-        transfer(escrow.arbiter.fee_amount, escrow.arbiter.asset, escrow.seller.address);
+        require(!funded(escrow), StateError::CannotWithdrawAfterDesposit);
 
-        // If there is a previous proposal then we must transfer those funds back to the seller
-        let proposal = storage.arbiter_proposal.get(identifier);
-        if proposal.is_some() {
-            transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
-            // Not needed as long as the entire contract handles state correctly but leaving it in
-            // for conceptual closure at the slight expense of users
-            storage.arbiter_proposal.remove(identifier);
-        }
+        close_unfunded(escrow, identifier);
 
         log(WithdrawnCollateralEvent { identifier });
     }
@@ -360,13 +640,48 @@ impl Escrow for Contract {
 
 impl Info for Contract {
     #[storage(read)]
-    fn arbiter_proposal(identifier: u64) -> Option<Arbiter> {
-        storage.arbiter_proposal.get(identifier)
+    fn arbiter_proposal(identifier: u64, position: u64) -> Option<Arbiter> {
+        storage.arbiter_proposal.get((identifier, position))
     }
 
     #[storage(read)]
-    fn assets(identifier: u64) -> Option<Asset> {
-        storage.assets.get(identifier)
+    fn arbiters(identifier: u64, position: u64) -> Option<Arbiter> {
+        let escrow = storage.escrows.get(identifier);
+
+        if escrow.is_none() || escrow.unwrap().panel.arbiter_count <= position {
+            return Option::None;
+        }
+
+        storage.arbiters.get(escrow.unwrap().panel.first_arbiter_index + position)
+    }
+
+    #[storage(read)]
+    fn assets(identifier: u64) -> Vec<Asset> {
+        let escrow = storage.escrows.get(identifier);
+        let mut assets = Vec::new();
+
+        if escrow.is_none() {
+            return assets;
+        }
+
+        let mut index = 0;
+        while index < escrow.unwrap().asset_count {
+            assets.push(storage.assets.get(escrow.unwrap().first_asset_index + index).unwrap());
+            index += 1;
+        }
+
+        assets
+    }
+
+    #[storage(read)]
+    fn buyers(identifier: u64, position: u64) -> Option<Buyer> {
+        let escrow = storage.escrows.get(identifier);
+
+        if escrow.is_none() || escrow.unwrap().buyer_count <= position {
+            return Option::None;
+        }
+
+        storage.buyers.get(escrow.unwrap().first_buyer_index + position)
     }
 
     #[storage(read)]
@@ -378,4 +693,388 @@ impl Info for Contract {
     fn escrow_count() -> u64 {
         storage.escrow_count
     }
+
+    #[storage(read)]
+    fn milestones(identifier: u64, milestone: u64) -> Option<MilestoneInfo> {
+        let escrow = storage.escrows.get(identifier);
+
+        if escrow.is_none() || escrow.unwrap().milestone_count <= milestone {
+            return Option::None;
+        }
+
+        storage.milestones.get(escrow.unwrap().first_milestone_index + milestone)
+    }
+
+    #[storage(read)]
+    fn rulings(identifier: u64, position: u64) -> Option<Ruling> {
+        storage.rulings.get((identifier, position))
+    }
+
+    #[storage(read)]
+    fn user_escrow_count(role: Role, user: Identity) -> u64 {
+        storage.user_escrow_count.get((user, role)).unwrap_or(0)
+    }
+
+    #[storage(read)]
+    fn user_escrows(count: u64, role: Role, start: u64, user: Identity) -> Vec<u64> {
+        let user_escrow_count = storage.user_escrow_count.get((user, role)).unwrap_or(0);
+        let mut identifiers = Vec::new();
+
+        let mut index = start;
+        while index < user_escrow_count && identifiers.len() < count {
+            identifiers.push(storage.user_escrows.get((user, role, index)).unwrap());
+            index += 1;
+        }
+
+        identifiers
+    }
+}
+
+/// Whether every buyer of the escrow has approved the proposal for `position` on the panel
+#[storage(read)]
+fn arbiter_approved(escrow: EscrowInfo, identifier: u64, position: u64) -> bool {
+    let mut index = 0;
+    while index < escrow.buyer_count {
+        let buyer = storage.buyers.get(escrow.first_buyer_index + index).unwrap();
+        if storage.arbiter_approvals.get((identifier, position, buyer.address)).is_none() {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
+/// Position of `user` among the buyers of the escrow
+#[storage(read)]
+fn buyer_position(escrow: EscrowInfo, user: Identity) -> Option<u64> {
+    let mut position = 0;
+    while position < escrow.buyer_count {
+        if storage.buyers.get(escrow.first_buyer_index + position).unwrap().address == user {
+            return Option::Some(position);
+        }
+
+        position += 1;
+    }
+
+    Option::None
+}
+
+/// Removes the approvals of the buyers for the proposal for `position` on the panel
+#[storage(read, write)]
+fn clear_arbiter_approvals(escrow: EscrowInfo, identifier: u64, position: u64) {
+    let mut index = 0;
+    while index < escrow.buyer_count {
+        let buyer = storage.buyers.get(escrow.first_buyer_index + index).unwrap();
+        storage.arbiter_approvals.remove((identifier, position, buyer.address));
+
+        index += 1;
+    }
+}
+
+/// Completes an escrow that has not been funded, returning any partial deposits to the buyers and
+/// the collateral to the seller
+#[storage(read, write)]
+fn close_unfunded(ref mut escrow: EscrowInfo, identifier: u64) {
+    escrow.state = State::Completed;
+    storage.escrows.insert(identifier, escrow);
+
+    // Partial deposits are returned to the buyers who made them
+    if 0 < escrow.deposited_amount {
+        refund_buyers(escrow, escrow.deposited_amount);
+    }
+
+    return_collateral(escrow, identifier);
+}
+
+/// Ruling that settles a dispute in favour of the default party of the panel when no arbiter has
+/// ruled, the panel is not paid for the dispute
+#[storage(read)]
+fn default_ruling(escrow: EscrowInfo) -> Ruling {
+    let user = match escrow.panel.default_party {
+        Party::Buyers => storage.buyers.get(escrow.first_buyer_index).unwrap().address,
+        Party::Seller => escrow.seller.address,
+    };
+
+    Ruling {
+        payment_amount: 0,
+        user,
+    }
+}
+
+/// Whether the buyers have deposited the whole amount of the asset they pay in
+#[storage(read)]
+fn funded(escrow: EscrowInfo) -> bool {
+    if escrow.asset.is_none() {
+        return false;
+    }
+
+    // The first matching asset sets the amount, as it does upon deposit
+    let mut index = 0;
+    while index < escrow.asset_count {
+        let asset = storage.assets.get(escrow.first_asset_index + index).unwrap();
+        if asset.id == escrow.asset.unwrap() {
+            return asset.amount == escrow.deposited_amount;
+        }
+
+        index += 1;
+    }
+
+    false
+}
+
+/// Records the escrow in the index of the escrows in which `user` has had `role`
+#[storage(read, write)]
+fn index_escrow(identifier: u64, role: Role, user: Identity) {
+    let user_escrow_count = storage.user_escrow_count.get((user, role)).unwrap_or(0);
+
+    storage.user_escrows.insert((user, role, user_escrow_count), identifier);
+    storage.user_escrow_count.insert((user, role), user_escrow_count + 1);
+}
+
+/// Number of arbiters on the panel of the escrow who submitted `ruling` on the current dispute
+///
+/// Rulings in favour of different buyers match as the milestone is split between all of them
+#[storage(read)]
+fn matching_rulings(escrow: EscrowInfo, identifier: u64, ruling: Ruling) -> u64 {
+    let mut count = 0;
+    let mut position = 0;
+    while position < escrow.panel.arbiter_count {
+        let submitted = storage.rulings.get((identifier, position));
+        if submitted.is_some() && submitted.unwrap().payment_amount == ruling.payment_amount && (submitted.unwrap().user == escrow.seller.address) == (ruling.user == escrow.seller.address) {
+            count += 1;
+        }
+
+        position += 1;
+    }
+
+    count
+}
+
+/// Ruling with the median payment amount of the rulings submitted on the current dispute which
+/// sends the disputed milestone to the side chosen by most of them, or to the buyers on a tie
+#[storage(read)]
+fn median_ruling(escrow: EscrowInfo, identifier: u64) -> Ruling {
+    let mut payment_amounts = Vec::new();
+    let mut seller_rulings = 0;
+    let mut position = 0;
+    while position < escrow.panel.arbiter_count {
+        let ruling = storage.rulings.get((identifier, position));
+        if ruling.is_some() {
+            payment_amounts.push(ruling.unwrap().payment_amount);
+            if ruling.unwrap().user == escrow.seller.address {
+                seller_rulings += 1;
+            }
+        }
+
+        position += 1;
+    }
+
+    let user = if payment_amounts.len() < seller_rulings * 2 {
+        escrow.seller.address
+    } else {
+        storage.buyers.get(escrow.first_buyer_index).unwrap().address
+    };
+
+    Ruling {
+        payment_amount: median(payment_amounts),
+        user,
+    }
+}
+
+/// Sum of the remaining fees of the arbiters on the panel of the escrow
+#[storage(read)]
+fn panel_fees(escrow: EscrowInfo) -> u64 {
+    let mut fees = 0;
+    let mut position = 0;
+    while position < escrow.panel.arbiter_count {
+        fees += storage.arbiters.get(escrow.panel.first_arbiter_index + position).unwrap().fee_amount;
+        position += 1;
+    }
+
+    fees
+}
+
+/// Position of `user` on the panel of the escrow
+#[storage(read)]
+fn panel_position(escrow: EscrowInfo, user: Identity) -> Option<u64> {
+    let mut position = 0;
+    while position < escrow.panel.arbiter_count {
+        if storage.arbiters.get(escrow.panel.first_arbiter_index + position).unwrap().address == user {
+            return Option::Some(position);
+        }
+
+        position += 1;
+    }
+
+    Option::None
+}
+
+/// Height after which the seller can take payment for the current milestone of the escrow
+#[storage(read)]
+fn payment_deadline(escrow: EscrowInfo) -> u64 {
+    // Milestones only exist once the buyers have deposited into an escrow created without them
+    if escrow.current_milestone < escrow.milestone_count {
+        let milestone = storage.milestones.get(escrow.first_milestone_index + escrow.current_milestone).unwrap();
+        if milestone.deadline.is_some() {
+            return milestone.deadline.unwrap();
+        }
+    }
+
+    escrow.deadline
+}
+
+/// Splits `amount` of the deposit between the buyers of the escrow in proportion to their deposits
+#[storage(read)]
+fn refund_buyers(escrow: EscrowInfo, amount: u64) {
+    // Each share is the difference between the running totals so the rounding never leaves any of
+    // the amount behind
+    let mut deposited = 0;
+    let mut refunded = 0;
+    let mut position = 0;
+    while position < escrow.buyer_count {
+        let buyer = storage.buyers.get(escrow.first_buyer_index + position).unwrap();
+        deposited += buyer.deposited_amount;
+
+        let total = (U128::from((0, amount)) * U128::from((0, deposited)) / U128::from((0, escrow.deposited_amount))).as_u64().unwrap();
+        let share = total - refunded;
+        refunded = total;
+
+        if share != 0 {
+            transfer(share, escrow.asset.unwrap(), buyer.address);
+        }
+
+        position += 1;
+    }
+}
+
+/// Resolves the current dispute of the escrow with `ruling`, paying the arbiters who ruled their
+/// share of the payment and settling the disputed milestone
+#[storage(read, write)]
+fn resolve(ref mut escrow: EscrowInfo, identifier: u64, ruling: Ruling) {
+    // The fees of the panel may have been lowered by accepting a proposal since the ruling
+    let fees = panel_fees(escrow);
+    let payment_amount = if fees < ruling.payment_amount {
+        fees
+    } else {
+        ruling.payment_amount
+    };
+
+    // Each arbiter who ruled takes a share of the payment in proportion to their fee
+    let mut position = 0;
+    while 0 < payment_amount && position < escrow.panel.arbiter_count {
+        if storage.rulings.get((identifier, position)).is_some() {
+            let index = escrow.panel.first_arbiter_index + position;
+            let mut arbiter = storage.arbiters.get(index).unwrap();
+            let share = (U128::from((0, payment_amount)) * U128::from((0, arbiter.fee_amount)) / U128::from((0, fees))).as_u64().unwrap();
+
+            arbiter.fee_amount -= share;
+            storage.arbiters.set(index, arbiter);
+
+            if share != 0 {
+                transfer(share, arbiter.asset, arbiter.address);
+            }
+        }
+
+        position += 1;
+    }
+
+    let milestone = escrow.current_milestone;
+    let state = if ruling.user == escrow.seller.address {
+        MilestoneState::Released
+    } else {
+        MilestoneState::Returned
+    };
+
+    let amount = settle_milestone(escrow, identifier, state);
+    storage.escrows.insert(identifier, escrow);
+
+    if state == MilestoneState::Released {
+        transfer(amount, escrow.asset.unwrap(), escrow.seller.address);
+    } else {
+        refund_buyers(escrow, amount);
+    }
+
+    if escrow.state == State::Completed {
+        return_collateral(escrow, identifier);
+    }
+
+    log(ResolvedDisputeEvent {
+        identifier,
+        milestone,
+        user: ruling.user,
+    });
+}
+
+/// Returns the remaining fees of the panel, and the fees of any proposed arbiters, to the seller
+#[storage(read, write)]
+fn return_collateral(escrow: EscrowInfo, identifier: u64) {
+    let mut position = 0;
+    while position < escrow.panel.arbiter_count {
+        let arbiter = storage.arbiters.get(escrow.panel.first_arbiter_index + position).unwrap();
+        if arbiter.fee_amount != 0 {
+            transfer(arbiter.fee_amount, arbiter.asset, escrow.seller.address);
+        }
+
+        // If there is a previous proposal then we must transfer those funds back to the seller
+        let proposal = storage.arbiter_proposal.get((identifier, position));
+        if proposal.is_some() {
+            transfer(proposal.unwrap().fee_amount, proposal.unwrap().asset, escrow.seller.address);
+            // Not needed as long as the entire contract handles state correctly but leaving it in
+            // for conceptual closure at the slight expense of users
+            storage.arbiter_proposal.remove((identifier, position));
+        }
+
+        position += 1;
+    }
+}
+
+/// Settles the current milestone in favour of the seller or buyers and moves the escrow onto the
+/// next milestone, completing the escrow after the last one
+///
+/// Returns the amount of the settled milestone
+#[storage(read, write)]
+fn settle_milestone(ref mut escrow: EscrowInfo, identifier: u64, state: MilestoneState) -> u64 {
+    let index = escrow.first_milestone_index + escrow.current_milestone;
+    let mut milestone = storage.milestones.get(index).unwrap();
+
+    milestone.state = state;
+    storage.milestones.set(index, milestone);
+
+    // A dispute, along with its rulings, only applies to the milestone it has been raised for
+    if 0 < escrow.ruling_count {
+        let mut position = 0;
+        while position < escrow.panel.arbiter_count {
+            storage.rulings.remove((identifier, position));
+            position += 1;
+        }
+    }
+
+    escrow.current_milestone += 1;
+    escrow.disputed = false;
+    escrow.resolution_deadline = 0;
+    escrow.ruling_count = 0;
+
+    if escrow.current_milestone == escrow.milestone_count {
+        escrow.state = State::Completed;
+    }
+
+    milestone.amount
+}
+
+/// Whether every buyer of the escrow has approved the transfer of `milestone` to the seller
+#[storage(read)]
+fn transfer_approved(escrow: EscrowInfo, identifier: u64, milestone: u64) -> bool {
+    let mut index = 0;
+    while index < escrow.buyer_count {
+        let buyer = storage.buyers.get(escrow.first_buyer_index + index).unwrap();
+        if storage.transfer_approvals.get((identifier, milestone, buyer.address)).is_none() {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
 }
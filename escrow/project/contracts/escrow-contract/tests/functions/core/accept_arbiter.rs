@@ -9,7 +9,7 @@ use crate::utils::{
 mod success {
     use super::*;
     use crate::utils::{
-        interface::{core::propose_arbiter, info::escrows},
+        interface::{core::propose_arbiter, info::arbiters},
         setup::{asset_amount, AcceptedArbiterEvent},
     };
 
@@ -37,30 +37,36 @@ mod success {
             defaults.deadline,
         )
         .await;
-        propose_arbiter(arbiter_obj2.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj2.clone(), &seller, 0, 0).await;
 
         let initial_amount = asset_amount(&defaults.asset_id, &seller).await;
-        let initial_escrow = escrows(&seller, 0).await.unwrap();
-        let initial_proposal = arbiter_proposal(&seller, 0).await.unwrap();
+        let initial_arbiter = arbiters(&seller, 0, 0).await.unwrap();
+        let initial_proposal = arbiter_proposal(&seller, 0, 0).await.unwrap();
 
-        let response = accept_arbiter(&buyer, 0).await;
+        let response = accept_arbiter(&buyer, 0, 0).await;
 
         assert_eq!(payment_diff, initial_amount);
-        assert_eq!(arbiter_obj, initial_escrow.arbiter);
+        assert_eq!(arbiter_obj, initial_arbiter);
         assert_eq!(arbiter_obj2.clone(), initial_proposal);
         assert_eq!(
             defaults.asset_amount + payment_diff,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert_eq!(arbiter_obj2, escrows(&seller, 0).await.unwrap().arbiter);
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert_eq!(arbiter_obj2, arbiters(&seller, 0, 0).await.unwrap());
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
         let log = response
             .get_logs_with_type::<AcceptedArbiterEvent>()
             .unwrap();
         let event = log.get(0).unwrap();
 
-        assert_eq!(*event, AcceptedArbiterEvent { identifier: 0 });
+        assert_eq!(
+            *event,
+            AcceptedArbiterEvent {
+                identifier: 0,
+                position: 0
+            }
+        );
     }
 
     #[tokio::test]
@@ -98,40 +104,31 @@ mod success {
         )
         .await;
 
-        propose_arbiter(arbiter_obj2.clone(), &seller, 0).await;
-        propose_arbiter(arbiter_obj2.clone(), &seller, 1).await;
+        propose_arbiter(arbiter_obj2.clone(), &seller, 0, 0).await;
+        propose_arbiter(arbiter_obj2.clone(), &seller, 1, 0).await;
 
         assert_eq!(
             payment_diff * 2,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert_eq!(arbiter_obj2, arbiter_proposal(&seller, 0).await.unwrap());
-        assert_eq!(arbiter_obj2, arbiter_proposal(&seller, 1).await.unwrap());
-        assert_eq!(
-            arbiter_obj.clone(),
-            escrows(&seller, 0).await.unwrap().arbiter
-        );
-        assert_eq!(
-            arbiter_obj.clone(),
-            escrows(&seller, 1).await.unwrap().arbiter
-        );
+        assert_eq!(arbiter_obj2, arbiter_proposal(&seller, 0, 0).await.unwrap());
+        assert_eq!(arbiter_obj2, arbiter_proposal(&seller, 1, 0).await.unwrap());
+        assert_eq!(arbiter_obj.clone(), arbiters(&seller, 0, 0).await.unwrap());
+        assert_eq!(arbiter_obj.clone(), arbiters(&seller, 1, 0).await.unwrap());
 
-        let response1 = accept_arbiter(&buyer, 0).await;
+        let response1 = accept_arbiter(&buyer, 0, 0).await;
         let asset_amount1 = asset_amount(&defaults.asset_id, &seller).await;
-        let response2 = accept_arbiter(&buyer, 1).await;
+        let response2 = accept_arbiter(&buyer, 1, 0).await;
 
         assert_eq!(defaults.asset_amount + payment_diff * 2, asset_amount1);
         assert_eq!(
             defaults.asset_amount * 2 + payment_diff * 2,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert_eq!(arbiter_proposal(&seller, 0).await, None);
-        assert_eq!(arbiter_proposal(&seller, 1).await, None);
-        assert_eq!(
-            arbiter_obj2.clone(),
-            escrows(&seller, 0).await.unwrap().arbiter,
-        );
-        assert_eq!(arbiter_obj2, escrows(&seller, 1).await.unwrap().arbiter,);
+        assert_eq!(arbiter_proposal(&seller, 0, 0).await, None);
+        assert_eq!(arbiter_proposal(&seller, 1, 0).await, None);
+        assert_eq!(arbiter_obj2.clone(), arbiters(&seller, 0, 0).await.unwrap(),);
+        assert_eq!(arbiter_obj2, arbiters(&seller, 1, 0).await.unwrap());
 
         let log1 = response1
             .get_logs_with_type::<AcceptedArbiterEvent>()
@@ -143,8 +140,20 @@ mod success {
         let event1 = log1.get(0).unwrap();
         let event2 = log2.get(0).unwrap();
 
-        assert_eq!(*event1, AcceptedArbiterEvent { identifier: 0 });
-        assert_eq!(*event2, AcceptedArbiterEvent { identifier: 1 });
+        assert_eq!(
+            *event1,
+            AcceptedArbiterEvent {
+                identifier: 0,
+                position: 0
+            }
+        );
+        assert_eq!(
+            *event2,
+            AcceptedArbiterEvent {
+                identifier: 1,
+                position: 0
+            }
+        );
     }
 }
 
@@ -174,7 +183,7 @@ mod revert {
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
         transfer_to_seller(&buyer, 0).await;
-        accept_arbiter(&buyer, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
     }
 
     #[tokio::test]
@@ -197,7 +206,7 @@ mod revert {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        accept_arbiter(&seller, 0).await;
+        accept_arbiter(&seller, 0, 0).await;
     }
 
     #[tokio::test]
@@ -220,6 +229,6 @@ mod revert {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        accept_arbiter(&buyer, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
     }
 }
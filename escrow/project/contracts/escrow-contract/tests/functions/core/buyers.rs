@@ -0,0 +1,529 @@
+use crate::utils::{
+    interface::core::{create_escrow_with_panel, deposit},
+    setup::{create_arbiter, create_asset, mint, setup_with_arbiters, RESOLUTION_WINDOW},
+};
+
+mod success {
+
+    use super::*;
+    use crate::utils::{
+        interface::{
+            core::{
+                accept_arbiter, dispute, propose_arbiter, resolve_dispute, return_deposit,
+                transfer_to_seller, withdraw_collateral,
+            },
+            info::{arbiters, buyers, escrows},
+        },
+        setup::{
+            asset_amount, create_buyer, create_milestone, ApprovedTransferEvent, DepositEvent,
+            State,
+        },
+    };
+    use fuels::{prelude::Address, types::Identity};
+
+    #[tokio::test]
+    async fn deposits_from_several_buyers() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, 50, &defaults.asset).await;
+        mint(&users[1], 30, &defaults.asset).await;
+        mint(&users[2], 20, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1], &users[2]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert_eq!(3, escrow.buyer_count);
+        assert_eq!(0, escrow.first_buyer_index);
+
+        deposit(50, &defaults.asset_id, &buyer, 0).await;
+        let response = deposit(30, &defaults.asset_id, &users[1], 0).await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert_eq!(80, escrow.deposited_amount);
+        assert_eq!(0, escrow.milestone_count);
+
+        deposit(20, &defaults.asset_id, &users[2], 0).await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert_eq!(defaults.asset_amount, escrow.deposited_amount);
+        assert_eq!(1, escrow.milestone_count);
+        assert_eq!(
+            buyers(&seller, 0, 0).await.unwrap(),
+            create_buyer(&buyer, 50).await
+        );
+        assert_eq!(
+            buyers(&seller, 0, 1).await.unwrap(),
+            create_buyer(&users[1], 30).await
+        );
+        assert_eq!(
+            buyers(&seller, 0, 2).await.unwrap(),
+            create_buyer(&users[2], 20).await
+        );
+
+        let log = response.get_logs_with_type::<DepositEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            DepositEvent {
+                amount: 30,
+                asset: defaults.asset_id,
+                buyer: Identity::Address(Address::from(users[1].wallet.address())),
+                identifier: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_deposit_in_proportion() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let first_milestone = create_milestone(60, None).await;
+        let second_milestone = create_milestone(40, None).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, 50, &defaults.asset).await;
+        mint(&users[1], 30, &defaults.asset).await;
+        mint(&users[2], 20, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1], &users[2]],
+            &seller,
+            defaults.deadline,
+            vec![first_milestone, second_milestone],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(50, &defaults.asset_id, &buyer, 0).await;
+        deposit(30, &defaults.asset_id, &users[1], 0).await;
+        deposit(20, &defaults.asset_id, &users[2], 0).await;
+        transfer_to_seller(&buyer, 0).await;
+        transfer_to_seller(&users[1], 0).await;
+        transfer_to_seller(&users[2], 0).await;
+        return_deposit(&seller, 0).await;
+
+        // The remaining 40 are split 50 : 30 : 20 between the buyers
+        assert_eq!(20, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(12, asset_amount(&defaults.asset_id, &users[1]).await);
+        assert_eq!(8, asset_amount(&defaults.asset_id, &users[2]).await);
+        assert_eq!(
+            defaults.asset_amount + 60,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+    }
+
+    #[tokio::test]
+    async fn transfers_milestone_once_every_buyer_approves() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, 90, &defaults.asset).await;
+        mint(&users[1], 10, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(90, &defaults.asset_id, &buyer, 0).await;
+        deposit(10, &defaults.asset_id, &users[1], 0).await;
+
+        // A single buyer cannot release the deposits of the others
+        let response = transfer_to_seller(&users[1], 0).await;
+
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Pending
+        ));
+
+        let log = response
+            .get_logs_with_type::<ApprovedTransferEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ApprovedTransferEvent {
+                buyer: Identity::Address(Address::from(users[1].wallet.address())),
+                identifier: 0,
+                milestone: 0
+            }
+        );
+
+        transfer_to_seller(&buyer, 0).await;
+
+        assert_eq!(
+            defaults.asset_amount * 2,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+    }
+
+    #[tokio::test]
+    async fn accepts_arbiter_once_every_buyer_approves() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let replacement = create_arbiter(&users[2], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj.clone()],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        propose_arbiter(replacement.clone(), &seller, 0, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
+
+        assert_eq!(arbiter_obj, arbiters(&seller, 0, 0).await.unwrap());
+
+        // A new proposal discards the approvals of the previous one
+        propose_arbiter(replacement.clone(), &seller, 0, 0).await;
+        accept_arbiter(&users[1], 0, 0).await;
+
+        assert_eq!(arbiter_obj, arbiters(&seller, 0, 0).await.unwrap());
+
+        accept_arbiter(&buyer, 0, 0).await;
+
+        assert_eq!(replacement, arbiters(&seller, 0, 0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn splits_disputed_milestone_between_buyers() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, 34, &defaults.asset).await;
+        mint(&users[1], 33, &defaults.asset).await;
+        mint(&users[2], 33, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1], &users[2]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(34, &defaults.asset_id, &buyer, 0).await;
+        deposit(33, &defaults.asset_id, &users[1], 0).await;
+        deposit(33, &defaults.asset_id, &users[2], 0).await;
+        dispute(&users[1], 0).await;
+        resolve_dispute(&users[0], 0, 0, &users[2]).await;
+
+        // A ruling for any of the buyers refunds all of them
+        assert_eq!(34, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(33, asset_amount(&defaults.asset_id, &users[1]).await);
+        assert_eq!(33, asset_amount(&defaults.asset_id, &users[2]).await);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+    }
+
+    #[tokio::test]
+    async fn refunds_partial_deposits_when_withdrawing_collateral() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, 50, &defaults.asset).await;
+        mint(&users[1], 30, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1], &users[2]],
+            &seller,
+            9,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(50, &defaults.asset_id, &buyer, 0).await;
+        deposit(30, &defaults.asset_id, &users[1], 0).await;
+
+        // Let the deadline pass
+        mint(&users[2], 1, &defaults.asset).await;
+
+        withdraw_collateral(&seller, 0).await;
+
+        assert_eq!(50, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(30, asset_amount(&defaults.asset_id, &users[1]).await);
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+    use crate::utils::{
+        interface::core::{accept_arbiter, dispute, propose_arbiter, transfer_to_seller},
+        setup::create_asset_with_salt,
+    };
+    use fuels::tx::ContractId;
+
+    #[tokio::test]
+    #[should_panic(expected = "UnspecifiedBuyers")]
+    async fn when_buyers_are_not_specified() {
+        let (users, _buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyBuyer")]
+    async fn when_buyer_is_specified_twice() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotBeBuyer")]
+    async fn when_arbiter_is_one_of_the_buyers() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1], &users[0]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "IncorrectAssetSent")]
+    async fn when_buyers_deposit_different_assets() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let (id, salted_asset) = create_asset_with_salt([1u8; 32], users[1].wallet.clone()).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let other_asset = create_asset(defaults.asset_amount, ContractId::from(*id)).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        mint(&users[1], defaults.asset_amount, &salted_asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone(), other_asset],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(50, &defaults.asset_id, &buyer, 0).await;
+        deposit(50, &ContractId::from(*id), &users[1], 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "IncorrectAssetAmount")]
+    async fn when_deposits_exceed_asset_amount() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        mint(&users[1], defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(60, &defaults.asset_id, &buyer, 0).await;
+        deposit(60, &defaults.asset_id, &users[1], 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotDisputeBeforeDesposit")]
+    async fn when_disputing_before_whole_amount_is_deposited() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(50, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyApproved")]
+    async fn when_buyer_approves_transfer_twice() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        transfer_to_seller(&buyer, 0).await;
+        transfer_to_seller(&buyer, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyApproved")]
+    async fn when_buyer_approves_arbiter_twice() {
+        let (users, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj = create_arbiter(&users[0], defaults.asset_id, defaults.asset_amount).await;
+        let replacement = create_arbiter(&users[2], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &users[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        propose_arbiter(replacement, &seller, 0, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
+    }
+}
@@ -9,7 +9,9 @@ mod success {
     use super::*;
     use crate::utils::{
         interface::info::{assets, escrow_count, escrows},
-        setup::{asset_amount, escrow_info, CreatedEscrowEvent},
+        setup::{
+            asset_amount, create_panel, escrow_info, CreatedEscrowEvent, Party, RESOLUTION_WINDOW,
+        },
     };
 
     #[tokio::test]
@@ -25,7 +27,7 @@ mod success {
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(0, escrow_count(&seller).await);
-        assert!(matches!(assets(&seller, 0).await, None));
+        assert!(assets(&seller, 0).await.is_empty());
         assert!(matches!(escrows(&seller, 0).await, None));
 
         let response = create_escrow(
@@ -41,18 +43,24 @@ mod success {
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
         assert_eq!(1, escrow_count(&seller).await);
-        assert_eq!(assets(&seller, 0).await.unwrap(), asset);
+        assert_eq!(assets(&seller, 0).await, vec![asset.clone()]);
         assert_eq!(
             escrows(&seller, 0).await.unwrap(),
             escrow_info(
-                arbiter_obj.clone(),
                 1,
-                &buyer,
+                1,
                 None,
                 0,
+                0,
                 defaults.deadline,
                 false,
                 0,
+                0,
+                0,
+                0,
+                create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                0,
+                0,
                 &seller,
                 false
             )
@@ -66,14 +74,20 @@ mod success {
             *event,
             CreatedEscrowEvent {
                 escrow: escrow_info(
-                    arbiter_obj.clone(),
                     1,
-                    &buyer,
+                    1,
                     None,
                     0,
+                    0,
                     defaults.deadline,
                     false,
                     0,
+                    0,
+                    0,
+                    0,
+                    create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                    0,
+                    0,
                     &seller,
                     false
                 )
@@ -96,7 +110,7 @@ mod success {
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(0, escrow_count(&seller).await);
-        assert!(matches!(assets(&seller, 0).await, None));
+        assert!(assets(&seller, 0).await.is_empty());
         assert!(matches!(escrows(&seller, 0).await, None));
 
         let response = create_escrow(
@@ -112,20 +126,27 @@ mod success {
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
         assert_eq!(1, escrow_count(&seller).await);
-        assert_eq!(assets(&seller, 0).await.unwrap(), asset);
-        assert_eq!(assets(&seller, 1).await.unwrap(), asset);
-        assert_eq!(assets(&seller, 2).await.unwrap(), asset);
+        assert_eq!(
+            assets(&seller, 0).await,
+            vec![asset.clone(), asset.clone(), asset.clone()]
+        );
         assert_eq!(
             escrows(&seller, 0).await.unwrap(),
             escrow_info(
-                arbiter_obj.clone(),
                 3,
-                &buyer,
+                1,
                 None,
                 0,
+                0,
                 defaults.deadline,
                 false,
                 0,
+                0,
+                0,
+                0,
+                create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                0,
+                0,
                 &seller,
                 false
             )
@@ -139,14 +160,20 @@ mod success {
             *event,
             CreatedEscrowEvent {
                 escrow: escrow_info(
-                    arbiter_obj.clone(),
                     3,
-                    &buyer,
+                    1,
                     None,
                     0,
+                    0,
                     defaults.deadline,
                     false,
                     0,
+                    0,
+                    0,
+                    0,
+                    create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                    0,
+                    0,
                     &seller,
                     false
                 )
@@ -169,7 +196,7 @@ mod success {
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(0, escrow_count(&seller).await);
-        assert!(matches!(assets(&seller, 0).await, None));
+        assert!(assets(&seller, 0).await.is_empty());
         assert!(matches!(escrows(&seller, 0).await, None));
 
         let response1 = create_escrow(
@@ -188,18 +215,24 @@ mod success {
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(1, escrow_count(&seller).await);
-        assert_eq!(assets(&seller, 0).await.unwrap(), asset);
+        assert_eq!(assets(&seller, 0).await, vec![asset.clone()]);
         assert_eq!(
             escrows(&seller, 0).await.unwrap(),
             escrow_info(
-                arbiter_obj.clone(),
                 1,
-                &buyer,
+                1,
                 None,
                 0,
+                0,
                 defaults.deadline,
                 false,
                 0,
+                0,
+                0,
+                0,
+                create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                0,
+                0,
                 &seller,
                 false
             )
@@ -219,18 +252,24 @@ mod success {
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
         assert_eq!(2, escrow_count(&seller).await);
-        assert_eq!(assets(&seller, 1).await.unwrap(), asset);
+        assert_eq!(assets(&seller, 1).await, vec![asset.clone()]);
         assert_eq!(
             escrows(&seller, 1).await.unwrap(),
             escrow_info(
-                arbiter_obj.clone(),
                 1,
-                &buyer,
+                1,
                 None,
                 0,
+                0,
                 defaults.deadline,
                 false,
                 1,
+                1,
+                0,
+                0,
+                create_panel(1, Party::Buyers, 1, 1, RESOLUTION_WINDOW).await,
+                0,
+                0,
                 &seller,
                 false
             )
@@ -250,14 +289,20 @@ mod success {
             *event1,
             CreatedEscrowEvent {
                 escrow: escrow_info(
-                    arbiter_obj.clone(),
                     1,
-                    &buyer,
+                    1,
                     None,
                     0,
+                    0,
                     defaults.deadline,
                     false,
                     0,
+                    0,
+                    0,
+                    0,
+                    create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                    0,
+                    0,
                     &seller,
                     false
                 )
@@ -269,14 +314,20 @@ mod success {
             *event2,
             CreatedEscrowEvent {
                 escrow: escrow_info(
-                    arbiter_obj.clone(),
                     1,
-                    &buyer,
+                    1,
                     None,
                     0,
+                    0,
                     defaults.deadline,
                     false,
                     1,
+                    1,
+                    0,
+                    0,
+                    create_panel(1, Party::Buyers, 1, 1, RESOLUTION_WINDOW).await,
+                    0,
+                    0,
                     &seller,
                     false
                 )
@@ -8,9 +8,10 @@ mod success {
 
     use super::*;
     use crate::utils::{
-        interface::info::escrows,
-        setup::{asset_amount, DepositEvent},
+        interface::info::{buyers, escrows},
+        setup::{asset_amount, create_buyer, DepositEvent},
     };
+    use fuels::{prelude::Address, types::Identity};
 
     #[tokio::test]
     async fn deposits() {
@@ -37,16 +38,24 @@ mod success {
         );
 
         let escrow = escrows(&seller, 0).await.unwrap();
-        assert!(matches!(escrow.buyer.asset, None));
-        assert_eq!(0, escrow.buyer.deposited_amount);
+        assert!(matches!(escrow.asset, None));
+        assert_eq!(0, escrow.deposited_amount);
+        assert_eq!(
+            buyers(&seller, 0, 0).await.unwrap(),
+            create_buyer(&buyer, 0).await
+        );
 
         let response = deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
 
-        let escrow = escrows(&seller, 0).await.unwrap().buyer;
+        let escrow = escrows(&seller, 0).await.unwrap();
         assert_eq!(escrow.asset, Some(defaults.asset_id));
         assert_eq!(defaults.asset_amount, escrow.deposited_amount);
+        assert_eq!(
+            buyers(&seller, 0, 0).await.unwrap(),
+            create_buyer(&buyer, defaults.asset_amount).await
+        );
 
         let log = response.get_logs_with_type::<DepositEvent>().unwrap();
         let event = log.get(0).unwrap();
@@ -54,12 +63,51 @@ mod success {
         assert_eq!(
             *event,
             DepositEvent {
+                amount: defaults.asset_amount,
                 asset: defaults.asset_id,
+                buyer: Identity::Address(Address::from(buyer.wallet.address())),
                 identifier: 0
             }
         );
     }
 
+    #[tokio::test]
+    async fn deposits_in_parts() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let part = defaults.asset_amount / 4;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+        deposit(part, &defaults.asset_id, &buyer, 0).await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+        assert_eq!(escrow.asset, Some(defaults.asset_id));
+        assert_eq!(part, escrow.deposited_amount);
+        assert_eq!(0, escrow.milestone_count);
+
+        deposit(defaults.asset_amount - part, &defaults.asset_id, &buyer, 0).await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+        assert_eq!(defaults.asset_amount, escrow.deposited_amount);
+        assert_eq!(1, escrow.milestone_count);
+        assert_eq!(
+            buyers(&seller, 0, 0).await.unwrap(),
+            create_buyer(&buyer, defaults.asset_amount).await
+        );
+    }
+
     #[tokio::test]
     async fn deposits_to_two_escrows() {
         let (arbiter, buyer, seller, defaults) = setup().await;
@@ -97,10 +145,10 @@ mod success {
         let escrow1 = escrows(&seller, 0).await.unwrap();
         let escrow2 = escrows(&seller, 0).await.unwrap();
 
-        assert!(matches!(escrow1.buyer.asset, None));
-        assert!(matches!(escrow2.buyer.asset, None));
-        assert_eq!(0, escrow1.buyer.deposited_amount);
-        assert_eq!(0, escrow2.buyer.deposited_amount);
+        assert!(matches!(escrow1.asset, None));
+        assert!(matches!(escrow2.asset, None));
+        assert_eq!(0, escrow1.deposited_amount);
+        assert_eq!(0, escrow2.deposited_amount);
 
         let response1 = deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
 
@@ -111,8 +159,8 @@ mod success {
         assert_eq!(defaults.asset_amount, asset_amount1);
         assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
 
-        let escrow3 = escrows(&seller, 0).await.unwrap().buyer;
-        let escrow4 = escrows(&seller, 0).await.unwrap().buyer;
+        let escrow3 = escrows(&seller, 0).await.unwrap();
+        let escrow4 = escrows(&seller, 1).await.unwrap();
 
         assert_eq!(escrow3.asset, Some(defaults.asset_id));
         assert_eq!(escrow4.asset, Some(defaults.asset_id));
@@ -127,14 +175,18 @@ mod success {
         assert_eq!(
             *event1,
             DepositEvent {
+                amount: defaults.asset_amount,
                 asset: defaults.asset_id,
+                buyer: Identity::Address(Address::from(buyer.wallet.address())),
                 identifier: 0
             }
         );
         assert_eq!(
             *event2,
             DepositEvent {
+                amount: defaults.asset_amount,
                 asset: defaults.asset_id,
+                buyer: Identity::Address(Address::from(buyer.wallet.address())),
                 identifier: 1
             }
         );
@@ -255,7 +307,7 @@ mod revert {
             defaults.deadline,
         )
         .await;
-        deposit(defaults.asset_amount - 1, &defaults.asset_id, &buyer, 0).await;
+        deposit(defaults.asset_amount + 1, &defaults.asset_id, &buyer, 0).await;
     }
 
     #[tokio::test]
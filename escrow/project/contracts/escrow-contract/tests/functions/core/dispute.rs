@@ -37,7 +37,13 @@ mod success {
         let log = response.get_logs_with_type::<DisputeEvent>().unwrap();
         let event = log.get(0).unwrap();
 
-        assert_eq!(*event, DisputeEvent { identifier: 0 });
+        assert_eq!(
+            *event,
+            DisputeEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
     }
 
     #[tokio::test]
@@ -85,8 +91,20 @@ mod success {
         let event1 = log1.get(0).unwrap();
         let event2 = log2.get(0).unwrap();
 
-        assert_eq!(*event1, DisputeEvent { identifier: 0 });
-        assert_eq!(*event2, DisputeEvent { identifier: 1 });
+        assert_eq!(
+            *event1,
+            DisputeEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
+        assert_eq!(
+            *event2,
+            DisputeEvent {
+                identifier: 1,
+                milestone: 0
+            }
+        );
     }
 }
 
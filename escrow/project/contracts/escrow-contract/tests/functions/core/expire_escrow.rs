@@ -0,0 +1,190 @@
+use crate::utils::{
+    interface::core::{create_escrow_with_panel, deposit, expire_escrow},
+    setup::{
+        create_arbiter, create_asset, mint, produce_blocks, setup_with_arbiters, RESOLUTION_WINDOW,
+    },
+};
+
+mod success {
+
+    use super::*;
+    use crate::utils::{
+        interface::info::escrows,
+        setup::{asset_amount, ExpiredEscrowEvent, State},
+    };
+
+    #[tokio::test]
+    async fn expires_escrow_without_deposits() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+
+        produce_blocks(&buyer, defaults.deadline).await;
+
+        let response = expire_escrow(&buyer, 0).await;
+
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+
+        let log = response.get_logs_with_type::<ExpiredEscrowEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(*event, ExpiredEscrowEvent { identifier: 0 });
+    }
+
+    #[tokio::test]
+    async fn refunds_partial_deposits() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, 50, &defaults.asset).await;
+        mint(&members[1], 30, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer, &members[1]],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(50, &defaults.asset_id, &buyer, 0).await;
+        deposit(30, &defaults.asset_id, &members[1], 0).await;
+
+        produce_blocks(&buyer, defaults.deadline).await;
+
+        // Any user can expire the escrow, not only its parties
+        expire_escrow(&members[2], 0).await;
+
+        assert_eq!(50, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(30, asset_amount(&defaults.asset_id, &members[1]).await);
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "StateNotPending")]
+    async fn when_escrow_is_not_pending() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+
+        produce_blocks(&buyer, defaults.deadline).await;
+
+        expire_escrow(&buyer, 0).await;
+        expire_escrow(&buyer, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotWithdrawBeforeDeadline")]
+    async fn when_deadline_has_not_passed() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        expire_escrow(&buyer, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotWithdrawAfterDesposit")]
+    async fn when_buyers_have_deposited() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+
+        produce_blocks(&buyer, defaults.deadline).await;
+
+        expire_escrow(&buyer, 0).await;
+    }
+}
@@ -0,0 +1,526 @@
+use crate::utils::{
+    interface::core::{create_escrow_with_milestones, deposit},
+    setup::{create_arbiter, create_asset, create_milestone, mint, setup},
+};
+
+mod success {
+
+    use super::*;
+    use crate::utils::{
+        interface::{
+            core::{
+                create_escrow, dispute, propose_arbiter, resolve_dispute, return_deposit,
+                take_payment, transfer_to_seller,
+            },
+            info::{arbiters, escrows, milestones},
+        },
+        setup::{
+            asset_amount, DisputeEvent, MilestoneState, PaymentTakenEvent, ResolvedDisputeEvent,
+            State, TransferredToSellerEvent,
+        },
+    };
+    use fuels::{prelude::Address, types::Identity};
+
+    #[tokio::test]
+    async fn creates_escrow_with_milestones() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let first_milestone = create_milestone(60, None).await;
+        let second_milestone = create_milestone(40, Some(defaults.deadline + 10)).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![first_milestone.clone(), second_milestone.clone()],
+        )
+        .await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert_eq!(0, escrow.current_milestone);
+        assert_eq!(0, escrow.first_milestone_index);
+        assert_eq!(2, escrow.milestone_count);
+
+        let milestone = milestones(&seller, 0, 0).await.unwrap();
+
+        assert_eq!(first_milestone.amount, milestone.amount);
+        assert_eq!(first_milestone.deadline, milestone.deadline);
+        assert!(matches!(milestone.state, MilestoneState::Pending));
+
+        let milestone = milestones(&seller, 0, 1).await.unwrap();
+
+        assert_eq!(second_milestone.amount, milestone.amount);
+        assert_eq!(second_milestone.deadline, milestone.deadline);
+        assert!(matches!(milestone.state, MilestoneState::Pending));
+        assert!(matches!(milestones(&seller, 0, 2).await, None));
+    }
+
+    #[tokio::test]
+    async fn releases_whole_deposit_without_milestones() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+
+        assert_eq!(0, escrows(&seller, 0).await.unwrap().milestone_count);
+        assert!(matches!(milestones(&seller, 0, 0).await, None));
+
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+
+        assert_eq!(1, escrows(&seller, 0).await.unwrap().milestone_count);
+
+        let milestone = milestones(&seller, 0, 0).await.unwrap();
+
+        assert_eq!(defaults.asset_amount, milestone.amount);
+        assert_eq!(None, milestone.deadline);
+        assert!(matches!(milestone.state, MilestoneState::Pending));
+    }
+
+    #[tokio::test]
+    async fn transfers_milestones_to_seller_one_at_a_time() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(60, None).await,
+                create_milestone(40, None).await,
+            ],
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+
+        let response1 = transfer_to_seller(&buyer, 0).await;
+
+        assert_eq!(60, asset_amount(&defaults.asset_id, &seller).await);
+        assert_eq!(1, escrows(&seller, 0).await.unwrap().current_milestone);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Pending
+        ));
+        assert!(matches!(
+            milestones(&seller, 0, 0).await.unwrap().state,
+            MilestoneState::Released
+        ));
+        assert!(matches!(
+            milestones(&seller, 0, 1).await.unwrap().state,
+            MilestoneState::Pending
+        ));
+
+        let response2 = transfer_to_seller(&buyer, 0).await;
+
+        // The arbiter fee is only returned once the last milestone has been released
+        assert_eq!(
+            defaults.asset_amount * 2,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(2, escrows(&seller, 0).await.unwrap().current_milestone);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+        assert!(matches!(
+            milestones(&seller, 0, 1).await.unwrap().state,
+            MilestoneState::Released
+        ));
+
+        let log1 = response1
+            .get_logs_with_type::<TransferredToSellerEvent>()
+            .unwrap();
+        let log2 = response2
+            .get_logs_with_type::<TransferredToSellerEvent>()
+            .unwrap();
+        let event1 = log1.get(0).unwrap();
+        let event2 = log2.get(0).unwrap();
+
+        assert_eq!(
+            *event1,
+            TransferredToSellerEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
+        assert_eq!(
+            *event2,
+            TransferredToSellerEvent {
+                identifier: 0,
+                milestone: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_dispute_of_current_milestone() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let payment_amount = 30;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(60, None).await,
+                create_milestone(40, None).await,
+            ],
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+
+        let dispute_response = dispute(&buyer, 0).await;
+        let response = resolve_dispute(&arbiter, 0, payment_amount, &buyer).await;
+
+        // Only the disputed milestone is returned and the escrow carries on with the next one
+        assert_eq!(60, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(
+            payment_amount,
+            asset_amount(&defaults.asset_id, &arbiter).await
+        );
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert_eq!(1, escrow.current_milestone);
+        assert!(!escrow.disputed);
+        assert_eq!(
+            defaults.asset_amount - payment_amount,
+            arbiters(&seller, 0, 0).await.unwrap().fee_amount
+        );
+        assert!(matches!(escrow.state, State::Pending));
+        assert!(matches!(
+            milestones(&seller, 0, 0).await.unwrap().state,
+            MilestoneState::Returned
+        ));
+
+        transfer_to_seller(&buyer, 0).await;
+
+        assert_eq!(
+            40 + defaults.asset_amount - payment_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+
+        let dispute_log = dispute_response
+            .get_logs_with_type::<DisputeEvent>()
+            .unwrap();
+        let log = response
+            .get_logs_with_type::<ResolvedDisputeEvent>()
+            .unwrap();
+        let dispute_event = dispute_log.get(0).unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *dispute_event,
+            DisputeEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
+        assert_eq!(
+            *event,
+            ResolvedDisputeEvent {
+                identifier: 0,
+                milestone: 0,
+                user: Identity::Address(Address::from(buyer.wallet.address()))
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_remaining_milestones() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(50, None).await,
+                create_milestone(30, None).await,
+                create_milestone(20, None).await,
+            ],
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        transfer_to_seller(&buyer, 0).await;
+
+        assert_eq!(50, asset_amount(&defaults.asset_id, &seller).await);
+        assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
+
+        return_deposit(&seller, 0).await;
+
+        assert_eq!(50, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(
+            50 + defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert_eq!(3, escrows(&seller, 0).await.unwrap().current_milestone);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+        assert!(matches!(
+            milestones(&seller, 0, 0).await.unwrap().state,
+            MilestoneState::Released
+        ));
+        assert!(matches!(
+            milestones(&seller, 0, 1).await.unwrap().state,
+            MilestoneState::Returned
+        ));
+        assert!(matches!(
+            milestones(&seller, 0, 2).await.unwrap().state,
+            MilestoneState::Returned
+        ));
+    }
+
+    #[tokio::test]
+    async fn takes_payment_for_current_milestone() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            7,
+            vec![
+                create_milestone(60, None).await,
+                create_milestone(40, Some(defaults.deadline)).await,
+            ],
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+
+        // Advances the height past the deadline of the escrow given SDK limitations for block
+        // manipulation
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
+
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+
+        let response = take_payment(&seller, 0).await;
+
+        assert_eq!(60, asset_amount(&defaults.asset_id, &seller).await);
+        assert_eq!(1, escrows(&seller, 0).await.unwrap().current_milestone);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Pending
+        ));
+
+        let log = response.get_logs_with_type::<PaymentTakenEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            PaymentTakenEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+    use crate::utils::interface::core::{propose_arbiter, take_payment};
+    use fuels::tx::ContractId;
+
+    #[tokio::test]
+    #[should_panic(expected = "AmountCannotBeZero")]
+    async fn when_milestone_amount_is_zero() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(defaults.asset_amount, None).await,
+                create_milestone(0, None).await,
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AmountsDoNotMatchAssets")]
+    async fn when_milestone_amounts_do_not_match_assets() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(60, None).await,
+                create_milestone(60, None).await,
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "RequireSingleAsset")]
+    async fn when_milestones_are_set_for_multiple_assets() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let asset2 = create_asset(defaults.asset_amount, ContractId::from([2u8; 32])).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone(), asset2.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(60, None).await,
+                create_milestone(defaults.asset_amount - 60, None).await,
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DeadlinesMustIncrease")]
+    async fn when_milestone_deadline_is_not_after_escrow_deadline() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![create_milestone(defaults.asset_amount, Some(defaults.deadline)).await],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DeadlinesMustIncrease")]
+    async fn when_milestone_deadlines_are_not_in_order() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![
+                create_milestone(60, Some(defaults.deadline + 20)).await,
+                create_milestone(40, Some(defaults.deadline + 10)).await,
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotTakePaymentBeforeDeadline")]
+    async fn when_milestone_deadline_is_not_reached() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            7,
+            vec![create_milestone(defaults.asset_amount, Some(defaults.deadline)).await],
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
+        take_payment(&seller, 0).await;
+    }
+}
@@ -1,10 +1,15 @@
 mod accept_arbiter;
+mod buyers;
 mod create_escrow;
 mod deposit;
 mod dispute;
+mod expire_escrow;
+mod milestones;
+mod panel;
 mod propose_arbiter;
 mod resolve_dispute;
 mod return_deposit;
+mod settle_dispute;
 mod take_payment;
 mod transfer_to_seller;
 mod withdraw_collateral;
@@ -0,0 +1,520 @@
+use crate::utils::{
+    interface::core::{create_escrow_with_panel, deposit, dispute, resolve_dispute},
+    setup::{create_arbiter, create_asset, mint, setup_with_arbiters, RESOLUTION_WINDOW},
+};
+
+mod success {
+
+    use super::*;
+    use crate::utils::{
+        interface::{
+            core::{accept_arbiter, propose_arbiter},
+            info::{arbiters, escrows, rulings},
+        },
+        setup::{
+            asset_amount, create_panel, create_ruling, Party, ResolvedDisputeEvent, State,
+            SubmittedRulingEvent,
+        },
+    };
+    use fuels::{prelude::Address, types::Identity};
+
+    #[tokio::test]
+    async fn creates_escrow_with_panel() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 3,
+            panel.clone(),
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+
+        assert_eq!(
+            create_panel(3, Party::Buyers, 0, 2, RESOLUTION_WINDOW).await,
+            escrows(&seller, 0).await.unwrap().panel
+        );
+        assert_eq!(panel[0], arbiters(&seller, 0, 0).await.unwrap());
+        assert_eq!(panel[1], arbiters(&seller, 0, 1).await.unwrap());
+        assert_eq!(panel[2], arbiters(&seller, 0, 2).await.unwrap());
+        assert!(matches!(arbiters(&seller, 0, 3).await, None));
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+    }
+
+    #[tokio::test]
+    async fn resolves_when_majority_agrees() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let payment_amount = 60;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 3,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            3,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+
+        let response = resolve_dispute(&members[0], 0, payment_amount, &seller).await;
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert!(escrow.disputed);
+        assert_eq!(1, escrow.ruling_count);
+        assert_eq!(
+            create_ruling(payment_amount, &seller).await,
+            rulings(&seller, 0, 0).await.unwrap()
+        );
+        assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
+
+        let log = response
+            .get_logs_with_type::<SubmittedRulingEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            SubmittedRulingEvent {
+                arbiter: Identity::Address(Address::from(members[0].wallet.address())),
+                identifier: 0,
+                milestone: 0,
+                ruling: create_ruling(payment_amount, &seller).await
+            }
+        );
+
+        let response = resolve_dispute(&members[1], 0, payment_amount, &seller).await;
+
+        // Each arbiter who ruled is paid a third of the payment and the rest of the fees is returned
+        let share = payment_amount / 3;
+
+        assert_eq!(share, asset_amount(&defaults.asset_id, &members[0]).await);
+        assert_eq!(share, asset_amount(&defaults.asset_id, &members[1]).await);
+        assert_eq!(0, asset_amount(&defaults.asset_id, &members[2]).await);
+        assert_eq!(
+            defaults.asset_amount + defaults.asset_amount * 3 - share * 2,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
+
+        let escrow = escrows(&seller, 0).await.unwrap();
+
+        assert!(!escrow.disputed);
+        assert_eq!(0, escrow.ruling_count);
+        assert!(matches!(escrow.state, State::Completed));
+        assert!(matches!(rulings(&seller, 0, 0).await, None));
+        assert!(matches!(rulings(&seller, 0, 1).await, None));
+
+        let log = response
+            .get_logs_with_type::<ResolvedDisputeEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ResolvedDisputeEvent {
+                identifier: 0,
+                milestone: 0,
+                user: Identity::Address(Address::from(seller.wallet.address()))
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_with_median_ruling_when_quorum_is_reached() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 3,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+        resolve_dispute(&members[0], 0, 30, &buyer).await;
+        let response = resolve_dispute(&members[1], 0, 90, &seller).await;
+
+        // The rulings are tied so the buyer is refunded and the median payment of 60 is split
+        let share = 60 / 3;
+
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &buyer).await
+        );
+        assert_eq!(share, asset_amount(&defaults.asset_id, &members[0]).await);
+        assert_eq!(share, asset_amount(&defaults.asset_id, &members[1]).await);
+        assert_eq!(
+            defaults.asset_amount * 3 - share * 2,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+
+        let log = response
+            .get_logs_with_type::<ResolvedDisputeEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ResolvedDisputeEvent {
+                identifier: 0,
+                milestone: 0,
+                user: Identity::Address(Address::from(buyer.wallet.address()))
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn discards_ruling_of_replaced_arbiter() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(4).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members[..3].iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+        let replacement =
+            create_arbiter(&members[3], defaults.asset_id, defaults.asset_amount).await;
+
+        mint(&seller, defaults.asset_amount * 4, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 3,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            3,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+        resolve_dispute(&members[0], 0, 60, &seller).await;
+
+        assert_eq!(1, escrows(&seller, 0).await.unwrap().ruling_count);
+
+        propose_arbiter(replacement.clone(), &seller, 0, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
+
+        assert_eq!(replacement, arbiters(&seller, 0, 0).await.unwrap());
+        assert_eq!(0, escrows(&seller, 0).await.unwrap().ruling_count);
+        assert!(matches!(rulings(&seller, 0, 0).await, None));
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+    use crate::utils::interface::core::{accept_arbiter, propose_arbiter};
+
+    #[tokio::test]
+    #[should_panic(expected = "UnspecifiedArbiters")]
+    async fn when_membersare_not_specified() {
+        let (_members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidQuorum")]
+    async fn when_quorum_is_zero() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            0,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidQuorum")]
+    async fn when_quorum_is_larger_than_panel() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ResolutionWindowCannotBeZero")]
+    async fn when_resolution_window_is_zero() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            0,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ResolutionWindowTooLong")]
+    async fn when_resolution_window_is_too_long() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            u64::MAX,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyOnPanel")]
+    async fn when_arbiter_is_on_panel_twice() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 2,
+            vec![arbiter_obj.clone(), arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyRuled")]
+    async fn when_arbiter_has_already_ruled() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 2,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+        resolve_dispute(&members[0], 0, 0, &buyer).await;
+        resolve_dispute(&members[0], 0, 0, &buyer).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "PositionOutOfBounds")]
+    async fn when_proposing_arbiter_outside_of_panel() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let arbiter_obj2 =
+            create_arbiter(&members[1], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        propose_arbiter(arbiter_obj2, &seller, 0, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyOnPanel")]
+    async fn when_proposing_arbiter_already_on_panel() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 2,
+            panel.clone(),
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        propose_arbiter(panel[1].clone(), &seller, 0, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyOnPanel")]
+    async fn when_accepting_arbiter_who_has_taken_another_position() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(4).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members[..3].iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+        let replacement =
+            create_arbiter(&members[3], defaults.asset_id, defaults.asset_amount).await;
+
+        mint(&seller, defaults.asset_amount * 6, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 3,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+
+        // Both proposals are valid while the replacement is not on the panel yet
+        propose_arbiter(replacement.clone(), &seller, 0, 0).await;
+        propose_arbiter(replacement, &seller, 0, 1).await;
+        accept_arbiter(&buyer, 0, 0).await;
+        accept_arbiter(&buyer, 0, 1).await;
+    }
+}
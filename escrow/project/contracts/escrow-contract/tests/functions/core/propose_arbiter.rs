@@ -37,12 +37,12 @@ mod success {
             defaults.asset_amount,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
-        let response = propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        let response = propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
-        assert_eq!(arbiter_proposal(&seller, 0).await.unwrap(), arbiter_obj);
+        assert_eq!(arbiter_proposal(&seller, 0, 0).await.unwrap(), arbiter_obj);
 
         let log = response
             .get_logs_with_type::<ProposedArbiterEvent>()
@@ -53,7 +53,8 @@ mod success {
             *event,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj,
-                identifier: 0
+                identifier: 0,
+                position: 0
             }
         );
     }
@@ -87,23 +88,23 @@ mod success {
             defaults.asset_amount * 2,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
-        let response1 = propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        let response1 = propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(
             defaults.asset_amount,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert_eq!(arbiter_proposal(&seller, 0).await.unwrap(), arbiter_obj);
+        assert_eq!(arbiter_proposal(&seller, 0, 0).await.unwrap(), arbiter_obj);
 
-        let response2 = propose_arbiter(arbiter_obj2.clone(), &seller, 0).await;
+        let response2 = propose_arbiter(arbiter_obj2.clone(), &seller, 0, 0).await;
 
         assert_eq!(
             defaults.asset_amount + payment_diff,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert_eq!(arbiter_proposal(&seller, 0).await.unwrap(), arbiter_obj2);
+        assert_eq!(arbiter_proposal(&seller, 0, 0).await.unwrap(), arbiter_obj2);
 
         let log1 = response1
             .get_logs_with_type::<ProposedArbiterEvent>()
@@ -118,14 +119,16 @@ mod success {
             *event1,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj.clone(),
-                identifier: 0
+                identifier: 0,
+                position: 0
             }
         );
         assert_eq!(
             *event2,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj2,
-                identifier: 0
+                identifier: 0,
+                position: 0
             }
         );
     }
@@ -162,26 +165,26 @@ mod success {
             defaults.asset_amount * 2,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
-        assert!(matches!(arbiter_proposal(&seller, 1).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 1, 0).await, None));
 
-        let response1 = propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        let response1 = propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(
             defaults.asset_amount,
             asset_amount(&defaults.asset_id, &seller).await
         );
 
-        let response2 = propose_arbiter(arbiter_obj.clone(), &seller, 1).await;
+        let response2 = propose_arbiter(arbiter_obj.clone(), &seller, 1, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 0).await.unwrap()
+            arbiter_proposal(&seller, 0, 0).await.unwrap()
         );
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 1).await.unwrap()
+            arbiter_proposal(&seller, 1, 0).await.unwrap()
         );
 
         let log1 = response1
@@ -197,14 +200,16 @@ mod success {
             *event1,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj.clone(),
-                identifier: 0
+                identifier: 0,
+                position: 0
             }
         );
         assert_eq!(
             *event2,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj,
-                identifier: 1
+                identifier: 1,
+                position: 0
             }
         );
     }
@@ -243,50 +248,50 @@ mod success {
             defaults.asset_amount * 4,
             asset_amount(&defaults.asset_id, &seller).await
         );
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
-        assert!(matches!(arbiter_proposal(&seller, 1).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 1, 0).await, None));
 
-        let response1 = propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        let response1 = propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(
             defaults.asset_amount * 3,
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(
-            arbiter_proposal(&seller, 0).await.unwrap(),
+            arbiter_proposal(&seller, 0, 0).await.unwrap(),
             arbiter_obj.clone()
         );
 
-        let response2 = propose_arbiter(arbiter_obj.clone(), &seller, 1).await;
+        let response2 = propose_arbiter(arbiter_obj.clone(), &seller, 1, 0).await;
 
         assert_eq!(
             defaults.asset_amount * 2,
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(
-            arbiter_proposal(&seller, 1).await.unwrap(),
+            arbiter_proposal(&seller, 1, 0).await.unwrap(),
             arbiter_obj.clone()
         );
 
-        let response3 = propose_arbiter(arbiter_obj2.clone(), &seller, 0).await;
+        let response3 = propose_arbiter(arbiter_obj2.clone(), &seller, 0, 0).await;
 
         assert_eq!(
             defaults.asset_amount * 2 + 1,
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(
-            arbiter_proposal(&seller, 0).await.unwrap(),
+            arbiter_proposal(&seller, 0, 0).await.unwrap(),
             arbiter_obj2.clone()
         );
 
-        let response4 = propose_arbiter(arbiter_obj2.clone(), &seller, 1).await;
+        let response4 = propose_arbiter(arbiter_obj2.clone(), &seller, 1, 0).await;
 
         assert_eq!(
             defaults.asset_amount * 2 + 2,
             asset_amount(&defaults.asset_id, &seller).await
         );
         assert_eq!(
-            arbiter_proposal(&seller, 1).await.unwrap(),
+            arbiter_proposal(&seller, 1, 0).await.unwrap(),
             arbiter_obj2.clone()
         );
 
@@ -311,28 +316,32 @@ mod success {
             *event1,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj.clone(),
-                identifier: 0
+                identifier: 0,
+                position: 0
             }
         );
         assert_eq!(
             *event2,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj.clone(),
-                identifier: 1
+                identifier: 1,
+                position: 0
             }
         );
         assert_eq!(
             *event3,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj2.clone(),
-                identifier: 0
+                identifier: 0,
+                position: 0
             }
         );
         assert_eq!(
             *event4,
             ProposedArbiterEvent {
                 arbiter: arbiter_obj2,
-                identifier: 1
+                identifier: 1,
+                position: 0
             }
         );
     }
@@ -364,7 +373,7 @@ mod revert {
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
         transfer_to_seller(&buyer, 0).await;
-        propose_arbiter(arbiter_obj, &seller, 0).await;
+        propose_arbiter(arbiter_obj, &seller, 0, 0).await;
     }
 
     #[tokio::test]
@@ -387,7 +396,7 @@ mod revert {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        propose_arbiter(arbiter_obj, &buyer, 0).await;
+        propose_arbiter(arbiter_obj, &buyer, 0, 0).await;
     }
 
     #[tokio::test]
@@ -412,7 +421,7 @@ mod revert {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        propose_arbiter(arbiter_obj_buyer, &seller, 0).await;
+        propose_arbiter(arbiter_obj_buyer, &seller, 0, 0).await;
     }
 
     #[tokio::test]
@@ -437,7 +446,7 @@ mod revert {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        propose_arbiter(arbiter_obj_seller, &seller, 0).await;
+        propose_arbiter(arbiter_obj_seller, &seller, 0, 0).await;
     }
 
     #[tokio::test]
@@ -461,7 +470,7 @@ mod revert {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        propose_arbiter(arbiter_obj_zero, &seller, 0).await;
+        propose_arbiter(arbiter_obj_zero, &seller, 0, 0).await;
     }
 
     #[tokio::test]
@@ -494,7 +503,7 @@ mod revert {
         seller
             .contract
             .methods()
-            .propose_arbiter(arbiter_obj, 0)
+            .propose_arbiter(arbiter_obj, 0, 0)
             .tx_params(tx_params)
             .call_params(call_params)
             .unwrap()
@@ -539,7 +548,7 @@ mod revert {
         seller
             .contract
             .methods()
-            .propose_arbiter(arbiter_obj_unequal, 0)
+            .propose_arbiter(arbiter_obj_unequal, 0, 0)
             .tx_params(tx_params)
             .call_params(call_params)
             .unwrap()
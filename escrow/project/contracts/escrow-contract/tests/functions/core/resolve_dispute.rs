@@ -71,6 +71,7 @@ mod success {
             *event,
             ResolvedDisputeEvent {
                 identifier: 0,
+                milestone: 0,
                 user: Identity::Address(Address::from(buyer.wallet.address()))
             }
         );
@@ -136,6 +137,7 @@ mod success {
             *event,
             ResolvedDisputeEvent {
                 identifier: 0,
+                milestone: 0,
                 user: Identity::Address(Address::from(buyer.wallet.address()))
             }
         );
@@ -196,6 +198,7 @@ mod success {
             *event,
             ResolvedDisputeEvent {
                 identifier: 0,
+                milestone: 0,
                 user: Identity::Address(Address::from(seller.wallet.address()))
             }
         );
@@ -256,6 +259,7 @@ mod success {
             *event,
             ResolvedDisputeEvent {
                 identifier: 0,
+                milestone: 0,
                 user: Identity::Address(Address::from(seller.wallet.address()))
             }
         );
@@ -280,7 +284,7 @@ mod success {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
@@ -293,7 +297,7 @@ mod success {
         ));
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 0).await.unwrap()
+            arbiter_proposal(&seller, 0, 0).await.unwrap()
         );
 
         let response = resolve_dispute(&arbiter, 0, arbiter_obj.fee_amount, &buyer).await;
@@ -310,7 +314,7 @@ mod success {
             defaults.asset_amount,
             asset_amount(&defaults.asset_id, &arbiter).await
         );
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
         assert!(matches!(
             escrows(&seller, 0).await.unwrap().state,
             State::Completed
@@ -325,6 +329,7 @@ mod success {
             *event,
             ResolvedDisputeEvent {
                 identifier: 0,
+                milestone: 0,
                 user: Identity::Address(Address::from(buyer.wallet.address()))
             }
         );
@@ -426,6 +431,7 @@ mod success {
             *event1,
             ResolvedDisputeEvent {
                 identifier: 0,
+                milestone: 0,
                 user: Identity::Address(Address::from(buyer.wallet.address()))
             }
         );
@@ -433,6 +439,7 @@ mod success {
             *event2,
             ResolvedDisputeEvent {
                 identifier: 1,
+                milestone: 0,
                 user: Identity::Address(Address::from(seller.wallet.address()))
             }
         );
@@ -77,7 +77,7 @@ mod success {
             defaults.deadline,
         )
         .await;
-        propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
@@ -88,7 +88,7 @@ mod success {
         ));
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 0).await.unwrap()
+            arbiter_proposal(&seller, 0, 0).await.unwrap()
         );
 
         let response = return_deposit(&seller, 0).await;
@@ -105,7 +105,7 @@ mod success {
             escrows(&seller, 0).await.unwrap().state,
             State::Completed
         ));
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
         let log = response
             .get_logs_with_type::<ReturnedDepositEvent>()
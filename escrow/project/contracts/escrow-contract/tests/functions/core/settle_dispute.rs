@@ -0,0 +1,303 @@
+use crate::utils::{
+    interface::core::{create_escrow_with_panel, deposit, dispute, settle_dispute},
+    setup::{
+        create_arbiter, create_asset, mint, produce_blocks, setup_with_arbiters, RESOLUTION_WINDOW,
+    },
+};
+
+mod success {
+
+    use super::*;
+    use crate::utils::{
+        interface::{
+            core::{create_escrow_with_default_party, resolve_dispute},
+            info::{escrows, rulings},
+        },
+        setup::{asset_amount, Party, ResolvedDisputeEvent, State},
+    };
+    use fuels::{prelude::Address, types::Identity};
+
+    #[tokio::test]
+    async fn settles_with_submitted_rulings() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(3).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let payment_amount = 60;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 3,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            3,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+        resolve_dispute(&members[0], 0, payment_amount, &seller).await;
+
+        assert!(escrows(&seller, 0).await.unwrap().disputed);
+
+        // The resolution window has passed by the time the next transaction is included
+        let response = settle_dispute(&buyer, 0).await;
+
+        let share = payment_amount / 3;
+
+        assert_eq!(share, asset_amount(&defaults.asset_id, &members[0]).await);
+        assert_eq!(0, asset_amount(&defaults.asset_id, &members[1]).await);
+        assert_eq!(
+            defaults.asset_amount + defaults.asset_amount * 3 - share,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+        assert!(matches!(rulings(&seller, 0, 0).await, None));
+
+        let log = response
+            .get_logs_with_type::<ResolvedDisputeEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ResolvedDisputeEvent {
+                identifier: 0,
+                milestone: 0,
+                user: Identity::Address(Address::from(seller.wallet.address()))
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_milestone_to_buyers_without_rulings() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+
+        // The panel never rules so the resolution window passes
+        produce_blocks(&buyer, RESOLUTION_WINDOW + 1).await;
+
+        let response = settle_dispute(&buyer, 0).await;
+
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &buyer).await
+        );
+        assert_eq!(
+            defaults.asset_amount,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert_eq!(0, asset_amount(&defaults.asset_id, &members[0]).await);
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+
+        let log = response
+            .get_logs_with_type::<ResolvedDisputeEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ResolvedDisputeEvent {
+                identifier: 0,
+                milestone: 0,
+                user: Identity::Address(Address::from(buyer.wallet.address()))
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn releases_milestone_to_default_party_without_rulings() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_default_party(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            Party::Seller,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+
+        // The panel never rules so the resolution window passes
+        produce_blocks(&seller, RESOLUTION_WINDOW + 1).await;
+
+        settle_dispute(&seller, 0).await;
+
+        assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
+        assert_eq!(
+            defaults.asset_amount * 2,
+            asset_amount(&defaults.asset_id, &seller).await
+        );
+        assert!(matches!(
+            escrows(&seller, 0).await.unwrap().state,
+            State::Completed
+        ));
+    }
+}
+
+mod revert {
+
+    use super::*;
+    use crate::utils::interface::core::{resolve_dispute, transfer_to_seller};
+
+    #[tokio::test]
+    #[should_panic(expected = "StateNotPending")]
+    async fn when_escrow_is_not_pending() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        transfer_to_seller(&buyer, 0).await;
+        settle_dispute(&buyer, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotDisputed")]
+    async fn when_not_disputed() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        settle_dispute(&buyer, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Unauthorized")]
+    async fn when_caller_is_not_buyer_or_seller() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 2,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+        resolve_dispute(&members[0], 0, 0, &buyer).await;
+        settle_dispute(&members[0], 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotSettleBeforeResolutionDeadline")]
+    async fn when_resolution_deadline_has_not_passed() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount,
+            vec![arbiter_obj],
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            1,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+        settle_dispute(&buyer, 0).await;
+    }
+}
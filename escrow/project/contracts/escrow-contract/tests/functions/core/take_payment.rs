@@ -58,7 +58,13 @@ mod success {
         //     .unwrap();
         // let event = log.get(0).unwrap();
 
-        // assert_eq!(*event, PaymentTakenEvent { identifier: 0 });
+        // assert_eq!(
+        //     *event,
+        //     PaymentTakenEvent {
+        //         identifier: 0,
+        //         milestone: 0
+        //     }
+        // );
     }
 
     #[tokio::test]
@@ -83,7 +89,7 @@ mod success {
 
         // This should really be above `deposit` but given SDK limitations for block manipulation
         // we put this here
-        propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
@@ -93,7 +99,7 @@ mod success {
         ));
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 0).await.unwrap()
+            arbiter_proposal(&seller, 0, 0).await.unwrap()
         );
 
         let response = take_payment(&seller, 0).await;
@@ -107,12 +113,18 @@ mod success {
             escrows(&seller, 0).await.unwrap().state,
             State::Completed
         ));
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
         let log = response.get_logs_with_type::<PaymentTakenEvent>().unwrap();
         let event = log.get(0).unwrap();
 
-        assert_eq!(*event, PaymentTakenEvent { identifier: 0 });
+        assert_eq!(
+            *event,
+            PaymentTakenEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
     }
 
     #[tokio::test]
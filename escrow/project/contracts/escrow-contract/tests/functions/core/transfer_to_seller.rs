@@ -58,7 +58,13 @@ mod success {
             .unwrap();
         let event = log.get(0).unwrap();
 
-        assert_eq!(*event, TransferredToSellerEvent { identifier: 0 });
+        assert_eq!(
+            *event,
+            TransferredToSellerEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
     }
 
     #[tokio::test]
@@ -80,7 +86,7 @@ mod success {
         )
         .await;
         deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
-        propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &buyer).await);
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
@@ -90,7 +96,7 @@ mod success {
         ));
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 0).await.unwrap()
+            arbiter_proposal(&seller, 0, 0).await.unwrap()
         );
 
         let response = transfer_to_seller(&buyer, 0).await;
@@ -104,14 +110,20 @@ mod success {
             escrows(&seller, 0).await.unwrap().state,
             State::Completed
         ));
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
         let log = response
             .get_logs_with_type::<TransferredToSellerEvent>()
             .unwrap();
         let event = log.get(0).unwrap();
 
-        assert_eq!(*event, TransferredToSellerEvent { identifier: 0 });
+        assert_eq!(
+            *event,
+            TransferredToSellerEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
     }
 
     #[tokio::test]
@@ -189,8 +201,20 @@ mod success {
         let event1 = log1.get(0).unwrap();
         let event2 = log2.get(0).unwrap();
 
-        assert_eq!(*event1, TransferredToSellerEvent { identifier: 0 });
-        assert_eq!(*event2, TransferredToSellerEvent { identifier: 1 });
+        assert_eq!(
+            *event1,
+            TransferredToSellerEvent {
+                identifier: 0,
+                milestone: 0
+            }
+        );
+        assert_eq!(
+            *event2,
+            TransferredToSellerEvent {
+                identifier: 1,
+                milestone: 0
+            }
+        );
     }
 }
 
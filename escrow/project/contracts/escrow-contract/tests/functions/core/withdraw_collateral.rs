@@ -76,7 +76,7 @@ mod success {
             5,
         )
         .await;
-        propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
         assert_eq!(0, asset_amount(&defaults.asset_id, &seller).await);
         assert!(matches!(
@@ -85,7 +85,7 @@ mod success {
         ));
         assert_eq!(
             arbiter_obj.clone(),
-            arbiter_proposal(&seller, 0).await.unwrap()
+            arbiter_proposal(&seller, 0, 0).await.unwrap()
         );
 
         let response = withdraw_collateral(&seller, 0).await;
@@ -98,7 +98,7 @@ mod success {
             escrows(&seller, 0).await.unwrap().state,
             State::Completed
         ));
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
         let log = response
             .get_logs_with_type::<WithdrawnCollateralEvent>()
@@ -11,7 +11,7 @@ mod success {
     #[tokio::test]
     async fn returns_none() {
         let (_arbiter, _buyer, seller, _defaults) = setup().await;
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
     }
 
     #[tokio::test]
@@ -32,10 +32,10 @@ mod success {
         )
         .await;
 
-        assert!(matches!(arbiter_proposal(&seller, 0).await, None));
+        assert!(matches!(arbiter_proposal(&seller, 0, 0).await, None));
 
-        propose_arbiter(arbiter_obj.clone(), &seller, 0).await;
+        propose_arbiter(arbiter_obj.clone(), &seller, 0, 0).await;
 
-        assert_eq!(arbiter_proposal(&seller, 0).await.unwrap(), arbiter_obj);
+        assert_eq!(arbiter_proposal(&seller, 0, 0).await.unwrap(), arbiter_obj);
     }
 }
@@ -0,0 +1,45 @@
+mod success {
+
+    use crate::utils::{
+        interface::{
+            core::{accept_arbiter, create_escrow, propose_arbiter},
+            info::arbiters,
+        },
+        setup::{create_arbiter, create_asset, mint, setup},
+    };
+
+    #[tokio::test]
+    async fn returns_none() {
+        let (_arbiter, _buyer, seller, _defaults) = setup().await;
+        assert!(matches!(arbiters(&seller, 0, 0).await, None));
+    }
+
+    #[tokio::test]
+    async fn returns_arbiter() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let arbiter_obj2 =
+            create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount - 1).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+
+        assert_eq!(arbiters(&seller, 0, 0).await.unwrap(), arbiter_obj);
+        assert!(matches!(arbiters(&seller, 0, 1).await, None));
+
+        propose_arbiter(arbiter_obj2.clone(), &seller, 0, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
+
+        assert_eq!(arbiters(&seller, 0, 0).await.unwrap(), arbiter_obj2);
+    }
+}
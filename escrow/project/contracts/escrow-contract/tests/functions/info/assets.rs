@@ -4,11 +4,12 @@ mod success {
         interface::{core::create_escrow, info::assets},
         setup::{create_arbiter, create_asset, mint, setup},
     };
+    use fuels::tx::ContractId;
 
     #[tokio::test]
-    async fn returns_none() {
+    async fn returns_no_assets() {
         let (_arbiter, _buyer, seller, _defaults) = setup().await;
-        assert!(matches!(assets(&seller, 0).await, None));
+        assert!(assets(&seller, 0).await.is_empty());
     }
 
     #[tokio::test]
@@ -19,7 +20,7 @@ mod success {
 
         mint(&seller, defaults.asset_amount, &defaults.asset).await;
 
-        assert!(matches!(assets(&seller, 0).await, None));
+        assert!(assets(&seller, 0).await.is_empty());
 
         create_escrow(
             defaults.asset_amount,
@@ -32,6 +33,40 @@ mod success {
         )
         .await;
 
-        assert_eq!(assets(&seller, 0).await.unwrap(), asset);
+        assert_eq!(assets(&seller, 0).await, vec![asset.clone()]);
+    }
+
+    #[tokio::test]
+    async fn returns_every_asset_of_escrow() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let other_asset = create_asset(defaults.asset_amount, ContractId::from([1u8; 32])).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![other_asset.clone(), asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+
+        assert_eq!(assets(&seller, 0).await, vec![asset.clone()]);
+        assert_eq!(assets(&seller, 1).await, vec![other_asset, asset]);
+        assert!(assets(&seller, 2).await.is_empty());
     }
 }
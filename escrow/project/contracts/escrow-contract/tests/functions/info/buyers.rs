@@ -0,0 +1,49 @@
+mod success {
+
+    use crate::utils::{
+        interface::{
+            core::{create_escrow, deposit},
+            info::buyers,
+        },
+        setup::{create_arbiter, create_asset, create_buyer, mint, setup},
+    };
+
+    #[tokio::test]
+    async fn returns_none() {
+        let (_arbiter, _buyer, seller, _defaults) = setup().await;
+        assert!(matches!(buyers(&seller, 0, 0).await, None));
+    }
+
+    #[tokio::test]
+    async fn returns_buyer() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+
+        assert_eq!(
+            buyers(&seller, 0, 0).await.unwrap(),
+            create_buyer(&buyer, 0).await
+        );
+        assert!(matches!(buyers(&seller, 0, 1).await, None));
+
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+
+        assert_eq!(
+            buyers(&seller, 0, 0).await.unwrap(),
+            create_buyer(&buyer, defaults.asset_amount).await
+        );
+    }
+}
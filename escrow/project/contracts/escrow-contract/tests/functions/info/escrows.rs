@@ -2,7 +2,10 @@ mod success {
 
     use crate::utils::{
         interface::{core::create_escrow, info::escrows},
-        setup::{create_arbiter, create_asset, escrow_info, mint, setup},
+        setup::{
+            create_arbiter, create_asset, create_panel, escrow_info, mint, setup, Party,
+            RESOLUTION_WINDOW,
+        },
     };
 
     #[tokio::test]
@@ -35,14 +38,20 @@ mod success {
         assert_eq!(
             escrows(&seller, 0).await.unwrap(),
             escrow_info(
-                arbiter_obj,
                 1,
-                &buyer,
+                1,
                 None,
                 0,
+                0,
                 defaults.deadline,
                 false,
                 0,
+                0,
+                0,
+                0,
+                create_panel(1, Party::Buyers, 0, 1, RESOLUTION_WINDOW).await,
+                0,
+                0,
                 &seller,
                 false
             )
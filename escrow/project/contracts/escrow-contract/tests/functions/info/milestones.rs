@@ -0,0 +1,50 @@
+mod success {
+
+    use crate::utils::{
+        interface::{core::create_escrow_with_milestones, info::milestones},
+        setup::{
+            create_arbiter, create_asset, create_milestone, mint, setup, MilestoneInfo,
+            MilestoneState,
+        },
+    };
+
+    #[tokio::test]
+    async fn returns_none() {
+        let (_arbiter, _buyer, seller, _defaults) = setup().await;
+        assert!(matches!(milestones(&seller, 0, 0).await, None));
+    }
+
+    #[tokio::test]
+    async fn returns_milestone_info() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let milestone = create_milestone(defaults.asset_amount, None).await;
+
+        mint(&seller, defaults.asset_amount, &defaults.asset).await;
+
+        assert!(matches!(milestones(&seller, 0, 0).await, None));
+
+        create_escrow_with_milestones(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+            vec![milestone.clone()],
+        )
+        .await;
+
+        assert_eq!(
+            milestones(&seller, 0, 0).await.unwrap(),
+            MilestoneInfo {
+                amount: milestone.amount,
+                deadline: milestone.deadline,
+                state: MilestoneState::Pending,
+            }
+        );
+        assert!(matches!(milestones(&seller, 0, 1).await, None));
+    }
+}
@@ -1,4 +1,10 @@
 mod arbiter_proposal;
+mod arbiters;
 mod assets;
+mod buyers;
 mod escrow_count;
 mod escrows;
+mod milestones;
+mod rulings;
+mod user_escrow_count;
+mod user_escrows;
@@ -0,0 +1,57 @@
+mod success {
+
+    use crate::utils::{
+        interface::{
+            core::{create_escrow_with_panel, deposit, dispute, resolve_dispute},
+            info::rulings,
+        },
+        setup::{
+            create_arbiter, create_asset, create_ruling, mint, setup, setup_with_arbiters,
+            RESOLUTION_WINDOW,
+        },
+    };
+
+    #[tokio::test]
+    async fn returns_none() {
+        let (_arbiter, _buyer, seller, _defaults) = setup().await;
+        assert!(matches!(rulings(&seller, 0, 0).await, None));
+    }
+
+    #[tokio::test]
+    async fn returns_ruling() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+        let mut panel = vec![];
+        for arbiter in members.iter() {
+            panel.push(create_arbiter(arbiter, defaults.asset_id, defaults.asset_amount).await);
+        }
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        mint(&buyer, defaults.asset_amount, &defaults.asset).await;
+        create_escrow_with_panel(
+            defaults.asset_amount * 2,
+            panel,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            vec![&buyer],
+            &seller,
+            defaults.deadline,
+            vec![],
+            2,
+            RESOLUTION_WINDOW,
+        )
+        .await;
+        deposit(defaults.asset_amount, &defaults.asset_id, &buyer, 0).await;
+        dispute(&buyer, 0).await;
+
+        assert!(matches!(rulings(&seller, 0, 1).await, None));
+
+        resolve_dispute(&members[1], 0, 10, &buyer).await;
+
+        assert!(matches!(rulings(&seller, 0, 0).await, None));
+        assert_eq!(
+            rulings(&seller, 0, 1).await.unwrap(),
+            create_ruling(10, &buyer).await
+        );
+    }
+}
@@ -0,0 +1,99 @@
+mod success {
+
+    use crate::utils::{
+        interface::{
+            core::{accept_arbiter, create_escrow, propose_arbiter},
+            info::user_escrow_count,
+        },
+        setup::{create_arbiter, create_asset, mint, setup, setup_with_arbiters, Role},
+    };
+
+    #[tokio::test]
+    async fn returns_zero() {
+        let (_members, buyer, seller, _defaults) = setup_with_arbiters(1).await;
+
+        assert_eq!(0, user_escrow_count(&seller, Role::Seller, &seller).await);
+        assert_eq!(0, user_escrow_count(&seller, Role::Buyer, &buyer).await);
+    }
+
+    #[tokio::test]
+    async fn returns_escrow_count_of_each_role() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let arbiter_obj2 =
+            create_arbiter(&members[1], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+
+        assert_eq!(2, user_escrow_count(&seller, Role::Seller, &seller).await);
+        assert_eq!(2, user_escrow_count(&seller, Role::Buyer, &buyer).await);
+        assert_eq!(
+            2,
+            user_escrow_count(&seller, Role::Arbiter, &members[0]).await
+        );
+        assert_eq!(0, user_escrow_count(&seller, Role::Buyer, &seller).await);
+        assert_eq!(
+            0,
+            user_escrow_count(&seller, Role::Arbiter, &members[1]).await
+        );
+
+        propose_arbiter(arbiter_obj2, &seller, 1, 0).await;
+        accept_arbiter(&buyer, 1, 0).await;
+
+        assert_eq!(
+            1,
+            user_escrow_count(&seller, Role::Arbiter, &members[1]).await
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_count_arbiter_accepted_for_own_position_twice() {
+        let (arbiter, buyer, seller, defaults) = setup().await;
+        let arbiter_obj = create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount).await;
+        let arbiter_obj2 =
+            create_arbiter(&arbiter, defaults.asset_id, defaults.asset_amount - 1).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 2, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+
+        assert_eq!(1, user_escrow_count(&seller, Role::Arbiter, &arbiter).await);
+
+        // the arbiter holding the position is proposed again with a lower fee
+        propose_arbiter(arbiter_obj2, &seller, 0, 0).await;
+        accept_arbiter(&buyer, 0, 0).await;
+
+        assert_eq!(1, user_escrow_count(&seller, Role::Arbiter, &arbiter).await);
+    }
+}
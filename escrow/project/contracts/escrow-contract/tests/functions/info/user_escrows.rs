@@ -0,0 +1,108 @@
+mod success {
+
+    use crate::utils::{
+        interface::{
+            core::{accept_arbiter, create_escrow, propose_arbiter},
+            info::user_escrows,
+        },
+        setup::{create_arbiter, create_asset, mint, setup_with_arbiters, Role},
+    };
+
+    #[tokio::test]
+    async fn returns_no_escrows() {
+        let (_members, _buyer, seller, _defaults) = setup_with_arbiters(1).await;
+
+        assert!(user_escrows(&seller, 10, Role::Seller, 0, &seller)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_pages_of_escrows() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(1).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        for _ in 0..3 {
+            create_escrow(
+                defaults.asset_amount,
+                &arbiter_obj,
+                &defaults.asset_id,
+                vec![asset.clone()],
+                &buyer,
+                &seller,
+                defaults.deadline,
+            )
+            .await;
+        }
+
+        assert_eq!(
+            vec![0, 1],
+            user_escrows(&seller, 2, Role::Seller, 0, &seller).await
+        );
+        assert_eq!(
+            vec![2],
+            user_escrows(&seller, 2, Role::Seller, 2, &seller).await
+        );
+        assert!(user_escrows(&seller, 2, Role::Seller, 3, &seller)
+            .await
+            .is_empty());
+        assert_eq!(
+            vec![0, 1, 2],
+            user_escrows(&seller, 10, Role::Buyer, 0, &buyer).await
+        );
+        assert_eq!(
+            vec![1, 2],
+            user_escrows(&seller, 10, Role::Arbiter, 1, &members[0]).await
+        );
+        assert!(user_escrows(&seller, 10, Role::Buyer, 0, &seller)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_escrows_of_accepted_arbiter() {
+        let (members, buyer, seller, defaults) = setup_with_arbiters(2).await;
+        let arbiter_obj =
+            create_arbiter(&members[0], defaults.asset_id, defaults.asset_amount).await;
+        let arbiter_obj2 =
+            create_arbiter(&members[1], defaults.asset_id, defaults.asset_amount).await;
+        let asset = create_asset(defaults.asset_amount, defaults.asset_id).await;
+
+        mint(&seller, defaults.asset_amount * 3, &defaults.asset).await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+        create_escrow(
+            defaults.asset_amount,
+            &arbiter_obj,
+            &defaults.asset_id,
+            vec![asset.clone()],
+            &buyer,
+            &seller,
+            defaults.deadline,
+        )
+        .await;
+        propose_arbiter(arbiter_obj2, &seller, 1, 0).await;
+
+        assert!(user_escrows(&seller, 10, Role::Arbiter, 0, &members[1])
+            .await
+            .is_empty());
+
+        accept_arbiter(&buyer, 1, 0).await;
+
+        assert_eq!(
+            vec![1],
+            user_escrows(&seller, 10, Role::Arbiter, 0, &members[1]).await
+        );
+    }
+}
@@ -1,15 +1,19 @@
-use crate::utils::setup::{Arbiter, Asset, User};
+use crate::utils::setup::{Arbiter, Asset, Milestone, Party, User, RESOLUTION_WINDOW};
 use fuels::{
     prelude::{AssetId, CallParameters, ContractId, TxParameters},
     programs::call_response::FuelCallResponse,
     types::Identity,
 };
 
-pub(crate) async fn accept_arbiter(caller: &User, identifier: u64) -> FuelCallResponse<()> {
+pub(crate) async fn accept_arbiter(
+    caller: &User,
+    identifier: u64,
+    position: u64,
+) -> FuelCallResponse<()> {
     caller
         .contract
         .methods()
-        .accept_arbiter(identifier)
+        .accept_arbiter(identifier, position)
         .append_variable_outputs(1)
         .call()
         .await
@@ -24,6 +28,85 @@ pub(crate) async fn create_escrow(
     buyer: &User,
     caller: &User,
     deadline: u64,
+) -> FuelCallResponse<()> {
+    create_escrow_with_milestones(
+        amount,
+        arbiter,
+        asset,
+        assets,
+        buyer,
+        caller,
+        deadline,
+        vec![],
+    )
+    .await
+}
+
+pub(crate) async fn create_escrow_with_milestones(
+    amount: u64,
+    arbiter: &Arbiter,
+    asset: &ContractId,
+    assets: Vec<Asset>,
+    buyer: &User,
+    caller: &User,
+    deadline: u64,
+    milestones: Vec<Milestone>,
+) -> FuelCallResponse<()> {
+    create_escrow_with_panel(
+        amount,
+        vec![arbiter.clone()],
+        asset,
+        assets,
+        vec![buyer],
+        caller,
+        deadline,
+        milestones,
+        1,
+        RESOLUTION_WINDOW,
+    )
+    .await
+}
+
+pub(crate) async fn create_escrow_with_panel(
+    amount: u64,
+    arbiters: Vec<Arbiter>,
+    asset: &ContractId,
+    assets: Vec<Asset>,
+    buyers: Vec<&User>,
+    caller: &User,
+    deadline: u64,
+    milestones: Vec<Milestone>,
+    quorum: u64,
+    resolution_window: u64,
+) -> FuelCallResponse<()> {
+    create_escrow_with_default_party(
+        amount,
+        arbiters,
+        asset,
+        assets,
+        buyers,
+        caller,
+        deadline,
+        Party::Buyers,
+        milestones,
+        quorum,
+        resolution_window,
+    )
+    .await
+}
+
+pub(crate) async fn create_escrow_with_default_party(
+    amount: u64,
+    arbiters: Vec<Arbiter>,
+    asset: &ContractId,
+    assets: Vec<Asset>,
+    buyers: Vec<&User>,
+    caller: &User,
+    deadline: u64,
+    default_party: Party,
+    milestones: Vec<Milestone>,
+    quorum: u64,
+    resolution_window: u64,
 ) -> FuelCallResponse<()> {
     let tx_params = TxParameters::new(None, Some(1_000_000), None);
     let call_params =
@@ -33,10 +116,17 @@ pub(crate) async fn create_escrow(
         .contract
         .methods()
         .create_escrow(
-            arbiter.clone(),
+            arbiters,
             assets,
-            Identity::Address(buyer.wallet.address().into()),
+            buyers
+                .iter()
+                .map(|buyer| Identity::Address(buyer.wallet.address().into()))
+                .collect(),
             deadline,
+            default_party,
+            milestones,
+            quorum,
+            resolution_window,
         )
         .tx_params(tx_params)
         .call_params(call_params)
@@ -78,10 +168,22 @@ pub(crate) async fn dispute(caller: &User, identifier: u64) -> FuelCallResponse<
         .unwrap()
 }
 
+pub(crate) async fn expire_escrow(caller: &User, identifier: u64) -> FuelCallResponse<()> {
+    caller
+        .contract
+        .methods()
+        .expire_escrow(identifier)
+        .append_variable_outputs(5)
+        .call()
+        .await
+        .unwrap()
+}
+
 pub(crate) async fn propose_arbiter(
     arbiter: Arbiter,
     caller: &User,
     identifier: u64,
+    position: u64,
 ) -> FuelCallResponse<()> {
     let tx_params = TxParameters::new(None, Some(1_000_000), None);
     let call_params = CallParameters::new(
@@ -93,7 +195,7 @@ pub(crate) async fn propose_arbiter(
     caller
         .contract
         .methods()
-        .propose_arbiter(arbiter, identifier)
+        .propose_arbiter(arbiter, identifier, position)
         .tx_params(tx_params)
         .call_params(call_params)
         .unwrap()
@@ -117,7 +219,7 @@ pub(crate) async fn resolve_dispute(
             payment_amount,
             Identity::Address(user.wallet.address().into()),
         )
-        .append_variable_outputs(4)
+        .append_variable_outputs(8)
         .call()
         .await
         .unwrap()
@@ -128,7 +230,18 @@ pub(crate) async fn return_deposit(caller: &User, identifier: u64) -> FuelCallRe
         .contract
         .methods()
         .return_deposit(identifier)
-        .append_variable_outputs(3)
+        .append_variable_outputs(5)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn settle_dispute(caller: &User, identifier: u64) -> FuelCallResponse<()> {
+    caller
+        .contract
+        .methods()
+        .settle_dispute(identifier)
+        .append_variable_outputs(8)
         .call()
         .await
         .unwrap()
@@ -161,7 +274,7 @@ pub(crate) async fn withdraw_collateral(caller: &User, identifier: u64) -> FuelC
         .contract
         .methods()
         .withdraw_collateral(identifier)
-        .append_variable_outputs(2)
+        .append_variable_outputs(5)
         .call()
         .await
         .unwrap()
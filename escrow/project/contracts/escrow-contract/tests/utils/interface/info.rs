@@ -1,17 +1,33 @@
-use crate::utils::setup::{Arbiter, Asset, EscrowInfo, User};
+use crate::utils::setup::{Arbiter, Asset, Buyer, EscrowInfo, MilestoneInfo, Role, Ruling, User};
+use fuels::types::Identity;
 
-pub(crate) async fn arbiter_proposal(caller: &User, identifier: u64) -> Option<Arbiter> {
+pub(crate) async fn arbiter_proposal(
+    caller: &User,
+    identifier: u64,
+    position: u64,
+) -> Option<Arbiter> {
     caller
         .contract
         .methods()
-        .arbiter_proposal(identifier)
+        .arbiter_proposal(identifier, position)
         .call()
         .await
         .unwrap()
         .value
 }
 
-pub(crate) async fn assets(caller: &User, identifier: u64) -> Option<Asset> {
+pub(crate) async fn arbiters(caller: &User, identifier: u64, position: u64) -> Option<Arbiter> {
+    caller
+        .contract
+        .methods()
+        .arbiters(identifier, position)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn assets(caller: &User, identifier: u64) -> Vec<Asset> {
     caller
         .contract
         .methods()
@@ -22,6 +38,17 @@ pub(crate) async fn assets(caller: &User, identifier: u64) -> Option<Asset> {
         .value
 }
 
+pub(crate) async fn buyers(caller: &User, identifier: u64, position: u64) -> Option<Buyer> {
+    caller
+        .contract
+        .methods()
+        .buyers(identifier, position)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
 pub(crate) async fn escrows(caller: &User, identifier: u64) -> Option<EscrowInfo> {
     caller
         .contract
@@ -43,3 +70,62 @@ pub(crate) async fn escrow_count(caller: &User) -> u64 {
         .unwrap()
         .value
 }
+
+pub(crate) async fn milestones(
+    caller: &User,
+    identifier: u64,
+    milestone: u64,
+) -> Option<MilestoneInfo> {
+    caller
+        .contract
+        .methods()
+        .milestones(identifier, milestone)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn rulings(caller: &User, identifier: u64, position: u64) -> Option<Ruling> {
+    caller
+        .contract
+        .methods()
+        .rulings(identifier, position)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn user_escrow_count(caller: &User, role: Role, user: &User) -> u64 {
+    caller
+        .contract
+        .methods()
+        .user_escrow_count(role, Identity::Address(user.wallet.address().into()))
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn user_escrows(
+    caller: &User,
+    count: u64,
+    role: Role,
+    start: u64,
+    user: &User,
+) -> Vec<u64> {
+    caller
+        .contract
+        .methods()
+        .user_escrows(
+            count,
+            role,
+            start,
+            Identity::Address(user.wallet.address().into()),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value
+}
@@ -1,7 +1,8 @@
 use fuels::{
     prelude::{
-        abigen, launch_custom_provider_and_get_wallets, Address, AssetId, Configurables, Contract,
-        ContractId, Salt, StorageConfiguration, TxParameters, WalletUnlocked, WalletsConfig,
+        abigen, launch_custom_provider_and_get_wallets, Address, AssetId, Config, Configurables,
+        Contract, ContractId, Salt, StorageConfiguration, TxParameters, WalletUnlocked,
+        WalletsConfig,
     },
     types::Identity,
 };
@@ -23,6 +24,8 @@ const ASSET_CONTRACT_STORAGE_PATH: &str =
 const ESCROW_CONTRACT_BINARY_PATH: &str = "./out/debug/escrow-contract.bin";
 const ESCROW_CONTRACT_STORAGE_PATH: &str = "./out/debug/escrow-contract-storage_slots.json";
 
+pub(crate) const RESOLUTION_WINDOW: u64 = 1;
+
 pub(crate) struct Defaults {
     pub(crate) asset: MyAsset,
     pub(crate) asset_amount: u64,
@@ -73,29 +76,73 @@ pub(crate) async fn create_asset_with_salt(
     (asset_id.clone().into(), MyAsset::new(asset_id, wallet))
 }
 
+pub(crate) async fn create_buyer(user: &User, deposited_amount: u64) -> Buyer {
+    Buyer {
+        address: Identity::Address(user.wallet.address().into()),
+        deposited_amount,
+    }
+}
+
+pub(crate) async fn create_milestone(amount: u64, deadline: Option<u64>) -> Milestone {
+    Milestone { amount, deadline }
+}
+
+pub(crate) async fn create_panel(
+    arbiter_count: u64,
+    default_party: Party,
+    first_arbiter_index: u64,
+    quorum: u64,
+    resolution_window: u64,
+) -> Panel {
+    Panel {
+        arbiter_count,
+        default_party,
+        first_arbiter_index,
+        quorum,
+        resolution_window,
+    }
+}
+
+pub(crate) async fn create_ruling(payment_amount: u64, user: &User) -> Ruling {
+    Ruling {
+        payment_amount,
+        user: Identity::Address(user.wallet.address().into()),
+    }
+}
+
 pub(crate) async fn escrow_info(
-    arbiter: Arbiter,
     asset_count: u64,
-    buyer: &User,
+    buyer_count: u64,
     asset: Option<ContractId>,
     deposited_amount: u64,
+    current_milestone: u64,
     deadline: u64,
     disputed: bool,
     first_asset_index: u64,
+    first_buyer_index: u64,
+    first_milestone_index: u64,
+    milestone_count: u64,
+    panel: Panel,
+    resolution_deadline: u64,
+    ruling_count: u64,
     seller: &User,
     state: bool,
 ) -> EscrowInfo {
     EscrowInfo {
-        arbiter,
+        asset,
         asset_count,
-        buyer: Buyer {
-            address: Identity::Address(Address::from(buyer.wallet.address())),
-            asset,
-            deposited_amount,
-        },
+        buyer_count,
+        current_milestone,
         deadline,
+        deposited_amount,
         disputed,
         first_asset_index,
+        first_buyer_index,
+        first_milestone_index,
+        milestone_count,
+        panel,
+        resolution_deadline,
+        ruling_count,
         seller: Seller {
             address: Identity::Address(Address::from(seller.wallet.address())),
         },
@@ -116,8 +163,23 @@ pub(crate) async fn mint(user: &User, amount: u64, contract: &MyAsset) {
         .unwrap();
 }
 
+pub(crate) async fn produce_blocks(user: &User, blocks: u64) {
+    user.wallet
+        .get_provider()
+        .unwrap()
+        .produce_blocks(blocks, None)
+        .await
+        .unwrap();
+}
+
 pub(crate) async fn setup() -> (User, User, User, Defaults) {
-    let number_of_wallets = 4;
+    let (mut arbiters, buyer, seller, defaults) = setup_with_arbiters(1).await;
+
+    (arbiters.pop().unwrap(), buyer, seller, defaults)
+}
+
+pub(crate) async fn setup_with_arbiters(arbiter_count: u64) -> (Vec<User>, User, User, Defaults) {
+    let number_of_wallets = 3 + arbiter_count;
     let coins_per_wallet = 1;
     let amount_per_coin = 1_000_000;
 
@@ -127,10 +189,15 @@ pub(crate) async fn setup() -> (User, User, User, Defaults) {
         Some(amount_per_coin),
     );
 
-    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None).await;
+    let provider_config = Config {
+        manual_blocks_enabled: true, // Necessary so the `produce_blocks` API can be used locally
+        ..Config::local_node()
+    };
+
+    let mut wallets =
+        launch_custom_provider_and_get_wallets(config, Some(provider_config), None).await;
 
     let deployer_wallet = wallets.pop().unwrap();
-    let arbiter_wallet = wallets.pop().unwrap();
     let buyer_wallet = wallets.pop().unwrap();
     let seller_wallet = wallets.pop().unwrap();
 
@@ -154,10 +221,13 @@ pub(crate) async fn setup() -> (User, User, User, Defaults) {
 
     let asset = MyAsset::new(asset_id.clone(), deployer_wallet);
 
-    let arbiter = User {
-        contract: Escrow::new(escrow_id.clone(), arbiter_wallet.clone()),
-        wallet: arbiter_wallet,
-    };
+    let arbiters = wallets
+        .into_iter()
+        .map(|wallet| User {
+            contract: Escrow::new(escrow_id.clone(), wallet.clone()),
+            wallet,
+        })
+        .collect();
 
     let buyer = User {
         contract: Escrow::new(escrow_id.clone(), buyer_wallet.clone()),
@@ -176,5 +246,5 @@ pub(crate) async fn setup() -> (User, User, User, Defaults) {
         deadline: 100,
     };
 
-    (arbiter, buyer, seller, defaults)
+    (arbiters, buyer, seller, defaults)
 }
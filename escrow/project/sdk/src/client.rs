@@ -0,0 +1,171 @@
+use crate::interface::{Arbiter, Asset, Buyer, Escrow, EscrowInfo, MilestoneInfo, Role, Ruling};
+use fuels::{
+    prelude::{Bech32ContractId, ContractId, WalletUnlocked},
+    types::{errors::Error, Identity},
+};
+
+// Number of escrow identifiers requested from the contract at a time
+const PAGE_SIZE: u64 = 50;
+
+/// Everything the contract stores about an escrow
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscrowView {
+    /// Arbiters on the panel in order of their position
+    pub arbiters: Vec<Arbiter>,
+    /// Arbiter proposed by the seller for each position on the panel
+    pub arbiter_proposals: Vec<Option<Arbiter>>,
+    /// Assets that the escrow accepts
+    pub assets: Vec<Asset>,
+    /// Buyers of the escrow along with their deposits
+    pub buyers: Vec<Buyer>,
+    /// Identifier of the escrow
+    pub identifier: u64,
+    /// Counts, indexes and state of the escrow
+    pub info: EscrowInfo,
+    /// Milestones the deposit is released in
+    pub milestones: Vec<MilestoneInfo>,
+    /// Ruling of each position on the panel on the current dispute
+    pub rulings: Vec<Option<Ruling>>,
+}
+
+/// Client of the escrow contract that reads escrows without sending transactions
+pub struct EscrowClient {
+    // Identifier of the escrow contract
+    id: ContractId,
+    // Bindings of the escrow contract called by the wallet
+    instance: Escrow,
+}
+
+impl EscrowClient {
+    pub fn new(id: ContractId, wallet: WalletUnlocked) -> Self {
+        Self {
+            id,
+            instance: Escrow::new(Bech32ContractId::from(id), wallet),
+        }
+    }
+
+    pub fn id(&self) -> ContractId {
+        self.id
+    }
+
+    pub fn instance(&self) -> &Escrow {
+        &self.instance
+    }
+
+    /// Every getter of the escrow with `identifier` assembled into a single view, `None` if the
+    /// escrow does not exist
+    pub async fn escrow(&self, identifier: u64) -> Result<Option<EscrowView>, Error> {
+        let methods = self.instance.methods();
+
+        let info = match methods.escrows(identifier).simulate().await?.value {
+            Some(info) => info,
+            None => return Ok(None),
+        };
+
+        let mut arbiters = Vec::with_capacity(info.panel.arbiter_count as usize);
+        let mut arbiter_proposals = Vec::with_capacity(info.panel.arbiter_count as usize);
+        let mut rulings = Vec::with_capacity(info.panel.arbiter_count as usize);
+        for position in 0..info.panel.arbiter_count {
+            if let Some(arbiter) = methods
+                .arbiters(identifier, position)
+                .simulate()
+                .await?
+                .value
+            {
+                arbiters.push(arbiter);
+            }
+            arbiter_proposals.push(
+                methods
+                    .arbiter_proposal(identifier, position)
+                    .simulate()
+                    .await?
+                    .value,
+            );
+            rulings.push(
+                methods
+                    .rulings(identifier, position)
+                    .simulate()
+                    .await?
+                    .value,
+            );
+        }
+
+        let mut buyers = Vec::with_capacity(info.buyer_count as usize);
+        for position in 0..info.buyer_count {
+            if let Some(buyer) = methods.buyers(identifier, position).simulate().await?.value {
+                buyers.push(buyer);
+            }
+        }
+
+        // Escrows created without milestones only have one once the buyers have deposited
+        let mut milestones = Vec::with_capacity(info.milestone_count as usize);
+        for milestone in 0..info.milestone_count {
+            if let Some(milestone) = methods
+                .milestones(identifier, milestone)
+                .simulate()
+                .await?
+                .value
+            {
+                milestones.push(milestone);
+            }
+        }
+
+        Ok(Some(EscrowView {
+            arbiters,
+            arbiter_proposals,
+            assets: methods.assets(identifier).simulate().await?.value,
+            buyers,
+            identifier,
+            info,
+            milestones,
+            rulings,
+        }))
+    }
+
+    /// Identifiers of every escrow in which `user` has had `role`, in the order they took it on
+    pub async fn user_escrows(&self, role: Role, user: Identity) -> Result<Vec<u64>, Error> {
+        let methods = self.instance.methods();
+        let user_escrow_count = methods
+            .user_escrow_count(role.clone(), user.clone())
+            .simulate()
+            .await?
+            .value;
+
+        let mut identifiers = Vec::with_capacity(user_escrow_count as usize);
+        while (identifiers.len() as u64) < user_escrow_count {
+            let page = methods
+                .user_escrows(
+                    PAGE_SIZE,
+                    role.clone(),
+                    identifiers.len() as u64,
+                    user.clone(),
+                )
+                .simulate()
+                .await?
+                .value;
+
+            if page.is_empty() {
+                break;
+            }
+            identifiers.extend(page);
+        }
+
+        Ok(identifiers)
+    }
+
+    /// Views of every escrow in which `user` has had `role`
+    pub async fn user_escrow_views(
+        &self,
+        role: Role,
+        user: Identity,
+    ) -> Result<Vec<EscrowView>, Error> {
+        let mut views = vec![];
+        for identifier in self.user_escrows(role, user).await? {
+            if let Some(view) = self.escrow(identifier).await? {
+                views.push(view);
+            }
+        }
+
+        Ok(views)
+    }
+}
@@ -0,0 +1,6 @@
+use fuels::prelude::abigen;
+
+abigen!(Contract(
+    name = "Escrow",
+    abi = "./contracts/escrow-contract/out/debug/escrow-contract-abi.json"
+));
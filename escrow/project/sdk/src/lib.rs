@@ -0,0 +1,7 @@
+//! Client for reading the escrows of the escrow contract
+//!
+//! The state of an escrow is spread over several getters of the `Info` ABI, [`client::EscrowClient`]
+//! assembles it into a single [`client::EscrowView`] and pages through the escrows of a user.
+
+pub mod client;
+pub mod interface;
@@ -0,0 +1,92 @@
+use crate::utils::{create_escrow, identity, setup, ARBITER_FEE, DEADLINE};
+use escrow_sdk::interface::{Asset, Buyer, Role};
+use fuels::prelude::{ContractId, BASE_ASSET_ID};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn assembles_escrow_view() {
+        let (client, users) = setup().await;
+        let assets = vec![
+            Asset {
+                amount: 100,
+                id: ContractId::from(*BASE_ASSET_ID),
+            },
+            Asset {
+                amount: 200,
+                id: ContractId::from([1u8; 32]),
+            },
+        ];
+
+        create_escrow(&client, &users, assets.clone()).await;
+
+        let view = client.escrow(0).await.unwrap().unwrap();
+
+        assert_eq!(view.identifier, 0);
+        assert_eq!(view.info.deadline, DEADLINE);
+        assert_eq!(view.assets, assets);
+        assert_eq!(
+            view.buyers,
+            vec![Buyer {
+                address: identity(&users.buyer),
+                deposited_amount: 0,
+            }]
+        );
+        assert_eq!(view.arbiters.len(), 1);
+        assert_eq!(view.arbiters[0].address, identity(&users.arbiter));
+        assert_eq!(view.arbiters[0].fee_amount, ARBITER_FEE);
+        assert_eq!(view.arbiter_proposals, vec![None]);
+        assert!(view.milestones.is_empty());
+        assert_eq!(view.rulings, vec![None]);
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_missing_escrow() {
+        let (client, _users) = setup().await;
+
+        assert!(client.escrow(0).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn lists_escrows_of_each_role() {
+        let (client, users) = setup().await;
+        let asset = Asset {
+            amount: 100,
+            id: ContractId::from(*BASE_ASSET_ID),
+        };
+
+        create_escrow(&client, &users, vec![asset.clone()]).await;
+        create_escrow(&client, &users, vec![asset]).await;
+
+        let seller = identity(&users.seller);
+        let buyer = identity(&users.buyer);
+
+        assert_eq!(
+            client
+                .user_escrows(Role::Seller, seller.clone())
+                .await
+                .unwrap(),
+            vec![0, 1]
+        );
+        assert_eq!(
+            client.user_escrows(Role::Buyer, buyer).await.unwrap(),
+            vec![0, 1]
+        );
+        assert!(client
+            .user_escrows(Role::Buyer, seller.clone())
+            .await
+            .unwrap()
+            .is_empty());
+
+        let views = client
+            .user_escrow_views(Role::Seller, seller)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            views.iter().map(|view| view.identifier).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+    }
+}
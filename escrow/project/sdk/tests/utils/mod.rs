@@ -0,0 +1,87 @@
+use escrow_sdk::{
+    client::EscrowClient,
+    interface::{Arbiter, Asset, Party},
+};
+use fuels::{
+    prelude::{
+        launch_custom_provider_and_get_wallets, CallParameters, Contract, ContractId,
+        StorageConfiguration, TxParameters, WalletUnlocked, WalletsConfig, BASE_ASSET_ID,
+    },
+    types::Identity,
+};
+
+const ESCROW_CONTRACT_BINARY_PATH: &str =
+    "../contracts/escrow-contract/out/debug/escrow-contract.bin";
+const ESCROW_CONTRACT_STORAGE_PATH: &str =
+    "../contracts/escrow-contract/out/debug/escrow-contract-storage_slots.json";
+
+pub const ARBITER_FEE: u64 = 10;
+pub const DEADLINE: u64 = 100;
+
+pub struct Users {
+    pub arbiter: WalletUnlocked,
+    pub buyer: WalletUnlocked,
+    pub seller: WalletUnlocked,
+}
+
+pub fn identity(wallet: &WalletUnlocked) -> Identity {
+    Identity::Address(wallet.address().into())
+}
+
+pub async fn setup() -> (EscrowClient, Users) {
+    let config = WalletsConfig::new(Some(3), Some(1), Some(1_000_000));
+    let mut wallets = launch_custom_provider_and_get_wallets(config, None, None).await;
+
+    let arbiter = wallets.pop().unwrap();
+    let buyer = wallets.pop().unwrap();
+    let seller = wallets.pop().unwrap();
+
+    let id = Contract::deploy(
+        ESCROW_CONTRACT_BINARY_PATH,
+        &seller,
+        TxParameters::default(),
+        StorageConfiguration::with_storage_path(Some(ESCROW_CONTRACT_STORAGE_PATH.to_string())),
+    )
+    .await
+    .unwrap();
+
+    let client = EscrowClient::new(id.into(), seller.clone());
+
+    (
+        client,
+        Users {
+            arbiter,
+            buyer,
+            seller,
+        },
+    )
+}
+
+// Creates an escrow of the seller in the base asset, paying the arbiter fee in the base asset
+pub async fn create_escrow(client: &EscrowClient, users: &Users, assets: Vec<Asset>) {
+    let arbiter = Arbiter {
+        address: identity(&users.arbiter),
+        asset: ContractId::from(*BASE_ASSET_ID),
+        fee_amount: ARBITER_FEE,
+    };
+    let call_params = CallParameters::new(Some(ARBITER_FEE), Some(BASE_ASSET_ID), None);
+
+    client
+        .instance()
+        .methods()
+        .create_escrow(
+            vec![arbiter],
+            assets,
+            vec![identity(&users.buyer)],
+            DEADLINE,
+            Party::Buyers,
+            vec![],
+            1,
+            1,
+        )
+        .call_params(call_params)
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+}
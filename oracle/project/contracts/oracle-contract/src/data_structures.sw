@@ -1,6 +1,7 @@
 library data_structures;
 
 use core::ops::Eq;
+use std::block::height;
 
 pub enum State {
     NotInitialized: (),
@@ -16,3 +17,34 @@ impl Eq for State {
         }
     }
 }
+
+pub struct Round {
+    /// Identifier of the round, starting at 1 and incremented with every update of the asset
+    id: u64,
+    /// Price of the asset recorded in the round
+    price: u64,
+    /// Timestamp of the oldest submission the price of the round was aggregated from
+    timestamp: u64,
+    /// Height of the block in which the round was recorded
+    height: u64,
+}
+
+impl Round {
+    pub fn new(id: u64, price: u64, timestamp: u64) -> Self {
+        Self {
+            id,
+            price,
+            timestamp,
+            height: height(),
+        }
+    }
+}
+
+pub struct Submission {
+    /// Price submitted by the reporter
+    price: u64,
+    /// Reporter that submitted the price
+    reporter: Identity,
+    /// Timestamp of the block in which the price was submitted
+    timestamp: u64,
+}
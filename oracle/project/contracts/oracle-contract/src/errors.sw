@@ -2,4 +2,21 @@ library errors;
 
 pub enum AccessError {
     NotOwner: (),
+    NotReporter: (),
+}
+
+pub enum PriceError {
+    PriceNotSet: (),
+    StalePrice: (),
+}
+
+pub enum ReporterError {
+    AlreadySubmitted: (),
+    ReporterAlreadyAdded: (),
+    ReporterNotFound: (),
+}
+
+pub enum ThresholdError {
+    ThresholdCannotBeZero: (),
+    ThresholdExceedsReporters: (),
 }
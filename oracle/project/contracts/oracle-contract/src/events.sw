@@ -1,6 +1,43 @@
 library events;
 
-pub struct PriceUpdateEvent {
-    /// Updated price
+dep data_structures;
+
+use data_structures::Round;
+
+pub struct PriceSubmittedEvent {
+    /// Asset whose price has been submitted
+    asset: ContractId,
+    /// Submitted price
     price: u64,
+    /// Reporter that submitted the price
+    reporter: Identity,
+    /// Identifier of the round the price has been submitted for
+    round: u64,
+}
+
+pub struct PriceUpdateEvent {
+    /// Asset whose price has been updated
+    asset: ContractId,
+    /// Round recorded for the updated price
+    round: Round,
+}
+
+pub struct ReporterAddedEvent {
+    /// Reporter that has been authorized to submit prices
+    reporter: Identity,
+}
+
+pub struct ReporterRemovedEvent {
+    /// Reporter that is no longer authorized to submit prices
+    reporter: Identity,
+}
+
+pub struct SubmissionWindowUpdatedEvent {
+    /// Number of seconds a submitted price counts towards the pending round
+    submission_window: u64,
+}
+
+pub struct ThresholdUpdatedEvent {
+    /// Number of submissions required to finalize a round
+    threshold: u64,
 }
@@ -1,24 +1,166 @@
 library interface;
 
+dep data_structures;
+
+use data_structures::Round;
+
 abi Oracle {
-    /// Return the owner (node) of the oracle
+    /// Authorizes a reporter to submit prices
+    ///
+    /// # Arguments
+    ///
+    /// - `reporter` - Identity of the new reporter
+    ///
+    /// # Reverts
+    ///
+    /// * When the message sender is not the owner
+    /// * When the reporter has already been added
+    #[storage(read, write)]
+    fn add_reporter(reporter: Identity);
+
+    /// Return whether a reporter has submitted a price that still counts towards the pending round of an asset
+    ///
+    /// A submission stops counting once it is older than the submission window or its reporter has been removed
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - Identifier of the tracked asset
+    /// - `reporter` - Identity of the reporter
+    #[storage(read)]
+    fn has_submitted(asset: ContractId, reporter: Identity) -> bool;
+
+    /// Return whether an identity is authorized to submit prices
+    ///
+    /// # Arguments
+    ///
+    /// - `reporter` - Identity to check
+    #[storage(read)]
+    fn is_reporter(reporter: Identity) -> bool;
+
+    /// Return the latest round recorded for an asset or None if the price has never been set
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - Identifier of the tracked asset
+    #[storage(read)]
+    fn latest_round(asset: ContractId) -> Option<Round>;
+
+    /// Return the owner of the oracle who manages the reporters
     ///
     /// The owner is initialized to the first deterministically generated wallet using the SDK in Forc.toml
     fn owner() -> Identity;
 
-    /// Return price of asset
+    /// Return the price of an asset or None if the price has never been set
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - Identifier of the tracked asset
+    #[storage(read)]
+    fn price(asset: ContractId) -> Option<u64>;
+
+    /// Return the price of an asset provided it has been updated within the last `max_age` seconds
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - Identifier of the tracked asset
+    /// - `max_age` - Maximum number of seconds since the price was last updated
+    ///
+    /// # Reverts
+    ///
+    /// * When the price of the asset has never been set
+    /// * When the price of the asset was last updated more than `max_age` seconds ago
     #[storage(read)]
-    fn price() -> u64;
+    fn price_no_older_than(asset: ContractId, max_age: u64) -> u64;
 
-    /// Changes the price in storage to the value of `price`
+    /// Revokes the authorization of a reporter to submit prices
     ///
     /// # Arguments
     ///
+    /// - `reporter` - Identity of the reporter
+    ///
+    /// # Reverts
+    ///
+    /// * When the message sender is not the owner
+    /// * When the identity is not a reporter
+    /// * When fewer reporters than the threshold would remain
+    #[storage(read, write)]
+    fn remove_reporter(reporter: Identity);
+
+    /// Return the number of authorized reporters
+    #[storage(read)]
+    fn reporter_count() -> u64;
+
+    /// Return a round recorded for an asset or None if the round does not exist
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - Identifier of the tracked asset
+    /// - `id` - Identifier of the round
+    #[storage(read)]
+    fn round(asset: ContractId, id: u64) -> Option<Round>;
+
+    /// Submits the value of `price` for the pending round of `asset`
+    ///
+    /// Once the threshold of submissions that still count is reached the median of their prices is recorded as a new round
+    /// stamped with the time of the oldest of them
+    /// A reporter whose previous submission has expired replaces it
+    ///
+    /// # Arguments
+    ///
+    /// - `asset` - Identifier of the tracked asset
     /// - `price` - New price of tracked asset
     ///
     /// # Reverts
     ///
+    /// * When the message sender is not a reporter
+    /// * When the reporter has already submitted a price that still counts towards the pending round
+    #[storage(read, write)]
+    fn set_price(asset: ContractId, price: u64);
+
+    /// Submits prices for the pending rounds of several assets in a single call
+    ///
+    /// # Arguments
+    ///
+    /// - `prices` - Pairs of tracked asset identifiers and their new prices
+    ///
+    /// # Reverts
+    ///
+    /// * When the message sender is not a reporter
+    /// * When the reporter has already submitted a price that still counts towards the pending round of an asset
+    #[storage(read, write)]
+    fn set_prices(prices: Vec<(ContractId, u64)>);
+
+    /// Changes the number of seconds a submitted price counts towards the pending round
+    ///
+    /// # Arguments
+    ///
+    /// - `submission_window` - Number of seconds
+    ///
+    /// # Reverts
+    ///
     /// * When the message sender is not the owner
-    #[storage(write)]
-    fn set_price(price: u64);
+    #[storage(read, write)]
+    fn set_submission_window(submission_window: u64);
+
+    /// Changes the number of submissions required to record a new round
+    ///
+    /// # Arguments
+    ///
+    /// - `threshold` - Number of submissions
+    ///
+    /// # Reverts
+    ///
+    /// * When the message sender is not the owner
+    /// * When the threshold is zero
+    /// * When the threshold is greater than the number of reporters
+    #[storage(read, write)]
+    fn set_threshold(threshold: u64);
+
+    /// Return the number of seconds a submitted price counts towards the pending round
+    #[storage(read)]
+    fn submission_window() -> u64;
+
+    /// Return the number of submissions required to record a new round
+    #[storage(read)]
+    fn threshold() -> u64;
 }
@@ -4,37 +4,266 @@ dep data_structures;
 dep errors;
 dep events;
 dep interface;
+dep utils;
 
-use std::auth::msg_sender;
+use std::{auth::msg_sender, block::timestamp};
 
-use data_structures::State;
-use errors::AccessError;
-use events::PriceUpdateEvent;
+use data_structures::{Round, State, Submission};
+use errors::{AccessError, PriceError, ReporterError, ThresholdError};
+use events::{
+    PriceSubmittedEvent,
+    PriceUpdateEvent,
+    ReporterAddedEvent,
+    ReporterRemovedEvent,
+    SubmissionWindowUpdatedEvent,
+    ThresholdUpdatedEvent,
+};
 use interface::Oracle;
+use utils::median;
 
 storage {
-    // Current price of tracked asset
-    // TODO use option when https://github.com/FuelLabs/fuels-rs/issues/415 is fixed
-    price: u64 = 0,
+    /// Identifier of the latest round of each tracked asset
+    /// Map(asset => round id)
+    latest_round_ids: StorageMap<ContractId, u64> = StorageMap {},
+    /// Number of authorized reporters
+    reporter_count: u64 = 0,
+    /// Identities authorized to submit prices
+    /// Map(reporter => authorized)
+    reporters: StorageMap<Identity, bool> = StorageMap {},
+    /// Every round recorded for the tracked assets
+    /// Map((asset, round id) => round)
+    rounds: StorageMap<(ContractId, u64), Round> = StorageMap {},
+    /// Number of reporters that have submitted a price for a pending round
+    /// Map((asset, round id) => count)
+    submission_count: StorageMap<(ContractId, u64), u64> = StorageMap {},
+    /// Position of the submission of each reporter for a pending round
+    /// Map((asset, round id, reporter) => index)
+    submission_indexes: StorageMap<(ContractId, u64, Identity), u64> = StorageMap {},
+    /// Number of seconds a submitted price counts towards the pending round
+    submission_window: u64 = 3600,
+    /// Latest price submitted by each reporter for a pending round in order of their first submission
+    /// Map((asset, round id, index) => submission)
+    submissions: StorageMap<(ContractId, u64, u64), Submission> = StorageMap {},
+    /// Number of submissions required to record a new round
+    threshold: u64 = 1,
 }
 
 // TODO treat owner as an identity once https://github.com/FuelLabs/sway/issues/2647 is fixed
 impl Oracle for Contract {
+    #[storage(read, write)]
+    fn add_reporter(reporter: Identity) {
+        require(msg_sender().unwrap() == Identity::Address(Address::from(OWNER)), AccessError::NotOwner);
+        require(!is_authorized(reporter), ReporterError::ReporterAlreadyAdded);
+
+        storage.reporters.insert(reporter, true);
+        storage.reporter_count += 1;
+
+        log(ReporterAddedEvent { reporter });
+    }
+
+    #[storage(read)]
+    fn has_submitted(asset: ContractId, reporter: Identity) -> bool {
+        let round_id = storage.latest_round_ids.get(asset).unwrap_or(0) + 1;
+        match storage.submission_indexes.get((asset, round_id, reporter)) {
+            Option::Some(index) => counts(storage.submissions.get((asset, round_id, index)).unwrap()),
+            Option::None => false,
+        }
+    }
+
+    #[storage(read)]
+    fn is_reporter(reporter: Identity) -> bool {
+        is_authorized(reporter)
+    }
+
+    #[storage(read)]
+    fn latest_round(asset: ContractId) -> Option<Round> {
+        find_latest_round(asset)
+    }
+
     fn owner() -> Identity {
         Identity::Address(Address::from(OWNER))
     }
 
     #[storage(read)]
-    fn price() -> u64 {
-        storage.price
+    fn price(asset: ContractId) -> Option<u64> {
+        match find_latest_round(asset) {
+            Option::Some(round) => Option::Some(round.price),
+            Option::None => Option::None,
+        }
+    }
+
+    #[storage(read)]
+    fn price_no_older_than(asset: ContractId, max_age: u64) -> u64 {
+        let round = find_latest_round(asset);
+
+        require(round.is_some(), PriceError::PriceNotSet);
+
+        let round = round.unwrap();
+
+        require(timestamp() - round.timestamp <= max_age, PriceError::StalePrice);
+
+        round.price
+    }
+
+    #[storage(read, write)]
+    fn remove_reporter(reporter: Identity) {
+        require(msg_sender().unwrap() == Identity::Address(Address::from(OWNER)), AccessError::NotOwner);
+        require(is_authorized(reporter), ReporterError::ReporterNotFound);
+        require(storage.threshold < storage.reporter_count, ThresholdError::ThresholdExceedsReporters);
+
+        storage.reporters.insert(reporter, false);
+        storage.reporter_count -= 1;
+
+        log(ReporterRemovedEvent { reporter });
+    }
+
+    #[storage(read)]
+    fn reporter_count() -> u64 {
+        storage.reporter_count
+    }
+
+    #[storage(read)]
+    fn round(asset: ContractId, id: u64) -> Option<Round> {
+        storage.rounds.get((asset, id))
+    }
+
+    #[storage(read, write)]
+    fn set_price(asset: ContractId, price: u64) {
+        let reporter = msg_sender().unwrap();
+        require(is_authorized(reporter), AccessError::NotReporter);
+
+        submit_price(asset, price, reporter);
+    }
+
+    #[storage(read, write)]
+    fn set_prices(prices: Vec<(ContractId, u64)>) {
+        let reporter = msg_sender().unwrap();
+        require(is_authorized(reporter), AccessError::NotReporter);
+
+        let mut index = 0;
+        while index < prices.len() {
+            let (asset, price) = prices.get(index).unwrap();
+
+            submit_price(asset, price, reporter);
+
+            index += 1;
+        }
+    }
+
+    #[storage(read, write)]
+    fn set_submission_window(submission_window: u64) {
+        require(msg_sender().unwrap() == Identity::Address(Address::from(OWNER)), AccessError::NotOwner);
+
+        storage.submission_window = submission_window;
+
+        log(SubmissionWindowUpdatedEvent { submission_window });
     }
 
-    #[storage(write)]
-    fn set_price(price: u64) {
+    #[storage(read, write)]
+    fn set_threshold(threshold: u64) {
         require(msg_sender().unwrap() == Identity::Address(Address::from(OWNER)), AccessError::NotOwner);
+        require(0 < threshold, ThresholdError::ThresholdCannotBeZero);
+        require(threshold <= storage.reporter_count, ThresholdError::ThresholdExceedsReporters);
+
+        storage.threshold = threshold;
+
+        log(ThresholdUpdatedEvent { threshold });
+    }
+
+    #[storage(read)]
+    fn submission_window() -> u64 {
+        storage.submission_window
+    }
+
+    #[storage(read)]
+    fn threshold() -> u64 {
+        storage.threshold
+    }
+}
+
+/// Whether a submission still counts towards the pending round, i.e. its reporter is authorized and it is within the submission window
+#[storage(read)]
+fn counts(submission: Submission) -> bool {
+    is_authorized(submission.reporter) && timestamp() - submission.timestamp <= storage.submission_window
+}
 
-        storage.price = price;
+#[storage(read)]
+fn find_latest_round(asset: ContractId) -> Option<Round> {
+    match storage.latest_round_ids.get(asset) {
+        Option::Some(id) => storage.rounds.get((asset, id)),
+        Option::None => Option::None,
+    }
+}
+
+#[storage(read)]
+fn is_authorized(reporter: Identity) -> bool {
+    storage.reporters.get(reporter).unwrap_or(false)
+}
+
+#[storage(read, write)]
+fn record_round(asset: ContractId, price: u64, timestamp: u64) {
+    let id = storage.latest_round_ids.get(asset).unwrap_or(0) + 1;
+    let round = Round::new(id, price, timestamp);
+
+    storage.rounds.insert((asset, id), round);
+    storage.latest_round_ids.insert(asset, id);
+
+    log(PriceUpdateEvent { asset, round });
+}
+
+#[storage(read, write)]
+fn submit_price(asset: ContractId, price: u64, reporter: Identity) {
+    let round_id = storage.latest_round_ids.get(asset).unwrap_or(0) + 1;
+    let submission = Submission {
+        price,
+        reporter,
+        timestamp: timestamp(),
+    };
+
+    // A reporter may submit again once their previous submission no longer counts, replacing it
+    match storage.submission_indexes.get((asset, round_id, reporter)) {
+        Option::Some(index) => {
+            require(!counts(storage.submissions.get((asset, round_id, index)).unwrap()), ReporterError::AlreadySubmitted);
+
+            storage.submissions.insert((asset, round_id, index), submission);
+        },
+        Option::None => {
+            let index = storage.submission_count.get((asset, round_id)).unwrap_or(0);
+
+            storage.submissions.insert((asset, round_id, index), submission);
+            storage.submission_indexes.insert((asset, round_id, reporter), index);
+            storage.submission_count.insert((asset, round_id), index + 1);
+        },
+    }
+
+    log(PriceSubmittedEvent {
+        asset,
+        price,
+        reporter,
+        round: round_id,
+    });
+
+    // Expired submissions and those of removed reporters are left out of the median and of the round time
+    let submission_count = storage.submission_count.get((asset, round_id)).unwrap();
+    let mut prices = Vec::new();
+    let mut oldest_timestamp = submission.timestamp;
+    let mut index = 0;
+    while index < submission_count {
+        let pending = storage.submissions.get((asset, round_id, index)).unwrap();
+
+        if counts(pending) {
+            prices.push(pending.price);
+
+            if pending.timestamp < oldest_timestamp {
+                oldest_timestamp = pending.timestamp;
+            }
+        }
+
+        index += 1;
+    }
 
-        log(PriceUpdateEvent { price });
+    // Record the round with the median of the submitted prices once enough reporters agree
+    if storage.threshold <= prices.len() {
+        record_round(asset, median(prices), oldest_timestamp);
     }
 }
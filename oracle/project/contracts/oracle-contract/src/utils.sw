@@ -0,0 +1,56 @@
+library utils;
+
+/// Returns the median of `values`, averaging the two middle values for an even number of values
+///
+/// # Arguments
+///
+/// - `values` - Non-empty list of values
+pub fn median(values: Vec<u64>) -> u64 {
+    let middle = values.len() / 2;
+
+    if values.len() % 2 == 1 {
+        nth_smallest(values, middle)
+    } else {
+        let lower = nth_smallest(values, middle - 1);
+        let upper = nth_smallest(values, middle);
+        lower + (upper - lower) / 2
+    }
+}
+
+/// Returns the value at position `rank` if `values` were sorted in ascending order
+///
+/// The list of submissions is small therefore counting is preferred over sorting a copy in memory
+///
+/// # Arguments
+///
+/// - `values` - Non-empty list of values
+/// - `rank` - Zero based position in the sorted values
+fn nth_smallest(values: Vec<u64>, rank: u64) -> u64 {
+    let mut result = 0;
+    let mut index = 0;
+    while index < values.len() {
+        let candidate = values.get(index).unwrap();
+
+        let mut smaller = 0;
+        let mut equal = 0;
+        let mut other = 0;
+        while other < values.len() {
+            let value = values.get(other).unwrap();
+            if value < candidate {
+                smaller += 1;
+            } else if value == candidate {
+                equal += 1;
+            }
+            other += 1;
+        }
+
+        if smaller <= rank && rank < smaller + equal {
+            result = candidate;
+            break;
+        }
+
+        index += 1;
+    }
+
+    result
+}
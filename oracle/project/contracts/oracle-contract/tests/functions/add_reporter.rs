@@ -0,0 +1,54 @@
+use utils::{
+    abi_calls::{add_reporter, is_reporter, reporter_count},
+    test_helpers::{identity, setup},
+};
+
+mod success {
+    use super::*;
+    use utils::ReporterAddedEvent;
+
+    #[tokio::test]
+    async fn adds_reporter() {
+        let (user, wallets) = setup().await;
+        let reporter = identity(&wallets[1]);
+
+        let response = add_reporter(&user.oracle, reporter.clone()).await;
+
+        let log = response.get_logs_with_type::<ReporterAddedEvent>().unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ReporterAddedEvent {
+                reporter: reporter.clone()
+            }
+        );
+        assert!(is_reporter(&user.oracle, reporter).await);
+        assert_eq!(reporter_count(&user.oracle).await, 2);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (user, wallets) = setup().await;
+        user.oracle
+            .with_wallet(wallets[1].clone())
+            .unwrap()
+            .methods()
+            .add_reporter(identity(&wallets[1]))
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ReporterAlreadyAdded")]
+    async fn when_reporter_already_added() {
+        let (user, wallets) = setup().await;
+        add_reporter(&user.oracle, identity(&wallets[0])).await;
+    }
+}
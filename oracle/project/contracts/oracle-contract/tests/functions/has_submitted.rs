@@ -0,0 +1,48 @@
+use fuels::prelude::ContractId;
+use std::time::Duration;
+use utils::{
+    abi_calls::{has_submitted, set_price, set_submission_window},
+    test_helpers::{identity, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn tracks_submissions_for_pending_round() {
+        let (user, wallets) = setup_with_reporters(2, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+        let (reporter_1, reporter_2) = (identity(&wallets[0]), identity(&wallets[1]));
+
+        assert!(!has_submitted(&user.oracle, asset, reporter_1.clone()).await);
+
+        set_price(&user.oracle, asset, 1000).await;
+
+        assert!(has_submitted(&user.oracle, asset, reporter_1.clone()).await);
+        assert!(!has_submitted(&user.oracle, asset, reporter_2.clone()).await);
+
+        // Once the round is recorded the reporters may submit for the next round
+        let oracle = user.oracle.with_wallet(wallets[1].clone()).unwrap();
+        set_price(&oracle, asset, 1000).await;
+
+        assert!(!has_submitted(&user.oracle, asset, reporter_1).await);
+        assert!(!has_submitted(&user.oracle, asset, reporter_2).await);
+    }
+
+    #[tokio::test]
+    async fn ignores_expired_submissions() {
+        let (user, wallets) = setup_with_reporters(2, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+        let reporter = identity(&wallets[0]);
+
+        set_submission_window(&user.oracle, 1).await;
+        set_price(&user.oracle, asset, 1000).await;
+
+        assert!(has_submitted(&user.oracle, asset, reporter.clone()).await);
+
+        // Let the submission fall out of the submission window
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        assert!(!has_submitted(&user.oracle, asset, reporter).await);
+    }
+}
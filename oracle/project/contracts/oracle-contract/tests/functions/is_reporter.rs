@@ -0,0 +1,20 @@
+use utils::{
+    abi_calls::is_reporter,
+    test_helpers::{identity, setup},
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_is_reporter_after_setup() {
+        let (user, wallets) = setup().await;
+        assert!(is_reporter(&user.oracle, identity(&wallets[0])).await);
+    }
+
+    #[tokio::test]
+    async fn other_wallet_is_not_reporter() {
+        let (user, wallets) = setup().await;
+        assert!(!is_reporter(&user.oracle, identity(&wallets[1])).await);
+    }
+}
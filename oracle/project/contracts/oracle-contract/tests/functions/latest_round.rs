@@ -0,0 +1,34 @@
+use fuels::prelude::ContractId;
+use utils::{
+    abi_calls::{latest_round, set_price},
+    test_helpers::setup,
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn can_get_latest_round() {
+        let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+        let first_round = latest_round(&user.oracle, asset).await.unwrap();
+        set_price(&user.oracle, asset, 2000).await;
+        let second_round = latest_round(&user.oracle, asset).await.unwrap();
+
+        assert_eq!(first_round.id, 1);
+        assert_eq!(first_round.price, 1000);
+        assert_eq!(second_round.id, 2);
+        assert_eq!(second_round.price, 2000);
+        assert!(second_round.height > first_round.height);
+        assert!(second_round.timestamp >= first_round.timestamp);
+    }
+
+    #[tokio::test]
+    async fn can_get_latest_round_when_not_initialized() {
+        let (user, _) = setup().await;
+        let round = latest_round(&user.oracle, ContractId::from([1u8; 32])).await;
+        assert_eq!(round, None);
+    }
+}
@@ -1,3 +1,16 @@
+mod add_reporter;
+mod has_submitted;
+mod is_reporter;
+mod latest_round;
 mod owner;
 mod price;
+mod price_no_older_than;
+mod remove_reporter;
+mod reporter_count;
+mod round;
 mod set_price;
+mod set_prices;
+mod set_submission_window;
+mod set_threshold;
+mod submission_window;
+mod threshold;
@@ -1,3 +1,4 @@
+use fuels::prelude::ContractId;
 use utils::{
     abi_calls::{price, set_price},
     test_helpers::setup,
@@ -9,16 +10,27 @@ mod success {
     #[tokio::test]
     async fn can_get_price() {
         let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
         let set_price_amount: u64 = 1000;
-        set_price(&user.oracle, set_price_amount).await;
-        let price = price(&user.oracle).await;
-        assert_eq!(price, set_price_amount);
+        set_price(&user.oracle, asset, set_price_amount).await;
+        let price = price(&user.oracle, asset).await;
+        assert_eq!(price, Some(set_price_amount));
     }
 
     #[tokio::test]
     async fn can_get_price_when_not_initialized() {
         let (user, _) = setup().await;
-        let price = price(&user.oracle).await;
-        assert_eq!(price, 0);
+        let price = price(&user.oracle, ContractId::from([1u8; 32])).await;
+        assert_eq!(price, None);
+    }
+
+    #[tokio::test]
+    async fn can_get_prices_of_different_assets() {
+        let (user, _) = setup().await;
+        let (asset_1, asset_2) = (ContractId::from([1u8; 32]), ContractId::from([2u8; 32]));
+        set_price(&user.oracle, asset_1, 1000).await;
+        set_price(&user.oracle, asset_2, 2000).await;
+        assert_eq!(price(&user.oracle, asset_1).await, Some(1000));
+        assert_eq!(price(&user.oracle, asset_2).await, Some(2000));
     }
 }
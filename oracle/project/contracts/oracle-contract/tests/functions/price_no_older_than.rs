@@ -0,0 +1,62 @@
+use fuels::prelude::ContractId;
+use utils::{
+    abi_calls::{price_no_older_than, set_price},
+    test_helpers::{setup, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn can_get_fresh_price() {
+        let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+
+        assert_eq!(price_no_older_than(&user.oracle, asset, 60).await, 1000);
+    }
+}
+
+mod revert {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    #[should_panic(expected = "PriceNotSet")]
+    async fn when_price_not_set() {
+        let (user, _) = setup().await;
+        price_no_older_than(&user.oracle, ContractId::from([1u8; 32]), 60).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "StalePrice")]
+    async fn when_price_is_stale() {
+        let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+
+        // Let the block timestamp move past the timestamp of the recorded round
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        price_no_older_than(&user.oracle, asset, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "StalePrice")]
+    async fn when_oldest_submission_is_stale() {
+        let (user, wallets) = setup_with_reporters(2, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+
+        // The round is recorded later but keeps the time of the first submission
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        let oracle = user.oracle.with_wallet(wallets[1].clone()).unwrap();
+        set_price(&oracle, asset, 1000).await;
+
+        price_no_older_than(&user.oracle, asset, 1).await;
+    }
+}
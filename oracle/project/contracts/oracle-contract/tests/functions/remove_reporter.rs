@@ -0,0 +1,79 @@
+use utils::{
+    abi_calls::{is_reporter, remove_reporter, reporter_count},
+    test_helpers::{identity, setup, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+    use utils::ReporterRemovedEvent;
+
+    #[tokio::test]
+    async fn removes_reporter() {
+        let (user, wallets) = setup_with_reporters(2, 1).await;
+        let reporter = identity(&wallets[1]);
+
+        let response = remove_reporter(&user.oracle, reporter.clone()).await;
+
+        let log = response
+            .get_logs_with_type::<ReporterRemovedEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            ReporterRemovedEvent {
+                reporter: reporter.clone()
+            }
+        );
+        assert!(!is_reporter(&user.oracle, reporter).await);
+        assert_eq!(reporter_count(&user.oracle).await, 1);
+    }
+}
+
+mod revert {
+    use super::*;
+    use fuels::prelude::ContractId;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (user, wallets) = setup_with_reporters(2, 1).await;
+        user.oracle
+            .with_wallet(wallets[1].clone())
+            .unwrap()
+            .methods()
+            .remove_reporter(identity(&wallets[1]))
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ReporterNotFound")]
+    async fn when_reporter_not_found() {
+        let (user, wallets) = setup().await;
+        remove_reporter(&user.oracle, identity(&wallets[1])).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ThresholdExceedsReporters")]
+    async fn when_threshold_would_exceed_reporters() {
+        let (user, wallets) = setup_with_reporters(2, 2).await;
+        remove_reporter(&user.oracle, identity(&wallets[1])).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotReporter")]
+    async fn when_removed_reporter_sets_price() {
+        let (user, wallets) = setup_with_reporters(2, 1).await;
+        remove_reporter(&user.oracle, identity(&wallets[1])).await;
+        user.oracle
+            .with_wallet(wallets[1].clone())
+            .unwrap()
+            .methods()
+            .set_price(ContractId::from([1u8; 32]), 1000)
+            .call()
+            .await
+            .unwrap();
+    }
+}
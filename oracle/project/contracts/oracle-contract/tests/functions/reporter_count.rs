@@ -0,0 +1,17 @@
+use utils::{
+    abi_calls::reporter_count,
+    test_helpers::{setup, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn can_get_reporter_count() {
+        let (user, _) = setup().await;
+        assert_eq!(reporter_count(&user.oracle).await, 1);
+
+        let (user, _) = setup_with_reporters(3, 1).await;
+        assert_eq!(reporter_count(&user.oracle).await, 3);
+    }
+}
@@ -0,0 +1,37 @@
+use fuels::prelude::ContractId;
+use utils::{
+    abi_calls::{round, set_price},
+    test_helpers::setup,
+};
+
+mod success {
+    use super::*;
+    use utils::abi_calls::latest_round;
+
+    #[tokio::test]
+    async fn can_get_previous_rounds() {
+        let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+        set_price(&user.oracle, asset, 2000).await;
+
+        let first_round = round(&user.oracle, asset, 1).await.unwrap();
+        let second_round = round(&user.oracle, asset, 2).await.unwrap();
+
+        assert_eq!(first_round.price, 1000);
+        assert_eq!(second_round.price, 2000);
+        assert_eq!(Some(second_round), latest_round(&user.oracle, asset).await);
+    }
+
+    #[tokio::test]
+    async fn can_get_round_that_does_not_exist() {
+        let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+
+        assert_eq!(round(&user.oracle, asset, 0).await, None);
+        assert_eq!(round(&user.oracle, asset, 2).await, None);
+    }
+}
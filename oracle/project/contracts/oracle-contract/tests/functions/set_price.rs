@@ -1,19 +1,27 @@
+use fuels::prelude::ContractId;
 use utils::{
-    abi_calls::{price, set_price},
-    test_helpers::setup,
+    abi_calls::{latest_round, price, set_price},
+    test_helpers::{setup, setup_with_reporters},
 };
 
 mod success {
     use super::*;
-    use utils::PriceUpdateEvent;
+    use std::time::Duration;
+    use utils::{
+        abi_calls::{remove_reporter, set_submission_window},
+        test_helpers::identity,
+        PriceSubmittedEvent, PriceUpdateEvent,
+    };
 
     #[tokio::test]
     async fn can_set_price() {
         let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
         let set_price_amount: u64 = 1000;
 
-        let response = set_price(&user.oracle, set_price_amount).await;
-        let price = price(&user.oracle).await;
+        let response = set_price(&user.oracle, asset, set_price_amount).await;
+        let price = price(&user.oracle, asset).await;
+        let round = latest_round(&user.oracle, asset).await.unwrap();
 
         let log = response.get_logs_with_type::<PriceUpdateEvent>().unwrap();
         let event = log.get(0).unwrap();
@@ -21,10 +29,131 @@ mod success {
         assert_eq!(
             *event,
             PriceUpdateEvent {
-                price: set_price_amount
+                asset,
+                round: round.clone()
+            }
+        );
+        assert_eq!(round.id, 1);
+        assert_eq!(round.price, set_price_amount);
+        assert_eq!(price, Some(set_price_amount));
+    }
+
+    #[tokio::test]
+    async fn can_overwrite_price() {
+        let (user, _) = setup().await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+        set_price(&user.oracle, asset, 2000).await;
+
+        assert_eq!(price(&user.oracle, asset).await, Some(2000));
+        assert_eq!(latest_round(&user.oracle, asset).await.unwrap().id, 2);
+    }
+
+    #[tokio::test]
+    async fn waits_for_threshold_of_submissions() {
+        let (user, wallets) = setup_with_reporters(3, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        let response = set_price(&user.oracle, asset, 1000).await;
+
+        assert!(response
+            .get_logs_with_type::<PriceUpdateEvent>()
+            .unwrap()
+            .is_empty());
+        assert_eq!(price(&user.oracle, asset).await, None);
+
+        let oracle = user.oracle.with_wallet(wallets[1].clone()).unwrap();
+        let response = set_price(&oracle, asset, 2000).await;
+
+        assert_eq!(
+            response
+                .get_logs_with_type::<PriceUpdateEvent>()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(price(&user.oracle, asset).await, Some(1500));
+    }
+
+    #[tokio::test]
+    async fn records_median_of_submissions() {
+        let (user, wallets) = setup_with_reporters(3, 3).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        for (wallet, submitted_price) in wallets.iter().take(3).zip([3000, 1000, 2000]) {
+            let oracle = user.oracle.with_wallet(wallet.clone()).unwrap();
+            set_price(&oracle, asset, submitted_price).await;
+        }
+
+        let round = latest_round(&user.oracle, asset).await.unwrap();
+
+        assert_eq!(round.id, 1);
+        assert_eq!(round.price, 2000);
+    }
+
+    #[tokio::test]
+    async fn drops_expired_submissions() {
+        let (user, wallets) = setup_with_reporters(2, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_submission_window(&user.oracle, 1).await;
+        set_price(&user.oracle, asset, 1000).await;
+
+        // Let the first submission fall out of the submission window
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        let oracle = user.oracle.with_wallet(wallets[1].clone()).unwrap();
+        set_price(&oracle, asset, 2000).await;
+
+        assert_eq!(price(&user.oracle, asset).await, None);
+
+        // The reporter of the expired submission may replace it
+        set_price(&user.oracle, asset, 3000).await;
+
+        assert_eq!(price(&user.oracle, asset).await, Some(2500));
+    }
+
+    #[tokio::test]
+    async fn drops_submissions_of_removed_reporters() {
+        let (user, wallets) = setup_with_reporters(3, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        let oracle = user.oracle.with_wallet(wallets[1].clone()).unwrap();
+        set_price(&oracle, asset, 1000).await;
+        remove_reporter(&user.oracle, identity(&wallets[1])).await;
+
+        set_price(&user.oracle, asset, 2000).await;
+
+        assert_eq!(price(&user.oracle, asset).await, None);
+
+        let oracle = user.oracle.with_wallet(wallets[2].clone()).unwrap();
+        set_price(&oracle, asset, 3000).await;
+
+        assert_eq!(price(&user.oracle, asset).await, Some(2500));
+    }
+
+    #[tokio::test]
+    async fn emits_submission_event() {
+        let (user, wallets) = setup_with_reporters(2, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        let response = set_price(&user.oracle, asset, 1000).await;
+
+        let log = response
+            .get_logs_with_type::<PriceSubmittedEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            PriceSubmittedEvent {
+                asset,
+                price: 1000,
+                reporter: identity(&wallets[0]),
+                round: 1,
             }
         );
-        assert_eq!(price, set_price_amount);
     }
 }
 
@@ -32,16 +161,26 @@ mod revert {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "NotOwner")]
-    async fn when_not_owner() {
+    #[should_panic(expected = "NotReporter")]
+    async fn when_not_reporter() {
         let (user, wallets) = setup().await;
         user.oracle
             .with_wallet(wallets[1].clone())
             .unwrap()
             .methods()
-            .set_price(1000)
+            .set_price(ContractId::from([1u8; 32]), 1000)
             .call()
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadySubmitted")]
+    async fn when_already_submitted_for_round() {
+        let (user, _) = setup_with_reporters(2, 2).await;
+        let asset = ContractId::from([1u8; 32]);
+
+        set_price(&user.oracle, asset, 1000).await;
+        set_price(&user.oracle, asset, 1000).await;
+    }
 }
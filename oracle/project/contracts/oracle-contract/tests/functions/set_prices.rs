@@ -0,0 +1,69 @@
+use fuels::prelude::ContractId;
+use utils::{
+    abi_calls::{latest_round, price, set_prices},
+    test_helpers::setup,
+};
+
+mod success {
+    use super::*;
+    use utils::PriceUpdateEvent;
+
+    #[tokio::test]
+    async fn can_set_prices() {
+        let (user, _) = setup().await;
+        let (asset_1, asset_2) = (ContractId::from([1u8; 32]), ContractId::from([2u8; 32]));
+
+        let response = set_prices(&user.oracle, vec![(asset_1, 1000), (asset_2, 2000)]).await;
+
+        let log = response.get_logs_with_type::<PriceUpdateEvent>().unwrap();
+        let round_1 = latest_round(&user.oracle, asset_1).await.unwrap();
+        let round_2 = latest_round(&user.oracle, asset_2).await.unwrap();
+
+        assert_eq!(
+            log,
+            vec![
+                PriceUpdateEvent {
+                    asset: asset_1,
+                    round: round_1.clone()
+                },
+                PriceUpdateEvent {
+                    asset: asset_2,
+                    round: round_2.clone()
+                },
+            ]
+        );
+        assert_eq!(round_1.id, 1);
+        assert_eq!(round_2.id, 1);
+        assert_eq!(price(&user.oracle, asset_1).await, Some(1000));
+        assert_eq!(price(&user.oracle, asset_2).await, Some(2000));
+    }
+
+    #[tokio::test]
+    async fn can_set_no_prices() {
+        let (user, _) = setup().await;
+
+        let response = set_prices(&user.oracle, vec![]).await;
+
+        let log = response.get_logs_with_type::<PriceUpdateEvent>().unwrap();
+
+        assert!(log.is_empty());
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotReporter")]
+    async fn when_not_reporter() {
+        let (user, wallets) = setup().await;
+        user.oracle
+            .with_wallet(wallets[1].clone())
+            .unwrap()
+            .methods()
+            .set_prices(vec![(ContractId::from([1u8; 32]), 1000)])
+            .call()
+            .await
+            .unwrap();
+    }
+}
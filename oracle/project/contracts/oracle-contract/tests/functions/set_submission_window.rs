@@ -0,0 +1,47 @@
+use utils::{
+    abi_calls::{set_submission_window, submission_window},
+    test_helpers::{setup, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+    use utils::SubmissionWindowUpdatedEvent;
+
+    #[tokio::test]
+    async fn sets_submission_window() {
+        let (user, _) = setup().await;
+
+        let response = set_submission_window(&user.oracle, 60).await;
+
+        let log = response
+            .get_logs_with_type::<SubmissionWindowUpdatedEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(
+            *event,
+            SubmissionWindowUpdatedEvent {
+                submission_window: 60
+            }
+        );
+        assert_eq!(submission_window(&user.oracle).await, 60);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (user, wallets) = setup_with_reporters(2, 1).await;
+        user.oracle
+            .with_wallet(wallets[1].clone())
+            .unwrap()
+            .methods()
+            .set_submission_window(60)
+            .call()
+            .await
+            .unwrap();
+    }
+}
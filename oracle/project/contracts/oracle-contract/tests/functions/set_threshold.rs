@@ -0,0 +1,56 @@
+use utils::{
+    abi_calls::{set_threshold, threshold},
+    test_helpers::{setup, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+    use utils::ThresholdUpdatedEvent;
+
+    #[tokio::test]
+    async fn sets_threshold() {
+        let (user, _) = setup_with_reporters(3, 1).await;
+
+        let response = set_threshold(&user.oracle, 2).await;
+
+        let log = response
+            .get_logs_with_type::<ThresholdUpdatedEvent>()
+            .unwrap();
+        let event = log.get(0).unwrap();
+
+        assert_eq!(*event, ThresholdUpdatedEvent { threshold: 2 });
+        assert_eq!(threshold(&user.oracle).await, 2);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (user, wallets) = setup_with_reporters(2, 1).await;
+        user.oracle
+            .with_wallet(wallets[1].clone())
+            .unwrap()
+            .methods()
+            .set_threshold(2)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ThresholdCannotBeZero")]
+    async fn when_threshold_is_zero() {
+        let (user, _) = setup().await;
+        set_threshold(&user.oracle, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ThresholdExceedsReporters")]
+    async fn when_threshold_exceeds_reporters() {
+        let (user, _) = setup().await;
+        set_threshold(&user.oracle, 2).await;
+    }
+}
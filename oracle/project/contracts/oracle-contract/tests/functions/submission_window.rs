@@ -0,0 +1,23 @@
+use utils::{
+    abi_calls::{set_submission_window, submission_window},
+    test_helpers::setup,
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn can_get_default_submission_window() {
+        let (user, _) = setup().await;
+        assert_eq!(submission_window(&user.oracle).await, 3600);
+    }
+
+    #[tokio::test]
+    async fn can_get_submission_window() {
+        let (user, _) = setup().await;
+
+        set_submission_window(&user.oracle, 60).await;
+
+        assert_eq!(submission_window(&user.oracle).await, 60);
+    }
+}
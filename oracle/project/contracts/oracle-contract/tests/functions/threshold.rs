@@ -0,0 +1,20 @@
+use utils::{
+    abi_calls::threshold,
+    test_helpers::{setup, setup_with_reporters},
+};
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn can_get_default_threshold() {
+        let (user, _) = setup().await;
+        assert_eq!(threshold(&user.oracle).await, 1);
+    }
+
+    #[tokio::test]
+    async fn can_get_threshold() {
+        let (user, _) = setup_with_reporters(3, 2).await;
+        assert_eq!(threshold(&user.oracle).await, 2);
+    }
+}
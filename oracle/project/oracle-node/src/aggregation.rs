@@ -0,0 +1,136 @@
+use crate::{PriceProvider, BASIS_POINTS};
+use async_trait::async_trait;
+use futures::future::join_all;
+use std::{fmt, time::Duration};
+use tokio::time::timeout;
+
+/// Configures how the prices of several providers are combined into a single price
+#[derive(Clone, Debug)]
+pub struct AggregationConfig {
+    /// Maximum deviation from the median, in basis points, before a price is rejected as an outlier
+    pub max_deviation_bps: u64,
+    /// Duration after which a provider that has not responded is treated as stale
+    pub max_response_time: Duration,
+    /// Minimum number of accepted prices required before a price is returned
+    pub quorum: usize,
+}
+
+impl Default for AggregationConfig {
+    fn default() -> Self {
+        Self {
+            max_deviation_bps: 500,
+            max_response_time: Duration::from_secs(5),
+            quorum: 1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AggregationError {
+    NoProviders,
+    QuorumNotReached { required: usize, accepted: usize },
+}
+
+impl fmt::Display for AggregationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoProviders => write!(f, "no price providers have been configured"),
+            Self::QuorumNotReached { required, accepted } => write!(
+                f,
+                "quorum not reached: {accepted} price(s) accepted but {required} required"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AggregationError {}
+
+/// Combines the prices of several providers into a single price
+///
+/// Every provider is queried concurrently. Providers which fail or do not respond within
+/// `max_response_time` are dropped, prices that deviate too far from the median are rejected and
+/// the median of the remaining prices is returned once the quorum is reached.
+pub struct AggregatePriceProvider {
+    // Configuration used to filter and combine the provided prices
+    config: AggregationConfig,
+    // Sources of the prices which are aggregated
+    providers: Vec<Box<dyn PriceProvider + Send + Sync>>,
+}
+
+impl AggregatePriceProvider {
+    pub fn new(
+        providers: Vec<Box<dyn PriceProvider + Send + Sync>>,
+        config: AggregationConfig,
+    ) -> Self {
+        Self { config, providers }
+    }
+}
+
+#[async_trait]
+impl PriceProvider for AggregatePriceProvider {
+    /// Get the median price of all providers which responded in time and are in agreement
+    async fn get_price(&self) -> anyhow::Result<u64> {
+        if self.providers.is_empty() {
+            return Err(AggregationError::NoProviders.into());
+        }
+
+        let responses = join_all(
+            self.providers
+                .iter()
+                .map(|provider| timeout(self.config.max_response_time, provider.get_price())),
+        )
+        .await;
+
+        // Drop the providers which timed out or returned an error
+        let prices: Vec<u64> = responses
+            .into_iter()
+            .filter_map(|response| response.ok()?.ok())
+            .collect();
+
+        let accepted = reject_outliers(prices, self.config.max_deviation_bps);
+
+        if accepted.is_empty() || accepted.len() < self.config.quorum {
+            return Err(AggregationError::QuorumNotReached {
+                required: self.config.quorum.max(1),
+                accepted: accepted.len(),
+            }
+            .into());
+        }
+
+        Ok(median(&accepted).unwrap())
+    }
+}
+
+/// Returns the median of `prices`, or `None` when there are no prices
+///
+/// For an even number of prices the two middle values are averaged, rounding down.
+pub fn median(prices: &[u64]) -> Option<u64> {
+    let mut sorted = prices.to_vec();
+    sorted.sort_unstable();
+
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        length if length % 2 == 1 => Some(sorted[middle]),
+        _ => {
+            let (lower, upper) = (sorted[middle - 1], sorted[middle]);
+            Some(lower + (upper - lower) / 2)
+        }
+    }
+}
+
+/// Removes the prices which deviate from the median by more than `max_deviation_bps`
+pub fn reject_outliers(prices: Vec<u64>, max_deviation_bps: u64) -> Vec<u64> {
+    let median = match median(&prices) {
+        Some(median) => median as u128,
+        None => return prices,
+    };
+
+    prices
+        .into_iter()
+        .filter(|price| {
+            let deviation = (*price as u128).abs_diff(median) * BASIS_POINTS;
+            deviation <= median * max_deviation_bps as u128
+        })
+        .collect()
+}
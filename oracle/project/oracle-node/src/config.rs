@@ -0,0 +1,307 @@
+use crate::{
+    aggregation::{AggregatePriceProvider, AggregationConfig},
+    errors::ConfigError,
+    mapping::PriceMapping,
+    policy::DeviationHeartbeatPolicy,
+    NetworkPriceProvider, PriceProvider,
+};
+use fuels::{prelude::ContractId, signers::fuel_crypto::SecretKey};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use std::{
+    collections::HashSet, env, fs, net::SocketAddr, path::Path, str::FromStr, time::Duration,
+};
+
+// Largest number of decimals for which a price of one still fits into a u64
+const MAX_DECIMALS: u32 = 19;
+
+/// Configuration of the oracle node read from a TOML file
+#[derive(Debug, Deserialize)]
+pub struct NodeConfig {
+    /// Assets whose prices are pushed to the oracle contract
+    pub feeds: Vec<FeedConfig>,
+    /// Local endpoint exposing the metrics and health of the node, disabled when omitted
+    pub metrics: Option<MetricsConfig>,
+    /// Connection to the network and the oracle contract
+    pub network: NetworkConfig,
+    /// When fetched prices are published
+    #[serde(default)]
+    pub policy: PolicyConfig,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NetworkConfig {
+    /// Identifier of the deployed oracle contract
+    pub contract_id: String,
+    /// Url of the fuel-core GraphQL endpoint
+    pub provider_url: String,
+    /// Private key of the reporter wallet, read from `WALLET_SECRET` when omitted
+    pub wallet_secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MetricsConfig {
+    /// Socket address the metrics endpoint listens on
+    pub address: String,
+    /// Seconds after which a job which has not run is reported as unhealthy
+    ///
+    /// Must be greater than the period of every feed since a job runs only once per period
+    #[serde(default = "default_max_age")]
+    pub max_age: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct PolicyConfig {
+    /// Minimum deviation from the on-chain price, in basis points, which triggers an update
+    pub deviation_bps: u64,
+    /// Maximum number of seconds between two updates regardless of the price movement
+    pub heartbeat: u64,
+}
+
+/// A single asset whose price is pushed to the oracle contract
+#[derive(Debug, Deserialize)]
+pub struct FeedConfig {
+    /// Identifier of the tracked asset in the oracle contract
+    pub asset: String,
+    /// Number of decimals of the price pushed to the oracle contract
+    #[serde(default = "default_decimals")]
+    pub decimals: u32,
+    /// Seconds to wait between two price updates
+    pub period: u64,
+    /// Endpoints queried for the price of the asset, aggregated into a single price
+    pub providers: Vec<ProviderConfig>,
+    /// Currency the price is quoted in, used as the default path of the providers
+    #[serde(default = "default_quote")]
+    pub quote: String,
+}
+
+/// An endpoint responding with the price of an asset in a JSON body
+#[derive(Debug, Deserialize)]
+pub struct ProviderConfig {
+    /// Selector of the price in the response, the quote currency of the feed when omitted
+    ///
+    /// Either a JSON pointer such as `/data/amount` or a field selector such as `data.amount`
+    pub path: Option<String>,
+    /// Url of the endpoint
+    pub url: String,
+}
+
+fn default_decimals() -> u32 {
+    9
+}
+
+fn default_max_age() -> u64 {
+    300
+}
+
+fn default_quote() -> String {
+    "USD".to_string()
+}
+
+impl NodeConfig {
+    /// Reads and validates the configuration file at `path`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        contents.parse()
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        self.network.contract_id()?;
+        self.network.provider_url()?;
+        if let Some(metrics) = &self.metrics {
+            metrics.address()?;
+        }
+
+        if self.feeds.is_empty() {
+            return Err(ConfigError::invalid(
+                "feeds",
+                "at least one feed must be configured",
+            ));
+        }
+
+        let mut assets = HashSet::new();
+        for feed in &self.feeds {
+            if !assets.insert(feed.asset_id()?) {
+                return Err(ConfigError::invalid(
+                    "feeds.asset",
+                    format!("'{}' is configured more than once", feed.asset),
+                ));
+            }
+            if feed.period == 0 {
+                return Err(ConfigError::invalid(
+                    "feeds.period",
+                    format!("the period of '{}' must be greater than zero", feed.asset),
+                ));
+            }
+            if let Some(metrics) = &self.metrics {
+                if metrics.max_age <= feed.period {
+                    return Err(ConfigError::invalid(
+                        "metrics.max_age",
+                        format!(
+                            "must be greater than the period of '{}' for the job to be healthy",
+                            feed.asset
+                        ),
+                    ));
+                }
+            }
+            if feed.decimals > MAX_DECIMALS {
+                return Err(ConfigError::invalid(
+                    "feeds.decimals",
+                    format!(
+                        "'{}' cannot have more than {MAX_DECIMALS} decimals",
+                        feed.asset
+                    ),
+                ));
+            }
+            if feed.providers.is_empty() {
+                return Err(ConfigError::invalid(
+                    "feeds.providers",
+                    format!("'{}' needs at least one provider", feed.asset),
+                ));
+            }
+            for provider in &feed.providers {
+                provider.url()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for NodeConfig {
+    type Err = ConfigError;
+
+    /// Parses and validates the contents of a configuration file
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let config: Self = toml::from_str(contents).map_err(ConfigError::Parse)?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl NetworkConfig {
+    pub fn contract_id(&self) -> Result<ContractId, ConfigError> {
+        ContractId::from_str(&self.contract_id).map_err(|_| {
+            ConfigError::invalid(
+                "network.contract_id",
+                format!("'{}' is not a valid contract id", self.contract_id),
+            )
+        })
+    }
+
+    pub fn provider_url(&self) -> Result<Url, ConfigError> {
+        self.provider_url.parse().map_err(|_| {
+            ConfigError::invalid(
+                "network.provider_url",
+                format!("'{}' is not a valid url", self.provider_url),
+            )
+        })
+    }
+
+    /// Returns the private key of the reporter wallet from the configuration or the environment
+    pub fn wallet_secret(&self) -> Result<SecretKey, ConfigError> {
+        let secret = match &self.wallet_secret {
+            Some(secret) => secret.clone(),
+            None => env::var("WALLET_SECRET").map_err(|_| {
+                ConfigError::invalid(
+                    "network.wallet_secret",
+                    "must be set in the config file or the WALLET_SECRET environment variable",
+                )
+            })?,
+        };
+
+        SecretKey::from_str(&secret).map_err(|_| {
+            ConfigError::invalid("network.wallet_secret", "is not a valid private key")
+        })
+    }
+}
+
+impl MetricsConfig {
+    pub fn address(&self) -> Result<SocketAddr, ConfigError> {
+        self.address.parse().map_err(|_| {
+            ConfigError::invalid(
+                "metrics.address",
+                format!("'{}' is not a valid socket address", self.address),
+            )
+        })
+    }
+
+    pub fn max_age(&self) -> Duration {
+        Duration::from_secs(self.max_age)
+    }
+}
+
+impl Default for PolicyConfig {
+    fn default() -> Self {
+        Self {
+            deviation_bps: 50,
+            heartbeat: 3600,
+        }
+    }
+}
+
+impl PolicyConfig {
+    pub fn policy(&self) -> DeviationHeartbeatPolicy {
+        DeviationHeartbeatPolicy::new(self.deviation_bps, Duration::from_secs(self.heartbeat))
+    }
+}
+
+impl FeedConfig {
+    pub fn asset_id(&self) -> Result<ContractId, ConfigError> {
+        ContractId::from_str(&self.asset).map_err(|_| {
+            ConfigError::invalid(
+                "feeds.asset",
+                format!("'{}' is not a valid asset id", self.asset),
+            )
+        })
+    }
+
+    pub fn period(&self) -> Duration {
+        Duration::from_secs(self.period)
+    }
+
+    /// Builds a provider which aggregates the prices of every provider of the feed
+    ///
+    /// A majority of the providers must agree on the price for it to be published
+    pub fn price_provider(&self, client: &Client) -> Result<AggregatePriceProvider, ConfigError> {
+        let providers = self
+            .providers
+            .iter()
+            .map(|provider| {
+                Ok(Box::new(NetworkPriceProvider::with_mapping(
+                    client.clone(),
+                    provider.url()?,
+                    provider.mapping(&self.quote, self.decimals),
+                )) as Box<dyn PriceProvider + Send + Sync>)
+            })
+            .collect::<Result<Vec<_>, ConfigError>>()?;
+
+        let config = AggregationConfig {
+            quorum: providers.len() / 2 + 1,
+            ..AggregationConfig::default()
+        };
+
+        Ok(AggregatePriceProvider::new(providers, config))
+    }
+}
+
+impl ProviderConfig {
+    /// Maps the response of the provider to a price with `decimals` decimals
+    pub fn mapping(&self, quote: &str, decimals: u32) -> PriceMapping {
+        PriceMapping::new(self.path.as_deref().unwrap_or(quote), decimals)
+    }
+
+    pub fn url(&self) -> Result<Url, ConfigError> {
+        self.url.parse().map_err(|_| {
+            ConfigError::invalid(
+                "feeds.providers.url",
+                format!("'{}' is not a valid url", self.url),
+            )
+        })
+    }
+}
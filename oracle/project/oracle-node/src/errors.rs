@@ -0,0 +1,120 @@
+use std::{fmt, path::PathBuf, time::Duration};
+
+/// Errors reported by the updater job through its receipts channel
+#[derive(Debug)]
+pub enum OracleError {
+    /// The circuit breaker is open and no attempt was made to fetch or update the price
+    CircuitOpen { retry_in: Duration },
+    /// The price could not be fetched from the price provider
+    Fetch(anyhow::Error),
+    /// The current price could not be read from the oracle contract
+    Read(anyhow::Error),
+    /// The oracle contract could not be updated with the fetched price
+    Update(anyhow::Error),
+}
+
+impl fmt::Display for OracleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CircuitOpen { retry_in } => {
+                write!(f, "circuit breaker is open, retrying in {retry_in:?}")
+            }
+            Self::Fetch(error) => write!(f, "failed to fetch price: {error}"),
+            Self::Read(error) => write!(f, "failed to read oracle price: {error}"),
+            Self::Update(error) => write!(f, "failed to update oracle: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for OracleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CircuitOpen { .. } => None,
+            Self::Fetch(error) | Self::Read(error) | Self::Update(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+/// Failure to submit a price to the oracle contract
+#[derive(Debug)]
+pub enum SubmissionError {
+    /// Nothing was sent, e.g., the node could not be reached, so the price may be submitted again
+    BeforeSubmission(anyhow::Error),
+    /// The transaction may have been sent and included, so the price must not be submitted again
+    Ambiguous(anyhow::Error),
+}
+
+impl SubmissionError {
+    /// Whether the failure happened before any transaction was sent
+    pub fn is_before_submission(&self) -> bool {
+        matches!(self, Self::BeforeSubmission(_))
+    }
+}
+
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BeforeSubmission(error) => write!(f, "{error}"),
+            Self::Ambiguous(error) => {
+                write!(f, "the transaction may have been submitted: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmissionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BeforeSubmission(error) | Self::Ambiguous(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+/// Errors found while reading the configuration of the node
+#[derive(Debug)]
+pub enum ConfigError {
+    /// A field of the configuration holds an invalid value
+    Invalid { field: String, reason: String },
+    /// The configuration file could not be read
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The configuration file is not valid TOML or is missing required fields
+    Parse(toml::de::Error),
+}
+
+impl ConfigError {
+    pub fn invalid(field: &str, reason: impl Into<String>) -> Self {
+        Self::Invalid {
+            field: field.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid { field, reason } => write!(f, "invalid `{field}`: {reason}"),
+            Self::Io { path, source } => {
+                write!(
+                    f,
+                    "could not read config file '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::Parse(error) => write!(f, "could not parse config file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid { .. } => None,
+            Self::Io { source, .. } => Some(source),
+            Self::Parse(error) => Some(error),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{errors::SubmissionError, PriceUpdater, Submission};
+use async_trait::async_trait;
+use fuels::{prelude::ContractId, types::Identity};
+use utils::Oracle;
+
+/// Submits the price of a single asset to the oracle contract as one of its reporters
+pub struct FeedUpdater {
+    // Identifier of the asset whose price is updated
+    asset: ContractId,
+    // Oracle contract tracking the asset
+    oracle: Oracle,
+    // Identity of the wallet submitting prices on behalf of the node
+    reporter: Identity,
+}
+
+impl FeedUpdater {
+    pub fn new(oracle: Oracle, asset: ContractId, reporter: Identity) -> Self {
+        Self {
+            asset,
+            oracle,
+            reporter,
+        }
+    }
+}
+
+#[async_trait]
+impl PriceUpdater for FeedUpdater {
+    /// Read the price of the asset without submitting a transaction, zero if it has never been set
+    async fn current_price(&self) -> anyhow::Result<u64> {
+        let methods = self.oracle.methods();
+        Ok(methods
+            .price(self.asset)
+            .simulate()
+            .await?
+            .value
+            .unwrap_or_default())
+    }
+
+    /// Submit the price of the asset and return the receipts
+    ///
+    /// Nothing is submitted while the other reporters have yet to complete the pending round
+    /// this reporter has already submitted a price for
+    ///
+    /// Failing to send the transaction is ambiguous as it may have been included regardless
+    async fn set_price(&self, price: u64) -> Result<Submission, SubmissionError> {
+        let methods = self.oracle.methods();
+
+        let has_submitted = methods
+            .has_submitted(self.asset, self.reporter.clone())
+            .simulate()
+            .await
+            .map_err(|error| SubmissionError::BeforeSubmission(error.into()))?
+            .value;
+        if has_submitted {
+            return Ok(Submission::AwaitingRound);
+        }
+
+        let receipts = methods
+            .set_price(self.asset, price)
+            .call()
+            .await
+            .map_err(|error| SubmissionError::Ambiguous(error.into()))?
+            .receipts;
+        Ok(Submission::Submitted(receipts))
+    }
+}
@@ -1,55 +1,403 @@
+pub mod aggregation;
+pub mod config;
+pub mod errors;
+pub mod feeds;
+pub mod mapping;
+pub mod metrics;
+pub mod policy;
+pub mod retry;
+
 use async_trait::async_trait;
-use fuels::tx::Receipt;
-use futures::executor::block_on;
+use errors::{OracleError, SubmissionError};
+use fuels::{core::try_from_bytes, tx::Receipt};
+use mapping::PriceMapping;
+use metrics::JobMetrics;
+use policy::{AlwaysUpdate, UpdatePolicy};
 use reqwest::{Client, Url};
-use serde::Deserialize;
-use std::time::Duration;
+use retry::{CircuitBreaker, CircuitBreakerConfig, RetryConfig};
+use std::{
+    fmt,
+    future::Future,
+    sync::{atomic::Ordering, Arc},
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc::Receiver;
-use tokio::task::JoinHandle;
+use tokio::task::{JoinError, JoinHandle};
 use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+use utils::PriceUpdateEvent;
+
+// Deviations are expressed in basis points where 10_000 basis points equal 100%
+const BASIS_POINTS: u128 = 10_000;
+
+/// Outcome of a price published to the oracle contract
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceUpdate {
+    /// Log receipts emitted while publishing the price
+    pub receipts: Vec<Receipt>,
+    /// Rounds recorded by the oracle contract, decoded from the log receipts
+    pub rounds: Vec<PriceUpdateEvent>,
+}
 
-// Decimal precision of the asset we are pushing prices to
-const DECIMAL_PRECISION: f64 = 1e9;
+impl PriceUpdate {
+    pub fn new(receipts: Vec<Receipt>) -> Self {
+        let rounds = decode_rounds(&receipts);
+        Self { receipts, rounds }
+    }
+}
+
+/// Outcome of a run of an updater job which did not end in an error
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunOutcome {
+    /// The fetched price was published to the oracle contract
+    Updated(PriceUpdate),
+    /// The update policy decided not to publish the fetched price
+    Skipped,
+    /// Nothing was published as the submission of the reporter still counts towards the pending round
+    AwaitingRound,
+}
+
+impl RunOutcome {
+    /// Returns the published price update, if any
+    pub fn price_update(self) -> Option<PriceUpdate> {
+        match self {
+            RunOutcome::Updated(price_update) => Some(price_update),
+            RunOutcome::Skipped | RunOutcome::AwaitingRound => None,
+        }
+    }
+}
 
-// Used to deserialize the USD price of ETH from an api endpoint
-// We must allow non_snake_case because the JSON field we are deserializing is spelled that way
-#[allow(non_snake_case)]
-#[derive(Deserialize)]
-struct USDPrice {
-    USD: f64,
+/// Outcome of submitting a price to the oracle contract
+#[derive(Clone, Debug, PartialEq)]
+pub enum Submission {
+    /// The price was submitted and the transaction emitted these receipts
+    Submitted(Vec<Receipt>),
+    /// Nothing was submitted as the submission of the reporter still counts towards the pending round
+    AwaitingRound,
 }
 
-/// Spawns a thread to periodically fetch the price of an asset and update the oracle smart contract with that price
+/// Decodes the rounds recorded by the oracle contract from its log receipts
+///
+/// Log data which does not describe a `PriceUpdateEvent` is skipped
+pub fn decode_rounds(receipts: &[Receipt]) -> Vec<PriceUpdateEvent> {
+    receipts
+        .iter()
+        .filter_map(|receipt| match receipt {
+            Receipt::LogData { data, .. } => try_from_bytes::<PriceUpdateEvent>(data).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Configures when the updater job publishes prices and how it recovers from failures
+#[derive(Clone)]
+pub struct JobConfig {
+    /// Stops the job once cancelled, shared by every job spawned with this configuration
+    pub cancellation: CancellationToken,
+    /// Stops the job from making requests after repeated failures
+    pub circuit_breaker: CircuitBreakerConfig,
+    /// Records the activity of the job
+    pub metrics: Arc<JobMetrics>,
+    /// Decides which fetched prices are published to the oracle contract
+    pub policy: Arc<dyn UpdatePolicy + Send + Sync>,
+    /// Retries a failed fetch or update with exponential backoff
+    pub retry: RetryConfig,
+}
+
+impl Default for JobConfig {
+    fn default() -> Self {
+        Self {
+            cancellation: CancellationToken::new(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            metrics: Arc::new(JobMetrics::default()),
+            policy: Arc::new(AlwaysUpdate),
+            retry: RetryConfig::default(),
+        }
+    }
+}
+
+/// Counts of what an updater job did over its lifetime, reported once the job has stopped
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JobSummary {
+    /// Number of runs which published nothing as the reporter was waiting on the pending round
+    pub awaiting_round: u64,
+    /// Number of runs which ended in an error, including those skipped by the circuit breaker
+    pub failures: u64,
+    /// Number of times the job fetched a price or was stopped by the circuit breaker
+    pub runs: u64,
+    /// Number of fetched prices the update policy decided not to publish
+    pub skipped: u64,
+    /// Number of prices published to the oracle contract
+    pub updates: u64,
+}
+
+impl JobSummary {
+    fn record(&mut self, outcome: &Result<RunOutcome, OracleError>) {
+        self.runs += 1;
+        match outcome {
+            Ok(RunOutcome::Updated(_)) => self.updates += 1,
+            Ok(RunOutcome::Skipped) => self.skipped += 1,
+            Ok(RunOutcome::AwaitingRound) => self.awaiting_round += 1,
+            Err(_) => self.failures += 1,
+        }
+    }
+}
+
+impl fmt::Display for JobSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} run(s): {} update(s), {} skipped, {} awaiting round, {} failure(s)",
+            self.runs, self.updates, self.skipped, self.awaiting_round, self.failures
+        )
+    }
+}
+
+/// Controls a running updater job
+pub struct JobHandle {
+    // Stops the job once cancelled
+    cancellation: CancellationToken,
+    // Task running the job which resolves to its summary
+    handle: JoinHandle<JobSummary>,
+}
+
+impl JobHandle {
+    /// Asks the job to stop, an update which is in flight is allowed to finish
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Waits for the job to stop and returns its summary
+    pub async fn join(self) -> Result<JobSummary, JoinError> {
+        self.handle.await
+    }
+
+    /// Asks the job to stop and waits for it, returning its summary
+    pub async fn shutdown(self) -> Result<JobSummary, JoinError> {
+        self.cancel();
+        self.join().await
+    }
+}
+
+/// Spawns a task to periodically fetch the price of an asset and update the oracle smart contract with that price
+///
+/// Uses the default `JobConfig`, see `spawn_oracle_updater_job_with_config`
 ///
 /// # Arguments
 /// - `price_updater` - updates the oracle contract with new prices
 /// - `period` - duration to wait before fetching and updating the price for the oracle
 /// - `price_fetcher` - fetches the latest price for an asset
 pub fn spawn_oracle_updater_job(
-    price_updater: impl PriceUpdater + Send + 'static,
+    price_updater: impl PriceUpdater + Send + Sync + 'static,
     period: Duration,
-    price_fetcher: impl PriceProvider + Send + 'static,
-) -> (JoinHandle<()>, Receiver<anyhow::Result<Vec<Receipt>>>) {
-    // Variables to send log receipts out of the thread
+    price_fetcher: impl PriceProvider + Send + Sync + 'static,
+) -> (JobHandle, Receiver<Result<PriceUpdate, OracleError>>) {
+    spawn_oracle_updater_job_with_config(price_updater, period, price_fetcher, JobConfig::default())
+}
+
+/// Spawns a task to periodically fetch the price of an asset and update the oracle smart contract with that price
+///
+/// Failures are retried with exponential backoff and reported on the receipts channel instead of
+/// stopping the job. After repeated failures the circuit breaker pauses the job for a cooldown.
+/// Prices rejected by the update policy are not published and nothing is sent on the channel.
+///
+/// The job runs until it is cancelled through the returned handle or `config.cancellation`, or
+/// until the receiver is dropped. Cancellation is observed between updates so that an update
+/// which is in flight is completed and reported before the job stops.
+///
+/// # Arguments
+/// - `price_updater` - updates the oracle contract with new prices
+/// - `period` - duration to wait before fetching and updating the price for the oracle
+/// - `price_fetcher` - fetches the latest price for an asset
+/// - `config` - update policy, retry, circuit breaker and cancellation configuration
+pub fn spawn_oracle_updater_job_with_config(
+    price_updater: impl PriceUpdater + Send + Sync + 'static,
+    period: Duration,
+    price_fetcher: impl PriceProvider + Send + Sync + 'static,
+    config: JobConfig,
+) -> (JobHandle, Receiver<Result<PriceUpdate, OracleError>>) {
+    // Variables to send price updates out of the task
     let (sender, receiver) = tokio::sync::mpsc::channel(100);
-    let handle = tokio::task::spawn_blocking(move || loop {
-        let usd_price = block_on(price_fetcher.get_price()).unwrap();
-        // Update the oracle with the latest price and get the log receipts
-        let log_receipts = price_updater.set_price(usd_price).map(|receipts| {
-            receipts
-                .into_iter()
-                .filter(|receipt| matches!(receipt, Receipt::Log { .. } | Receipt::LogData { .. }))
-                .collect()
-        });
-
-        // Send log receipts out of the thread
-        block_on(sender.send(log_receipts)).unwrap();
-        block_on(sleep(period));
+    let cancellation = config.cancellation.child_token();
+    let job_cancellation = cancellation.clone();
+
+    let handle = tokio::spawn(async move {
+        let mut circuit_breaker = CircuitBreaker::new(config.circuit_breaker.clone());
+        let mut summary = JobSummary::default();
+        // Time at which this job last published a price
+        let mut last_update: Option<Instant> = None;
+
+        while !job_cancellation.is_cancelled() {
+            let outcome = match circuit_breaker.remaining_cooldown(Instant::now()) {
+                Some(retry_in) => Err(OracleError::CircuitOpen { retry_in }),
+                None => {
+                    let since_last_update = last_update.map(|time| time.elapsed());
+                    let outcome = fetch_and_update(
+                        &price_fetcher,
+                        &price_updater,
+                        &config,
+                        since_last_update,
+                    )
+                    .await;
+                    match outcome {
+                        Ok(RunOutcome::Updated(_)) => {
+                            circuit_breaker.record_success();
+                            last_update = Some(Instant::now());
+                        }
+                        Ok(RunOutcome::Skipped) => circuit_breaker.record_success(),
+                        // Nothing was published so the job has neither updated nor failed
+                        Ok(RunOutcome::AwaitingRound) => {}
+                        Err(_) => circuit_breaker.record_failure(Instant::now()),
+                    }
+                    outcome
+                }
+            };
+
+            summary.record(&outcome);
+            config.metrics.record_run();
+            if let Err(error) = &outcome {
+                config.metrics.record_error(error);
+            }
+
+            // Send the price update out of the task and stop once nobody is listening
+            if let Some(price_update) = outcome.map(RunOutcome::price_update).transpose() {
+                if sender.send(price_update).await.is_err() {
+                    break;
+                }
+            }
+
+            tokio::select! {
+                _ = job_cancellation.cancelled() => break,
+                _ = sleep(period) => {}
+            }
+        }
+
+        summary
     });
-    // Return the thread handle and channel receiver
-    // This allows us to control the thread
-    // and receive the log receipts from outside the thread
-    (handle, receiver)
+
+    // Return the job handle and channel receiver
+    // This allows us to control the job
+    // and receive the log receipts from outside the task
+    (
+        JobHandle {
+            cancellation,
+            handle,
+        },
+        receiver,
+    )
+}
+
+/// Fetches the latest price and updates the oracle with it a single time
+///
+/// Each step is retried on failure as configured in `config`. Returns `RunOutcome::Skipped` when
+/// the update policy of `config` decided not to publish the price.
+pub async fn update_once(
+    price_fetcher: &impl PriceProvider,
+    price_updater: &impl PriceUpdater,
+    config: &JobConfig,
+) -> Result<RunOutcome, OracleError> {
+    fetch_and_update(price_fetcher, price_updater, config, None).await
+}
+
+/// Fetches the latest price and updates the oracle with it, retrying each step on failure
+///
+/// Only a published price is recorded as an update in the metrics of the job
+async fn fetch_and_update(
+    price_fetcher: &impl PriceProvider,
+    price_updater: &impl PriceUpdater,
+    config: &JobConfig,
+    since_last_update: Option<Duration>,
+) -> Result<RunOutcome, OracleError> {
+    let retry = &config.retry;
+    let metrics = &config.metrics;
+    let started = Instant::now();
+
+    let usd_price = with_retry(retry, || price_fetcher.get_price())
+        .await
+        .map_err(OracleError::Fetch)?;
+    metrics
+        .last_fetched_price
+        .store(usd_price, Ordering::Relaxed);
+
+    let onchain_price = with_retry(retry, || price_updater.current_price())
+        .await
+        .map_err(OracleError::Read)?;
+    metrics
+        .last_onchain_price
+        .store(onchain_price, Ordering::Relaxed);
+
+    if !config
+        .policy
+        .should_update(usd_price, onchain_price, since_last_update)
+    {
+        metrics.skipped_total.fetch_add(1, Ordering::Relaxed);
+        return Ok(RunOutcome::Skipped);
+    }
+
+    // Update the oracle with the latest price and get the log receipts
+    // Retrying after the transaction may have been sent could publish the price twice
+    let submission = with_retry_if(
+        retry,
+        || price_updater.set_price(usd_price),
+        SubmissionError::is_before_submission,
+    )
+    .await
+    .map_err(|error| OracleError::Update(error.into()))?;
+    let receipts = match submission {
+        Submission::Submitted(receipts) => receipts,
+        Submission::AwaitingRound => {
+            metrics.awaiting_round_total.fetch_add(1, Ordering::Relaxed);
+            return Ok(RunOutcome::AwaitingRound);
+        }
+    };
+
+    let gas_used = receipts
+        .iter()
+        .map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => *gas_used,
+            _ => 0,
+        })
+        .sum();
+    metrics.record_update(started.elapsed(), gas_used);
+
+    Ok(RunOutcome::Updated(PriceUpdate::new(
+        receipts
+            .into_iter()
+            .filter(|receipt| matches!(receipt, Receipt::Log { .. } | Receipt::LogData { .. }))
+            .collect(),
+    )))
+}
+
+/// Runs `operation` until it succeeds or the retries are exhausted, returning the last error
+async fn with_retry<T, F: Future<Output = anyhow::Result<T>>>(
+    retry: &RetryConfig,
+    operation: impl FnMut() -> F,
+) -> anyhow::Result<T> {
+    with_retry_if(retry, operation, |_| true).await
+}
+
+/// Runs `operation` until it succeeds, fails with an error which is not `retryable` or the retries
+/// are exhausted, returning the last error
+async fn with_retry_if<T, E, F: Future<Output = Result<T, E>>>(
+    retry: &RetryConfig,
+    mut operation: impl FnMut() -> F,
+    retryable: impl Fn(&E) -> bool,
+) -> Result<T, E> {
+    let mut attempt = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt >= retry.max_retries || !retryable(&error) => return Err(error),
+            Err(_) => {
+                sleep(retry.backoff(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
 }
 
 /// Fetches the latest price info to provide to the oracle contract
@@ -58,17 +406,35 @@ pub trait PriceProvider {
     async fn get_price(&self) -> anyhow::Result<u64>;
 }
 
+#[async_trait]
+impl<T: PriceProvider + Send + Sync + ?Sized> PriceProvider for Box<T> {
+    async fn get_price(&self) -> anyhow::Result<u64> {
+        (**self).get_price().await
+    }
+}
+
 #[derive(Clone)]
 pub struct NetworkPriceProvider {
     // Makes network requests to fetch price info
     client: Client,
+    // Locates and converts the price in the JSON response
+    mapping: PriceMapping,
     // Url endpoint to make requests on
     url: Url,
 }
 
 impl NetworkPriceProvider {
+    /// Creates a provider reading the `USD` field of the response with 9 decimals
     pub fn new(client: Client, url: Url) -> Self {
-        Self { client, url }
+        Self::with_mapping(client, url, PriceMapping::default())
+    }
+
+    pub fn with_mapping(client: Client, url: Url, mapping: PriceMapping) -> Self {
+        Self {
+            client,
+            mapping,
+            url,
+        }
     }
 }
 
@@ -82,21 +448,21 @@ impl PriceProvider for NetworkPriceProvider {
             .get(self.url.clone())
             .send()
             .await?
-            .json::<USDPrice>()
+            .error_for_status()?
+            .json::<serde_json::Value>()
             .await?;
-        Ok((response.USD * DECIMAL_PRECISION) as u64)
+        Ok(self.mapping.extract(&response)?)
     }
 }
 
 /// Updates the oracle contract with the specified price
+#[async_trait]
 pub trait PriceUpdater {
-    fn set_price(&self, price: u64) -> anyhow::Result<Vec<Receipt>>;
-}
+    /// Returns the price currently stored in the oracle contract
+    async fn current_price(&self) -> anyhow::Result<u64>;
 
-impl PriceUpdater for utils::Oracle {
-    /// Set the price for the oracle contract and return the log receipts
-    fn set_price(&self, price: u64) -> anyhow::Result<Vec<Receipt>> {
-        let methods = self.methods();
-        Ok(block_on(methods.set_price(price).call())?.receipts)
-    }
+    /// Submits the price to the oracle contract unless the reporter is awaiting the pending round
+    ///
+    /// Only failures reported as `SubmissionError::BeforeSubmission` are retried
+    async fn set_price(&self, price: u64) -> Result<Submission, SubmissionError>;
 }
@@ -1,56 +1,262 @@
+use anyhow::bail;
+use clap::{Parser, Subcommand};
 use dotenv::dotenv;
 use fuels::client::FuelClient;
 use fuels::prelude::{Bech32ContractId, ContractId, Provider, WalletUnlocked};
-use fuels::signers::fuel_crypto::SecretKey;
-use oracle_node::{spawn_oracle_updater_job, NetworkPriceProvider};
-use reqwest::Url;
-use std::env;
-use std::str::FromStr;
+use fuels::tx::Address;
+use fuels::types::Identity;
+use futures::future::join_all;
+use oracle_node::{
+    config::NodeConfig, feeds::FeedUpdater, metrics, metrics::Metrics, policy::UpdatePolicy,
+    spawn_oracle_updater_job_with_config, update_once, JobConfig, JobHandle, PriceProvider,
+    PriceUpdate, RunOutcome,
+};
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use utils::Oracle;
 
+/// Pushes the prices of off-chain assets to the oracle contract
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// Path of the TOML file configuring the node
+    #[arg(short, long, default_value = "config.toml")]
+    config: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Periodically publish the price of every feed until the node is stopped
+    Run,
+    /// Publish the price of every feed a single time
+    Once,
+    /// Print the latest round recorded by the oracle for every feed
+    Status,
+    /// Fetch the price of every feed and print whether it would be published without publishing it
+    DryRun,
+}
+
+/// Connection to the oracle contract shared by every feed
+struct Node {
+    // Makes network requests to the price endpoints
+    client: reqwest::Client,
+    // Identifier of the oracle contract
+    id: Bech32ContractId,
+    // Identity of the wallet submitting prices on behalf of the node
+    reporter: Identity,
+    // Wallet used to interact with the oracle contract
+    wallet: WalletUnlocked,
+}
+
 #[tokio::main]
-async fn main() {
-    let (oracle, client, api_url) = setup();
-    let (handle, _receipts_receiver) = spawn_oracle_updater_job(
-        oracle,
-        Duration::from_secs(10),
-        NetworkPriceProvider::new(client, api_url),
-    );
-    handle.await.unwrap();
-}
-
-/// Iniitialize and return objects for use in main
-fn setup() -> (Oracle, reqwest::Client, Url) {
-    let root_env_path = env::current_dir().unwrap();
-    let env_path = root_env_path.join("project").join("oracle-node");
-    env::set_current_dir(env_path).unwrap();
+async fn main() -> anyhow::Result<()> {
     dotenv().ok();
+    let cli = Cli::parse();
+
+    let config = NodeConfig::from_file(&cli.config)?;
+    let node = Node::connect(&config)?;
+
+    match cli.command {
+        Command::Run => run(&config, &node).await,
+        Command::Once => once(&config, &node).await,
+        Command::Status => status(&config, &node).await,
+        Command::DryRun => dry_run(&config, &node).await,
+    }
+}
+
+impl Node {
+    fn connect(config: &NodeConfig) -> anyhow::Result<Self> {
+        let id = Bech32ContractId::from(config.network.contract_id()?);
+        let provider = Provider::new(FuelClient::new(config.network.provider_url()?)?);
+        let wallet =
+            WalletUnlocked::new_from_private_key(config.network.wallet_secret()?, Some(provider));
+        let reporter = Identity::Address(Address::from(wallet.address()));
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            id,
+            reporter,
+            wallet,
+        })
+    }
+
+    fn oracle(&self) -> Oracle {
+        Oracle::new(self.id.clone(), self.wallet.clone())
+    }
+
+    fn feed_updater(&self, asset: ContractId) -> FeedUpdater {
+        FeedUpdater::new(self.oracle(), asset, self.reporter.clone())
+    }
+
+    /// Fails unless the wallet of the node has been authorized to submit prices
+    async fn ensure_reporter(&self) -> anyhow::Result<()> {
+        let is_reporter = self
+            .oracle()
+            .methods()
+            .is_reporter(self.reporter.clone())
+            .simulate()
+            .await?
+            .value;
+        if !is_reporter {
+            bail!(
+                "wallet '{}' is not a reporter of the oracle",
+                self.wallet.address()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Drives every feed in its own job with its own price provider and period
+async fn run(config: &NodeConfig, node: &Node) -> anyhow::Result<()> {
+    node.ensure_reporter().await?;
 
-    let client = reqwest::Client::new();
+    let policy = Arc::new(config.policy.policy());
+    let metrics = Arc::new(Metrics::default());
 
-    let api_url_str = env::var("API_URL").expect("API_URL must be set.");
-    let api_url = api_url_str
-        .parse()
-        .unwrap_or_else(|_| panic!("API_URL: '{api_url_str}' is not a valid URL!"));
+    let mut handles = vec![];
+    for feed in &config.feeds {
+        let job_config = JobConfig {
+            metrics: metrics.register(&feed.asset),
+            policy: policy.clone(),
+            ..JobConfig::default()
+        };
+        let (handle, mut receiver) = spawn_oracle_updater_job_with_config(
+            node.feed_updater(feed.asset_id()?),
+            feed.period(),
+            feed.price_provider(&node.client)?,
+            job_config,
+        );
 
-    let id = Bech32ContractId::from(
-        ContractId::from_str(
-            &env::var("ORACLE_CONTRACT_ID").expect("ORACLE_CONTRACT_ID must be set."),
-        )
-        .unwrap(),
-    );
+        // Report the outcome of every update as a job stops once nobody is listening
+        let asset = feed.asset.clone();
+        tokio::spawn(async move {
+            while let Some(price_update) = receiver.recv().await {
+                match price_update {
+                    Ok(price_update) => report(&asset, &price_update),
+                    Err(error) => eprintln!("{asset}: {error}"),
+                }
+            }
+        });
 
-    let provider = Provider::new(
-        FuelClient::new(env::var("FUEL_PROVIDER_URL").expect("FUEL_PROVIDER_URL must be set."))
-            .unwrap(),
-    );
+        handles.push(handle);
+    }
 
-    let key = SecretKey::from_str(&env::var("WALLET_SECRET").expect("WALLET_SECRET must be set."))
-        .unwrap();
+    if let Some(metrics_config) = &config.metrics {
+        let address = metrics_config.address()?;
+        let max_age = metrics_config.max_age();
+        tokio::spawn(async move {
+            if let Err(error) = metrics::serve(address, metrics, max_age).await {
+                eprintln!("metrics endpoint stopped: {error}");
+            }
+        });
+    }
 
-    let unlocked = WalletUnlocked::new_from_private_key(key, Some(provider));
-    let oracle = Oracle::new(id, unlocked);
+    // Stop every job once the node is interrupted, letting updates in flight complete
+    tokio::signal::ctrl_c().await?;
+    println!("shutting down");
+
+    let summaries = join_all(handles.into_iter().map(JobHandle::shutdown)).await;
+    for (feed, summary) in config.feeds.iter().zip(summaries) {
+        println!("{}: {}", feed.asset, summary?);
+    }
+    Ok(())
+}
+
+/// Publishes the price of every feed regardless of the update policy
+async fn once(config: &NodeConfig, node: &Node) -> anyhow::Result<()> {
+    node.ensure_reporter().await?;
+
+    for feed in &config.feeds {
+        let price_updater = node.feed_updater(feed.asset_id()?);
+        let price_fetcher = feed.price_provider(&node.client)?;
+
+        let outcome = update_once(&price_fetcher, &price_updater, &JobConfig::default()).await?;
+
+        match outcome {
+            RunOutcome::Updated(price_update) => report(&feed.asset, &price_update),
+            RunOutcome::AwaitingRound => {
+                println!("{}: awaiting the pending round", feed.asset)
+            }
+            RunOutcome::Skipped => {}
+        }
+    }
+    Ok(())
+}
+
+async fn status(config: &NodeConfig, node: &Node) -> anyhow::Result<()> {
+    let methods = node.oracle().methods();
+
+    let is_reporter = methods
+        .is_reporter(node.reporter.clone())
+        .simulate()
+        .await?
+        .value;
+    let threshold = methods.threshold().simulate().await?.value;
+    let reporter_count = methods.reporter_count().simulate().await?.value;
+    println!("reporter: {} ({is_reporter})", node.wallet.address());
+    println!("threshold: {threshold} of {reporter_count} reporter(s)");
+
+    for feed in &config.feeds {
+        let asset = feed.asset_id()?;
+        match methods.latest_round(asset).simulate().await?.value {
+            Some(round) => println!(
+                "{}: round {} with price {} at height {} and timestamp {}",
+                feed.asset, round.id, round.price, round.height, round.timestamp
+            ),
+            None => println!("{}: no price has been recorded", feed.asset),
+        }
+
+        let has_submitted = methods
+            .has_submitted(asset, node.reporter.clone())
+            .simulate()
+            .await?
+            .value;
+        if has_submitted {
+            println!("{}: waiting for the other reporters", feed.asset);
+        }
+    }
+    Ok(())
+}
+
+/// Prints the fetched price of every feed next to its on-chain price without publishing it
+///
+/// The heartbeat of the policy is not taken into account, only the deviation of the price
+async fn dry_run(config: &NodeConfig, node: &Node) -> anyhow::Result<()> {
+    let policy = config.policy.policy();
+    let methods = node.oracle().methods();
+
+    for feed in &config.feeds {
+        let price = feed.price_provider(&node.client)?.get_price().await?;
+        let latest_round = methods
+            .latest_round(feed.asset_id()?)
+            .simulate()
+            .await?
+            .value;
+
+        let (onchain_price, since_last_update) = match latest_round {
+            Some(round) => (round.price, Some(Duration::ZERO)),
+            None => (0, None),
+        };
+        let would_publish = policy.should_update(price, onchain_price, since_last_update);
+
+        println!(
+            "{}: fetched {price}, on-chain {onchain_price}, would publish: {would_publish}",
+            feed.asset
+        );
+    }
+    Ok(())
+}
 
-    (oracle, client, api_url)
+fn report(asset: &str, price_update: &PriceUpdate) {
+    for event in &price_update.rounds {
+        println!(
+            "{asset}: recorded round {} with price {}",
+            event.round.id, event.round.price
+        );
+    }
 }
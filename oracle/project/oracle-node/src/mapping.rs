@@ -0,0 +1,134 @@
+use serde_json::Value;
+use std::fmt;
+
+/// Locates the price in the JSON body of a response and converts it to an integer with a fixed
+/// number of decimals
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceMapping {
+    /// Number of decimals of the converted price
+    pub decimals: u32,
+    /// Selector of the price in the response
+    ///
+    /// Either a JSON pointer such as `/data/amount` or a field selector such as `data.amount`
+    /// where numeric segments index into arrays
+    pub path: String,
+}
+
+impl PriceMapping {
+    pub fn new(path: &str, decimals: u32) -> Self {
+        Self {
+            decimals,
+            path: path.to_string(),
+        }
+    }
+
+    /// Returns the selected price of `body` converted to `decimals` decimals
+    ///
+    /// The price may be a JSON number or a decimal string. Digits beyond `decimals` are truncated.
+    pub fn extract(&self, body: &Value) -> Result<u64, MappingError> {
+        let value = self
+            .select(body)
+            .ok_or_else(|| MappingError::MissingField(self.path.clone()))?;
+
+        let decimal = match value {
+            Value::Number(number) => number.to_string(),
+            Value::String(string) => string.clone(),
+            _ => return Err(MappingError::NotANumber(value.to_string())),
+        };
+
+        to_fixed_point(&decimal, self.decimals)
+    }
+
+    fn select<'a>(&self, body: &'a Value) -> Option<&'a Value> {
+        if self.path.starts_with('/') {
+            return body.pointer(&self.path);
+        }
+
+        let path = self.path.strip_prefix("$.").unwrap_or(&self.path);
+        path.split('.')
+            .try_fold(body, |value, segment| match value {
+                Value::Array(values) => values.get(segment.parse::<usize>().ok()?),
+                _ => value.get(segment),
+            })
+    }
+}
+
+impl Default for PriceMapping {
+    fn default() -> Self {
+        Self::new("USD", 9)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MappingError {
+    MissingField(String),
+    Negative(String),
+    NotANumber(String),
+    Overflow(String),
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(path) => write!(f, "the response has no price at '{path}'"),
+            Self::Negative(price) => write!(f, "the price '{price}' is negative"),
+            Self::NotANumber(price) => write!(f, "the price '{price}' is not a number"),
+            Self::Overflow(price) => write!(f, "the price '{price}' does not fit into a u64"),
+        }
+    }
+}
+
+impl std::error::Error for MappingError {}
+
+/// Converts a decimal string such as `1234.5678` or `1.5e-3` into an integer with `decimals`
+/// decimals without going through a floating point number
+///
+/// Digits beyond `decimals` are truncated.
+pub fn to_fixed_point(decimal: &str, decimals: u32) -> Result<u64, MappingError> {
+    let not_a_number = || MappingError::NotANumber(decimal.to_string());
+    let overflow = || MappingError::Overflow(decimal.to_string());
+
+    let trimmed = decimal.trim();
+    if trimmed.starts_with('-') {
+        return Err(MappingError::Negative(decimal.to_string()));
+    }
+    let trimmed = trimmed.strip_prefix('+').unwrap_or(trimmed);
+
+    let (mantissa, exponent) = match trimmed.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent.parse::<i32>().map_err(|_| not_a_number())? as i64,
+        ),
+        None => (trimmed, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let is_digits = |digits: &str| digits.bytes().all(|digit| digit.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return Err(not_a_number());
+    }
+
+    // The digits are scaled by 10^scale to express the price with `decimals` decimals
+    let digits = format!("{integer}{fraction}");
+    let scale = exponent + decimals as i64 - fraction.len() as i64;
+
+    let kept = match usize::try_from(-scale) {
+        Ok(truncated) => &digits[..digits.len().saturating_sub(truncated)],
+        Err(_) => &digits[..],
+    };
+
+    let value = kept.bytes().try_fold(0u64, |value, digit| {
+        value.checked_mul(10)?.checked_add((digit - b'0') as u64)
+    });
+    let value = value.ok_or_else(overflow)?;
+
+    if value == 0 || scale <= 0 {
+        return Ok(value);
+    }
+
+    u32::try_from(scale)
+        .ok()
+        .and_then(|scale| 10u64.checked_pow(scale))
+        .and_then(|multiplier| value.checked_mul(multiplier))
+        .ok_or_else(overflow)
+}
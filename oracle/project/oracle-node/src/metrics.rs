@@ -0,0 +1,252 @@
+use crate::errors::OracleError;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Counters and gauges recorded by a single updater job
+#[derive(Debug, Default)]
+pub struct JobMetrics {
+    /// Number of runs which published nothing as the reporter was waiting on the pending round
+    pub awaiting_round_total: AtomicU64,
+    /// Number of times the circuit breaker prevented the job from running
+    pub circuit_open_total: AtomicU64,
+    /// Number of failed price fetches
+    pub fetch_errors_total: AtomicU64,
+    /// Total gas spent publishing prices
+    pub gas_used_total: AtomicU64,
+    /// Last price fetched from the price providers
+    pub last_fetched_price: AtomicU64,
+    /// Last price read from the oracle contract
+    pub last_onchain_price: AtomicU64,
+    /// Unix time, in seconds, at which the job last ran
+    pub last_run_timestamp: AtomicU64,
+    /// Unix time, in seconds, at which the job last published a price
+    pub last_update_timestamp: AtomicU64,
+    /// Milliseconds taken by the last published update from fetching the price to its receipts
+    pub last_update_latency_ms: AtomicU64,
+    /// Number of failed reads of the on-chain price
+    pub read_errors_total: AtomicU64,
+    /// Number of fetched prices the update policy decided not to publish
+    pub skipped_total: AtomicU64,
+    /// Number of failed price updates
+    pub update_errors_total: AtomicU64,
+    /// Number of published prices
+    pub updates_total: AtomicU64,
+}
+
+impl JobMetrics {
+    /// Records that the job ran at the current time
+    pub fn record_run(&self) {
+        self.last_run_timestamp
+            .store(unix_timestamp(SystemTime::now()), Ordering::Relaxed);
+    }
+
+    /// Increments the error counter matching `error`
+    pub fn record_error(&self, error: &OracleError) {
+        let counter = match error {
+            OracleError::CircuitOpen { .. } => &self.circuit_open_total,
+            OracleError::Fetch(_) => &self.fetch_errors_total,
+            OracleError::Read(_) => &self.read_errors_total,
+            OracleError::Update(_) => &self.update_errors_total,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a published price along with the time it took and the gas it cost
+    pub fn record_update(&self, latency: Duration, gas_used: u64) {
+        self.updates_total.fetch_add(1, Ordering::Relaxed);
+        self.gas_used_total.fetch_add(gas_used, Ordering::Relaxed);
+        self.last_update_latency_ms
+            .store(latency.as_millis() as u64, Ordering::Relaxed);
+        self.last_update_timestamp
+            .store(unix_timestamp(SystemTime::now()), Ordering::Relaxed);
+    }
+
+    /// Whether the job has run within `max_age` of `now`
+    pub fn is_alive(&self, now: SystemTime, max_age: Duration) -> bool {
+        let last_run = self.last_run_timestamp.load(Ordering::Relaxed);
+        last_run != 0 && unix_timestamp(now).saturating_sub(last_run) <= max_age.as_secs()
+    }
+}
+
+/// Metrics of every updater job run by the node, labelled by the asset of the job
+#[derive(Debug, Default)]
+pub struct Metrics {
+    // Metrics of each job along with the asset they are labelled with
+    jobs: Mutex<Vec<(String, Arc<JobMetrics>)>>,
+}
+
+impl Metrics {
+    /// Creates the metrics of a new job labelled with `asset`
+    pub fn register(&self, asset: &str) -> Arc<JobMetrics> {
+        let job = Arc::new(JobMetrics::default());
+        self.jobs
+            .lock()
+            .unwrap()
+            .push((asset.to_string(), job.clone()));
+        job
+    }
+
+    /// Whether every job has run within `max_age` of `now`
+    pub fn is_healthy(&self, now: SystemTime, max_age: Duration) -> bool {
+        self.jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, job)| job.is_alive(now, max_age))
+    }
+
+    /// Renders the metrics of every job in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let jobs = self.jobs.lock().unwrap();
+        let mut output = String::new();
+
+        let families: [(&str, &str, &str, fn(&JobMetrics) -> &AtomicU64); 13] = [
+            (
+                "oracle_awaiting_round_total",
+                "counter",
+                "Number of runs which published nothing while awaiting the pending round",
+                |job| &job.awaiting_round_total,
+            ),
+            (
+                "oracle_circuit_open_total",
+                "counter",
+                "Number of times the circuit breaker prevented the job from running",
+                |job| &job.circuit_open_total,
+            ),
+            (
+                "oracle_fetch_errors_total",
+                "counter",
+                "Number of failed price fetches",
+                |job| &job.fetch_errors_total,
+            ),
+            (
+                "oracle_gas_used_total",
+                "counter",
+                "Total gas spent publishing prices",
+                |job| &job.gas_used_total,
+            ),
+            (
+                "oracle_last_fetched_price",
+                "gauge",
+                "Last price fetched from the price providers",
+                |job| &job.last_fetched_price,
+            ),
+            (
+                "oracle_last_onchain_price",
+                "gauge",
+                "Last price read from the oracle contract",
+                |job| &job.last_onchain_price,
+            ),
+            (
+                "oracle_last_run_timestamp_seconds",
+                "gauge",
+                "Unix time at which the job last ran",
+                |job| &job.last_run_timestamp,
+            ),
+            (
+                "oracle_last_update_latency_milliseconds",
+                "gauge",
+                "Time taken by the last published update",
+                |job| &job.last_update_latency_ms,
+            ),
+            (
+                "oracle_last_update_timestamp_seconds",
+                "gauge",
+                "Unix time at which the job last published a price",
+                |job| &job.last_update_timestamp,
+            ),
+            (
+                "oracle_read_errors_total",
+                "counter",
+                "Number of failed reads of the on-chain price",
+                |job| &job.read_errors_total,
+            ),
+            (
+                "oracle_skipped_updates_total",
+                "counter",
+                "Number of fetched prices which were not published",
+                |job| &job.skipped_total,
+            ),
+            (
+                "oracle_update_errors_total",
+                "counter",
+                "Number of failed price updates",
+                |job| &job.update_errors_total,
+            ),
+            (
+                "oracle_updates_total",
+                "counter",
+                "Number of published prices",
+                |job| &job.updates_total,
+            ),
+        ];
+
+        for (name, kind, help, metric) in families {
+            writeln!(output, "# HELP {name} {help}").unwrap();
+            writeln!(output, "# TYPE {name} {kind}").unwrap();
+            for (asset, job) in jobs.iter() {
+                let value = metric(job).load(Ordering::Relaxed);
+                writeln!(output, "{name}{{asset=\"{asset}\"}} {value}").unwrap();
+            }
+        }
+
+        output
+    }
+}
+
+/// Serves the metrics on `/metrics` and the health of the jobs on `/health` until the future is dropped
+///
+/// `/health` responds with `503 Service Unavailable` once any job has not run for `max_age`
+pub async fn serve(
+    address: SocketAddr,
+    metrics: Arc<Metrics>,
+    max_age: Duration,
+) -> anyhow::Result<()> {
+    let make_service = make_service_fn(move |_| {
+        let metrics = metrics.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = respond(&metrics, max_age, request);
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    Server::try_bind(&address)?.serve(make_service).await?;
+    Ok(())
+}
+
+fn respond(metrics: &Metrics, max_age: Duration, request: Request<Body>) -> Response<Body> {
+    let (status, body) = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/metrics") => (StatusCode::OK, metrics.render()),
+        (&Method::GET, "/health") if metrics.is_healthy(SystemTime::now(), max_age) => {
+            (StatusCode::OK, "ok".to_string())
+        }
+        (&Method::GET, "/health") => (StatusCode::SERVICE_UNAVAILABLE, "stale".to_string()),
+        _ => (StatusCode::NOT_FOUND, "not found".to_string()),
+    };
+
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "text/plain; version=0.0.4")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+fn unix_timestamp(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
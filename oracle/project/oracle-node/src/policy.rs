@@ -0,0 +1,70 @@
+use crate::BASIS_POINTS;
+use std::time::Duration;
+
+/// Decides whether a freshly fetched price is published to the oracle contract
+pub trait UpdatePolicy {
+    /// Returns true when `price` should be published
+    ///
+    /// # Arguments
+    /// - `price` - latest price fetched by the price provider
+    /// - `onchain_price` - price currently stored in the oracle contract
+    /// - `since_last_update` - time since the job last published a price, `None` if it never has
+    fn should_update(
+        &self,
+        price: u64,
+        onchain_price: u64,
+        since_last_update: Option<Duration>,
+    ) -> bool;
+}
+
+/// Publishes every fetched price
+#[derive(Clone, Debug, Default)]
+pub struct AlwaysUpdate;
+
+impl UpdatePolicy for AlwaysUpdate {
+    fn should_update(
+        &self,
+        _price: u64,
+        _onchain_price: u64,
+        _since_last_update: Option<Duration>,
+    ) -> bool {
+        true
+    }
+}
+
+/// Publishes a price when it has moved far enough from the on-chain price or when the heartbeat
+/// interval has passed since the last update
+#[derive(Clone, Debug)]
+pub struct DeviationHeartbeatPolicy {
+    /// Minimum deviation from the on-chain price, in basis points, which triggers an update
+    pub deviation_bps: u64,
+    /// Maximum duration between two updates regardless of the price movement
+    pub heartbeat: Duration,
+}
+
+impl DeviationHeartbeatPolicy {
+    pub fn new(deviation_bps: u64, heartbeat: Duration) -> Self {
+        Self {
+            deviation_bps,
+            heartbeat,
+        }
+    }
+}
+
+impl UpdatePolicy for DeviationHeartbeatPolicy {
+    fn should_update(
+        &self,
+        price: u64,
+        onchain_price: u64,
+        since_last_update: Option<Duration>,
+    ) -> bool {
+        match since_last_update {
+            None => true,
+            Some(elapsed) if elapsed >= self.heartbeat => true,
+            Some(_) => {
+                let deviation = (price.abs_diff(onchain_price) as u128) * BASIS_POINTS;
+                deviation > (onchain_price as u128) * (self.deviation_bps as u128)
+            }
+        }
+    }
+}
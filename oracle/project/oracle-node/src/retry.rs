@@ -0,0 +1,98 @@
+use std::time::{Duration, Instant};
+
+/// Configures how often and how quickly a failed fetch or update is retried
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between two retries
+    pub max_backoff: Duration,
+    /// Number of retries after the initial attempt has failed
+    pub max_retries: u32,
+    /// Factor by which the delay grows after every retry
+    pub multiplier: u32,
+}
+
+impl RetryConfig {
+    /// Returns the delay before retry number `attempt`, starting at zero
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(self.multiplier.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            max_retries: 3,
+            multiplier: 2,
+        }
+    }
+}
+
+/// Configures when the circuit breaker stops the updater job from making requests
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerConfig {
+    /// Duration the circuit stays open before another attempt is made
+    pub cooldown: Duration,
+    /// Number of consecutive failed updates that opens the circuit, zero disables the breaker
+    pub failure_threshold: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            cooldown: Duration::from_secs(60),
+            failure_threshold: 5,
+        }
+    }
+}
+
+/// Stops requests from being made while the price provider or the network is failing
+///
+/// Once the circuit has been opened and the cooldown has passed a single attempt is let through.
+/// A success closes the circuit again while a failure reopens it for another cooldown.
+pub struct CircuitBreaker {
+    // Configuration of the thresholds and durations
+    config: CircuitBreakerConfig,
+    // Number of failures since the last success
+    consecutive_failures: u32,
+    // Time at which the circuit was last opened
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            consecutive_failures: 0,
+            opened_at: None,
+        }
+    }
+
+    /// Returns the remaining cooldown if the circuit is open at `now`
+    pub fn remaining_cooldown(&self, now: Instant) -> Option<Duration> {
+        let opened_at = self.opened_at?;
+        self.config
+            .cooldown
+            .checked_sub(now.saturating_duration_since(opened_at))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    pub fn record_failure(&mut self, now: Instant) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if self.config.failure_threshold > 0
+            && self.consecutive_failures >= self.config.failure_threshold
+        {
+            self.opened_at = Some(now);
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+    }
+}
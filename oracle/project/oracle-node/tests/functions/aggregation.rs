@@ -0,0 +1,140 @@
+use crate::functions::{DelayedPriceProvider, FailingPriceProvider, HardcodedPriceProvider};
+use oracle_node::{
+    aggregation::{AggregatePriceProvider, AggregationConfig, AggregationError},
+    PriceProvider,
+};
+use std::time::Duration;
+
+fn hardcoded(prices: &[u64]) -> Vec<Box<dyn PriceProvider + Send + Sync>> {
+    prices
+        .iter()
+        .map(|price| {
+            Box::new(HardcodedPriceProvider { price: *price })
+                as Box<dyn PriceProvider + Send + Sync>
+        })
+        .collect()
+}
+
+mod success {
+    use super::*;
+    use oracle_node::aggregation::{median, reject_outliers};
+
+    #[tokio::test]
+    async fn returns_median_price() {
+        let aggregator =
+            AggregatePriceProvider::new(hardcoded(&[100, 102, 101]), AggregationConfig::default());
+
+        assert_eq!(aggregator.get_price().await.unwrap(), 101);
+    }
+
+    #[tokio::test]
+    async fn averages_middle_prices_when_count_is_even() {
+        let aggregator = AggregatePriceProvider::new(
+            hardcoded(&[100, 103, 101, 102]),
+            AggregationConfig::default(),
+        );
+
+        assert_eq!(aggregator.get_price().await.unwrap(), 101);
+    }
+
+    #[tokio::test]
+    async fn rejects_outliers() {
+        let config = AggregationConfig {
+            max_deviation_bps: 100,
+            quorum: 3,
+            ..AggregationConfig::default()
+        };
+        let aggregator = AggregatePriceProvider::new(hardcoded(&[1000, 1005, 995, 5000]), config);
+
+        assert_eq!(aggregator.get_price().await.unwrap(), 1000);
+    }
+
+    #[tokio::test]
+    async fn drops_failed_providers() {
+        let mut providers = hardcoded(&[100, 100]);
+        providers.push(Box::new(FailingPriceProvider));
+        let config = AggregationConfig {
+            quorum: 2,
+            ..AggregationConfig::default()
+        };
+        let aggregator = AggregatePriceProvider::new(providers, config);
+
+        assert_eq!(aggregator.get_price().await.unwrap(), 100);
+    }
+
+    #[tokio::test]
+    async fn drops_stale_providers() {
+        let mut providers = hardcoded(&[100]);
+        providers.push(Box::new(DelayedPriceProvider {
+            delay: Duration::from_millis(500),
+            price: 200,
+        }));
+        let config = AggregationConfig {
+            max_deviation_bps: 10_000,
+            max_response_time: Duration::from_millis(100),
+            ..AggregationConfig::default()
+        };
+        let aggregator = AggregatePriceProvider::new(providers, config);
+
+        assert_eq!(aggregator.get_price().await.unwrap(), 100);
+    }
+
+    #[test]
+    fn median_of_empty_prices_is_none() {
+        assert_eq!(median(&[]), None);
+    }
+
+    #[test]
+    fn keeps_prices_within_deviation() {
+        assert_eq!(reject_outliers(vec![100, 105, 95], 500), vec![100, 105, 95]);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[tokio::test]
+    async fn when_there_are_no_providers() {
+        let aggregator = AggregatePriceProvider::new(vec![], AggregationConfig::default());
+
+        let error = aggregator.get_price().await.unwrap_err();
+
+        assert_eq!(
+            error.downcast_ref::<AggregationError>(),
+            Some(&AggregationError::NoProviders)
+        );
+    }
+
+    #[tokio::test]
+    async fn when_quorum_is_not_reached() {
+        let mut providers = hardcoded(&[100]);
+        providers.push(Box::new(FailingPriceProvider));
+        let config = AggregationConfig {
+            quorum: 2,
+            ..AggregationConfig::default()
+        };
+        let aggregator = AggregatePriceProvider::new(providers, config);
+
+        let error = aggregator.get_price().await.unwrap_err();
+
+        assert_eq!(
+            error.downcast_ref::<AggregationError>(),
+            Some(&AggregationError::QuorumNotReached {
+                required: 2,
+                accepted: 1
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn when_outliers_leave_too_few_prices() {
+        let config = AggregationConfig {
+            max_deviation_bps: 100,
+            quorum: 2,
+            ..AggregationConfig::default()
+        };
+        let aggregator = AggregatePriceProvider::new(hardcoded(&[100, 200]), config);
+
+        assert!(aggregator.get_price().await.is_err());
+    }
+}
@@ -0,0 +1,289 @@
+use oracle_node::{config::NodeConfig, errors::ConfigError};
+use std::{fs, time::Duration};
+
+const NETWORK: &str = r#"
+    [network]
+    contract_id = "0x3926c54eb171e0d0bb7921b15e96fd08e53bd1cbd5244e2d0d997b045811779f"
+    provider_url = "http://localhost:4000/graphql"
+"#;
+
+fn parse(feeds: &str) -> Result<NodeConfig, ConfigError> {
+    format!("{NETWORK}{feeds}").parse()
+}
+
+fn invalid_field(error: ConfigError) -> String {
+    match error {
+        ConfigError::Invalid { field, .. } => field,
+        error => panic!("expected an invalid field, got: {error}"),
+    }
+}
+
+mod success {
+    use super::*;
+    use fuels::prelude::ContractId;
+    use oracle_node::mapping::PriceMapping;
+
+    #[test]
+    fn reads_config_from_file() {
+        let path = std::env::temp_dir().join("oracle-node-reads-config.toml");
+        fs::write(
+            &path,
+            format!(
+                r#"{NETWORK}
+                [policy]
+                deviation_bps = 100
+                heartbeat = 60
+
+                [[feeds]]
+                asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+                decimals = 6
+                period = 10
+                providers = [
+                    {{ url = "http://localhost:8080/a" }},
+                    {{ url = "http://localhost:8080/b", path = "data.amount" }},
+                ]
+                quote = "EUR"
+
+                [[feeds]]
+                asset = "0x0202020202020202020202020202020202020202020202020202020202020202"
+                period = 60
+                providers = [{{ url = "http://localhost:8080/c" }}]
+                "#
+            ),
+        )
+        .unwrap();
+
+        let config = NodeConfig::from_file(&path).unwrap();
+
+        assert_eq!(config.policy.deviation_bps, 100);
+        assert_eq!(config.policy.heartbeat, 60);
+        assert_eq!(config.feeds.len(), 2);
+        assert_eq!(
+            config.feeds[0].asset_id().unwrap(),
+            ContractId::from([1u8; 32])
+        );
+        assert_eq!(config.feeds[0].decimals, 6);
+        assert_eq!(
+            config.feeds[0].providers[0].mapping(&config.feeds[0].quote, 6),
+            PriceMapping::new("EUR", 6)
+        );
+        assert_eq!(
+            config.feeds[0].providers[1].mapping(&config.feeds[0].quote, 6),
+            PriceMapping::new("data.amount", 6)
+        );
+        assert_eq!(config.feeds[1].decimals, 9);
+        assert_eq!(config.feeds[1].quote, "USD");
+        assert_eq!(config.feeds[1].period(), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn uses_default_policy() {
+        let config = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.policy.deviation_bps, 50);
+        assert_eq!(config.policy.heartbeat, 3600);
+    }
+
+    #[test]
+    fn reads_wallet_secret_from_config() {
+        let mut config = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#,
+        )
+        .unwrap();
+        config.network.wallet_secret =
+            Some("0x0000000000000000000000000000000000000000000000000000000000000001".to_string());
+
+        assert!(config.network.wallet_secret().is_ok());
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[test]
+    fn when_asset_is_invalid() {
+        let error = parse(
+            r#"
+            [[feeds]]
+            asset = "not an asset"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds.asset");
+    }
+
+    #[test]
+    fn when_asset_is_duplicated() {
+        let error = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 60
+            providers = [{ url = "http://localhost:8080/b" }]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds.asset");
+    }
+
+    #[test]
+    fn when_contract_id_is_invalid() {
+        let error = r#"
+            [network]
+            contract_id = "not a contract"
+            provider_url = "http://localhost:4000/graphql"
+
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#
+        .parse::<NodeConfig>()
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "network.contract_id");
+    }
+
+    #[test]
+    fn when_decimals_overflow() {
+        let error = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            decimals = 20
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds.decimals");
+    }
+
+    #[test]
+    fn when_file_does_not_exist() {
+        assert!(matches!(
+            NodeConfig::from_file("does-not-exist.toml"),
+            Err(ConfigError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn when_file_is_not_toml() {
+        assert!(matches!(parse("[[feeds]"), Err(ConfigError::Parse(_))));
+    }
+
+    #[test]
+    fn when_network_is_missing() {
+        let error = r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#
+        .parse::<NodeConfig>()
+        .unwrap_err();
+
+        assert!(matches!(error, ConfigError::Parse(_)));
+    }
+
+    #[test]
+    fn when_there_are_no_feeds() {
+        let error = format!("feeds = []\n{NETWORK}")
+            .parse::<NodeConfig>()
+            .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds");
+    }
+
+    #[test]
+    fn when_there_are_no_providers() {
+        let error = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = []
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds.providers");
+    }
+
+    #[test]
+    fn when_url_is_invalid() {
+        let error = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "not a url" }]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds.providers.url");
+    }
+
+    #[test]
+    fn when_period_is_zero() {
+        let error = parse(
+            r#"
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 0
+            providers = [{ url = "http://localhost:8080/a" }]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "feeds.period");
+    }
+
+    #[test]
+    fn when_max_age_does_not_exceed_period() {
+        let error = parse(
+            r#"
+            [metrics]
+            address = "127.0.0.1:9090"
+            max_age = 60
+
+            [[feeds]]
+            asset = "0x0101010101010101010101010101010101010101010101010101010101010101"
+            period = 10
+            providers = [{ url = "http://localhost:8080/a" }]
+
+            [[feeds]]
+            asset = "0x0202020202020202020202020202020202020202020202020202020202020202"
+            period = 60
+            providers = [{ url = "http://localhost:8080/b" }]
+            "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(invalid_field(error), "metrics.max_age");
+    }
+}
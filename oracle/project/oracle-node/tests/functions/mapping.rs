@@ -0,0 +1,169 @@
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Response, Server,
+};
+use oracle_node::{
+    mapping::{to_fixed_point, MappingError, PriceMapping},
+    NetworkPriceProvider, PriceProvider,
+};
+use serde_json::json;
+use std::{convert::Infallible, net::SocketAddr, time::Duration};
+
+/// Serves `body` as the response of every request made to `address`
+async fn mock_server(address: SocketAddr, body: &'static str) {
+    let make_service = make_service_fn(move |_| async move {
+        Ok::<_, Infallible>(service_fn(move |_| async move {
+            Ok::<_, Infallible>(Response::new(Body::from(body)))
+        }))
+    });
+    tokio::spawn(Server::bind(&address).serve(make_service));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+}
+
+mod success {
+    use super::*;
+
+    #[test]
+    fn converts_decimal_strings_exactly() {
+        assert_eq!(to_fixed_point("1234.5678", 9), Ok(1_234_567_800_000));
+        assert_eq!(to_fixed_point("0.1", 9), Ok(100_000_000));
+        assert_eq!(to_fixed_point("0.3", 18), Ok(300_000_000_000_000_000));
+        assert_eq!(to_fixed_point("42", 0), Ok(42));
+        assert_eq!(to_fixed_point(".5", 1), Ok(5));
+    }
+
+    #[test]
+    fn converts_exponents() {
+        assert_eq!(to_fixed_point("1.5e-3", 6), Ok(1_500));
+        assert_eq!(to_fixed_point("2E3", 2), Ok(200_000));
+    }
+
+    #[test]
+    fn truncates_extra_decimals() {
+        assert_eq!(to_fixed_point("1.23456789", 2), Ok(123));
+        assert_eq!(to_fixed_point("0.000000001", 6), Ok(0));
+    }
+
+    #[test]
+    fn selects_price_with_field_selector() {
+        let body = json!({ "data": { "prices": [{ "usd": "2000.5" }] } });
+
+        assert_eq!(
+            PriceMapping::new("data.prices.0.usd", 2).extract(&body),
+            Ok(200_050)
+        );
+    }
+
+    #[test]
+    fn selects_price_with_json_pointer() {
+        let body = json!({ "data": { "amount": 17.25 } });
+
+        assert_eq!(
+            PriceMapping::new("/data/amount", 3).extract(&body),
+            Ok(17_250)
+        );
+    }
+
+    #[tokio::test]
+    async fn fetches_price_from_server() {
+        let address = "127.0.0.1:9465".parse().unwrap();
+        mock_server(address, r#"{"bitcoin":{"eur":"27123.45"}}"#).await;
+
+        let provider = NetworkPriceProvider::with_mapping(
+            reqwest::Client::new(),
+            "http://127.0.0.1:9465/price".parse().unwrap(),
+            PriceMapping::new("bitcoin.eur", 9),
+        );
+
+        assert_eq!(provider.get_price().await.unwrap(), 27_123_450_000_000);
+    }
+
+    #[tokio::test]
+    async fn defaults_to_usd_field() {
+        let address = "127.0.0.1:9466".parse().unwrap();
+        mock_server(address, r#"{"USD":1234.5678}"#).await;
+
+        let provider = NetworkPriceProvider::new(
+            reqwest::Client::new(),
+            "http://127.0.0.1:9466/price".parse().unwrap(),
+        );
+
+        assert_eq!(provider.get_price().await.unwrap(), 1_234_567_800_000);
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[test]
+    fn when_price_is_negative() {
+        assert!(matches!(
+            to_fixed_point("-1.5", 9),
+            Err(MappingError::Negative(_))
+        ));
+    }
+
+    #[test]
+    fn when_price_is_not_a_number() {
+        for price in ["NaN", "inf", "", ".", "1.2.3", "1e", "abc"] {
+            assert!(matches!(
+                to_fixed_point(price, 9),
+                Err(MappingError::NotANumber(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn when_price_overflows() {
+        assert!(matches!(
+            to_fixed_point("18446744073709551616", 0),
+            Err(MappingError::Overflow(_))
+        ));
+        assert!(matches!(
+            to_fixed_point("18446744073.709551616", 9),
+            Err(MappingError::Overflow(_))
+        ));
+        assert!(matches!(
+            to_fixed_point("1e30", 0),
+            Err(MappingError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn when_field_is_missing() {
+        let body = json!({ "EUR": 1.0 });
+
+        assert_eq!(
+            PriceMapping::default().extract(&body),
+            Err(MappingError::MissingField("USD".to_string()))
+        );
+    }
+
+    #[test]
+    fn when_field_is_not_a_price() {
+        let body = json!({ "USD": { "price": 1.0 } });
+
+        assert!(matches!(
+            PriceMapping::default().extract(&body),
+            Err(MappingError::NotANumber(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn when_server_responds_with_negative_price() {
+        let address = "127.0.0.1:9467".parse().unwrap();
+        mock_server(address, r#"{"USD":-3.5}"#).await;
+
+        let provider = NetworkPriceProvider::new(
+            reqwest::Client::new(),
+            "http://127.0.0.1:9467/price".parse().unwrap(),
+        );
+
+        let error = provider.get_price().await.unwrap_err();
+
+        assert!(matches!(
+            error.downcast_ref::<MappingError>(),
+            Some(MappingError::Negative(_))
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::functions::{FailingPriceProvider, HardcodedPriceProvider, LoggingPriceUpdater};
+use oracle_node::{
+    metrics::{serve, JobMetrics, Metrics},
+    retry::RetryConfig,
+    spawn_oracle_updater_job_with_config, JobConfig,
+};
+use std::{
+    sync::{atomic::Ordering, Arc},
+    time::{Duration, SystemTime},
+};
+
+fn config(metrics: Arc<JobMetrics>) -> JobConfig {
+    JobConfig {
+        metrics,
+        retry: RetryConfig {
+            max_retries: 0,
+            ..RetryConfig::default()
+        },
+        ..JobConfig::default()
+    }
+}
+
+mod success {
+    use super::*;
+
+    #[tokio::test]
+    async fn job_records_published_prices() {
+        let metrics = Arc::new(JobMetrics::default());
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            LoggingPriceUpdater::new(),
+            Duration::from_millis(100),
+            HardcodedPriceProvider { price: 101 },
+            config(metrics.clone()),
+        );
+
+        receipts_receiver.recv().await.unwrap().unwrap();
+        receipts_receiver.recv().await.unwrap().unwrap();
+
+        assert_eq!(metrics.updates_total.load(Ordering::Relaxed), 2);
+        assert_eq!(metrics.last_fetched_price.load(Ordering::Relaxed), 101);
+        // The second update reads the price published by the first one
+        assert_eq!(metrics.last_onchain_price.load(Ordering::Relaxed), 101);
+        assert_ne!(metrics.last_update_timestamp.load(Ordering::Relaxed), 0);
+        assert!(metrics.is_alive(SystemTime::now(), Duration::from_secs(1)));
+    }
+
+    #[tokio::test]
+    async fn job_records_errors() {
+        let metrics = Arc::new(JobMetrics::default());
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            LoggingPriceUpdater::new(),
+            Duration::from_millis(100),
+            FailingPriceProvider,
+            config(metrics.clone()),
+        );
+
+        receipts_receiver.recv().await.unwrap().unwrap_err();
+
+        assert_eq!(metrics.fetch_errors_total.load(Ordering::Relaxed), 1);
+        assert_eq!(metrics.updates_total.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn renders_metrics_of_every_job() {
+        let metrics = Metrics::default();
+        metrics
+            .register("0x01")
+            .updates_total
+            .store(3, Ordering::Relaxed);
+        metrics
+            .register("0x02")
+            .gas_used_total
+            .store(42, Ordering::Relaxed);
+
+        let output = metrics.render();
+
+        assert!(output.contains("# TYPE oracle_updates_total counter"));
+        assert!(output.contains("oracle_updates_total{asset=\"0x01\"} 3"));
+        assert!(output.contains("oracle_gas_used_total{asset=\"0x02\"} 42"));
+    }
+
+    #[tokio::test]
+    async fn serves_metrics_and_health() {
+        let metrics = Arc::new(Metrics::default());
+        metrics.register("0x01").record_run();
+
+        let address = "127.0.0.1:9464".parse().unwrap();
+        tokio::spawn(serve(address, metrics, Duration::from_secs(60)));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let health = reqwest::get("http://127.0.0.1:9464/health").await.unwrap();
+        assert_eq!(health.status(), 200);
+
+        let body = reqwest::get("http://127.0.0.1:9464/metrics")
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert!(body.contains("oracle_last_run_timestamp_seconds{asset=\"0x01\"}"));
+    }
+}
+
+mod revert {
+    use super::*;
+
+    #[test]
+    fn when_job_has_never_run() {
+        let metrics = Metrics::default();
+        metrics.register("0x01");
+
+        assert!(!metrics.is_healthy(SystemTime::now(), Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn when_job_has_not_run_recently() {
+        let metrics = Metrics::default();
+        metrics.register("0x01").record_run();
+
+        let later = SystemTime::now() + Duration::from_secs(120);
+
+        assert!(!metrics.is_healthy(later, Duration::from_secs(60)));
+    }
+}
@@ -1,12 +1,20 @@
 use async_trait::async_trait;
 use fuels::tx::Receipt;
-use futures::executor::block_on;
-use oracle_node::{PriceProvider, PriceUpdater};
+use oracle_node::{errors::SubmissionError, PriceProvider, PriceUpdater, Submission};
 use std::borrow::BorrowMut;
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
+mod aggregation;
+mod config;
+mod mapping;
+mod metrics;
+mod policy;
+mod retry;
 mod run;
 
 struct Invocation {
@@ -34,15 +42,73 @@ impl LoggingPriceUpdater {
     }
 }
 
+#[async_trait]
 impl PriceUpdater for LoggingPriceUpdater {
-    fn set_price(&self, price: u64) -> anyhow::Result<Vec<Receipt>> {
-        block_on(self.invocations.lock())
-            .borrow_mut()
-            .push(Invocation {
-                price,
-                time: Instant::now(),
-            });
-        Ok(self.receipts.clone())
+    async fn current_price(&self) -> anyhow::Result<u64> {
+        Ok(self
+            .invocations
+            .lock()
+            .await
+            .last()
+            .map_or(0, |invocation| invocation.price))
+    }
+
+    async fn set_price(&self, price: u64) -> Result<Submission, SubmissionError> {
+        self.invocations.lock().await.borrow_mut().push(Invocation {
+            price,
+            time: Instant::now(),
+        });
+        Ok(Submission::Submitted(self.receipts.clone()))
+    }
+}
+
+struct AwaitingRoundPriceUpdater;
+
+#[async_trait]
+impl PriceUpdater for AwaitingRoundPriceUpdater {
+    async fn current_price(&self) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+
+    async fn set_price(&self, _price: u64) -> Result<Submission, SubmissionError> {
+        Ok(Submission::AwaitingRound)
+    }
+}
+
+struct FailingPriceUpdater {
+    // Number of times a price was submitted
+    attempts: Arc<AtomicU32>,
+    // Whether the transaction may have been sent before failing
+    ambiguous: bool,
+}
+
+impl FailingPriceUpdater {
+    pub fn new(ambiguous: bool) -> Self {
+        Self {
+            attempts: Arc::new(AtomicU32::new(0)),
+            ambiguous,
+        }
+    }
+
+    pub fn attempts(&self) -> Arc<AtomicU32> {
+        Arc::clone(&self.attempts)
+    }
+}
+
+#[async_trait]
+impl PriceUpdater for FailingPriceUpdater {
+    async fn current_price(&self) -> anyhow::Result<u64> {
+        Ok(0)
+    }
+
+    async fn set_price(&self, _price: u64) -> Result<Submission, SubmissionError> {
+        self.attempts.fetch_add(1, Ordering::SeqCst);
+        let error = anyhow::anyhow!("oracle could not be updated");
+        Err(if self.ambiguous {
+            SubmissionError::Ambiguous(error)
+        } else {
+            SubmissionError::BeforeSubmission(error)
+        })
     }
 }
 
@@ -59,3 +125,62 @@ impl PriceProvider for HardcodedPriceProvider {
         async { Ok(self.price) }.await
     }
 }
+
+#[derive(Clone)]
+struct FailingPriceProvider;
+
+#[cfg(test)]
+#[async_trait]
+impl PriceProvider for FailingPriceProvider {
+    async fn get_price(&self) -> anyhow::Result<u64> {
+        Err(anyhow::anyhow!("price could not be fetched"))
+    }
+}
+
+#[derive(Clone)]
+struct DelayedPriceProvider {
+    // Duration to wait before providing the price
+    delay: Duration,
+    // Hardcoded price to provide for testing
+    price: u64,
+}
+
+#[cfg(test)]
+#[async_trait]
+impl PriceProvider for DelayedPriceProvider {
+    async fn get_price(&self) -> anyhow::Result<u64> {
+        tokio::time::sleep(self.delay).await;
+        Ok(self.price)
+    }
+}
+
+struct FlakyPriceProvider {
+    // Number of requests which fail before the price is provided
+    failures: AtomicU32,
+    // Hardcoded price to provide for testing
+    price: u64,
+}
+
+impl FlakyPriceProvider {
+    pub fn new(failures: u32, price: u64) -> Self {
+        Self {
+            failures: AtomicU32::new(failures),
+            price,
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl PriceProvider for FlakyPriceProvider {
+    async fn get_price(&self) -> anyhow::Result<u64> {
+        let decrement = |failures: u32| failures.checked_sub(1);
+        match self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, decrement)
+        {
+            Ok(_) => Err(anyhow::anyhow!("price is temporarily unavailable")),
+            Err(_) => Ok(self.price),
+        }
+    }
+}
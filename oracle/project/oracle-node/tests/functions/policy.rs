@@ -0,0 +1,78 @@
+use crate::functions::{HardcodedPriceProvider, LoggingPriceUpdater};
+use oracle_node::policy::{AlwaysUpdate, DeviationHeartbeatPolicy, UpdatePolicy};
+use std::time::Duration;
+
+mod success {
+    use super::*;
+    use oracle_node::{spawn_oracle_updater_job_with_config, JobConfig};
+    use std::sync::Arc;
+
+    #[test]
+    fn always_update_publishes_unchanged_price() {
+        assert!(AlwaysUpdate.should_update(100, 100, Some(Duration::ZERO)));
+    }
+
+    #[test]
+    fn publishes_first_price() {
+        let policy = DeviationHeartbeatPolicy::new(100, Duration::from_secs(60));
+
+        assert!(policy.should_update(100, 100, None));
+    }
+
+    #[test]
+    fn publishes_when_deviation_is_exceeded() {
+        let policy = DeviationHeartbeatPolicy::new(100, Duration::from_secs(60));
+
+        assert!(policy.should_update(1011, 1000, Some(Duration::from_secs(1))));
+        assert!(policy.should_update(989, 1000, Some(Duration::from_secs(1))));
+    }
+
+    #[test]
+    fn skips_when_deviation_is_not_exceeded() {
+        let policy = DeviationHeartbeatPolicy::new(100, Duration::from_secs(60));
+
+        assert!(!policy.should_update(1010, 1000, Some(Duration::from_secs(1))));
+        assert!(!policy.should_update(990, 1000, Some(Duration::from_secs(1))));
+    }
+
+    #[test]
+    fn publishes_when_heartbeat_has_passed() {
+        let policy = DeviationHeartbeatPolicy::new(100, Duration::from_secs(60));
+
+        assert!(policy.should_update(1000, 1000, Some(Duration::from_secs(60))));
+    }
+
+    #[test]
+    fn publishes_when_onchain_price_is_not_set() {
+        let policy = DeviationHeartbeatPolicy::new(100, Duration::from_secs(60));
+
+        assert!(policy.should_update(1, 0, Some(Duration::from_secs(1))));
+    }
+
+    #[tokio::test]
+    async fn job_skips_unchanged_prices_until_heartbeat() {
+        let price_updater = LoggingPriceUpdater::new();
+        let invocations = price_updater.invocations();
+        let period = Duration::from_millis(100);
+        let config = JobConfig {
+            policy: Arc::new(DeviationHeartbeatPolicy::new(100, period * 5)),
+            ..JobConfig::default()
+        };
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            price_updater,
+            period,
+            HardcodedPriceProvider { price: 101 },
+            config,
+        );
+
+        // The first price is published immediately
+        assert!(receipts_receiver.recv().await.unwrap().is_ok());
+        tokio::time::sleep(period * 3).await;
+        assert_eq!(invocations.lock().await.len(), 1);
+
+        // The unchanged price is published again once the heartbeat has passed
+        assert!(receipts_receiver.recv().await.unwrap().is_ok());
+        assert_eq!(invocations.lock().await.len(), 2);
+    }
+}
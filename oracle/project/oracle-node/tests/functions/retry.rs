@@ -0,0 +1,95 @@
+use oracle_node::retry::{CircuitBreaker, CircuitBreakerConfig, RetryConfig};
+use std::time::{Duration, Instant};
+
+mod success {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let retry = RetryConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            max_retries: 3,
+            multiplier: 2,
+        };
+
+        assert_eq!(retry.backoff(0), Duration::from_millis(100));
+        assert_eq!(retry.backoff(1), Duration::from_millis(200));
+        assert_eq!(retry.backoff(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let retry = RetryConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(250),
+            max_retries: 3,
+            multiplier: 2,
+        };
+
+        assert_eq!(retry.backoff(2), Duration::from_millis(250));
+        assert_eq!(retry.backoff(u32::MAX), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn circuit_opens_after_consecutive_failures() {
+        let mut circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            cooldown: Duration::from_secs(60),
+            failure_threshold: 2,
+        });
+        let now = Instant::now();
+
+        circuit_breaker.record_failure(now);
+        assert_eq!(circuit_breaker.remaining_cooldown(now), None);
+
+        circuit_breaker.record_failure(now);
+        assert_eq!(
+            circuit_breaker.remaining_cooldown(now),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn circuit_closes_after_cooldown() {
+        let mut circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            cooldown: Duration::from_secs(60),
+            failure_threshold: 1,
+        });
+        let now = Instant::now();
+
+        circuit_breaker.record_failure(now);
+
+        assert_eq!(
+            circuit_breaker.remaining_cooldown(now + Duration::from_secs(60)),
+            None
+        );
+    }
+
+    #[test]
+    fn success_resets_circuit() {
+        let mut circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            cooldown: Duration::from_secs(60),
+            failure_threshold: 2,
+        });
+        let now = Instant::now();
+
+        circuit_breaker.record_failure(now);
+        circuit_breaker.record_success();
+        circuit_breaker.record_failure(now);
+
+        assert_eq!(circuit_breaker.remaining_cooldown(now), None);
+    }
+
+    #[test]
+    fn zero_threshold_disables_circuit() {
+        let mut circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            cooldown: Duration::from_secs(60),
+            failure_threshold: 0,
+        });
+        let now = Instant::now();
+
+        circuit_breaker.record_failure(now);
+
+        assert_eq!(circuit_breaker.remaining_cooldown(now), None);
+    }
+}
@@ -1,9 +1,20 @@
-use crate::functions::{HardcodedPriceProvider, LoggingPriceUpdater};
-use fuels::tx::Receipt;
+use crate::functions::{
+    AwaitingRoundPriceUpdater, DelayedPriceProvider, FailingPriceProvider, FailingPriceUpdater,
+    FlakyPriceProvider, HardcodedPriceProvider, LoggingPriceUpdater,
+};
+use fuels::{prelude::ContractId, tx::Receipt};
 use itertools::Itertools;
-use oracle_node::spawn_oracle_updater_job;
+use oracle_node::{
+    errors::OracleError,
+    metrics::JobMetrics,
+    retry::{CircuitBreakerConfig, RetryConfig},
+    spawn_oracle_updater_job, spawn_oracle_updater_job_with_config, JobConfig, JobSummary,
+};
 use std::borrow::Borrow;
+use std::sync::{atomic::Ordering, Arc};
 use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use utils::{PriceUpdateEvent, Round};
 
 mod success {
     use super::*;
@@ -77,10 +88,312 @@ mod success {
         );
 
         let receipts = vec![
-            receipts_receiver.recv().await.unwrap().unwrap(),
-            receipts_receiver.recv().await.unwrap().unwrap(),
+            receipts_receiver.recv().await.unwrap().unwrap().receipts,
+            receipts_receiver.recv().await.unwrap().unwrap().receipts,
         ];
 
         assert_eq!(receipts, vec![receipts_from_price_updater; 2]);
     }
+
+    #[tokio::test]
+    async fn rounds_are_decoded() {
+        let asset = ContractId::from([1u8; 32]);
+        let round = Round {
+            id: 1,
+            price: 101,
+            timestamp: 2,
+            height: 3,
+        };
+
+        // Encode the event the way the oracle contract logs it
+        let mut data = asset.to_vec();
+        for word in [round.id, round.price, round.timestamp, round.height] {
+            data.extend_from_slice(&word.to_be_bytes());
+        }
+
+        let mut price_updater = LoggingPriceUpdater::new();
+        price_updater.receipts = vec![Receipt::LogData {
+            id: Default::default(),
+            ra: 0,
+            rb: 0,
+            ptr: 0,
+            len: data.len() as u64,
+            digest: Default::default(),
+            data,
+            pc: 0,
+            is: 0,
+        }];
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job(
+            price_updater,
+            Duration::from_millis(500),
+            HardcodedPriceProvider { price: 101 },
+        );
+
+        let price_update = receipts_receiver.recv().await.unwrap().unwrap();
+
+        assert_eq!(price_update.rounds, vec![PriceUpdateEvent { asset, round }]);
+    }
+
+    #[tokio::test]
+    async fn fetch_errors_are_streamed_and_job_keeps_running() {
+        let config = JobConfig {
+            retry: no_retries(),
+            ..JobConfig::default()
+        };
+
+        let (handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            LoggingPriceUpdater::new(),
+            Duration::from_millis(100),
+            FailingPriceProvider,
+            config,
+        );
+
+        for _ in 0..2 {
+            let error = receipts_receiver.recv().await.unwrap().unwrap_err();
+            assert!(matches!(error, OracleError::Fetch(_)));
+        }
+        assert!(!handle.is_finished());
+    }
+
+    #[tokio::test]
+    async fn update_errors_are_streamed() {
+        let config = JobConfig {
+            retry: no_retries(),
+            ..JobConfig::default()
+        };
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            FailingPriceUpdater::new(true),
+            Duration::from_millis(100),
+            HardcodedPriceProvider { price: 101 },
+            config,
+        );
+
+        let error = receipts_receiver.recv().await.unwrap().unwrap_err();
+        assert!(matches!(error, OracleError::Update(_)));
+    }
+
+    #[tokio::test]
+    async fn failed_fetches_are_retried() {
+        let price_updater = LoggingPriceUpdater::new();
+        let invocations = price_updater.invocations();
+        let config = JobConfig {
+            retry: RetryConfig {
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(50),
+                max_retries: 2,
+                multiplier: 2,
+            },
+            ..JobConfig::default()
+        };
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            price_updater,
+            Duration::from_millis(500),
+            FlakyPriceProvider::new(2, 101),
+            config,
+        );
+
+        assert!(receipts_receiver.recv().await.unwrap().is_ok());
+        let invocations = invocations.lock().await;
+        assert_eq!(invocations.len(), 1);
+        assert_eq!(invocations[0].price, 101);
+    }
+
+    #[tokio::test]
+    async fn updates_failing_before_submission_are_retried() {
+        let price_updater = FailingPriceUpdater::new(false);
+        let attempts = price_updater.attempts();
+        let config = JobConfig {
+            retry: RetryConfig {
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(50),
+                max_retries: 2,
+                multiplier: 2,
+            },
+            ..JobConfig::default()
+        };
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            price_updater,
+            Duration::from_millis(500),
+            HardcodedPriceProvider { price: 101 },
+            config,
+        );
+
+        let error = receipts_receiver.recv().await.unwrap().unwrap_err();
+        assert!(matches!(error, OracleError::Update(_)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn ambiguous_updates_are_not_retried() {
+        let price_updater = FailingPriceUpdater::new(true);
+        let attempts = price_updater.attempts();
+        let config = JobConfig {
+            retry: RetryConfig {
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(50),
+                max_retries: 2,
+                multiplier: 2,
+            },
+            ..JobConfig::default()
+        };
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            price_updater,
+            Duration::from_millis(500),
+            HardcodedPriceProvider { price: 101 },
+            config,
+        );
+
+        let error = receipts_receiver.recv().await.unwrap().unwrap_err();
+        assert!(matches!(error, OracleError::Update(_)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn circuit_opens_after_repeated_failures() {
+        let config = JobConfig {
+            circuit_breaker: CircuitBreakerConfig {
+                cooldown: Duration::from_secs(60),
+                failure_threshold: 2,
+            },
+            retry: no_retries(),
+            ..JobConfig::default()
+        };
+
+        let (_handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            LoggingPriceUpdater::new(),
+            Duration::from_millis(50),
+            FailingPriceProvider,
+            config,
+        );
+
+        for _ in 0..2 {
+            let error = receipts_receiver.recv().await.unwrap().unwrap_err();
+            assert!(matches!(error, OracleError::Fetch(_)));
+        }
+        let error = receipts_receiver.recv().await.unwrap().unwrap_err();
+        assert!(matches!(error, OracleError::CircuitOpen { .. }));
+    }
+
+    #[tokio::test]
+    async fn job_stops_when_shut_down_and_reports_summary() {
+        let (handle, mut receipts_receiver) = spawn_oracle_updater_job(
+            LoggingPriceUpdater::new(),
+            Duration::from_secs(60),
+            HardcodedPriceProvider { price: 101 },
+        );
+
+        assert!(receipts_receiver.recv().await.unwrap().is_ok());
+        let summary = handle.shutdown().await.unwrap();
+
+        assert_eq!(
+            summary,
+            JobSummary {
+                awaiting_round: 0,
+                failures: 0,
+                runs: 1,
+                skipped: 0,
+                updates: 1,
+            }
+        );
+        assert!(receipts_receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn awaiting_round_is_not_counted_as_update() {
+        let metrics = Arc::new(JobMetrics::default());
+        let config = JobConfig {
+            metrics: metrics.clone(),
+            ..JobConfig::default()
+        };
+
+        let (handle, mut receipts_receiver) = spawn_oracle_updater_job_with_config(
+            AwaitingRoundPriceUpdater,
+            Duration::from_secs(60),
+            HardcodedPriceProvider { price: 101 },
+            config,
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let summary = handle.shutdown().await.unwrap();
+
+        assert_eq!(
+            summary,
+            JobSummary {
+                awaiting_round: 1,
+                failures: 0,
+                runs: 1,
+                skipped: 0,
+                updates: 0,
+            }
+        );
+        assert_eq!(metrics.awaiting_round_total.load(Ordering::Relaxed), 1);
+        assert_eq!(metrics.updates_total.load(Ordering::Relaxed), 0);
+        assert_eq!(metrics.last_update_timestamp.load(Ordering::Relaxed), 0);
+        // Nothing was published so nothing is sent out of the job
+        assert!(receipts_receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn update_in_flight_completes_before_shutdown() {
+        let price_updater = LoggingPriceUpdater::new();
+        let invocations = price_updater.invocations();
+
+        let (handle, mut receipts_receiver) = spawn_oracle_updater_job(
+            price_updater,
+            Duration::from_secs(60),
+            DelayedPriceProvider {
+                delay: Duration::from_millis(300),
+                price: 101,
+            },
+        );
+
+        // Cancel while the price is still being fetched
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let summary = handle.shutdown().await.unwrap();
+
+        assert_eq!(summary.updates, 1);
+        assert_eq!(invocations.lock().await.len(), 1);
+        assert!(receipts_receiver.recv().await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn jobs_stop_when_shared_token_is_cancelled() {
+        let cancellation = CancellationToken::new();
+        let config = JobConfig {
+            cancellation: cancellation.clone(),
+            ..JobConfig::default()
+        };
+
+        let (first, mut first_receiver) = spawn_oracle_updater_job_with_config(
+            LoggingPriceUpdater::new(),
+            Duration::from_secs(60),
+            HardcodedPriceProvider { price: 101 },
+            config.clone(),
+        );
+        let (second, mut second_receiver) = spawn_oracle_updater_job_with_config(
+            LoggingPriceUpdater::new(),
+            Duration::from_secs(60),
+            HardcodedPriceProvider { price: 102 },
+            config,
+        );
+
+        assert!(first_receiver.recv().await.unwrap().is_ok());
+        assert!(second_receiver.recv().await.unwrap().is_ok());
+        cancellation.cancel();
+
+        assert_eq!(first.join().await.unwrap().runs, 1);
+        assert_eq!(second.join().await.unwrap().runs, 1);
+    }
+}
+
+fn no_retries() -> RetryConfig {
+    RetryConfig {
+        max_retries: 0,
+        ..RetryConfig::default()
+    }
 }
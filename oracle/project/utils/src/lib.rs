@@ -20,29 +20,165 @@ pub mod paths {
 pub mod abi_calls {
     use super::*;
 
+    pub async fn add_reporter(contract: &Oracle, reporter: Identity) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .add_reporter(reporter)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn has_submitted(contract: &Oracle, asset: ContractId, reporter: Identity) -> bool {
+        contract
+            .methods()
+            .has_submitted(asset, reporter)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn is_reporter(contract: &Oracle, reporter: Identity) -> bool {
+        contract
+            .methods()
+            .is_reporter(reporter)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn latest_round(contract: &Oracle, asset: ContractId) -> Option<Round> {
+        contract
+            .methods()
+            .latest_round(asset)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
     pub async fn owner(contract: &Oracle) -> Identity {
         contract.methods().owner().call().await.unwrap().value
     }
 
-    pub async fn price(contract: &Oracle) -> u64 {
-        contract.methods().price().call().await.unwrap().value
+    pub async fn price(contract: &Oracle, asset: ContractId) -> Option<u64> {
+        contract.methods().price(asset).call().await.unwrap().value
+    }
+
+    pub async fn price_no_older_than(contract: &Oracle, asset: ContractId, max_age: u64) -> u64 {
+        contract
+            .methods()
+            .price_no_older_than(asset, max_age)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn remove_reporter(contract: &Oracle, reporter: Identity) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .remove_reporter(reporter)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn reporter_count(contract: &Oracle) -> u64 {
+        contract
+            .methods()
+            .reporter_count()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn round(contract: &Oracle, asset: ContractId, id: u64) -> Option<Round> {
+        contract
+            .methods()
+            .round(asset, id)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn set_price(
+        contract: &Oracle,
+        asset: ContractId,
+        new_price: u64,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_price(asset, new_price)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn set_prices(
+        contract: &Oracle,
+        new_prices: Vec<(ContractId, u64)>,
+    ) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_prices(new_prices)
+            .call()
+            .await
+            .unwrap()
     }
 
-    pub async fn set_price(contract: &Oracle, new_price: u64) -> FuelCallResponse<()> {
+    pub async fn set_submission_window(
+        contract: &Oracle,
+        submission_window: u64,
+    ) -> FuelCallResponse<()> {
         contract
             .methods()
-            .set_price(new_price)
+            .set_submission_window(submission_window)
             .call()
             .await
             .unwrap()
     }
+
+    pub async fn set_threshold(contract: &Oracle, threshold: u64) -> FuelCallResponse<()> {
+        contract
+            .methods()
+            .set_threshold(threshold)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn submission_window(contract: &Oracle) -> u64 {
+        contract
+            .methods()
+            .submission_window()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn threshold(contract: &Oracle) -> u64 {
+        contract.methods().threshold().call().await.unwrap().value
+    }
 }
 
 pub mod test_helpers {
 
     use super::*;
+    use abi_calls::{add_reporter, set_threshold};
+    use fuels::tx::Address;
     use paths::ORACLE_CONTRACT_BINARY_PATH;
 
+    pub fn identity(wallet: &WalletUnlocked) -> Identity {
+        Identity::Address(Address::from(wallet.address()))
+    }
+
+    /// Deploys the oracle and authorizes the owner as its only reporter
     pub async fn setup() -> (Metadata, Vec<WalletUnlocked>) {
         let wallets =
             launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None).await;
@@ -60,6 +196,23 @@ pub mod test_helpers {
             wallet: wallets[0].clone().lock(),
         };
 
+        add_reporter(&user.oracle, identity(&wallets[0])).await;
+
+        (user, wallets)
+    }
+
+    /// Deploys the oracle with the first `reporters` wallets authorized as reporters
+    pub async fn setup_with_reporters(
+        reporters: usize,
+        threshold: u64,
+    ) -> (Metadata, Vec<WalletUnlocked>) {
+        let (user, wallets) = setup().await;
+
+        for wallet in wallets.iter().take(reporters).skip(1) {
+            add_reporter(&user.oracle, identity(wallet)).await;
+        }
+        set_threshold(&user.oracle, threshold).await;
+
         (user, wallets)
     }
 }